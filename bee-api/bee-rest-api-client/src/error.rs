@@ -0,0 +1,21 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors occurring while querying the REST API of a node.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The request couldn't be sent or its response couldn't be read.
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    /// The node answered with an error.
+    #[error("API error {code}: {message}")]
+    Api {
+        /// The HTTP status code of the response.
+        code: u16,
+        /// The message of the error.
+        message: String,
+    },
+    /// The URL of the node is invalid.
+    #[error("invalid URL: {0}")]
+    Url(#[from] url::ParseError),
+}
@@ -0,0 +1,290 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A typed async client for the REST API of the Bee node software.
+//!
+//! The client speaks the same request and response types as the node, which are defined in `bee-rest-api`.
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod error;
+
+use bee_block::{
+    output::OutputId,
+    payload::{
+        dto::MilestonePayloadDto,
+        milestone::{MilestoneId, MilestoneIndex},
+        transaction::TransactionId,
+    },
+    BlockDto, BlockId,
+};
+use bee_rest_api::types::{
+    body::{DefaultErrorResponse, ErrorBody},
+    dtos::PeerDto,
+    responses::{
+        AddTokenResponse, BlockChildrenResponse, BlockConeResponse, BlockMetadataResponse, BlockReferencedByResponse,
+        InfoResponse, OutputMetadataResponse, OutputResponse, ReceiptsResponse, SubmitBlockResponse, TipsResponse,
+        TokensResponse, TreasuryResponse, UtxoChangesResponse,
+    },
+};
+use reqwest::{header::CONTENT_TYPE, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use url::Url;
+
+pub use self::error::Error;
+
+/// Content type of blocks in their packed binary form.
+const BYTE_CONTENT_TYPE: &str = "application/vnd.iota.serializer-v1";
+
+/// A client of the REST API of a node.
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: reqwest::Client,
+    url: Url,
+    jwt: Option<String>,
+}
+
+impl Client {
+    /// Creates a new [`Client`] of the node reachable at `url`, e.g. `http://localhost:14265`.
+    pub fn new(url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            http: reqwest::Client::new(),
+            url: Url::parse(url)?,
+            jwt: None,
+        })
+    }
+
+    /// Sets the JWT used to authenticate against protected routes.
+    #[must_use]
+    pub fn with_jwt(mut self, jwt: String) -> Self {
+        self.jwt.replace(jwt);
+        self
+    }
+
+    /// Returns general information about the node.
+    pub async fn info(&self) -> Result<InfoResponse, Error> {
+        self.get("api/v2/info").await
+    }
+
+    /// Returns tips that are ideal for attaching a block.
+    pub async fn tips(&self) -> Result<TipsResponse, Error> {
+        self.get("api/v2/tips").await
+    }
+
+    /// Returns the OpenAPI document of the node.
+    pub async fn openapi(&self) -> Result<Value, Error> {
+        self.get("api/v2/openapi.json").await
+    }
+
+    /// Submits a block and returns its identifier.
+    pub async fn submit_block(&self, block: &BlockDto) -> Result<SubmitBlockResponse, Error> {
+        self.post("api/v2/blocks", block).await
+    }
+
+    /// Submits a block in its packed binary form and returns its identifier.
+    pub async fn submit_block_raw(&self, bytes: Vec<u8>) -> Result<SubmitBlockResponse, Error> {
+        let request = self
+            .http
+            .post(self.url.join("api/v2/blocks")?)
+            .header(CONTENT_TYPE, BYTE_CONTENT_TYPE)
+            .body(bytes);
+
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    /// Returns a block.
+    pub async fn block(&self, block_id: &BlockId) -> Result<BlockDto, Error> {
+        self.get(&format!("api/v2/blocks/{}", block_id)).await
+    }
+
+    /// Returns the metadata of a block.
+    pub async fn block_metadata(&self, block_id: &BlockId) -> Result<BlockMetadataResponse, Error> {
+        self.get(&format!("api/v2/blocks/{}/metadata", block_id)).await
+    }
+
+    /// Returns a page of the children of a block, starting at `cursor` or at the first child.
+    pub async fn block_children(
+        &self,
+        block_id: &BlockId,
+        page_size: Option<usize>,
+        cursor: Option<&str>,
+    ) -> Result<BlockChildrenResponse, Error> {
+        let mut query = Vec::new();
+
+        if let Some(page_size) = page_size {
+            query.push(("pageSize", page_size.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor.to_string()));
+        }
+
+        let request = self
+            .http
+            .get(self.url.join(&format!("api/v2/blocks/{}/children", block_id))?)
+            .query(&query);
+
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    /// Returns the blocks of the past cone of a block, up to a depth.
+    pub async fn block_past_cone(&self, block_id: &BlockId, depth: Option<u32>) -> Result<BlockConeResponse, Error> {
+        self.cone(block_id, "past-cone", depth).await
+    }
+
+    /// Returns the blocks of the future cone of a block, up to a depth.
+    pub async fn block_future_cone(&self, block_id: &BlockId, depth: Option<u32>) -> Result<BlockConeResponse, Error> {
+        self.cone(block_id, "future-cone", depth).await
+    }
+
+    /// Returns the milestone that referenced a block and a path from the milestone block to it.
+    pub async fn block_referenced_by(&self, block_id: &BlockId) -> Result<BlockReferencedByResponse, Error> {
+        self.get(&format!("api/v2/blocks/{}/referenced-by", block_id)).await
+    }
+
+    /// Returns the block that included a transaction in the ledger.
+    pub async fn transaction_included_block(&self, transaction_id: &TransactionId) -> Result<BlockDto, Error> {
+        self.get(&format!("api/v2/transactions/{}/included-block", transaction_id))
+            .await
+    }
+
+    /// Returns an output and its metadata.
+    pub async fn output(&self, output_id: &OutputId) -> Result<OutputResponse, Error> {
+        self.get(&format!("api/v2/outputs/{}", output_id)).await
+    }
+
+    /// Returns the metadata of an output.
+    pub async fn output_metadata(&self, output_id: &OutputId) -> Result<OutputMetadataResponse, Error> {
+        self.get(&format!("api/v2/outputs/{}/metadata", output_id)).await
+    }
+
+    /// Returns a milestone by its identifier.
+    pub async fn milestone_by_id(&self, milestone_id: &MilestoneId) -> Result<MilestonePayloadDto, Error> {
+        self.get(&format!("api/v2/milestones/{}", milestone_id)).await
+    }
+
+    /// Returns a milestone by its index.
+    pub async fn milestone_by_index(&self, index: MilestoneIndex) -> Result<MilestonePayloadDto, Error> {
+        self.get(&format!("api/v2/milestones/by-index/{}", index)).await
+    }
+
+    /// Returns the outputs created and consumed by a milestone, by its identifier.
+    pub async fn utxo_changes_by_id(&self, milestone_id: &MilestoneId) -> Result<UtxoChangesResponse, Error> {
+        self.get(&format!("api/v2/milestones/{}/utxo-changes", milestone_id))
+            .await
+    }
+
+    /// Returns the outputs created and consumed by a milestone, by its index.
+    pub async fn utxo_changes_by_index(&self, index: MilestoneIndex) -> Result<UtxoChangesResponse, Error> {
+        self.get(&format!("api/v2/milestones/by-index/{}/utxo-changes", index))
+            .await
+    }
+
+    /// Returns all stored receipts.
+    pub async fn receipts(&self) -> Result<ReceiptsResponse, Error> {
+        self.get("api/v2/receipts").await
+    }
+
+    /// Returns the receipts of a milestone.
+    pub async fn receipts_at(&self, index: MilestoneIndex) -> Result<ReceiptsResponse, Error> {
+        self.get(&format!("api/v2/receipts/{}", index)).await
+    }
+
+    /// Returns the current treasury output.
+    pub async fn treasury(&self) -> Result<TreasuryResponse, Error> {
+        self.get("api/v2/treasury").await
+    }
+
+    /// Returns all peers of the node.
+    pub async fn peers(&self) -> Result<Vec<PeerDto>, Error> {
+        self.get("api/v2/peers").await
+    }
+
+    /// Returns a peer of the node.
+    pub async fn peer(&self, peer_id: &str) -> Result<PeerDto, Error> {
+        self.get(&format!("api/v2/peers/{}", peer_id)).await
+    }
+
+    /// Adds a peer, given as a multiaddress ending with its peer id, to the node.
+    pub async fn add_peer(&self, multi_address: &str, alias: Option<&str>) -> Result<PeerDto, Error> {
+        self.post(
+            "api/v2/peers",
+            &json!({ "multiAddress": multi_address, "alias": alias }),
+        )
+        .await
+    }
+
+    /// Removes a peer from the node.
+    pub async fn remove_peer(&self, peer_id: &str) -> Result<(), Error> {
+        let request = self.http.delete(self.url.join(&format!("api/v2/peers/{}", peer_id))?);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    /// Returns all API tokens issued by the node.
+    pub async fn tokens(&self) -> Result<TokensResponse, Error> {
+        self.get("api/v2/tokens").await
+    }
+
+    /// Issues an API token with the given scopes, expiring after `expires_in` seconds if set.
+    pub async fn add_token(&self, scopes: &[&str], expires_in: Option<u64>) -> Result<AddTokenResponse, Error> {
+        self.post("api/v2/tokens", &json!({ "scopes": scopes, "expiresIn": expires_in }))
+            .await
+    }
+
+    /// Revokes an API token.
+    pub async fn revoke_token(&self, token_id: &str) -> Result<(), Error> {
+        let request = self.http.delete(self.url.join(&format!("api/v2/tokens/{}", token_id))?);
+
+        self.send(request).await.map(|_| ())
+    }
+
+    async fn cone(&self, block_id: &BlockId, cone: &str, depth: Option<u32>) -> Result<BlockConeResponse, Error> {
+        let mut request = self
+            .http
+            .get(self.url.join(&format!("api/v2/blocks/{}/{}", block_id, cone))?);
+
+        if let Some(depth) = depth {
+            request = request.query(&[("depth", depth)]);
+        }
+
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        let request = self.http.get(self.url.join(path)?);
+
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    async fn post<B: serde::Serialize + ?Sized, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, Error> {
+        let request = self.http.post(self.url.join(path)?).json(body);
+
+        Ok(self.send(request).await?.json().await?)
+    }
+
+    async fn send(&self, mut request: RequestBuilder) -> Result<Response, Error> {
+        if let Some(jwt) = &self.jwt {
+            request = request.bearer_auth(jwt);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        // The node describes its errors in the body, but proxies in between may not.
+        let message = match response.json::<ErrorBody<DefaultErrorResponse>>().await {
+            Ok(body) => body.error.message,
+            Err(_) => status.canonical_reason().unwrap_or_default().to_string(),
+        };
+
+        Err(Error::Api {
+            code: status.as_u16(),
+            message,
+        })
+    }
+}
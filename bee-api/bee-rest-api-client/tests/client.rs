@@ -0,0 +1,216 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use bee_block::{
+    constant::TOKEN_SUPPLY,
+    output::OutputId,
+    parent::Parents,
+    payload::{transaction::TransactionId, TaggedDataPayload},
+    BlockBuilder, BlockDto,
+};
+use bee_rest_api_client::{Client, Error};
+use bee_test::sim::{SimNetwork, SIM_NETWORK_NAME};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn client(network: &SimNetwork, index: usize) -> Client {
+    Client::new(&format!("http://{}", network.api_address(index).unwrap())).unwrap()
+}
+
+// The REST API is started in the background, so the first requests may be refused.
+async fn wait_for_api(client: &Client) {
+    let deadline = tokio::time::Instant::now() + TIMEOUT;
+
+    while client.info().await.is_err() {
+        assert!(tokio::time::Instant::now() < deadline, "REST API didn't start in time");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn info_and_openapi() {
+    let network = SimNetwork::build()
+        .with_node_count(1)
+        .with_rest_api()
+        .finish()
+        .await
+        .unwrap();
+    let client = client(&network, 0);
+    wait_for_api(&client).await;
+
+    let info = client.info().await.unwrap();
+    assert_eq!(info.name, "bee-sim-0");
+    assert_eq!(info.protocol.network_name, SIM_NETWORK_NAME);
+
+    let openapi = client.openapi().await.unwrap();
+    assert_eq!(openapi["openapi"], "3.0.3");
+    assert!(openapi["paths"]["/api/v2/blocks/{blockId}"]["get"].is_object());
+    // All routes are public in the simulation.
+    assert!(openapi["paths"]["/api/v2/peers"]["get"]["security"].is_null());
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocks_are_submitted_and_fetched() {
+    let network = SimNetwork::build()
+        .with_node_count(1)
+        .with_rest_api()
+        .finish()
+        .await
+        .unwrap();
+    let client = client(&network, 0);
+    wait_for_api(&client).await;
+
+    let block_id = network.inject_tagged_data(0, vec![1], vec![2; 8]).await.unwrap();
+    let block = network.tangle(0).unwrap().get(&block_id).unwrap();
+
+    assert_eq!(client.block(&block_id).await.unwrap(), BlockDto::from(&block));
+    assert_eq!(
+        client.block_metadata(&block_id).await.unwrap().block_id,
+        block_id.to_string()
+    );
+
+    let block = BlockBuilder::<u64>::new(Parents::new(vec![block_id]).unwrap())
+        .with_payload(TaggedDataPayload::new(vec![3], vec![4; 8]).unwrap().into())
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap();
+    let submitted = client.submit_block(&BlockDto::from(&block)).await.unwrap();
+    assert_eq!(submitted.block_id, block.id().to_string());
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn milestones_and_ledger_are_queried() {
+    let mut network = SimNetwork::build()
+        .with_node_count(1)
+        .with_rest_api()
+        .finish()
+        .await
+        .unwrap();
+    let client = client(&network, 0);
+    wait_for_api(&client).await;
+
+    network.inject_tagged_data(0, vec![1], vec![2; 8]).await.unwrap();
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_confirmed_index(0, index, TIMEOUT).await.unwrap();
+
+    let milestone_id = *network
+        .tangle(0)
+        .unwrap()
+        .get_milestone_metadata(index)
+        .unwrap()
+        .unwrap()
+        .milestone_id();
+
+    let by_index = client.milestone_by_index(index).await.unwrap();
+    let by_id = client.milestone_by_id(&milestone_id).await.unwrap();
+    assert_eq!(by_index, by_id);
+    assert_eq!(by_index.index, *index);
+
+    let changes = client.utxo_changes_by_index(index).await.unwrap();
+    assert_eq!(changes, client.utxo_changes_by_id(&milestone_id).await.unwrap());
+    assert_eq!(changes.index, *index);
+    assert!(changes.created_outputs.is_empty());
+    assert!(changes.consumed_outputs.is_empty());
+
+    assert_eq!(client.treasury().await.unwrap().amount, TOKEN_SUPPLY.to_string());
+    assert!(client.receipts().await.unwrap().receipts.is_empty());
+    assert!(client.receipts_at(index).await.unwrap().receipts.is_empty());
+
+    let unknown = OutputId::new(TransactionId::null(), 0).unwrap();
+    assert!(matches!(
+        client.output(&unknown).await,
+        Err(Error::Api { code: 404, .. })
+    ));
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn peers_are_listed_and_removed() {
+    let network = SimNetwork::build()
+        .with_node_count(2)
+        .with_rest_api()
+        .finish()
+        .await
+        .unwrap();
+    let client = client(&network, 0);
+    wait_for_api(&client).await;
+
+    let peer_id = network.peer_id(1).unwrap().to_string();
+
+    let peers = client.peers().await.unwrap();
+    assert!(peers.iter().any(|peer| peer.id == peer_id));
+    assert_eq!(client.peer(&peer_id).await.unwrap().id, peer_id);
+
+    client.remove_peer(&peer_id).await.unwrap();
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tangle_is_explored() {
+    let mut network = SimNetwork::build()
+        .with_node_count(1)
+        .with_rest_api()
+        .finish()
+        .await
+        .unwrap();
+    let client = client(&network, 0);
+    wait_for_api(&client).await;
+
+    let parent_id = network.inject_tagged_data(0, vec![1], vec![2; 8]).await.unwrap();
+    let child = BlockBuilder::<u64>::new(Parents::new(vec![parent_id]).unwrap())
+        .with_payload(TaggedDataPayload::new(vec![3], vec![4; 8]).unwrap().into())
+        .with_nonce_provider(0, 0f64)
+        .finish()
+        .unwrap();
+    let child_id = network.submit_block(0, &child).await.unwrap();
+
+    let children = client.block_children(&parent_id, Some(1), None).await.unwrap();
+    assert_eq!(children.count, 1);
+    assert_eq!(children.children, vec![child_id.to_string()]);
+    assert_eq!(children.cursor, None);
+    // The memory storage doesn't limit the number of children.
+    assert_eq!(children.max_results, None);
+
+    let past_cone = client.block_past_cone(&child_id, Some(1)).await.unwrap();
+    assert_eq!(past_cone.blocks.len(), 1);
+    assert_eq!(past_cone.blocks[0].block_id, parent_id.to_string());
+    assert_eq!(past_cone.blocks[0].depth, 1);
+
+    let future_cone = client.block_future_cone(&parent_id, None).await.unwrap();
+    assert!(
+        future_cone
+            .blocks
+            .iter()
+            .any(|block| block.block_id == child_id.to_string())
+    );
+    assert!(!future_cone.truncated);
+
+    assert!(matches!(
+        client.block_past_cone(&child_id, Some(0)).await,
+        Err(Error::Api { code: 400, .. })
+    ));
+
+    // The blocks may only be picked as tips by a later milestone.
+    let referenced = loop {
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_confirmed_index(0, index, TIMEOUT).await.unwrap();
+
+        match client.block_referenced_by(&parent_id).await {
+            Ok(referenced) => break referenced,
+            Err(_) => assert!(*index < 5, "block wasn't referenced in time"),
+        }
+    };
+
+    assert_eq!(referenced.path.first(), Some(&referenced.milestone_block_id));
+    assert_eq!(referenced.path.last(), Some(&parent_id.to_string()));
+
+    network.shutdown().await.unwrap();
+}
@@ -1,30 +1,37 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, net::SocketAddr};
 
 use auth_helper::jwt::{Claims, JsonWebToken, TokenData};
 use axum::{
     async_trait,
-    extract::{Extension, FromRequest, OriginalUri, RequestParts, TypedHeader},
+    extract::{ConnectInfo, Extension, FromRequest, OriginalUri, RequestParts, TypedHeader},
     headers::{authorization::Bearer, Authorization},
-    http::Uri,
+    http::{Method, Uri},
 };
 #[cfg(feature = "dashboard")]
 use lazy_static::lazy_static;
 #[cfg(feature = "dashboard")]
 use regex::RegexSet;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[cfg(feature = "dashboard")]
 use crate::endpoints::config::route_to_regex;
-use crate::endpoints::{error::ApiError, storage::StorageBackend, ApiArgsFullNode};
+use crate::endpoints::{
+    error::ApiError,
+    storage::StorageBackend,
+    tokens::{self, Scope},
+    ApiArgsFullNode,
+};
 
 pub const API_AUDIENCE_CLAIM: &str = "api";
 pub const DASHBOARD_AUDIENCE_CLAIM: &str = "dashboard";
-const API_JWT_HINT: &str = "\"aud\":\"api\"";
+/// Rate limiting key shared by all the tokens that were issued without scopes.
+const LEGACY_TOKEN_KEY: &str = "legacy";
 #[cfg(feature = "dashboard")]
-const DASHBOARD_JWT_HINT: &str = "\"aud\":\"dashboard\"";
+const DASHBOARD_TOKEN_KEY: &str = "dashboard";
 
 #[cfg(feature = "dashboard")]
 lazy_static! {
@@ -57,11 +64,23 @@ where
 
     async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
         let OriginalUri(uri) = OriginalUri::from_request(req).await.map_err(|_| ApiError::Forbidden)?;
+        let method = req.method().clone();
 
         let Extension(args) = Extension::<ApiArgsFullNode<S>>::from_request(req)
             .await
             .map_err(|_| ApiError::Forbidden)?;
 
+        let rate_limit = args.rest_api_config.rate_limit().enabled();
+
+        // Every request counts towards the quota of the client IP address, authenticated or not.
+        if rate_limit {
+            if let Ok(ConnectInfo(address)) = ConnectInfo::<SocketAddr>::from_request(req).await {
+                if !args.ip_rate_limiter.check(address.ip()) {
+                    return Err(ApiError::TooManyRequests);
+                }
+            }
+        }
+
         // Check if the requested endpoint is open for public use.
         if args.rest_api_config.public_routes().is_match(&uri.to_string()) {
             return Ok(Auth { phantom: PhantomData });
@@ -75,48 +94,103 @@ where
             JsonWebToken::from(bearer.token().to_string())
         };
 
-        validate_jwt(uri, jwt, args).await?;
+        let token_key = validate_jwt(&method, uri, jwt, &args).await?;
+
+        if rate_limit && !args.token_rate_limiter.check(token_key) {
+            return Err(ApiError::TooManyRequests);
+        }
 
         Ok(Auth { phantom: PhantomData })
     }
 }
 
-async fn validate_jwt<B: StorageBackend>(
-    uri: Uri,
-    jwt: JsonWebToken,
-    args: ApiArgsFullNode<B>,
-) -> Result<(), ApiError> {
-    // Decode the JWT payload to find out how to validate it. The `aud` claim will indicate if it's an API
-    // JWT or a Dashboard JWT.
-    let jwt_payload = {
-        let jwt_string = jwt.to_string();
-        // Every JWT consists of 3 parts: 1) header, 2) payload, 3) signature.
-        // The different parts are separated by `.`.
-        let split = jwt_string.split('.').collect::<Vec<_>>();
-        // If there are less or more then 3 parts the given JWT is invalid.
-        if split.len() != 3 {
-            return Err(ApiError::Forbidden);
-        }
+/// The kinds of JWT, which are validated differently.
+#[derive(Debug, Eq, PartialEq)]
+enum JwtKind {
+    /// An API JWT with a `scopes` claim.
+    Scoped,
+    /// An API JWT issued without scopes.
+    Legacy,
+    /// A dashboard JWT.
+    Dashboard,
+}
 
-        // Get the base64 encoded payload.
-        let encoded_payload = split[1];
-        // Base64-decode the payload to bytes.
-        let decoded_payload_bytes = base64::decode(encoded_payload).map_err(|_| ApiError::Forbidden)?;
-        // Create a UTF-8 string from the decoded bytes.
-        String::from_utf8(decoded_payload_bytes).map_err(|_| ApiError::Forbidden)?
+/// Tells the kind of a JWT from its claims, without validating it.
+fn jwt_kind(jwt: &str) -> Option<JwtKind> {
+    // Every JWT consists of 3 parts: 1) header, 2) payload, 3) signature.
+    // The different parts are separated by `.`.
+    let split = jwt.split('.').collect::<Vec<_>>();
+    // If there are less or more then 3 parts the given JWT is invalid.
+    if split.len() != 3 {
+        return None;
+    }
+
+    // Base64-decode the payload and parse its claims.
+    let payload = base64::decode_config(split[1], base64::URL_SAFE_NO_PAD).ok()?;
+    let claims = serde_json::from_slice::<Value>(&payload).ok()?;
+    // The audience may be a single string or a list of them.
+    let has_audience = |audience: &str| match &claims["aud"] {
+        Value::String(aud) => aud == audience,
+        Value::Array(auds) => auds.iter().any(|aud| aud == audience),
+        _ => false,
     };
 
-    if jwt_payload.contains(API_JWT_HINT) {
-        if validate_api_jwt(&jwt, &args).is_ok() && args.rest_api_config.protected_routes().is_match(&uri.to_string()) {
-            return Ok(());
+    if has_audience(API_AUDIENCE_CLAIM) {
+        // Scoped tokens must never be validated as legacy tokens, even with an invalid `scopes` claim.
+        if claims.get("scopes").is_some() {
+            Some(JwtKind::Scoped)
+        } else {
+            Some(JwtKind::Legacy)
         }
+    } else if has_audience(DASHBOARD_AUDIENCE_CLAIM) {
+        Some(JwtKind::Dashboard)
     } else {
-        #[cfg(feature = "dashboard")]
-        if jwt_payload.contains(DASHBOARD_JWT_HINT)
-            && validate_dashboard_jwt(&jwt, &args).is_ok()
-            && DASHBOARD_ROUTES.is_match(&uri.to_string())
-        {
-            return Ok(());
+        None
+    }
+}
+
+/// Validates the JWT for the requested route and returns the key its requests are rate limited with.
+async fn validate_jwt<B: StorageBackend>(
+    method: &Method,
+    uri: Uri,
+    jwt: JsonWebToken,
+    args: &ApiArgsFullNode<B>,
+) -> Result<String, ApiError> {
+    // The claims of the JWT tell how to validate it.
+    match jwt_kind(&jwt.to_string()).ok_or(ApiError::Forbidden)? {
+        JwtKind::Scoped => {
+            let claims = tokens::decode_token(
+                &jwt.to_string(),
+                &args.node_id.to_string(),
+                args.rest_api_config.jwt_salt(),
+                args.node_keypair.secret().as_ref(),
+            )
+            .map_err(|_| ApiError::Forbidden)?;
+
+            if !args.token_store.is_revoked(&claims.jti)
+                && claims.allows(Scope::of_route(method, uri.path()))
+                && args.rest_api_config.protected_routes().is_match(&uri.to_string())
+            {
+                return Ok(claims.jti);
+            }
+        }
+        // Legacy tokens can not expire nor be revoked, so they only get the scopes configured for them.
+        JwtKind::Legacy => {
+            if args
+                .rest_api_config
+                .legacy_token_scopes()
+                .contains(&Scope::of_route(method, uri.path()))
+                && validate_api_jwt(&jwt, args).is_ok()
+                && args.rest_api_config.protected_routes().is_match(&uri.to_string())
+            {
+                return Ok(LEGACY_TOKEN_KEY.to_owned());
+            }
+        }
+        JwtKind::Dashboard => {
+            #[cfg(feature = "dashboard")]
+            if validate_dashboard_jwt(&jwt, args).is_ok() && DASHBOARD_ROUTES.is_match(&uri.to_string()) {
+                return Ok(DASHBOARD_TOKEN_KEY.to_owned());
+            }
         }
     }
 
@@ -151,3 +225,66 @@ fn validate_dashboard_jwt<B: StorageBackend>(
     )
     .map_err(|_| ApiError::Forbidden)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jwt(claims: Value) -> String {
+        format!(
+            "{}.{}.c2lnbmF0dXJl",
+            base64::encode_config(r#"{"alg":"HS256","typ":"JWT"}"#, base64::URL_SAFE_NO_PAD),
+            base64::encode_config(claims.to_string(), base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    #[test]
+    fn jwt_kind_from_claims() {
+        use serde_json::json;
+
+        assert_eq!(
+            jwt_kind(&jwt(json!({ "aud": "api", "scopes": ["read"] }))),
+            Some(JwtKind::Scoped)
+        );
+        assert_eq!(jwt_kind(&jwt(json!({ "aud": "api" }))), Some(JwtKind::Legacy));
+        assert_eq!(jwt_kind(&jwt(json!({ "aud": ["api"] }))), Some(JwtKind::Legacy));
+        assert_eq!(jwt_kind(&jwt(json!({ "aud": "dashboard" }))), Some(JwtKind::Dashboard));
+        assert_eq!(jwt_kind(&jwt(json!({ "aud": "mqtt" }))), None);
+        assert_eq!(jwt_kind(&jwt(json!({ "sub": "api" }))), None);
+    }
+
+    #[test]
+    fn jwt_kind_is_not_fooled_by_claim_values() {
+        use serde_json::json;
+
+        // Looking like another kind of token in a claim value must not change how the token is validated.
+        assert_eq!(
+            jwt_kind(&jwt(json!({ "aud": "api", "sub": "\"scopes\":" }))),
+            Some(JwtKind::Legacy)
+        );
+        assert_eq!(
+            jwt_kind(&jwt(json!({ "aud": "dashboard", "sub": "\"aud\":\"api\"" }))),
+            Some(JwtKind::Dashboard)
+        );
+        // A scoped token with an invalid `scopes` claim is still a scoped token, and rejected as such.
+        assert_eq!(
+            jwt_kind(&jwt(json!({ "aud": "api", "scopes": null }))),
+            Some(JwtKind::Scoped)
+        );
+    }
+
+    #[test]
+    fn jwt_kind_of_malformed_tokens() {
+        assert_eq!(jwt_kind(""), None);
+        assert_eq!(jwt_kind("a.b"), None);
+        assert_eq!(jwt_kind("a.b.c.d"), None);
+        assert_eq!(jwt_kind("a.!!!.c"), None);
+        assert_eq!(
+            jwt_kind(&format!(
+                "a.{}.c",
+                base64::encode_config("not json", base64::URL_SAFE_NO_PAD)
+            )),
+            None
+        );
+    }
+}
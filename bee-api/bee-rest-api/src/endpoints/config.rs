@@ -3,6 +3,7 @@
 
 use std::{
     net::{IpAddr, SocketAddr, ToSocketAddrs},
+    path::{Path, PathBuf},
     time::Duration,
 };
 
@@ -10,29 +11,54 @@ use multiaddr::{Multiaddr, Protocol};
 use regex::RegexSet;
 use serde::Deserialize;
 
+use crate::endpoints::tokens::Scope;
+
 /// Default REST API binding address.
 pub(crate) const DEFAULT_BIND_ADDRESS: &str = "/ip4/0.0.0.0/tcp/14265";
 /// Default JWT salt for REST API.
 pub(crate) const DEFAULT_JWT_SALT: &str = "Bee";
 /// Default routes that are available for public use and don't need JWT authentication.
-pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 10] = [
-    "/health",
+///
+/// The block routes are listed one by one, the cone, children, referencing milestone and tag queries are expensive
+/// and stay protected.
+pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 14] = [
+    "/health*",
     "/mqtt",
     "/api/v2/info",
     "/api/v2/tips",
-    "/api/v2/blocks*",
+    "/api/v2/blocks",
+    "/api/v2/blocks/:blockId",
+    "/api/v2/blocks/:blockId/metadata",
     "/api/v2/transactions*",
-    "/api/v2/milestones*",
+    "/api/v2/milestones/0x*",
+    "/api/v2/milestones/by-index/*",
     "/api/v2/outputs*",
     "/api/v2/treasury",
     "/api/v2/receipts*",
+    "/api/v2/openapi.json",
 ];
 /// Default routes that are protected and need JWT authentication.
-pub(crate) const DEFAULT_PROTECTED_ROUTES: [&str; 2] = ["/api/v2/*", "/api/plugins/*"];
+pub(crate) const DEFAULT_PROTECTED_ROUTES: [&str; 3] = ["/api/v2/*", "/api/plugins/*", "/metrics"];
 /// Enables the proof-of-work feature on the node per default.
 pub(crate) const DEFAULT_FEATURE_PROOF_OF_WORK: bool = true;
 /// Default value for the white flag solidification timeout.
 pub(crate) const DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT: Duration = Duration::from_secs(2);
+/// Default number of milestones the confirmed milestone may lag behind the latest one for the node to be healthy.
+pub(crate) const DEFAULT_HEALTH_CONFIRMED_THRESHOLD: u32 = 2;
+/// Default maximum age of the latest milestone for the node to be healthy.
+pub(crate) const DEFAULT_HEALTH_MILESTONE_AGE_MAX: Duration = Duration::from_secs(5 * 60);
+/// Default minimum number of connected peers for the node to be healthy.
+pub(crate) const DEFAULT_HEALTH_MIN_CONNECTED_PEERS: u8 = 1;
+/// Default path of the file that records the issued API tokens.
+pub(crate) const DEFAULT_TOKEN_STORE_PATH: &str = "./tokens.json";
+/// Disables request rate limiting per default.
+pub(crate) const DEFAULT_RATE_LIMIT_ENABLED: bool = false;
+/// Default number of requests a token may make per interval.
+pub(crate) const DEFAULT_RATE_LIMIT_TOKEN_QUOTA: u32 = 1000;
+/// Default number of requests an IP address may make per interval.
+pub(crate) const DEFAULT_RATE_LIMIT_IP_QUOTA: u32 = 300;
+/// Default interval of the rate limits.
+pub(crate) const DEFAULT_RATE_LIMIT_INTERVAL: Duration = Duration::from_secs(60);
 
 /// REST API configuration builder.
 #[derive(Default, Deserialize, PartialEq)]
@@ -56,6 +82,17 @@ pub struct RestApiConfigBuilder {
     /// Describes the white flag solidification timeout.
     #[serde(alias = "whiteFlagSolidificationTimeout")]
     white_flag_solidification_timeout: Option<u64>,
+    /// Thresholds of the health checks.
+    health: Option<HealthConfigBuilder>,
+    /// Path of the file that records the issued API tokens.
+    #[serde(alias = "tokenStorePath")]
+    token_store_path: Option<PathBuf>,
+    /// Request rate limits.
+    #[serde(alias = "rateLimit")]
+    rate_limit: Option<RateLimitConfigBuilder>,
+    /// Scopes granted to the tokens that were issued without scopes.
+    #[serde(alias = "legacyTokenScopes")]
+    legacy_token_scopes: Option<Vec<Scope>>,
 }
 
 impl RestApiConfigBuilder {
@@ -101,6 +138,30 @@ impl RestApiConfigBuilder {
         self
     }
 
+    /// Sets the thresholds of the health checks.
+    pub fn with_health(mut self, health: HealthConfigBuilder) -> Self {
+        self.health.replace(health);
+        self
+    }
+
+    /// Sets the path of the file that records the issued API tokens.
+    pub fn with_token_store_path(mut self, path: PathBuf) -> Self {
+        self.token_store_path.replace(path);
+        self
+    }
+
+    /// Sets the request rate limits.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfigBuilder) -> Self {
+        self.rate_limit.replace(rate_limit);
+        self
+    }
+
+    /// Sets the scopes granted to the tokens that were issued without scopes, none of them rejects these tokens.
+    pub fn with_legacy_token_scopes(mut self, scopes: Vec<Scope>) -> Self {
+        self.legacy_token_scopes.replace(scopes);
+        self
+    }
+
     /// Builds the REST API config.
     pub fn finish(self) -> RestApiConfig {
         let multi_addr = self
@@ -162,6 +223,12 @@ impl RestApiConfigBuilder {
             protected_routes,
             feature_proof_of_work,
             white_flag_solidification_timeout,
+            health: self.health.unwrap_or_default().finish(),
+            token_store_path: self
+                .token_store_path
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_TOKEN_STORE_PATH)),
+            rate_limit: self.rate_limit.unwrap_or_default().finish(),
+            legacy_token_scopes: self.legacy_token_scopes.unwrap_or_else(|| Scope::ALL.to_vec()),
         }
     }
 }
@@ -181,6 +248,14 @@ pub struct RestApiConfig {
     feature_proof_of_work: bool,
     /// Describes the white flag solidification timeout.
     white_flag_solidification_timeout: Duration,
+    /// Thresholds of the health checks.
+    health: HealthConfig,
+    /// Path of the file that records the issued API tokens.
+    token_store_path: PathBuf,
+    /// Request rate limits.
+    rate_limit: RateLimitConfig,
+    /// Scopes granted to the tokens that were issued without scopes.
+    legacy_token_scopes: Vec<Scope>,
 }
 
 impl RestApiConfig {
@@ -218,6 +293,219 @@ impl RestApiConfig {
     pub fn white_flag_solidification_timeout(&self) -> Duration {
         self.white_flag_solidification_timeout
     }
+
+    /// Returns the thresholds of the health checks.
+    pub fn health(&self) -> &HealthConfig {
+        &self.health
+    }
+
+    /// Returns the path of the file that records the issued API tokens.
+    pub fn token_store_path(&self) -> &Path {
+        &self.token_store_path
+    }
+
+    /// Returns the request rate limits.
+    pub fn rate_limit(&self) -> &RateLimitConfig {
+        &self.rate_limit
+    }
+
+    /// Returns the scopes granted to the tokens that were issued without scopes.
+    pub fn legacy_token_scopes(&self) -> &[Scope] {
+        &self.legacy_token_scopes
+    }
+}
+
+/// Health checks configuration builder.
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct HealthConfigBuilder {
+    /// Number of milestones the confirmed milestone may lag behind the latest one.
+    #[serde(alias = "confirmedThreshold")]
+    confirmed_threshold: Option<u32>,
+    /// Maximum age of the latest milestone, in seconds.
+    #[serde(alias = "milestoneAgeMax")]
+    milestone_age_max: Option<u64>,
+    /// Minimum number of connected peers.
+    #[serde(alias = "minConnectedPeers")]
+    min_connected_peers: Option<u8>,
+}
+
+impl HealthConfigBuilder {
+    /// Creates a new config builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of milestones the confirmed milestone may lag behind the latest one.
+    pub fn with_confirmed_threshold(mut self, threshold: u32) -> Self {
+        self.confirmed_threshold.replace(threshold);
+        self
+    }
+
+    /// Sets the maximum age of the latest milestone in seconds.
+    pub fn with_milestone_age_max(mut self, age: u64) -> Self {
+        self.milestone_age_max.replace(age);
+        self
+    }
+
+    /// Sets the minimum number of connected peers.
+    pub fn with_min_connected_peers(mut self, peers: u8) -> Self {
+        self.min_connected_peers.replace(peers);
+        self
+    }
+
+    /// Builds the health checks config.
+    pub fn finish(self) -> HealthConfig {
+        HealthConfig {
+            confirmed_threshold: self.confirmed_threshold.unwrap_or(DEFAULT_HEALTH_CONFIRMED_THRESHOLD),
+            milestone_age_max: self
+                .milestone_age_max
+                .map_or(DEFAULT_HEALTH_MILESTONE_AGE_MAX, Duration::from_secs),
+            min_connected_peers: self.min_connected_peers.unwrap_or(DEFAULT_HEALTH_MIN_CONNECTED_PEERS),
+        }
+    }
+}
+
+/// Health checks configuration.
+#[derive(Clone)]
+pub struct HealthConfig {
+    /// Number of milestones the confirmed milestone may lag behind the latest one.
+    confirmed_threshold: u32,
+    /// Maximum age of the latest milestone.
+    milestone_age_max: Duration,
+    /// Minimum number of connected peers.
+    min_connected_peers: u8,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        HealthConfigBuilder::new().finish()
+    }
+}
+
+impl HealthConfig {
+    /// Returns a builder for this config.
+    pub fn build() -> HealthConfigBuilder {
+        HealthConfigBuilder::new()
+    }
+
+    /// Returns the number of milestones the confirmed milestone may lag behind the latest one.
+    pub fn confirmed_threshold(&self) -> u32 {
+        self.confirmed_threshold
+    }
+
+    /// Returns the maximum age of the latest milestone.
+    pub fn milestone_age_max(&self) -> Duration {
+        self.milestone_age_max
+    }
+
+    /// Returns the minimum number of connected peers.
+    pub fn min_connected_peers(&self) -> u8 {
+        self.min_connected_peers
+    }
+}
+
+/// Request rate limits configuration builder.
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct RateLimitConfigBuilder {
+    /// Enables/disables request rate limiting.
+    enabled: Option<bool>,
+    /// Number of requests a token may make per interval.
+    #[serde(alias = "tokenQuota")]
+    token_quota: Option<u32>,
+    /// Number of requests an IP address may make per interval.
+    #[serde(alias = "ipQuota")]
+    ip_quota: Option<u32>,
+    /// Interval of the quotas, in seconds.
+    interval: Option<u64>,
+}
+
+impl RateLimitConfigBuilder {
+    /// Creates a new config builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables request rate limiting.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled.replace(enabled);
+        self
+    }
+
+    /// Sets the number of requests a token may make per interval.
+    pub fn with_token_quota(mut self, quota: u32) -> Self {
+        self.token_quota.replace(quota);
+        self
+    }
+
+    /// Sets the number of requests an IP address may make per interval.
+    pub fn with_ip_quota(mut self, quota: u32) -> Self {
+        self.ip_quota.replace(quota);
+        self
+    }
+
+    /// Sets the interval of the quotas in seconds.
+    pub fn with_interval(mut self, interval: u64) -> Self {
+        self.interval.replace(interval);
+        self
+    }
+
+    /// Builds the request rate limits config.
+    pub fn finish(self) -> RateLimitConfig {
+        RateLimitConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_RATE_LIMIT_ENABLED),
+            token_quota: self.token_quota.unwrap_or(DEFAULT_RATE_LIMIT_TOKEN_QUOTA),
+            ip_quota: self.ip_quota.unwrap_or(DEFAULT_RATE_LIMIT_IP_QUOTA),
+            interval: self.interval.map_or(DEFAULT_RATE_LIMIT_INTERVAL, Duration::from_secs),
+        }
+    }
+}
+
+/// Request rate limits configuration.
+#[derive(Clone)]
+pub struct RateLimitConfig {
+    /// Enables/disables request rate limiting.
+    enabled: bool,
+    /// Number of requests a token may make per interval.
+    token_quota: u32,
+    /// Number of requests an IP address may make per interval.
+    ip_quota: u32,
+    /// Interval of the quotas.
+    interval: Duration,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfigBuilder::new().finish()
+    }
+}
+
+impl RateLimitConfig {
+    /// Returns a builder for this config.
+    pub fn build() -> RateLimitConfigBuilder {
+        RateLimitConfigBuilder::new()
+    }
+
+    /// Returns whether request rate limiting is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the number of requests a token may make per interval.
+    pub fn token_quota(&self) -> u32 {
+        self.token_quota
+    }
+
+    /// Returns the number of requests an IP address may make per interval.
+    pub fn ip_quota(&self) -> u32 {
+        self.ip_quota
+    }
+
+    /// Returns the interval of the quotas.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
 }
 
 pub(crate) fn route_to_regex(route: &str) -> String {
@@ -226,7 +514,56 @@ pub(crate) fn route_to_regex(route: &str) -> String {
     let mut escaped: String = regex::escape(route);
     // Convert the escaped wildcard to a valid regex.
     escaped = escaped.replace("\\*", ".*");
+    // Convert the path parameters, like `:blockId`, to a regex matching a single path segment.
+    escaped = escaped
+        .split('/')
+        .map(|segment| if segment.starts_with(':') { "[^/?]+" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/");
     // End the regex.
     escaped.push('$');
     escaped
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(route: &str, uri: &str) -> bool {
+        RegexSet::new([route_to_regex(route)]).unwrap().is_match(uri)
+    }
+
+    #[test]
+    fn wildcard_routes() {
+        assert!(matches("/api/v2/outputs*", "/api/v2/outputs/0x01/metadata"));
+        assert!(matches("/api/v2/outputs*", "/api/v2/outputs?tag=0x01"));
+        assert!(!matches("/api/v2/outputs*", "/api/v2/info"));
+    }
+
+    #[test]
+    fn parameter_routes() {
+        assert!(matches("/api/v2/blocks/:blockId", "/api/v2/blocks/0x01"));
+        assert!(matches(
+            "/api/v2/blocks/:blockId/metadata",
+            "/api/v2/blocks/0x01/metadata"
+        ));
+        assert!(!matches("/api/v2/blocks/:blockId", "/api/v2/blocks/0x01/children"));
+        assert!(!matches("/api/v2/blocks/:blockId", "/api/v2/blocks/0x01?depth=100"));
+        assert!(!matches("/api/v2/blocks/:blockId", "/api/v2/blocks/"));
+    }
+
+    #[test]
+    fn default_public_block_routes() {
+        let config = RestApiConfig::build().finish();
+        let is_public = |uri| config.public_routes().is_match(uri);
+
+        assert!(is_public("/api/v2/blocks"));
+        assert!(is_public("/api/v2/blocks/0x01"));
+        assert!(is_public("/api/v2/blocks/0x01/metadata"));
+        assert!(!is_public("/api/v2/blocks?tag=0x01"));
+        assert!(!is_public("/api/v2/blocks/0x01/children"));
+        assert!(!is_public("/api/v2/blocks/0x01/past-cone?depth=10"));
+        assert!(!is_public("/api/v2/blocks/0x01/future-cone?depth=10"));
+        assert!(!is_public("/api/v2/blocks/0x01/referenced-by"));
+    }
+}
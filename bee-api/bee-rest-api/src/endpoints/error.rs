@@ -25,6 +25,10 @@ pub enum ApiError {
     InternalServerError,
     #[error("forbidden")]
     Forbidden,
+    #[error("too many requests")]
+    TooManyRequests,
+    #[error("internal server error: {0}")]
+    Tangle(#[from] bee_tangle::TangleError),
 }
 
 // Errors from dependencies that get exposed to the user.
@@ -33,6 +37,8 @@ pub enum DependencyError {
     #[error("{0}")]
     InvalidPath(#[from] axum::extract::rejection::PathRejection),
     #[error("{0}")]
+    InvalidQuery(#[from] axum::extract::rejection::QueryRejection),
+    #[error("{0}")]
     AxumJsonError(#[from] axum::extract::rejection::JsonRejection),
     #[error("{0}")]
     SerdeJsonError(#[from] serde_json::error::Error),
@@ -54,6 +60,8 @@ impl IntoResponse for ApiError {
             ApiError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
             ApiError::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
             ApiError::Forbidden => StatusCode::FORBIDDEN,
+            ApiError::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::Tangle(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ApiError::DependencyError(_) => StatusCode::BAD_REQUEST,
         };
 
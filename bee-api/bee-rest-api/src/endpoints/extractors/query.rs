@@ -0,0 +1,31 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    async_trait,
+    extract::{FromRequest, RequestParts},
+};
+use serde::de::DeserializeOwned;
+
+use crate::endpoints::error::{ApiError, DependencyError};
+
+// We define our own `Query` extractor that customizes the error from `axum::extract::Query`
+pub struct CustomQuery<T>(pub T);
+
+#[async_trait]
+impl<B, T> FromRequest<B> for CustomQuery<T>
+where
+    // these trait bounds are copied from `impl FromRequest for
+    // axum::extract::query::Query`
+    T: DeserializeOwned,
+    B: Send,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        match axum::extract::Query::<T>::from_request(req).await {
+            Ok(value) => Ok(Self(value.0)),
+            Err(e) => Err(ApiError::DependencyError(DependencyError::InvalidQuery(e))),
+        }
+    }
+}
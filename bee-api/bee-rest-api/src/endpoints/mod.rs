@@ -6,21 +6,36 @@ pub mod error;
 pub mod extractors;
 pub mod routes;
 pub mod storage;
+pub mod tokens;
 
 pub mod auth;
+mod rate_limit;
 
-use std::{any::TypeId, ops::Deref, sync::Arc};
+use std::{
+    any::TypeId,
+    net::{IpAddr, SocketAddr},
+    ops::Deref,
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use axum::{
     extract::Extension, handler::Handler, http::StatusCode, middleware::from_extractor, response::IntoResponse,
     routing::get, Router,
 };
-use bee_gossip::{Keypair, NetworkCommandSender, PeerId};
-use bee_ledger::workers::consensus::{ConsensusWorker, ConsensusWorkerCommand};
-use bee_protocol::workers::{
-    config::ProtocolConfig, BlockRequesterWorker, BlockSubmitterWorker, BlockSubmitterWorkerEvent, PeerManager,
-    PeerManagerResWorker, RequestedBlocks,
+use bee_gossip::{BanList, Keypair, NetworkCommandSender, PeerId};
+use bee_ledger::workers::{
+    base_token::BaseTokenConfig,
+    consensus::{ConsensusWorker, ConsensusWorkerCommand},
+    protocol_parameters::ProtocolParametersSchedule,
+    pruning::PruningStatistics,
+};
+use bee_protocol::{
+    types::metrics::{NodeMetrics, ThroughputMetrics},
+    workers::{
+        config::ProtocolConfig, BlockRequesterWorker, BlockSubmitterWorker, BlockSubmitterWorkerEvent, MetricsWorker,
+        PeerManager, PeerManagerResWorker, RequestedBlocks, RequestedMilestones,
+    },
 };
 use bee_runtime::{
     event::Bus,
@@ -33,7 +48,13 @@ use log::info;
 use tokio::sync::mpsc;
 
 use self::{config::RestApiConfig, storage::StorageBackend};
-use crate::endpoints::{auth::Auth, error::ApiError, routes::filter_all};
+use crate::endpoints::{
+    auth::Auth,
+    error::ApiError,
+    rate_limit::RateLimiter,
+    routes::{api::v2::control_jobs::ControlJobs, filter_all},
+    tokens::TokenStore,
+};
 
 pub(crate) const CONFIRMED_THRESHOLD: u32 = 5;
 
@@ -58,7 +79,9 @@ pub struct InitFullNodeConfig {
     pub protocol_config: ProtocolConfig,
     pub network_name: String,
     pub bech32_hrp: String,
-    #[cfg(feature = "dashboard")]
+    pub base_token: BaseTokenConfig,
+    pub autopeering_local: Option<bee_autopeering::Local>,
+    /// Username of the dashboard, only used when the `dashboard` feature is enabled.
     pub dashboard_username: String,
 }
 
@@ -89,16 +112,28 @@ pub struct ApiArgsFullNodeInner<B: StorageBackend> {
     pub(crate) protocol_config: ProtocolConfig,
     pub(crate) network_name: String,
     pub(crate) bech32_hrp: String,
+    pub(crate) base_token: BaseTokenConfig,
+    pub(crate) autopeering_local: Option<bee_autopeering::Local>,
     pub(crate) storage: ResourceHandle<B>,
     pub(crate) bus: ResourceHandle<Bus<'static>>,
     pub(crate) node_info: ResourceHandle<NodeInfo>,
     pub(crate) tangle: ResourceHandle<Tangle<B>>,
+    pub(crate) protocol_parameters: ResourceHandle<ProtocolParametersSchedule>,
     pub(crate) peer_manager: ResourceHandle<PeerManager>,
+    pub(crate) node_metrics: ResourceHandle<NodeMetrics>,
+    pub(crate) throughput_metrics: ResourceHandle<ThroughputMetrics>,
     pub(crate) requested_blocks: ResourceHandle<RequestedBlocks>,
+    pub(crate) requested_milestones: ResourceHandle<RequestedMilestones>,
+    pub(crate) pruning_statistics: ResourceHandle<PruningStatistics>,
     pub(crate) network_command_sender: ResourceHandle<NetworkCommandSender>,
+    pub(crate) ban_list: ResourceHandle<BanList>,
     pub(crate) block_submitter: mpsc::UnboundedSender<BlockSubmitterWorkerEvent>,
     pub(crate) block_requester: BlockRequesterWorker,
     pub(crate) consensus_worker: mpsc::UnboundedSender<ConsensusWorkerCommand>,
+    pub(crate) control_jobs: ControlJobs,
+    pub(crate) token_store: TokenStore,
+    pub(crate) ip_rate_limiter: RateLimiter<IpAddr>,
+    pub(crate) token_rate_limiter: RateLimiter<String>,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard_username: String,
 }
@@ -118,11 +153,18 @@ where
             TypeId::of::<TangleWorker>(),
             TypeId::of::<BlockSubmitterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<MetricsWorker>(),
+            TypeId::of::<BlockRequesterWorker>(),
+            TypeId::of::<ConsensusWorker>(),
         ]
         .leak()
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let token_store =
+            TokenStore::open(config.rest_api_config.token_store_path()).map_err(|e| WorkerError(Box::new(e)))?;
+        let rate_limit = config.rest_api_config.rate_limit().clone();
+
         let args = ApiArgsFullNode(Arc::new(ApiArgsFullNodeInner {
             node_id: config.node_id,
             node_keypair: config.node_keypair,
@@ -130,16 +172,28 @@ where
             protocol_config: config.protocol_config,
             network_name: config.network_name,
             bech32_hrp: config.bech32_hrp,
+            base_token: config.base_token,
+            autopeering_local: config.autopeering_local,
             storage: node.storage(),
             bus: node.bus(),
             node_info: node.info(),
             tangle: node.resource::<Tangle<N::Backend>>(),
+            protocol_parameters: node.resource::<ProtocolParametersSchedule>(),
             peer_manager: node.resource::<PeerManager>(),
+            node_metrics: node.resource::<NodeMetrics>(),
+            throughput_metrics: node.resource::<ThroughputMetrics>(),
             requested_blocks: node.resource::<RequestedBlocks>(),
+            requested_milestones: node.resource::<RequestedMilestones>(),
+            pruning_statistics: node.resource::<PruningStatistics>(),
             network_command_sender: node.resource::<NetworkCommandSender>(),
+            ban_list: node.resource::<BanList>(),
             block_submitter: node.worker::<BlockSubmitterWorker>().unwrap().tx.clone(),
             block_requester: node.worker::<BlockRequesterWorker>().unwrap().clone(),
             consensus_worker: node.worker::<ConsensusWorker>().unwrap().tx.clone(),
+            control_jobs: ControlJobs::default(),
+            token_store,
+            ip_rate_limiter: RateLimiter::new(rate_limit.ip_quota(), rate_limit.interval()),
+            token_rate_limiter: RateLimiter::new(rate_limit.token_quota(), rate_limit.interval()),
             #[cfg(feature = "dashboard")]
             dashboard_username: config.dashboard_username,
         }));
@@ -154,7 +208,7 @@ where
                 .fallback(fallback.into_service());
 
             axum::Server::bind(&args.rest_api_config.bind_socket_addr())
-                .serve(app.into_make_service())
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .with_graceful_shutdown(async {
                     shutdown.await.ok();
                 })
@@ -188,6 +242,8 @@ where
 
             let app = Router::new()
                 .route("/health", get(health_handler))
+                .route("/health/live", get(health_handler))
+                .route("/health/ready", get(health_handler))
                 .fallback(fallback.into_service());
 
             axum::Server::bind(&config.rest_api_config.bind_socket_addr())
@@ -0,0 +1,136 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// The windows of the keys that made requests recently.
+struct Windows<K> {
+    entries: HashMap<K, (Instant, u32)>,
+    swept_at: Instant,
+}
+
+/// A fixed-window request rate limiter.
+pub(crate) struct RateLimiter<K> {
+    quota: u32,
+    interval: Duration,
+    windows: Mutex<Windows<K>>,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    /// Creates a limiter allowing `quota` requests per `interval` and key.
+    pub(crate) fn new(quota: u32, interval: Duration) -> Self {
+        Self {
+            quota,
+            interval,
+            windows: Mutex::new(Windows {
+                entries: HashMap::new(),
+                swept_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Records a request for the given key and returns whether it is within the quota.
+    pub(crate) fn check(&self, key: K) -> bool {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: K, now: Instant) -> bool {
+        let mut windows = self.windows.lock().expect("poisoned lock");
+
+        // Drop the windows that have elapsed so that the map does not grow with every key ever seen. This is done once
+        // per interval, which amortizes it over the requests of the interval.
+        if now.saturating_duration_since(windows.swept_at) >= self.interval {
+            let interval = self.interval;
+            windows
+                .entries
+                .retain(|_, (start, _)| now.saturating_duration_since(*start) < interval);
+            windows.swept_at = now;
+        }
+
+        let (start, count) = windows.entries.entry(key).or_insert((now, 0));
+
+        if now.saturating_duration_since(*start) >= self.interval {
+            *start = now;
+            *count = 0;
+        }
+
+        if *count >= self.quota {
+            false
+        } else {
+            *count += 1;
+            true
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.windows.lock().expect("poisoned lock").entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn quota_per_window() {
+        let limiter = RateLimiter::new(3, INTERVAL);
+        let start = Instant::now();
+
+        assert!(limiter.check_at("a", start));
+        assert!(limiter.check_at("a", start + Duration::from_secs(1)));
+        assert!(limiter.check_at("a", start + Duration::from_secs(2)));
+        assert!(!limiter.check_at("a", start + Duration::from_secs(3)));
+        assert!(!limiter.check_at("a", start + INTERVAL - Duration::from_millis(1)));
+        // A new window starts once the interval has elapsed.
+        assert!(limiter.check_at("a", start + INTERVAL));
+        assert!(limiter.check_at("a", start + INTERVAL));
+        assert!(limiter.check_at("a", start + INTERVAL));
+        assert!(!limiter.check_at("a", start + INTERVAL));
+    }
+
+    #[test]
+    fn quota_per_key() {
+        let limiter = RateLimiter::new(1, INTERVAL);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("a", now));
+        assert!(!limiter.check_at("a", now));
+        assert!(limiter.check_at("b", now));
+        assert!(!limiter.check_at("b", now));
+    }
+
+    #[test]
+    fn zero_quota() {
+        let limiter = RateLimiter::new(0, INTERVAL);
+
+        assert!(!limiter.check_at("a", Instant::now()));
+    }
+
+    #[test]
+    fn elapsed_windows_are_swept_once_per_interval() {
+        let limiter = RateLimiter::new(1, INTERVAL);
+        let start = Instant::now();
+
+        for key in 0..2000 {
+            assert!(limiter.check_at(key, start));
+        }
+        assert_eq!(limiter.len(), 2000);
+
+        // The windows are still running.
+        assert!(limiter.check_at(2000, start + INTERVAL / 2));
+        assert_eq!(limiter.len(), 2001);
+
+        // The sweep keeps the window that is still running.
+        assert!(limiter.check_at(2001, start + INTERVAL));
+        assert_eq!(limiter.len(), 2);
+        assert!(!limiter.check_at(2000, start + INTERVAL));
+    }
+}
@@ -9,7 +9,10 @@ use std::{
 
 use axum::{extract::Extension, routing::post, Router};
 use bee_block::{payload::milestone::MilestoneIndex, BlockId};
-use bee_ledger::workers::consensus::{self, WhiteFlagMetadata};
+use bee_ledger::workers::{
+    consensus::{self, WhiteFlagMetadata},
+    error::Error as LedgerError,
+};
 use bee_protocol::workers::{event::BlockSolidified, request_block};
 use futures::channel::oneshot;
 use serde_json::Value;
@@ -22,7 +25,7 @@ use crate::{
         storage::StorageBackend,
         ApiArgsFullNode,
     },
-    types::responses::WhiteFlagResponse,
+    types::{dtos::ConflictingBlockDto, responses::WhiteFlagResponse},
 };
 
 pub(crate) fn filter<B: StorageBackend>() -> Router {
@@ -91,7 +94,15 @@ pub(crate) async fn white_flag<B: StorageBackend>(
     });
 
     for parent in parents.iter() {
-        if args.tangle.is_solid_block(parent).await {
+        let is_solid = match args.tangle.is_solid_block(parent).await {
+            Ok(is_solid) => is_solid,
+            Err(e) => {
+                args.bus.remove_listeners_by_id(TypeId::of::<Static>());
+                return Err(e.into());
+            }
+        };
+
+        if is_solid {
             if let Ok(mut to_solidify) = to_solidify.lock() {
                 to_solidify.remove(parent);
             }
@@ -116,7 +127,9 @@ pub(crate) async fn white_flag<B: StorageBackend>(
     }
 
     // TODO Actually pass the previous milestone id ?
-    let mut metadata = WhiteFlagMetadata::new(index, 0, None);
+    let mut metadata = WhiteFlagMetadata::new(index, 0, None)
+        .with_token_supply(args.base_token.token_supply())
+        .with_protocol_parameters(args.protocol_parameters.active_at(index));
 
     // Wait for all parents to get solid or the timeout to expire.
     let response = match timeout(args.rest_api_config.white_flag_solidification_timeout(), receiver).await {
@@ -124,10 +137,26 @@ pub(crate) async fn white_flag<B: StorageBackend>(
             // Did not timeout, parents are solid and white flag can happen.
             consensus::white_flag::<B>(&args.tangle, &args.storage, &parents, &mut metadata)
                 .await
-                .map_err(|e| ApiError::DependencyError(DependencyError::InvalidWhiteflag(e)))?;
+                .map_err(|e| match e {
+                    LedgerError::Tangle(e) => ApiError::Tangle(e),
+                    e => ApiError::DependencyError(DependencyError::InvalidWhiteflag(e)),
+                })?;
 
             Ok(WhiteFlagResponse {
                 merkle_tree_hash: metadata.applied_merkle_root().to_string(),
+                conflicting_blocks: metadata
+                    .excluded_conflicting_blocks()
+                    .iter()
+                    .map(|(block_id, conflict)| ConflictingBlockDto {
+                        block_id: block_id.to_string(),
+                        conflict_reason: *conflict as u8,
+                        conflict_diagnostic: metadata
+                            .conflict_diagnostics()
+                            .iter()
+                            .find(|(id, _)| id == block_id)
+                            .map(|(_, diagnostic)| diagnostic.into()),
+                    })
+                    .collect(),
             })
         }
         Err(_) => {
@@ -0,0 +1,197 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::{address::Address, output::OutputId, payload::milestone::MilestoneIndex};
+use bee_ledger::{
+    types::{AddressHistoryEntry, AddressHistoryEntryKind, AddressHistoryIndex, AddressHistoryPage, LedgerIndex},
+    workers::address_history::fetch_address_balance,
+};
+use bee_storage::access::Fetch;
+use log::error;
+use serde::Deserialize;
+
+use crate::{
+    endpoints::{
+        error::ApiError,
+        extractors::{path::CustomPath, query::CustomQuery},
+        storage::StorageBackend,
+        ApiArgsFullNode,
+    },
+    types::{
+        dtos::AddressHistoryEntryDto,
+        responses::{AddressBalanceResponse, AddressHistoryResponse},
+    },
+};
+
+const ADDRESS_HISTORY_PAGE_SIZE_DEFAULT: usize = 100;
+const ADDRESS_HISTORY_PAGE_SIZE_MAX: usize = 1000;
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new()
+        .route("/addresses/:bech32_address/history", get(address_history::<B>))
+        .route("/addresses/:bech32_address/balance", get(address_balance::<B>))
+}
+
+#[derive(Deserialize)]
+struct HistoryQuery {
+    #[serde(rename = "pageSize")]
+    page_size: Option<usize>,
+    cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BalanceQuery {
+    #[serde(rename = "milestoneIndex")]
+    milestone_index: Option<u32>,
+}
+
+fn parse_address<B: StorageBackend>(bech32_address: &str, args: &ApiArgsFullNode<B>) -> Result<Address, ApiError> {
+    match Address::try_from_bech32(bech32_address) {
+        Ok((hrp, address)) if hrp == args.bech32_hrp => Ok(address),
+        Ok(_) => Err(ApiError::BadRequest(
+            "invalid address: unexpected bech32 human-readable part",
+        )),
+        Err(_) => Err(ApiError::BadRequest(
+            "invalid address: expected a bech32-encoded address",
+        )),
+    }
+}
+
+fn fetch_address_history_index<B: StorageBackend>(storage: &B) -> Result<AddressHistoryIndex, ApiError> {
+    Fetch::<(), AddressHistoryIndex>::fetch(storage, &())
+        .map_err(|e| {
+            error!("cannot fetch from storage: {}", e);
+            ApiError::InternalServerError
+        })?
+        .ok_or(ApiError::ServiceUnavailable("the address history is disabled"))
+}
+
+/// Formats the cursor of the page starting from an entry as `<milestone index>.<kind>.<output ID>`, where the kind is
+/// 0 for a creation and 1 for a consumption.
+fn format_cursor(entry: &AddressHistoryEntry) -> String {
+    format!(
+        "{}.{}.{}",
+        *entry.milestone_index(),
+        entry.kind() as u8,
+        entry.output_id()
+    )
+}
+
+fn parse_cursor(cursor: &str) -> Option<AddressHistoryEntry> {
+    let mut parts = cursor.splitn(3, '.');
+    let milestone_index = MilestoneIndex(parts.next()?.parse().ok()?);
+    let kind = match parts.next()? {
+        "0" => AddressHistoryEntryKind::Created,
+        "1" => AddressHistoryEntryKind::Consumed,
+        _ => return None,
+    };
+    let output_id = parts.next()?.parse::<OutputId>().ok()?;
+
+    Some(AddressHistoryEntry::lower_bound(milestone_index, kind, output_id))
+}
+
+async fn address_history<B: StorageBackend>(
+    CustomPath(bech32_address): CustomPath<String>,
+    CustomQuery(query): CustomQuery<HistoryQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<AddressHistoryResponse, ApiError> {
+    let address = parse_address(&bech32_address, &args)?;
+    let history_index = fetch_address_history_index(&*args.storage)?;
+
+    let page_size = query.page_size.unwrap_or(ADDRESS_HISTORY_PAGE_SIZE_DEFAULT);
+
+    if page_size == 0 || page_size > ADDRESS_HISTORY_PAGE_SIZE_MAX {
+        return Err(ApiError::BadRequest(
+            "invalid page size: expected a value between 1 and 1000",
+        ));
+    }
+
+    let start = query
+        .cursor
+        .map(|cursor| parse_cursor(&cursor).ok_or(ApiError::BadRequest("invalid cursor")))
+        .transpose()?;
+
+    // One more entry than requested is fetched to start the next page from.
+    let mut entries = Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+        &*args.storage,
+        &AddressHistoryPage::new(address, start, page_size + 1),
+    )
+    .map_err(|e| {
+        error!("cannot fetch from storage: {}", e);
+        ApiError::InternalServerError
+    })?
+    .unwrap_or_default();
+
+    let next = if entries.len() > page_size { entries.pop() } else { None };
+
+    Ok(AddressHistoryResponse {
+        address: bech32_address,
+        history_index: *history_index,
+        entries: entries.iter().map(AddressHistoryEntryDto::from).collect(),
+        cursor: next.as_ref().map(format_cursor),
+    })
+}
+
+async fn address_balance<B: StorageBackend>(
+    CustomPath(bech32_address): CustomPath<String>,
+    CustomQuery(query): CustomQuery<BalanceQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<AddressBalanceResponse, ApiError> {
+    let address = parse_address(&bech32_address, &args)?;
+    let history_index = fetch_address_history_index(&*args.storage)?;
+    let ledger_index = Fetch::<(), LedgerIndex>::fetch(&*args.storage, &())
+        .map_err(|e| {
+            error!("cannot fetch from storage: {}", e);
+            ApiError::InternalServerError
+        })?
+        .ok_or(ApiError::ServiceUnavailable("the ledger is not initialized"))?;
+
+    let milestone_index = query.milestone_index.map_or(ledger_index.0, MilestoneIndex);
+
+    // The history of the outputs consumed before the address history index was removed, balances before it can not be
+    // computed anymore.
+    if milestone_index < history_index.0 || milestone_index > ledger_index.0 {
+        return Err(ApiError::BadRequest(
+            "invalid milestone index: expected a milestone between the address history index and the ledger index",
+        ));
+    }
+
+    let balance = fetch_address_balance(&*args.storage, &address, milestone_index).map_err(|e| {
+        error!("cannot compute the balance of {}: {}", bech32_address, e);
+        ApiError::InternalServerError
+    })?;
+
+    Ok(AddressBalanceResponse {
+        address: bech32_address,
+        milestone_index: *milestone_index,
+        balance: balance.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_block::payload::transaction::TransactionId;
+
+    use super::*;
+
+    #[test]
+    fn cursor_round_trip() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; TransactionId::LENGTH]), 3).unwrap();
+        let entry = AddressHistoryEntry::consumed(output_id, MilestoneIndex(42), 0, 0, MilestoneIndex(0), 0);
+
+        assert_eq!(parse_cursor(&format_cursor(&entry)), Some(entry));
+    }
+
+    #[test]
+    fn invalid_cursors() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; TransactionId::LENGTH]), 3)
+            .unwrap()
+            .to_string();
+
+        assert_eq!(parse_cursor("42.0"), None);
+        assert_eq!(parse_cursor(&format!("42.2.{}", output_id)), None);
+        assert_eq!(parse_cursor(&format!("-1.0.{}", output_id)), None);
+        assert_eq!(parse_cursor("42.0.0x2a"), None);
+    }
+}
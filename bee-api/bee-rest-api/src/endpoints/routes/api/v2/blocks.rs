@@ -43,7 +43,7 @@ pub(crate) async fn blocks_json<B: StorageBackend>(
     block_id: BlockId,
     args: ApiArgsFullNode<B>,
 ) -> Result<BlockResponse, ApiError> {
-    match args.tangle.get(&block_id) {
+    match args.tangle.get(&block_id)? {
         Some(block) => Ok(BlockResponse::Json(BlockDto::from(&block))),
         None => Err(ApiError::NotFound),
     }
@@ -53,7 +53,7 @@ pub(crate) async fn blocks_raw<B: StorageBackend>(
     block_id: BlockId,
     args: ApiArgsFullNode<B>,
 ) -> Result<BlockResponse, ApiError> {
-    match args.tangle.get(&block_id) {
+    match args.tangle.get(&block_id)? {
         Some(block) => Ok(BlockResponse::Raw(block.pack_to_vec())),
         None => Err(ApiError::NotFound),
     }
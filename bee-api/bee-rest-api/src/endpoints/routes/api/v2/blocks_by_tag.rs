@@ -0,0 +1,124 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::{payload::milestone::MilestoneIndex, BlockId};
+use bee_storage::access::Fetch;
+use bee_tangle::tagged_block::{PaddedTag, TaggedBlock, TaggedBlockPage};
+use log::error;
+use serde::Deserialize;
+
+use crate::{
+    endpoints::{error::ApiError, extractors::query::CustomQuery, storage::StorageBackend, ApiArgsFullNode},
+    types::{dtos::TaggedBlockDto, responses::BlocksByTagResponse},
+};
+
+const TAGGED_BLOCKS_PAGE_SIZE_DEFAULT: usize = 100;
+const TAGGED_BLOCKS_PAGE_SIZE_MAX: usize = 1000;
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/blocks", get(blocks_by_tag::<B>))
+}
+
+#[derive(Deserialize)]
+struct TagQuery {
+    tag: Option<String>,
+    #[serde(rename = "pageSize")]
+    page_size: Option<usize>,
+    cursor: Option<String>,
+}
+
+async fn blocks_by_tag<B: StorageBackend>(
+    CustomQuery(query): CustomQuery<TagQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<BlocksByTagResponse, ApiError> {
+    if !args.protocol_config.tag_index() {
+        return Err(ApiError::ServiceUnavailable("the tag index is disabled"));
+    }
+
+    let tag = query.tag.ok_or(ApiError::BadRequest("missing tag"))?;
+    let padded_tag = prefix_hex::decode::<Vec<u8>>(&tag)
+        .ok()
+        .and_then(|tag| PaddedTag::new(&tag))
+        .ok_or(ApiError::BadRequest(
+            "invalid tag: expected a hex-encoded tag of at most 64 bytes",
+        ))?;
+
+    let page_size = query.page_size.unwrap_or(TAGGED_BLOCKS_PAGE_SIZE_DEFAULT);
+
+    if page_size == 0 || page_size > TAGGED_BLOCKS_PAGE_SIZE_MAX {
+        return Err(ApiError::BadRequest(
+            "invalid page size: expected a value between 1 and 1000",
+        ));
+    }
+
+    let start = query
+        .cursor
+        .map(|cursor| parse_cursor(&cursor).ok_or(ApiError::BadRequest("invalid cursor")))
+        .transpose()?;
+
+    // One more tagged block than requested is fetched to start the next page from.
+    let mut tagged_blocks = Fetch::<TaggedBlockPage, Vec<TaggedBlock>>::fetch(
+        &*args.storage,
+        &TaggedBlockPage::new(padded_tag, start, page_size + 1),
+    )
+    .map_err(|e| {
+        error!("cannot fetch from storage: {}", e);
+        ApiError::InternalServerError
+    })?
+    .unwrap_or_default();
+
+    let next = if tagged_blocks.len() > page_size {
+        tagged_blocks.pop()
+    } else {
+        None
+    };
+
+    Ok(BlocksByTagResponse {
+        tag: prefix_hex::encode(padded_tag.tag()),
+        blocks: tagged_blocks
+            .iter()
+            .map(|tagged_block| TaggedBlockDto {
+                block_id: tagged_block.block_id().to_string(),
+                milestone_index: *tagged_block.milestone_index(),
+            })
+            .collect(),
+        cursor: next.as_ref().map(format_cursor),
+    })
+}
+
+/// Formats the cursor of the page starting from a tagged block as `<milestone index>.<block ID>`.
+fn format_cursor(tagged_block: &TaggedBlock) -> String {
+    format!("{}.{}", *tagged_block.milestone_index(), tagged_block.block_id())
+}
+
+fn parse_cursor(cursor: &str) -> Option<TaggedBlock> {
+    let (milestone_index, block_id) = cursor.split_once('.')?;
+
+    Some(TaggedBlock::new(
+        MilestoneIndex(milestone_index.parse().ok()?),
+        block_id.parse::<BlockId>().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trip() {
+        let tagged_block = TaggedBlock::new(MilestoneIndex(42), BlockId::new([0x2a; BlockId::LENGTH]));
+
+        assert_eq!(parse_cursor(&format_cursor(&tagged_block)), Some(tagged_block));
+    }
+
+    #[test]
+    fn invalid_cursors() {
+        let block_id = BlockId::new([0x2a; BlockId::LENGTH]).to_string();
+
+        assert_eq!(parse_cursor("42"), None);
+        assert_eq!(parse_cursor(&block_id), None);
+        assert_eq!(parse_cursor(&format!("-1.{}", block_id)), None);
+        assert_eq!(parse_cursor("42.0x2a"), None);
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::BlockId;
+use serde::Deserialize;
+
+use crate::{
+    endpoints::{
+        error::ApiError,
+        extractors::{path::CustomPath, query::CustomQuery},
+        storage::StorageBackend,
+        ApiArgsFullNode,
+    },
+    types::responses::BlockChildrenResponse,
+};
+
+const CHILDREN_PAGE_SIZE_DEFAULT: usize = 100;
+const CHILDREN_PAGE_SIZE_MAX: usize = 1000;
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/blocks/:block_id/children", get(blocks_children::<B>))
+}
+
+#[derive(Deserialize)]
+struct ChildrenQuery {
+    #[serde(rename = "pageSize")]
+    page_size: Option<usize>,
+    cursor: Option<String>,
+}
+
+async fn blocks_children<B: StorageBackend>(
+    CustomPath(block_id): CustomPath<BlockId>,
+    CustomQuery(query): CustomQuery<ChildrenQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<BlockChildrenResponse, ApiError> {
+    let page_size = query.page_size.unwrap_or(CHILDREN_PAGE_SIZE_DEFAULT);
+
+    if page_size == 0 || page_size > CHILDREN_PAGE_SIZE_MAX {
+        return Err(ApiError::BadRequest(
+            "invalid page size: expected a value between 1 and 1000",
+        ));
+    }
+
+    let start = match query.cursor {
+        Some(cursor) => cursor
+            .parse::<usize>()
+            .map_err(|_| ApiError::BadRequest("invalid cursor"))?,
+        None => 0,
+    };
+
+    let mut children = args.tangle.get_children(&block_id)?.unwrap_or_default();
+
+    if children.is_empty() && !args.tangle.contains(&block_id)? {
+        return Err(ApiError::NotFound);
+    }
+
+    // The storage returns the children in no particular order, sorting them keeps the pages stable.
+    children.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    let count = children.len();
+    let end = start.saturating_add(page_size).min(count);
+
+    Ok(BlockChildrenResponse {
+        block_id: block_id.to_string(),
+        max_results: args.storage.fetch_edge_limit(),
+        count,
+        children: children
+            .get(start..end)
+            .unwrap_or_default()
+            .iter()
+            .map(BlockId::to_string)
+            .collect(),
+        cursor: (end < count).then(|| end.to_string()),
+    })
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::BlockId;
+use bee_tangle::walker::{WalkItem, WalkOrder};
+use serde::Deserialize;
+
+use crate::{
+    endpoints::{
+        error::ApiError,
+        extractors::{path::CustomPath, query::CustomQuery},
+        storage::StorageBackend,
+        ApiArgsFullNode,
+    },
+    types::{dtos::ConeBlockDto, responses::BlockConeResponse},
+};
+
+const CONE_DEPTH_DEFAULT: u32 = 5;
+const CONE_DEPTH_MAX: u32 = 50;
+// Maximum number of blocks of a cone that are returned, wide cones are cut short.
+const CONE_BLOCKS_MAX: usize = 1000;
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new()
+        .route("/blocks/:block_id/past-cone", get(past_cone::<B>))
+        .route("/blocks/:block_id/future-cone", get(future_cone::<B>))
+}
+
+#[derive(Deserialize)]
+struct ConeQuery {
+    depth: Option<u32>,
+}
+
+enum Cone {
+    Past,
+    Future,
+}
+
+async fn past_cone<B: StorageBackend>(
+    CustomPath(block_id): CustomPath<BlockId>,
+    CustomQuery(query): CustomQuery<ConeQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<BlockConeResponse, ApiError> {
+    cone(Cone::Past, block_id, query, &args)
+}
+
+async fn future_cone<B: StorageBackend>(
+    CustomPath(block_id): CustomPath<BlockId>,
+    CustomQuery(query): CustomQuery<ConeQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<BlockConeResponse, ApiError> {
+    cone(Cone::Future, block_id, query, &args)
+}
+
+fn cone<B: StorageBackend>(
+    cone: Cone,
+    block_id: BlockId,
+    query: ConeQuery,
+    args: &ApiArgsFullNode<B>,
+) -> Result<BlockConeResponse, ApiError> {
+    let depth = query.depth.unwrap_or(CONE_DEPTH_DEFAULT);
+
+    if depth == 0 || depth > CONE_DEPTH_MAX {
+        return Err(ApiError::BadRequest("invalid depth: expected a value between 1 and 50"));
+    }
+
+    if !args.tangle.contains(&block_id)? {
+        return Err(ApiError::NotFound);
+    }
+
+    let walker = match cone {
+        Cone::Past => args.tangle.walk_past_cone([block_id]),
+        Cone::Future => args.tangle.walk_future_cone([block_id]),
+    }
+    .with_order(WalkOrder::BreadthFirst)
+    .with_max_depth(depth as usize)
+    .finish();
+
+    let mut blocks = Vec::new();
+    let mut truncated = false;
+
+    // The block itself is not part of its cone and the blocks that are not available, like solid entry points, are
+    // skipped.
+    for item in walker {
+        if let WalkItem::Matched { block_id, depth, .. } = item? {
+            if depth == 0 {
+                continue;
+            }
+            if blocks.len() == CONE_BLOCKS_MAX {
+                truncated = true;
+                break;
+            }
+
+            blocks.push(ConeBlockDto {
+                block_id: block_id.to_string(),
+                depth: depth as u32,
+            });
+        }
+    }
+
+    Ok(BlockConeResponse {
+        block_id: block_id.to_string(),
+        depth,
+        blocks,
+        truncated,
+    })
+}
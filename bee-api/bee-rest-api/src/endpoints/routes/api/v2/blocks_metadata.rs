@@ -2,7 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use axum::{extract::Extension, routing::get, Router};
-use bee_block::{payload::Payload, semantic::ConflictReason, BlockId};
+use bee_block::{
+    payload::Payload,
+    semantic::{ConflictDiagnostic, ConflictReason},
+    BlockId,
+};
+use bee_storage::access::Fetch;
+use log::error;
 
 use crate::{
     endpoints::{
@@ -23,7 +29,7 @@ async fn block_metadata<B: StorageBackend>(
         return Err(ApiError::ServiceUnavailable("the node is not synchronized"));
     }
 
-    match args.tangle.get_block_and_metadata(&block_id) {
+    match args.tangle.get_block_and_metadata(&block_id)? {
         Some((block, metadata)) => {
             // TODO: access constants from URTS
             let ybrsi_delta = 8;
@@ -123,6 +129,15 @@ async fn block_metadata<B: StorageBackend>(
                 )
             };
 
+            let conflict_diagnostic = if conflict_reason.is_some() {
+                Fetch::<BlockId, ConflictDiagnostic>::fetch(&*args.storage, &block_id).map_err(|e| {
+                    error!("cannot fetch from storage: {}", e);
+                    ApiError::InternalServerError
+                })?
+            } else {
+                None
+            };
+
             Ok(BlockMetadataResponse {
                 block_id: block_id.to_string(),
                 parents: block.parents().iter().map(BlockId::to_string).collect(),
@@ -131,6 +146,7 @@ async fn block_metadata<B: StorageBackend>(
                 milestone_index,
                 ledger_inclusion_state,
                 conflict_reason: conflict_reason.map(|c| c as u8),
+                conflict_diagnostic: conflict_diagnostic.as_ref().map(Into::into),
                 should_promote,
                 should_reattach,
             })
@@ -0,0 +1,74 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::BlockId;
+use bee_tangle::walker::{WalkItem, WalkOrder};
+
+use crate::{
+    endpoints::{error::ApiError, extractors::path::CustomPath, storage::StorageBackend, ApiArgsFullNode},
+    types::responses::BlockReferencedByResponse,
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/blocks/:block_id/referenced-by", get(blocks_referenced_by::<B>))
+}
+
+async fn blocks_referenced_by<B: StorageBackend>(
+    CustomPath(block_id): CustomPath<BlockId>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<BlockReferencedByResponse, ApiError> {
+    let index = args
+        .tangle
+        .get_metadata(&block_id)?
+        .and_then(|metadata| metadata.milestone_index())
+        .ok_or(ApiError::NotFound)?;
+    let milestone = args.tangle.get_milestone_metadata(index)?.ok_or(ApiError::NotFound)?;
+    let milestone_block_id = *milestone.block_id();
+
+    // Maps each block of the walk to the block it was first reached from, which gives a shortest path back to the
+    // milestone block.
+    let mut reached_from = HashMap::new();
+
+    // All blocks referenced by the milestone carry its index, so the walk doesn't leave its cone.
+    let walker = args
+        .tangle
+        .walk_past_cone([milestone_block_id])
+        .with_order(WalkOrder::BreadthFirst)
+        .with_predicate(move |_, _, metadata| metadata.milestone_index() == Some(index))
+        .finish();
+
+    for item in walker {
+        if let WalkItem::Matched {
+            block_id: id, block, ..
+        } = item?
+        {
+            if id == block_id {
+                break;
+            }
+            for parent in block.parents().iter() {
+                reached_from.entry(*parent).or_insert(id);
+            }
+        }
+    }
+
+    let mut path = vec![block_id];
+    let mut current = block_id;
+
+    while current != milestone_block_id {
+        current = *reached_from.get(&current).ok_or(ApiError::NotFound)?;
+        path.push(current);
+    }
+
+    path.reverse();
+
+    Ok(BlockReferencedByResponse {
+        block_id: block_id.to_string(),
+        milestone_index: *index,
+        milestone_id: milestone.milestone_id().to_string(),
+        milestone_block_id: milestone_block_id.to_string(),
+        path: path.iter().map(BlockId::to_string).collect(),
+    })
+}
@@ -5,7 +5,7 @@ use axum::{body::Bytes, extract::Extension, http::header::HeaderMap, routing::po
 use bee_block::{
     constant::PROTOCOL_VERSION,
     parent::Parents,
-    payload::{dto::PayloadDto, Payload},
+    payload::{dto::PayloadDto, transaction::TransactionEssence, Payload},
     Block, BlockBuilder, BlockId,
 };
 use bee_pow::providers::{miner::MinerBuilder, NonceProviderBuilder};
@@ -116,6 +116,8 @@ pub(crate) async fn submit_block_json<B: StorageBackend>(
         Some(parsed_nonce)
     };
 
+    verify_token_supply(payload.as_ref(), &args)?;
+
     let block = build_block(parents, payload, nonce, args.clone())?;
     let block_id = forward_to_block_submitter(block.pack_to_vec(), args).await?;
 
@@ -152,7 +154,11 @@ pub(crate) fn build_block<B: StorageBackend>(
         )
         .with_nonce_provider(
             MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
-            args.protocol_config.minimum_pow_score(),
+            args.protocol_parameters
+                .active_at(args.tangle.get_latest_milestone_index() + 1)
+                .map_or(args.protocol_config.minimum_pow_score(), |parameters| {
+                    parameters.min_pow_score() as f64
+                }),
         );
         if let Some(payload) = payload {
             builder = builder.with_payload(payload)
@@ -168,12 +174,38 @@ pub(crate) async fn submit_block_raw<B: StorageBackend>(
     block_bytes: Vec<u8>,
     args: ApiArgsFullNode<B>,
 ) -> Result<SubmitBlockResponse, ApiError> {
+    // Malformed blocks are rejected by the block submitter.
+    if let Ok(block) = Block::unpack_strict(&block_bytes) {
+        verify_token_supply(block.payload(), &args)?;
+    }
+
     let block_id = forward_to_block_submitter(block_bytes, args).await?;
     Ok(SubmitBlockResponse {
         block_id: block_id.to_string(),
     })
 }
 
+// Transaction outputs are only bounded syntactically by the maximum token supply, the supply this network is configured
+// with is checked here so that such transactions are not issued just to end up conflicting.
+fn verify_token_supply<B: StorageBackend>(
+    payload: Option<&Payload>,
+    args: &ApiArgsFullNode<B>,
+) -> Result<(), ApiError> {
+    if let Some(Payload::Transaction(transaction)) = payload {
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let token_supply = args
+            .protocol_parameters
+            .active_at(args.tangle.get_latest_milestone_index() + 1)
+            .map_or(args.base_token.token_supply(), |parameters| parameters.token_supply());
+
+        essence
+            .verify_token_supply(token_supply)
+            .map_err(|_| ApiError::BadRequest("invalid transaction: outputs exceed the token supply"))?;
+    }
+
+    Ok(())
+}
+
 pub(crate) async fn forward_to_block_submitter<B: StorageBackend>(
     block_bytes: Vec<u8>,
     args: ApiArgsFullNode<B>,
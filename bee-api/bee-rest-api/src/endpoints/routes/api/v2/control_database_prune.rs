@@ -0,0 +1,68 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::{extract::Extension, http::StatusCode, routing::post, Router};
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::workers::{
+    consensus::{ConsensusWorkerCommand, JobProgress},
+    pruning::PruningTarget,
+};
+use futures::channel::oneshot;
+use log::error;
+use serde_json::Value;
+
+use super::control_jobs::ControlJob;
+use crate::{
+    endpoints::{error::ApiError, extractors::json::CustomJson, storage::StorageBackend, ApiArgsFullNode},
+    types::responses::ControlJobResponse,
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/control/database/prune", post(control_database_prune::<B>))
+}
+
+async fn control_database_prune<B: StorageBackend>(
+    CustomJson(value): CustomJson<Value>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<(StatusCode, ControlJobResponse), ApiError> {
+    let index_json = &value["index"];
+    let depth_json = &value["depth"];
+
+    let target = match (index_json.is_null(), depth_json.is_null()) {
+        (false, true) => PruningTarget::Index(MilestoneIndex(
+            index_json
+                .as_u64()
+                .and_then(|index| u32::try_from(index).ok())
+                .ok_or(ApiError::BadRequest("invalid index: expected a milestone index"))?,
+        )),
+        (true, false) => PruningTarget::Depth(
+            depth_json
+                .as_u64()
+                .and_then(|depth| u32::try_from(depth).ok())
+                .ok_or(ApiError::BadRequest("invalid depth: expected a number of milestones"))?,
+        ),
+        _ => {
+            return Err(ApiError::BadRequest(
+                "invalid pruning target: expected either an index or a depth",
+            ));
+        }
+    };
+
+    let progress = Arc::new(JobProgress::default());
+    let (sender, receiver) = oneshot::channel();
+
+    if let Err(e) = args
+        .consensus_worker
+        .send(ConsensusWorkerCommand::Prune(target, progress.clone(), sender))
+    {
+        error!("request to consensus worker failed: {}", e);
+        return Err(ApiError::InternalServerError);
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        args.control_jobs.insert(ControlJob::Prune(progress, receiver)),
+    ))
+}
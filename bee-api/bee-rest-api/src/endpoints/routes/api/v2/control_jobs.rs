@@ -0,0 +1,173 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use axum::{extract::Extension, routing::get, Router};
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::{
+    types::snapshot::SnapshotKind,
+    workers::{consensus::JobProgress, error::Error},
+};
+use futures::channel::oneshot;
+
+use crate::{
+    endpoints::{error::ApiError, extractors::path::CustomPath, storage::StorageBackend, ApiArgsFullNode},
+    types::responses::{ControlJobProgress, ControlJobResponse},
+};
+
+// Number of jobs that are remembered; the oldest ones are forgotten first.
+const CONTROL_JOBS_MAX: usize = 100;
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/control/jobs/:job_id", get(control_jobs::<B>))
+}
+
+async fn control_jobs<B: StorageBackend>(
+    CustomPath(job_id): CustomPath<u64>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<ControlJobResponse, ApiError> {
+    args.control_jobs.get(job_id).ok_or(ApiError::NotFound)
+}
+
+/// A control job that is run by the consensus worker.
+pub(crate) enum ControlJob {
+    Prune(Arc<JobProgress>, oneshot::Receiver<Result<MilestoneIndex, Error>>),
+    CreateSnapshot(
+        SnapshotKind,
+        Arc<JobProgress>,
+        oneshot::Receiver<Result<(MilestoneIndex, MilestoneIndex), Error>>,
+    ),
+}
+
+impl ControlJob {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Prune(..) => "prune",
+            Self::CreateSnapshot(..) => "snapshot",
+        }
+    }
+
+    // Returns the progress of the job once it knows how many steps it takes.
+    fn progress(&self) -> Option<ControlJobProgress> {
+        let (done, total) = match self {
+            Self::Prune(progress, _) | Self::CreateSnapshot(_, progress, _) => progress.get(),
+        };
+
+        (total > 0).then(|| ControlJobProgress { done, total })
+    }
+
+    // Returns the outcome of the job if it has finished.
+    fn poll(&mut self) -> Option<Result<String, String>> {
+        let outcome = match self {
+            Self::Prune(_, receiver) => receiver.try_recv().map(|result| {
+                result.map(|result| {
+                    result
+                        .map(|index| format!("pruned the database up to milestone {}", index))
+                        .map_err(|e| e.to_string())
+                })
+            }),
+            Self::CreateSnapshot(kind, _, receiver) => receiver.try_recv().map(|result| {
+                let kind = match kind {
+                    SnapshotKind::Full => "full",
+                    SnapshotKind::Delta => "delta",
+                };
+
+                result.map(|result| {
+                    result
+                        .map(|(sep_index, ledger_index)| {
+                            format!(
+                                "created a {} snapshot with entry point index {} and ledger index {}",
+                                kind, sep_index, ledger_index
+                            )
+                        })
+                        .map_err(|e| e.to_string())
+                })
+            }),
+        };
+
+        match outcome {
+            Ok(outcome) => outcome,
+            Err(_) => Some(Err("the consensus worker stopped before finishing the job".to_string())),
+        }
+    }
+}
+
+enum ControlJobState {
+    Running(ControlJob),
+    Finished(&'static str, Result<String, String>),
+}
+
+/// Keeps track of the control jobs started through the API.
+#[derive(Default)]
+pub(crate) struct ControlJobs {
+    next_id: AtomicU64,
+    jobs: Mutex<BTreeMap<u64, ControlJobState>>,
+}
+
+impl ControlJobs {
+    /// Registers a running job and returns its state.
+    pub(crate) fn insert(&self, job: ControlJob) -> ControlJobResponse {
+        let job_id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let response = ControlJobResponse {
+            job_id,
+            kind: job.kind().to_string(),
+            state: "running".to_string(),
+            progress: None,
+            message: None,
+        };
+        let mut jobs = self.jobs.lock().unwrap();
+
+        jobs.insert(job_id, ControlJobState::Running(job));
+
+        while jobs.len() > CONTROL_JOBS_MAX {
+            let oldest = *jobs.keys().next().unwrap();
+            jobs.remove(&oldest);
+        }
+
+        response
+    }
+
+    /// Returns the current state of a job.
+    pub(crate) fn get(&self, job_id: u64) -> Option<ControlJobResponse> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let state = jobs.get_mut(&job_id)?;
+
+        if let ControlJobState::Running(job) = state {
+            if let Some(outcome) = job.poll() {
+                let kind = job.kind();
+                *state = ControlJobState::Finished(kind, outcome);
+            }
+        }
+
+        Some(match state {
+            ControlJobState::Running(job) => ControlJobResponse {
+                job_id,
+                kind: job.kind().to_string(),
+                state: "running".to_string(),
+                progress: job.progress(),
+                message: None,
+            },
+            ControlJobState::Finished(kind, Ok(message)) => ControlJobResponse {
+                job_id,
+                kind: kind.to_string(),
+                state: "succeeded".to_string(),
+                progress: None,
+                message: Some(message.clone()),
+            },
+            ControlJobState::Finished(kind, Err(message)) => ControlJobResponse {
+                job_id,
+                kind: kind.to_string(),
+                state: "failed".to_string(),
+                progress: None,
+                message: Some(message.clone()),
+            },
+        })
+    }
+}
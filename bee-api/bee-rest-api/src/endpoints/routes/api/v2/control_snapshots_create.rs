@@ -0,0 +1,79 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use axum::{extract::Extension, http::StatusCode, routing::post, Router};
+use bee_ledger::{
+    types::snapshot::SnapshotKind,
+    workers::{
+        consensus::{ConsensusWorkerCommand, JobProgress},
+        snapshot::export::resolve_snapshot_path,
+    },
+};
+use futures::channel::oneshot;
+use log::error;
+use serde_json::Value;
+
+use super::control_jobs::ControlJob;
+use crate::{
+    endpoints::{error::ApiError, extractors::json::CustomJson, storage::StorageBackend, ApiArgsFullNode},
+    types::responses::ControlJobResponse,
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/control/snapshots/create", post(control_snapshots_create::<B>))
+}
+
+async fn control_snapshots_create<B: StorageBackend>(
+    CustomJson(value): CustomJson<Value>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<(StatusCode, ControlJobResponse), ApiError> {
+    let kind_json = &value["kind"];
+    let path_json = &value["path"];
+
+    let kind = if kind_json.is_null() {
+        SnapshotKind::Full
+    } else {
+        match kind_json.as_str() {
+            Some("full") => SnapshotKind::Full,
+            Some("delta") => SnapshotKind::Delta,
+            _ => return Err(ApiError::BadRequest("invalid kind: expected \"full\" or \"delta\"")),
+        }
+    };
+
+    let path = PathBuf::from(
+        path_json
+            .as_str()
+            .ok_or(ApiError::BadRequest("invalid path: expected a file path"))?,
+    );
+
+    // The consensus worker resolves the path against the snapshot directory; reject what it would reject anyway.
+    if resolve_snapshot_path(Path::new(""), &path).is_none() {
+        return Err(ApiError::BadRequest(
+            "invalid path: expected a file path relative to the snapshot directory",
+        ));
+    }
+
+    let progress = Arc::new(JobProgress::default());
+    let (sender, receiver) = oneshot::channel();
+
+    if let Err(e) = args.consensus_worker.send(ConsensusWorkerCommand::CreateSnapshot(
+        kind,
+        path,
+        progress.clone(),
+        sender,
+    )) {
+        error!("request to consensus worker failed: {}", e);
+        return Err(ApiError::InternalServerError);
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        args.control_jobs
+            .insert(ControlJob::CreateSnapshot(kind, progress, receiver)),
+    ))
+}
@@ -2,13 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use axum::{extract::Extension, routing::get, Router};
-use bee_block::constant::{PROTOCOL_VERSION, TOKEN_SUPPLY};
+use bee_block::{constant::PROTOCOL_VERSION, protocol::ProtocolParameters};
 
 use crate::{
-    endpoints::{routes::health, storage::StorageBackend, ApiArgsFullNode},
+    endpoints::{error::ApiError, routes::health, storage::StorageBackend, ApiArgsFullNode},
     types::responses::{
         BaseTokenResponse, ConfirmedMilestoneResponse, InfoResponse, LatestMilestoneResponse, MetricsResponse,
-        ProtocolResponse, RentStructureResponse, StatusResponse,
+        PendingProtocolParametersResponse, ProtocolResponse, RentStructureResponse, StatusResponse,
     },
 };
 
@@ -16,12 +16,12 @@ pub(crate) fn filter<B: StorageBackend>() -> Router {
     Router::new().route("/info", get(info::<B>))
 }
 
-async fn info<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> InfoResponse {
+async fn info<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> Result<InfoResponse, ApiError> {
     let (latest_milestone_index, latest_milestone_metadata) = {
         let latest_milestone_index = args.tangle.get_latest_milestone_index();
         (
             latest_milestone_index,
-            args.tangle.get_milestone_metadata(latest_milestone_index),
+            args.tangle.get_milestone_metadata(latest_milestone_index)?,
         )
     };
 
@@ -29,15 +29,15 @@ async fn info<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>)
         let confirmed_milestone_index = args.tangle.get_confirmed_milestone_index();
         (
             confirmed_milestone_index,
-            args.tangle.get_milestone_metadata(confirmed_milestone_index),
+            args.tangle.get_milestone_metadata(confirmed_milestone_index)?,
         )
     };
 
-    InfoResponse {
+    Ok(InfoResponse {
         name: args.node_info.name.clone(),
         version: args.node_info.version.clone(),
         status: StatusResponse {
-            is_healthy: health::is_healthy(&args.tangle, &args.peer_manager),
+            is_healthy: health::readiness_issues(&args).is_empty(),
             // TODO: In future, the snapshot might make all data for the `latest_milestone` available.
             latest_milestone: LatestMilestoneResponse {
                 index: *latest_milestone_index,
@@ -62,31 +62,34 @@ async fn info<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>)
             },
             pruning_index: *args.tangle.get_pruning_index(),
         },
-        protocol: ProtocolResponse {
-            version: PROTOCOL_VERSION,
-            network_name: args.network_name.clone(),
-            bech32_hrp: args.bech32_hrp.clone(),
-            min_pow_score: args.protocol_config.minimum_pow_score(),
-            rent_structure: RentStructureResponse {
-                v_byte_cost: args.protocol_config.byte_cost().v_byte_cost,
-                v_byte_factor_key: args.protocol_config.byte_cost().v_byte_factor_key,
-                v_byte_factor_data: args.protocol_config.byte_cost().v_byte_factor_data,
-            },
-            token_supply: TOKEN_SUPPLY.to_string(),
-        },
+        // New blocks are going to be referenced by the next milestone at the earliest.
+        protocol: args
+            .protocol_parameters
+            .active_at(latest_milestone_index + 1)
+            .map_or_else(
+                || default_protocol_response(&args),
+                |parameters| protocol_response(&parameters),
+            ),
         base_token: BaseTokenResponse {
-            name: "Shimmer".to_string(), // TODO: don't hardcode
-            ticker_symbol: "SMR".to_string(),
-            unit: "SMR".to_string(),
-            subunit: Some("glow".to_string()),
-            decimals: 6,
-            use_metric_prefix: false,
-        },
-        metrics: MetricsResponse {
-            blocks_per_second: 0f64,            // TODO: use actual metrics values
-            referenced_blocks_per_second: 0f64, // TODO: use actual metrics values
-            referenced_rate: 0f64,              // TODO: use actual metrics values
+            name: args.base_token.name().to_string(),
+            ticker_symbol: args.base_token.ticker_symbol().to_string(),
+            unit: args.base_token.unit().to_string(),
+            subunit: args.base_token.subunit().map(ToString::to_string),
+            decimals: args.base_token.decimals(),
+            use_metric_prefix: args.base_token.use_metric_prefix(),
         },
+        metrics: args.throughput_metrics.latest().map_or(
+            MetricsResponse {
+                blocks_per_second: 0f64,
+                referenced_blocks_per_second: 0f64,
+                referenced_rate: 0f64,
+            },
+            |throughput| MetricsResponse {
+                blocks_per_second: throughput.blocks_per_second,
+                referenced_blocks_per_second: throughput.referenced_blocks_per_second,
+                referenced_rate: throughput.referenced_rate,
+            },
+        ),
         features: {
             let mut features = Vec::new();
             if args.rest_api_config.feature_proof_of_work() {
@@ -95,5 +98,60 @@ async fn info<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>)
             features
         },
         plugins: Vec::new(), // TODO: add actual plugins that the node supports
+        // The gossip service is announced under the network name.
+        external_addresses: args
+            .autopeering_local
+            .as_ref()
+            .map(|local| {
+                local
+                    .service_multiaddrs(&args.network_name)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        pending_protocol_parameters: args
+            .protocol_parameters
+            .pending_after(latest_milestone_index + 1)
+            .into_iter()
+            .map(
+                |(target_milestone_index, parameters)| PendingProtocolParametersResponse {
+                    target_milestone_index: *target_milestone_index,
+                    params: protocol_response(&parameters),
+                },
+            )
+            .collect(),
+    })
+}
+
+fn protocol_response(parameters: &ProtocolParameters) -> ProtocolResponse {
+    let byte_cost = parameters.byte_cost();
+
+    ProtocolResponse {
+        version: parameters.protocol_version(),
+        network_name: parameters.network_name().to_string(),
+        bech32_hrp: parameters.bech32_hrp().to_string(),
+        min_pow_score: parameters.min_pow_score() as f64,
+        rent_structure: RentStructureResponse {
+            v_byte_cost: byte_cost.v_byte_cost,
+            v_byte_factor_key: byte_cost.v_byte_factor_key,
+            v_byte_factor_data: byte_cost.v_byte_factor_data,
+        },
+        token_supply: parameters.token_supply().to_string(),
+    }
+}
+
+fn default_protocol_response<B: StorageBackend>(args: &ApiArgsFullNode<B>) -> ProtocolResponse {
+    ProtocolResponse {
+        version: PROTOCOL_VERSION,
+        network_name: args.network_name.clone(),
+        bech32_hrp: args.bech32_hrp.clone(),
+        min_pow_score: args.protocol_config.minimum_pow_score(),
+        rent_structure: RentStructureResponse {
+            v_byte_cost: args.protocol_config.byte_cost().v_byte_cost,
+            v_byte_factor_key: args.protocol_config.byte_cost().v_byte_factor_key,
+            v_byte_factor_data: args.protocol_config.byte_cost().v_byte_factor_data,
+        },
+        token_supply: args.base_token.token_supply().to_string(),
     }
 }
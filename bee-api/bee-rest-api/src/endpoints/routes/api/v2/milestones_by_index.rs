@@ -35,11 +35,11 @@ async fn get_milestone_payload<B: StorageBackend>(
     args: ApiArgsFullNode<B>,
     milestone_index: MilestoneIndex,
 ) -> Result<MilestonePayload, ApiError> {
-    let milestone_id = match args.tangle.get_milestone_metadata(milestone_index) {
+    let milestone_id = match args.tangle.get_milestone_metadata(milestone_index)? {
         Some(milestone_metadata) => *milestone_metadata.milestone_id(),
         None => return Err(ApiError::NotFound),
     };
-    match args.tangle.get_milestone(milestone_id) {
+    match args.tangle.get_milestone(milestone_id)? {
         Some(milestone_payload) => Ok(milestone_payload),
         None => Err(ApiError::NotFound),
     }
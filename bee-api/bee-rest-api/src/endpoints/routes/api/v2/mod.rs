@@ -1,25 +1,41 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod addresses_history;
 pub mod blocks;
+pub mod blocks_by_tag;
+pub mod blocks_children;
+pub mod blocks_cones;
 pub mod blocks_metadata;
+pub mod blocks_referenced_by;
 pub mod blocks_submit;
+pub mod control_database_prune;
+pub mod control_jobs;
+pub mod control_snapshots_create;
 pub mod info;
 pub mod milestones_by_id;
 pub mod milestones_by_index;
+pub mod openapi;
 pub mod outputs;
 pub mod outputs_metadata;
 pub mod peers;
 pub mod peers_add;
 pub mod peers_all;
+pub mod peers_bans;
+pub mod peers_bans_add;
+pub mod peers_bans_remove;
 pub mod peers_remove;
 pub mod receipts;
 pub mod receipts_at;
 pub mod tips;
+pub mod tokens_add;
+pub mod tokens_all;
+pub mod tokens_revoke;
 pub mod transactions_included_block;
 pub mod treasury;
 pub mod utxo_changes_by_id;
 pub mod utxo_changes_by_index;
+pub mod utxo_changes_range;
 
 use axum::Router;
 
@@ -29,23 +45,39 @@ pub(crate) fn filter<B: StorageBackend>() -> Router {
     Router::new().nest(
         "/v2",
         info::filter::<B>()
+            .merge(addresses_history::filter::<B>())
             .merge(blocks::filter::<B>())
+            .merge(blocks_by_tag::filter::<B>())
+            .merge(blocks_children::filter::<B>())
+            .merge(blocks_cones::filter::<B>())
             .merge(blocks_metadata::filter::<B>())
+            .merge(blocks_referenced_by::filter::<B>())
             .merge(blocks_submit::filter::<B>())
+            .merge(control_database_prune::filter::<B>())
+            .merge(control_jobs::filter::<B>())
+            .merge(control_snapshots_create::filter::<B>())
             .merge(milestones_by_id::filter::<B>())
             .merge(milestones_by_index::filter::<B>())
+            .merge(openapi::filter::<B>())
             .merge(outputs::filter::<B>())
             .merge(outputs_metadata::filter::<B>())
             .merge(peers::filter::<B>())
             .merge(peers_add::filter::<B>())
             .merge(peers_all::filter::<B>())
+            .merge(peers_bans::filter::<B>())
+            .merge(peers_bans_add::filter::<B>())
+            .merge(peers_bans_remove::filter::<B>())
             .merge(peers_remove::filter::<B>())
             .merge(receipts::filter::<B>())
             .merge(receipts_at::filter::<B>())
             .merge(tips::filter::<B>())
+            .merge(tokens_add::filter::<B>())
+            .merge(tokens_all::filter::<B>())
+            .merge(tokens_revoke::filter::<B>())
             .merge(transactions_included_block::filter::<B>())
             .merge(treasury::filter::<B>())
             .merge(utxo_changes_by_id::filter::<B>())
-            .merge(utxo_changes_by_index::filter::<B>()),
+            .merge(utxo_changes_by_index::filter::<B>())
+            .merge(utxo_changes_range::filter::<B>()),
     )
 }
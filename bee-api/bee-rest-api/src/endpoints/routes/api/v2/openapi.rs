@@ -0,0 +1,1613 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Json, Router};
+use serde_json::{json, Map, Value};
+
+use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, ApiArgsFullNode};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/openapi.json", get(openapi::<B>))
+}
+
+async fn openapi<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> Json<Value> {
+    Json(document(&args.rest_api_config, &args.node_info.version))
+}
+
+/// A parameter of an operation, taken from the path if it appears in it and from the query otherwise.
+struct Parameter {
+    name: &'static str,
+    description: &'static str,
+    schema: &'static str,
+}
+
+const BLOCK_ID: Parameter = Parameter {
+    name: "blockId",
+    description: "Identifier of the block.",
+    schema: "string",
+};
+const OUTPUT_ID: Parameter = Parameter {
+    name: "outputId",
+    description: "Identifier of the output.",
+    schema: "string",
+};
+const TRANSACTION_ID: Parameter = Parameter {
+    name: "transactionId",
+    description: "Identifier of the transaction.",
+    schema: "string",
+};
+const MILESTONE_ID: Parameter = Parameter {
+    name: "milestoneId",
+    description: "Identifier of the milestone.",
+    schema: "string",
+};
+const MILESTONE_INDEX: Parameter = Parameter {
+    name: "milestoneIndex",
+    description: "Index of the milestone.",
+    schema: "integer",
+};
+const BECH32_ADDRESS: Parameter = Parameter {
+    name: "bech32Address",
+    description: "Bech32-encoded address, with the human-readable part of the network.",
+    schema: "string",
+};
+const BALANCE_MILESTONE_INDEX: Parameter = Parameter {
+    name: "milestoneIndex",
+    description: "Index of the milestone, the ledger index by default.",
+    schema: "integer",
+};
+const START_INDEX: Parameter = Parameter {
+    name: "startIndex",
+    description: "Index of the first milestone of the range, above the pruning index.",
+    schema: "integer",
+};
+const END_INDEX: Parameter = Parameter {
+    name: "endIndex",
+    description: "Index of the last milestone of the range, at most the ledger index and 999 milestones after the first.",
+    schema: "integer",
+};
+const PEER_ID: Parameter = Parameter {
+    name: "peerId",
+    description: "Identifier of the peer.",
+    schema: "string",
+};
+const MULTI_ADDRESS: Parameter = Parameter {
+    name: "multiAddress",
+    description: "Banned multiaddress.",
+    schema: "string",
+};
+const PAGE_SIZE: Parameter = Parameter {
+    name: "pageSize",
+    description: "Maximum number of results of the page, between 1 and 1000, 100 by default.",
+    schema: "integer",
+};
+const CURSOR: Parameter = Parameter {
+    name: "cursor",
+    description: "Cursor of the page, as returned with the previous one.",
+    schema: "string",
+};
+const TAG: Parameter = Parameter {
+    name: "tag",
+    description: "Hex-encoded tag of the tagged data payloads, at most 64 bytes long.",
+    schema: "string",
+};
+const DEPTH: Parameter = Parameter {
+    name: "depth",
+    description: "Maximum number of edges between the block and the blocks of the cone, between 1 and 50, 5 by default.",
+    schema: "integer",
+};
+const JOB_ID: Parameter = Parameter {
+    name: "jobId",
+    description: "Identifier of the control job.",
+    schema: "integer",
+};
+const TOKEN_ID: Parameter = Parameter {
+    name: "tokenId",
+    description: "Identifier of the API token.",
+    schema: "string",
+};
+
+/// The content of a request or response body.
+enum Content {
+    None,
+    Json(&'static str),
+    /// Newline-delimited JSON values of a schema.
+    JsonLines(&'static str),
+    Text,
+}
+
+/// An operation of the API, mirroring a route registered in `routes`.
+struct Operation {
+    method: &'static str,
+    path: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    parameters: &'static [Parameter],
+    request: Content,
+    status: u16,
+    response: Content,
+}
+
+const OPERATIONS: &[Operation] = &[
+    Operation {
+        method: "get",
+        path: "/health",
+        tag: "health",
+        summary: "Returns whether the node is ready to serve requests.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("HealthResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/health/live",
+        tag: "health",
+        summary: "Returns whether the node is alive.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("HealthResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/health/ready",
+        tag: "health",
+        summary: "Returns whether the node is ready to serve requests.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("HealthResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/metrics",
+        tag: "metrics",
+        summary: "Returns the metrics of the node in the Prometheus text format.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Text,
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/info",
+        tag: "node",
+        summary: "Returns general information about the node.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("InfoResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/tips",
+        tag: "tangle",
+        summary: "Returns tips that are ideal for attaching a block.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("TipsResponse"),
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/blocks",
+        tag: "tangle",
+        summary: "Submits a block to the node.",
+        parameters: &[],
+        request: Content::Json("Block"),
+        status: 201,
+        response: Content::Json("SubmitBlockResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks",
+        tag: "tangle",
+        summary: "Returns a page of the blocks carrying a tagged data payload with a tag, if the tag index is enabled.",
+        parameters: &[TAG, PAGE_SIZE, CURSOR],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlocksByTagResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}",
+        tag: "tangle",
+        summary: "Returns a block.",
+        parameters: &[BLOCK_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("Block"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}/metadata",
+        tag: "tangle",
+        summary: "Returns the metadata of a block.",
+        parameters: &[BLOCK_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlockMetadataResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}/children",
+        tag: "tangle",
+        summary: "Returns a page of the children of a block.",
+        parameters: &[BLOCK_ID, PAGE_SIZE, CURSOR],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlockChildrenResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}/past-cone",
+        tag: "tangle",
+        summary: "Returns the blocks of the past cone of a block, up to a depth.",
+        parameters: &[BLOCK_ID, DEPTH],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlockConeResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}/future-cone",
+        tag: "tangle",
+        summary: "Returns the blocks of the future cone of a block, up to a depth.",
+        parameters: &[BLOCK_ID, DEPTH],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlockConeResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/blocks/{blockId}/referenced-by",
+        tag: "tangle",
+        summary: "Returns the milestone that referenced a block and a path from the milestone block to it.",
+        parameters: &[BLOCK_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BlockReferencedByResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/transactions/{transactionId}/included-block",
+        tag: "tangle",
+        summary: "Returns the block that included a transaction in the ledger.",
+        parameters: &[TRANSACTION_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("Block"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/milestones/{milestoneId}",
+        tag: "milestones",
+        summary: "Returns a milestone by its identifier.",
+        parameters: &[MILESTONE_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("MilestonePayload"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/milestones/{milestoneId}/utxo-changes",
+        tag: "milestones",
+        summary: "Returns the outputs created and consumed by a milestone, by its identifier.",
+        parameters: &[MILESTONE_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("UtxoChangesResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/milestones/by-index/{milestoneIndex}",
+        tag: "milestones",
+        summary: "Returns a milestone by its index.",
+        parameters: &[MILESTONE_INDEX],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("MilestonePayload"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/milestones/by-index/{milestoneIndex}/utxo-changes",
+        tag: "milestones",
+        summary: "Returns the outputs created and consumed by a milestone, by its index.",
+        parameters: &[MILESTONE_INDEX],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("UtxoChangesResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/milestones/utxo-changes",
+        tag: "milestones",
+        summary: "Streams the ledger changes of a range of milestones, with the outputs they created and consumed.",
+        parameters: &[START_INDEX, END_INDEX],
+        request: Content::None,
+        status: 200,
+        response: Content::JsonLines("MilestoneUtxoDiff"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/outputs/{outputId}",
+        tag: "utxo",
+        summary: "Returns an output and its metadata.",
+        parameters: &[OUTPUT_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("OutputResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/outputs/{outputId}/metadata",
+        tag: "utxo",
+        summary: "Returns the metadata of an output.",
+        parameters: &[OUTPUT_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("OutputMetadataResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/receipts",
+        tag: "utxo",
+        summary: "Returns all stored receipts.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("ReceiptsResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/receipts/{milestoneIndex}",
+        tag: "utxo",
+        summary: "Returns the receipts of a milestone.",
+        parameters: &[MILESTONE_INDEX],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("ReceiptsResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/treasury",
+        tag: "utxo",
+        summary: "Returns the current treasury output.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("TreasuryResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/addresses/{bech32Address}/history",
+        tag: "utxo",
+        summary: "Returns a page of the history of an address, if the address history is enabled.",
+        parameters: &[BECH32_ADDRESS, PAGE_SIZE, CURSOR],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("AddressHistoryResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/addresses/{bech32Address}/balance",
+        tag: "utxo",
+        summary: "Returns the balance of an address at a milestone, if the address history is enabled.",
+        parameters: &[BECH32_ADDRESS, BALANCE_MILESTONE_INDEX],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("AddressBalanceResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/peers",
+        tag: "peers",
+        summary: "Returns all peers of the node.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("PeersResponse"),
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/peers",
+        tag: "peers",
+        summary: "Adds a peer to the node.",
+        parameters: &[],
+        request: Content::Json("AddPeerRequest"),
+        status: 200,
+        response: Content::Json("Peer"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/peers/{peerId}",
+        tag: "peers",
+        summary: "Returns a peer of the node.",
+        parameters: &[PEER_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("Peer"),
+    },
+    Operation {
+        method: "delete",
+        path: "/api/v2/peers/{peerId}",
+        tag: "peers",
+        summary: "Removes a peer from the node.",
+        parameters: &[PEER_ID],
+        request: Content::None,
+        status: 204,
+        response: Content::None,
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/peers/bans",
+        tag: "peers",
+        summary: "Returns all banned peers and addresses.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("BansResponse"),
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/peers/bans",
+        tag: "peers",
+        summary: "Bans a peer or an address.",
+        parameters: &[],
+        request: Content::Json("BanRequest"),
+        status: 204,
+        response: Content::None,
+    },
+    Operation {
+        method: "delete",
+        path: "/api/v2/peers/bans/peer/{peerId}",
+        tag: "peers",
+        summary: "Lifts the ban of a peer.",
+        parameters: &[PEER_ID],
+        request: Content::None,
+        status: 204,
+        response: Content::None,
+    },
+    Operation {
+        method: "delete",
+        path: "/api/v2/peers/bans/address",
+        tag: "peers",
+        summary: "Lifts the ban of an address.",
+        parameters: &[MULTI_ADDRESS],
+        request: Content::None,
+        status: 204,
+        response: Content::None,
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/control/database/prune",
+        tag: "control",
+        summary: "Starts pruning the database up to a milestone index or depth.",
+        parameters: &[],
+        request: Content::Json("PruneRequest"),
+        status: 202,
+        response: Content::Json("ControlJobResponse"),
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/control/snapshots/create",
+        tag: "control",
+        summary: "Starts creating a snapshot file.",
+        parameters: &[],
+        request: Content::Json("CreateSnapshotRequest"),
+        status: 202,
+        response: Content::Json("ControlJobResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/control/jobs/{jobId}",
+        tag: "control",
+        summary: "Returns the state of a control job.",
+        parameters: &[JOB_ID],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("ControlJobResponse"),
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/tokens",
+        tag: "control",
+        summary: "Returns all API tokens issued by the node.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::Json("TokensResponse"),
+    },
+    Operation {
+        method: "post",
+        path: "/api/v2/tokens",
+        tag: "control",
+        summary: "Issues an API token with the given scopes and expiry.",
+        parameters: &[],
+        request: Content::Json("AddTokenRequest"),
+        status: 200,
+        response: Content::Json("AddTokenResponse"),
+    },
+    Operation {
+        method: "delete",
+        path: "/api/v2/tokens/{tokenId}",
+        tag: "control",
+        summary: "Revokes an API token.",
+        parameters: &[TOKEN_ID],
+        request: Content::None,
+        status: 204,
+        response: Content::None,
+    },
+    Operation {
+        method: "get",
+        path: "/api/v2/openapi.json",
+        tag: "node",
+        summary: "Returns this OpenAPI document.",
+        parameters: &[],
+        request: Content::None,
+        status: 200,
+        response: Content::None,
+    },
+    Operation {
+        method: "post",
+        path: "/api/plugins/debug/whiteflag",
+        tag: "debug",
+        summary: "Computes the white flag merkle tree hash of a milestone candidate.",
+        parameters: &[],
+        request: Content::Json("WhiteFlagRequest"),
+        status: 200,
+        response: Content::Json("WhiteFlagResponse"),
+    },
+];
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn content(content: &Content) -> Option<Value> {
+    match content {
+        Content::None => None,
+        Content::Json(schema) => Some(json!({ "application/json": { "schema": schema_ref(schema) } })),
+        Content::JsonLines(schema) => Some(json!({ "application/x-ndjson": { "schema": schema_ref(schema) } })),
+        Content::Text => Some(json!({ "text/plain": { "schema": { "type": "string" } } })),
+    }
+}
+
+// Path parameters are replaced by a placeholder so that the configured route patterns can be matched against them. The
+// placeholder looks like a hex-encoded identifier, which is what the public patterns of parameterized routes expect.
+// Query parameters are appended with the same placeholder, an operation is only public if it is with all of them.
+fn is_public(config: &RestApiConfig, operation: &Operation) -> bool {
+    let path = operation.path;
+    let mut concrete = String::with_capacity(path.len());
+    let mut in_parameter = false;
+
+    for c in path.chars() {
+        match c {
+            '{' => in_parameter = true,
+            '}' => {
+                in_parameter = false;
+                concrete.push_str("0x0");
+            }
+            c if !in_parameter => concrete.push(c),
+            _ => {}
+        }
+    }
+
+    let query = operation
+        .parameters
+        .iter()
+        .filter(|parameter| !path.contains(&format!("{{{}}}", parameter.name)))
+        .map(|parameter| format!("{}=0x0", parameter.name))
+        .collect::<Vec<_>>();
+
+    if !query.is_empty() {
+        concrete.push('?');
+        concrete.push_str(&query.join("&"));
+    }
+
+    config.public_routes().is_match(&concrete)
+}
+
+fn operation(config: &RestApiConfig, operation: &Operation) -> Value {
+    let mut value = Map::new();
+
+    value.insert("tags".to_string(), json!([operation.tag]));
+    value.insert("summary".to_string(), json!(operation.summary));
+
+    if !operation.parameters.is_empty() {
+        value.insert(
+            "parameters".to_string(),
+            operation
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let in_path = operation.path.contains(&format!("{{{}}}", parameter.name));
+                    let location = if in_path { "path" } else { "query" };
+
+                    json!({
+                        "name": parameter.name,
+                        "in": location,
+                        "required": in_path,
+                        "description": parameter.description,
+                        "schema": { "type": parameter.schema },
+                    })
+                })
+                .collect(),
+        );
+    }
+
+    if let Some(content) = content(&operation.request) {
+        value.insert(
+            "requestBody".to_string(),
+            json!({ "required": true, "content": content }),
+        );
+    }
+
+    let mut success = Map::new();
+    success.insert("description".to_string(), json!("Successful operation."));
+    if let Some(content) = content(&operation.response) {
+        success.insert("content".to_string(), content);
+    }
+
+    let error = json!({
+        "description": "Unsuccessful operation.",
+        "content": { "application/json": { "schema": schema_ref("ErrorResponse") } },
+    });
+
+    value.insert(
+        "responses".to_string(),
+        json!({
+            operation.status.to_string(): success,
+            "4XX": error,
+            "5XX": error,
+        }),
+    );
+
+    if !is_public(config, operation) {
+        value.insert("security".to_string(), json!([{ "jwt": [] }]));
+    }
+
+    Value::Object(value)
+}
+
+/// Builds the OpenAPI document of the routes, taking into account which ones are public.
+pub(crate) fn document(config: &RestApiConfig, version: &str) -> Value {
+    let mut paths = Map::new();
+
+    for op in OPERATIONS {
+        if let Value::Object(path) = paths.entry(op.path).or_insert_with(|| Value::Object(Map::new())) {
+            path.insert(op.method.to_string(), operation(config, op));
+        }
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Bee REST API",
+            "description": "The REST API of the Bee node software.",
+            "version": version,
+            "license": { "name": "Apache 2.0", "url": "http://www.apache.org/licenses/LICENSE-2.0.html" },
+        },
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                "jwt": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+            },
+            "schemas": schemas(),
+        },
+    })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+// OpenAPI 3.0 doesn't allow an empty list of required properties.
+fn object(required: &[&str], properties: Value) -> Value {
+    if required.is_empty() {
+        json!({ "type": "object", "properties": properties })
+    } else {
+        json!({ "type": "object", "required": required, "properties": properties })
+    }
+}
+
+// The schemas mirror the response types in `types::responses` and the DTOs in `types::dtos` and `bee_block`.
+fn schemas() -> Value {
+    let milestone = object(
+        &["index", "timestamp", "milestoneId"],
+        json!({ "index": integer(), "timestamp": integer(), "milestoneId": string() }),
+    );
+    let protocol = object(
+        &[
+            "version",
+            "networkName",
+            "bech32HRP",
+            "minPoWScore",
+            "rentStructure",
+            "tokenSupply",
+        ],
+        json!({
+            "version": integer(),
+            "networkName": string(),
+            "bech32HRP": string(),
+            "minPoWScore": number(),
+            "rentStructure": object(
+                &["vByteCost", "vByteFactorKey", "vByteFactorData"],
+                json!({ "vByteCost": integer(), "vByteFactorKey": integer(), "vByteFactorData": integer() }),
+            ),
+            "tokenSupply": string(),
+        }),
+    );
+
+    json!({
+        "ErrorResponse": object(
+            &["error"],
+            json!({ "error": object(&["code", "message"], json!({ "code": string(), "message": string() })) }),
+        ),
+        "HealthResponse": object(
+            &["isHealthy"],
+            json!({
+                "isHealthy": boolean(),
+                "issues": array(object(&["reason", "message"], json!({ "reason": string(), "message": string() }))),
+            }),
+        ),
+        "InfoResponse": object(
+            &["name", "version", "status", "protocol", "baseToken", "metrics", "features", "plugins"],
+            json!({
+                "name": string(),
+                "version": string(),
+                "status": object(
+                    &["isHealthy", "latestMilestone", "confirmedMilestone", "pruningIndex"],
+                    json!({
+                        "isHealthy": boolean(),
+                        "latestMilestone": milestone,
+                        "confirmedMilestone": milestone,
+                        "pruningIndex": integer(),
+                    }),
+                ),
+                "protocol": protocol,
+                "pendingProtocolParameters": array(object(
+                    &["targetMilestoneIndex", "params"],
+                    json!({ "targetMilestoneIndex": integer(), "params": protocol }),
+                )),
+                "baseToken": object(
+                    &["name", "tickerSymbol", "unit", "decimals", "useMetricPrefix"],
+                    json!({
+                        "name": string(),
+                        "tickerSymbol": string(),
+                        "unit": string(),
+                        "subunit": string(),
+                        "decimals": integer(),
+                        "useMetricPrefix": boolean(),
+                    }),
+                ),
+                "metrics": object(
+                    &["blocksPerSecond", "referencedBlocksPerSecond", "referencedRate"],
+                    json!({
+                        "blocksPerSecond": number(),
+                        "referencedBlocksPerSecond": number(),
+                        "referencedRate": number(),
+                    }),
+                ),
+                "features": array(string()),
+                "plugins": array(string()),
+                "externalAddresses": array(string()),
+            }),
+        ),
+        "TipsResponse": object(&["tips"], json!({ "tips": array(string()) })),
+        "Block": {
+            "type": "object",
+            "description": "A block as defined by TIP-24.",
+            "required": ["protocolVersion", "parents", "nonce"],
+            "properties": {
+                "protocolVersion": integer(),
+                "parents": array(string()),
+                "payload": { "type": "object", "description": "A payload as defined by its TIP." },
+                "nonce": string(),
+            },
+        },
+        "SubmitBlockResponse": object(&["blockId"], json!({ "blockId": string() })),
+        "BlockMetadataResponse": object(
+            &["blockId", "parents", "isSolid"],
+            json!({
+                "blockId": string(),
+                "parents": array(string()),
+                "isSolid": boolean(),
+                "referencedByMilestoneIndex": integer(),
+                "milestoneIndex": integer(),
+                "ledgerInclusionState": { "type": "string", "enum": ["conflicting", "included", "noTransaction"] },
+                "conflictReason": integer(),
+                "conflictDiagnostic": schema_ref("ConflictDiagnostic"),
+                "shouldPromote": boolean(),
+                "shouldReattach": boolean(),
+            }),
+        ),
+        "BlockChildrenResponse": object(
+            &["blockId", "count", "children"],
+            json!({
+                "blockId": string(),
+                "maxResults": integer(),
+                "count": integer(),
+                "children": array(string()),
+                "cursor": string(),
+            }),
+        ),
+        "BlocksByTagResponse": object(
+            &["tag", "blocks"],
+            json!({
+                "tag": string(),
+                "blocks": array(object(
+                    &["blockId", "milestoneIndex"],
+                    json!({ "blockId": string(), "milestoneIndex": integer() }),
+                )),
+                "cursor": string(),
+            }),
+        ),
+        "BlockConeResponse": object(
+            &["blockId", "depth", "blocks", "truncated"],
+            json!({
+                "blockId": string(),
+                "depth": integer(),
+                "blocks": array(object(&["blockId", "depth"], json!({ "blockId": string(), "depth": integer() }))),
+                "truncated": boolean(),
+            }),
+        ),
+        "BlockReferencedByResponse": object(
+            &["blockId", "milestoneIndex", "milestoneId", "milestoneBlockId", "path"],
+            json!({
+                "blockId": string(),
+                "milestoneIndex": integer(),
+                "milestoneId": string(),
+                "milestoneBlockId": string(),
+                "path": array(string()),
+            }),
+        ),
+        "MilestonePayload": {
+            "type": "object",
+            "description": "A milestone payload as defined by TIP-29.",
+        },
+        "UtxoChangesResponse": object(
+            &["index", "createdOutputs", "consumedOutputs"],
+            json!({ "index": integer(), "createdOutputs": array(string()), "consumedOutputs": array(string()) }),
+        ),
+        "MilestoneUtxoDiff": object(
+            &["index", "createdOutputs", "consumedOutputs"],
+            json!({
+                "index": integer(),
+                "createdOutputs": array(schema_ref("OutputResponse")),
+                "consumedOutputs": array(schema_ref("OutputResponse")),
+                "treasuryDiff": object(
+                    &["createdMilestoneId", "consumedMilestoneId"],
+                    json!({ "createdMilestoneId": string(), "consumedMilestoneId": string() }),
+                ),
+            }),
+        ),
+        "OutputMetadataResponse": object(
+            &[
+                "blockId",
+                "transactionId",
+                "outputIndex",
+                "isSpent",
+                "milestoneIndexBooked",
+                "milestoneTimestampBooked",
+                "ledgerIndex",
+            ],
+            json!({
+                "blockId": string(),
+                "transactionId": string(),
+                "outputIndex": integer(),
+                "isSpent": boolean(),
+                "milestoneIndexSpent": integer(),
+                "milestoneTimestampSpent": integer(),
+                "transactionIdSpent": string(),
+                "milestoneIndexBooked": integer(),
+                "milestoneTimestampBooked": integer(),
+                "ledgerIndex": integer(),
+            }),
+        ),
+        "OutputResponse": object(
+            &["metadata", "output"],
+            json!({
+                "metadata": schema_ref("OutputMetadataResponse"),
+                "output": { "type": "object", "description": "An output as defined by TIP-18." },
+            }),
+        ),
+        "ReceiptsResponse": object(
+            &["receipts"],
+            json!({
+                "receipts": array(object(
+                    &["receipt", "milestoneIndex"],
+                    json!({
+                        "receipt": { "type": "object", "description": "A receipt milestone option as defined by TIP-29." },
+                        "milestoneIndex": integer(),
+                    }),
+                )),
+            }),
+        ),
+        "TreasuryResponse": object(&["milestoneId", "amount"], json!({ "milestoneId": string(), "amount": string() })),
+        "AddressHistoryResponse": object(
+            &["address", "historyIndex", "entries"],
+            json!({
+                "address": string(),
+                "historyIndex": integer(),
+                "entries": array(object(
+                    &["milestoneIndex", "milestoneTimestamp", "consumed", "outputId", "amount"],
+                    json!({
+                        "milestoneIndex": integer(),
+                        "milestoneTimestamp": integer(),
+                        "consumed": boolean(),
+                        "outputId": string(),
+                        "amount": string(),
+                    }),
+                )),
+                "cursor": string(),
+            }),
+        ),
+        "AddressBalanceResponse": object(
+            &["address", "milestoneIndex", "balance"],
+            json!({ "address": string(), "milestoneIndex": integer(), "balance": string() }),
+        ),
+        "Peer": object(
+            &["id", "multiAddresses", "relation", "connected", "reconnect"],
+            json!({
+                "id": string(),
+                "multiAddresses": array(string()),
+                "alias": string(),
+                "relation": { "type": "string", "enum": ["known", "unknown", "autopeered"] },
+                "connected": boolean(),
+                "gossip": { "type": "object" },
+                "reconnect": object(
+                    &["failedDials"],
+                    json!({
+                        "failedDials": integer(),
+                        "lastFailure": { "type": "string", "enum": ["refused", "unreachable", "handshakeFailed"] },
+                        "nextDialAt": integer(),
+                    }),
+                ),
+            }),
+        ),
+        "PeersResponse": array(schema_ref("Peer")),
+        "AddPeerRequest": object(&["multiAddress"], json!({ "multiAddress": string(), "alias": string() })),
+        "Ban": object(
+            &["target"],
+            json!({ "target": string(), "expiresAt": integer(), "reason": string() }),
+        ),
+        "BansResponse": object(
+            &["peers", "addresses"],
+            json!({ "peers": array(schema_ref("Ban")), "addresses": array(schema_ref("Ban")) }),
+        ),
+        "BanRequest": object(
+            &[],
+            json!({ "peerId": string(), "multiAddress": string(), "expiresAt": integer(), "reason": string() }),
+        ),
+        "PruneRequest": object(&[], json!({ "index": integer(), "depth": integer() })),
+        "CreateSnapshotRequest": object(
+            &["path"],
+            json!({ "kind": { "type": "string", "enum": ["full", "delta"] }, "path": string() }),
+        ),
+        "ControlJobResponse": object(
+            &["jobId", "kind", "state"],
+            json!({
+                "jobId": integer(),
+                "kind": string(),
+                "state": { "type": "string", "enum": ["running", "succeeded", "failed"] },
+                "progress": object(&["done", "total"], json!({ "done": integer(), "total": integer() })),
+                "message": string(),
+            }),
+        ),
+        "Token": object(
+            &["id", "scopes", "issuedAt", "revoked"],
+            json!({
+                "id": string(),
+                "scopes": array(schema_ref("Scope")),
+                "issuedAt": integer(),
+                "expiresAt": integer(),
+                "revoked": boolean(),
+            }),
+        ),
+        "Scope": { "type": "string", "enum": ["read", "submit", "peers", "debug", "control"] },
+        "TokensResponse": object(&["tokens"], json!({ "tokens": array(schema_ref("Token")) })),
+        "AddTokenRequest": object(&["scopes"], json!({ "scopes": array(schema_ref("Scope")), "expiresIn": integer() })),
+        "AddTokenResponse": object(&["jwt", "token"], json!({ "jwt": string(), "token": schema_ref("Token") })),
+        "WhiteFlagRequest": object(
+            &["index", "timestamp", "parents"],
+            json!({ "index": integer(), "timestamp": integer(), "parents": array(string()) }),
+        ),
+        "ConflictDiagnostic": {
+            "type": "object",
+            "description": "Details of the first conflict found by the semantic validation of a transaction.",
+            "required": ["kind"],
+            "properties": { "kind": string() },
+            "additionalProperties": true,
+        },
+        "WhiteFlagResponse": object(
+            &["merkleTreeHash"],
+            json!({
+                "merkleTreeHash": string(),
+                "conflictingBlocks": array(object(
+                    &["blockId", "conflictReason"],
+                    json!({
+                        "blockId": string(),
+                        "conflictReason": integer(),
+                        "conflictDiagnostic": schema_ref("ConflictDiagnostic"),
+                    }),
+                )),
+            }),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeSet,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use bee_block::semantic::dto::ConflictDiagnosticDto;
+
+    use super::*;
+    use crate::types::{
+        body::{DefaultErrorResponse, ErrorBody},
+        dtos::{AddressHistoryEntryDto, BanDto, ConeBlockDto, LedgerInclusionStateDto, TaggedBlockDto, TokenDto},
+        responses::*,
+    };
+
+    // Replaces the path parameters, `:name` for axum and `{name}` for OpenAPI, by a placeholder.
+    fn normalize(path: &str) -> String {
+        path.split('/')
+            .map(|segment| {
+                if segment.starts_with(':') || segment.starts_with('{') {
+                    "{}"
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    // Collects the routes registered in the `routes` modules, which are nested along their directories.
+    fn registered_routes() -> BTreeSet<(String, String)> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/endpoints/routes");
+        let call = concat!(".", "route(");
+        let mut directories = vec![root.clone()];
+        let mut routes = BTreeSet::new();
+
+        while let Some(directory) = directories.pop() {
+            for entry in fs::read_dir(&directory).unwrap() {
+                let path: PathBuf = entry.unwrap().path();
+
+                if path.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+
+                let prefix = path
+                    .parent()
+                    .unwrap()
+                    .strip_prefix(&root)
+                    .unwrap()
+                    .components()
+                    .map(|component| format!("/{}", component.as_os_str().to_string_lossy()))
+                    .collect::<String>();
+                let source = fs::read_to_string(&path).unwrap();
+
+                for arguments in source.split(call).skip(1) {
+                    let mut depth = 1;
+                    let end = arguments
+                        .char_indices()
+                        .find(|(_, c)| {
+                            match c {
+                                '(' => depth += 1,
+                                ')' => depth -= 1,
+                                _ => {}
+                            }
+                            depth == 0
+                        })
+                        .unwrap()
+                        .0;
+                    let arguments = &arguments[..end];
+                    let route = arguments.split('"').nth(1).unwrap();
+
+                    for method in ["get", "post", "put", "patch", "delete"] {
+                        let registered = arguments
+                            .match_indices(&format!("{}(", method))
+                            .any(|(index, _)| !arguments[..index].ends_with(|c: char| c.is_alphanumeric() || c == '_'));
+
+                        if registered {
+                            routes.insert((method.to_string(), normalize(&format!("{}{}", prefix, route))));
+                        }
+                    }
+                }
+            }
+        }
+
+        routes
+    }
+
+    // Validates a value against the subset of JSON schema used by the document, rejecting undocumented properties.
+    fn validate(schemas: &Value, schema: &Value, value: &Value, at: &str) -> Result<(), String> {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            let schema = schemas
+                .get(name)
+                .ok_or_else(|| format!("{}: unknown schema {}", at, name))?;
+
+            return validate(schemas, schema, value, at);
+        }
+
+        if let Some(variants) = schema["enum"].as_array() {
+            if !variants.contains(value) {
+                return Err(format!("{}: {} is not one of {:?}", at, value, variants));
+            }
+        }
+
+        let valid = match schema["type"].as_str() {
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("integer") => value.is_u64() || value.is_i64(),
+            Some("number") => value.is_number(),
+            Some("boolean") => value.is_boolean(),
+            Some(kind) => return Err(format!("{}: unknown type {}", at, kind)),
+            None => true,
+        };
+
+        if !valid {
+            return Err(format!("{}: {} is not of type {}", at, value, schema["type"]));
+        }
+
+        if let Some(object) = value.as_object() {
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let required = required.as_str().unwrap();
+
+                if !object.contains_key(required) {
+                    return Err(format!("{}: missing required property {}", at, required));
+                }
+            }
+
+            if let Some(properties) = schema["properties"].as_object() {
+                let additional = schema["additionalProperties"].as_bool().unwrap_or(false);
+
+                for (key, value) in object {
+                    match properties.get(key) {
+                        Some(property) => validate(schemas, property, value, &format!("{}.{}", at, key))?,
+                        None if additional => {}
+                        None => return Err(format!("{}: undocumented property {}", at, key)),
+                    }
+                }
+            }
+        }
+
+        if let Some(items) = value.as_array() {
+            for (index, item) in items.iter().enumerate() {
+                validate(schemas, &schema["items"], item, &format!("{}[{}]", at, index))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn references(value: &Value, names: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(object) => {
+                if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                    names.insert(reference.trim_start_matches("#/components/schemas/").to_string());
+                }
+                object.values().for_each(|value| references(value, names));
+            }
+            Value::Array(array) => array.iter().for_each(|value| references(value, names)),
+            _ => {}
+        }
+    }
+
+    fn protocol() -> ProtocolResponse {
+        ProtocolResponse {
+            version: 2,
+            network_name: "alphanet-8".to_string(),
+            bech32_hrp: "rms".to_string(),
+            min_pow_score: 1000.0,
+            rent_structure: RentStructureResponse {
+                v_byte_cost: 500,
+                v_byte_factor_key: 10,
+                v_byte_factor_data: 1,
+            },
+            token_supply: "2779530283277761".to_string(),
+        }
+    }
+
+    fn token() -> TokenDto {
+        TokenDto {
+            id: "0x3b1c7f4b6fd1ae01".to_string(),
+            scopes: vec!["read".to_string(), "control".to_string()],
+            issued_at: 1_660_000_000,
+            expires_at: Some(1_660_086_400),
+            revoked: false,
+        }
+    }
+
+    // Examples of the responses, with all optional fields set so that each of them is checked.
+    fn examples() -> Vec<(&'static str, Value)> {
+        let block_id = "0x9a9e4a1f0bbc0ef1ac3a0b33d7b7b6b54c7d1bf4a1d8b9ba2ab9e4bc0bc0f6e3".to_string();
+        let output_id = "0x9a9e4a1f0bbc0ef1ac3a0b33d7b7b6b54c7d1bf4a1d8b9ba2ab9e4bc0bc0f6e30000".to_string();
+
+        vec![
+            (
+                "ErrorResponse",
+                serde_json::to_value(ErrorBody::new(DefaultErrorResponse {
+                    code: "400".to_string(),
+                    message: "invalid data provided".to_string(),
+                }))
+                .unwrap(),
+            ),
+            (
+                "HealthResponse",
+                serde_json::to_value(HealthResponse {
+                    is_healthy: false,
+                    issues: vec![HealthIssueResponse {
+                        reason: "notSynced".to_string(),
+                        message: "the node is not synced".to_string(),
+                    }],
+                })
+                .unwrap(),
+            ),
+            (
+                "InfoResponse",
+                serde_json::to_value(InfoResponse {
+                    name: "Bee".to_string(),
+                    version: "1.0.0".to_string(),
+                    status: StatusResponse {
+                        is_healthy: true,
+                        latest_milestone: LatestMilestoneResponse {
+                            index: 42,
+                            timestamp: 1_660_000_000,
+                            milestone_id: block_id.clone(),
+                        },
+                        confirmed_milestone: ConfirmedMilestoneResponse {
+                            index: 42,
+                            timestamp: 1_660_000_000,
+                            milestone_id: block_id.clone(),
+                        },
+                        pruning_index: 10,
+                    },
+                    protocol: protocol(),
+                    base_token: BaseTokenResponse {
+                        name: "Shimmer".to_string(),
+                        ticker_symbol: "SMR".to_string(),
+                        unit: "SMR".to_string(),
+                        subunit: Some("glow".to_string()),
+                        decimals: 6,
+                        use_metric_prefix: false,
+                    },
+                    metrics: MetricsResponse {
+                        blocks_per_second: 10.5,
+                        referenced_blocks_per_second: 10.0,
+                        referenced_rate: 95.2,
+                    },
+                    features: vec!["PoW".to_string()],
+                    plugins: vec!["debug".to_string()],
+                    external_addresses: vec!["/ip4/1.2.3.4/tcp/15600".to_string()],
+                    pending_protocol_parameters: vec![PendingProtocolParametersResponse {
+                        target_milestone_index: 100,
+                        params: protocol(),
+                    }],
+                })
+                .unwrap(),
+            ),
+            (
+                "TipsResponse",
+                serde_json::to_value(TipsResponse {
+                    tips: vec![block_id.clone()],
+                })
+                .unwrap(),
+            ),
+            (
+                "SubmitBlockResponse",
+                serde_json::to_value(SubmitBlockResponse {
+                    block_id: block_id.clone(),
+                })
+                .unwrap(),
+            ),
+            (
+                "BlockMetadataResponse",
+                serde_json::to_value(BlockMetadataResponse {
+                    block_id: block_id.clone(),
+                    parents: vec![block_id.clone()],
+                    is_solid: true,
+                    referenced_by_milestone_index: Some(42),
+                    milestone_index: Some(42),
+                    ledger_inclusion_state: Some(LedgerInclusionStateDto::Conflicting),
+                    conflict_reason: Some(14),
+                    conflict_diagnostic: Some(ConflictDiagnosticDto::UnlockFailed {
+                        input_index: 0,
+                        reason: 1,
+                    }),
+                    should_promote: Some(false),
+                    should_reattach: Some(false),
+                })
+                .unwrap(),
+            ),
+            (
+                "BlockChildrenResponse",
+                serde_json::to_value(BlockChildrenResponse {
+                    block_id: block_id.clone(),
+                    max_results: Some(100),
+                    count: 1,
+                    children: vec![block_id.clone()],
+                    cursor: Some("1".to_string()),
+                })
+                .unwrap(),
+            ),
+            (
+                "BlocksByTagResponse",
+                serde_json::to_value(BlocksByTagResponse {
+                    tag: "0x626565".to_string(),
+                    blocks: vec![TaggedBlockDto {
+                        block_id: block_id.clone(),
+                        milestone_index: 42,
+                    }],
+                    cursor: Some(format!("43.{}", block_id)),
+                })
+                .unwrap(),
+            ),
+            (
+                "BlockConeResponse",
+                serde_json::to_value(BlockConeResponse {
+                    block_id: block_id.clone(),
+                    depth: 5,
+                    blocks: vec![ConeBlockDto {
+                        block_id: block_id.clone(),
+                        depth: 1,
+                    }],
+                    truncated: false,
+                })
+                .unwrap(),
+            ),
+            (
+                "BlockReferencedByResponse",
+                serde_json::to_value(BlockReferencedByResponse {
+                    block_id: block_id.clone(),
+                    milestone_index: 42,
+                    milestone_id: block_id.clone(),
+                    milestone_block_id: block_id.clone(),
+                    path: vec![block_id.clone()],
+                })
+                .unwrap(),
+            ),
+            (
+                "OutputMetadataResponse",
+                serde_json::to_value(OutputMetadataResponse {
+                    block_id: block_id.clone(),
+                    transaction_id: block_id.clone(),
+                    output_index: 0,
+                    is_spent: true,
+                    milestone_index_spent: Some(43),
+                    milestone_timestamp_spent: Some(1_660_000_010),
+                    transaction_id_spent: Some(block_id.clone()),
+                    milestone_index_booked: 42,
+                    milestone_timestamp_booked: 1_660_000_000,
+                    ledger_index: 43,
+                })
+                .unwrap(),
+            ),
+            (
+                "TreasuryResponse",
+                serde_json::to_value(TreasuryResponse {
+                    milestone_id: block_id.clone(),
+                    amount: "1000".to_string(),
+                })
+                .unwrap(),
+            ),
+            (
+                "UtxoChangesResponse",
+                serde_json::to_value(UtxoChangesResponse {
+                    index: 42,
+                    created_outputs: vec![output_id.clone()],
+                    consumed_outputs: vec![output_id.clone()],
+                })
+                .unwrap(),
+            ),
+            (
+                "AddressHistoryResponse",
+                serde_json::to_value(AddressHistoryResponse {
+                    address: "rms1qp5hdsmdqudhyf8dmm0mf5plmz2ey2k4e9f46fdvtcx7qlyexl3ljfwgkau".to_string(),
+                    history_index: 42,
+                    entries: vec![AddressHistoryEntryDto {
+                        milestone_index: 42,
+                        milestone_timestamp: 1_660_000_000,
+                        consumed: false,
+                        output_id: output_id.clone(),
+                        amount: "1000".to_string(),
+                    }],
+                    cursor: Some(format!("43.0.{}", output_id)),
+                })
+                .unwrap(),
+            ),
+            (
+                "AddressBalanceResponse",
+                serde_json::to_value(AddressBalanceResponse {
+                    address: "rms1qp5hdsmdqudhyf8dmm0mf5plmz2ey2k4e9f46fdvtcx7qlyexl3ljfwgkau".to_string(),
+                    milestone_index: 42,
+                    balance: "1000".to_string(),
+                })
+                .unwrap(),
+            ),
+            (
+                "BansResponse",
+                serde_json::to_value(BansResponse {
+                    peers: vec![BanDto {
+                        target: "12D3KooWJWEKvSFbben74C7H4YtKjhPMTDxd7gP6zxWSUEeRBdy1".to_string(),
+                        expires_at: Some(1_660_000_000),
+                        reason: Some("spam".to_string()),
+                    }],
+                    addresses: vec![BanDto {
+                        target: "/ip4/1.2.3.4".to_string(),
+                        expires_at: None,
+                        reason: None,
+                    }],
+                })
+                .unwrap(),
+            ),
+            (
+                "ControlJobResponse",
+                serde_json::to_value(ControlJobResponse {
+                    job_id: 1,
+                    kind: "prune".to_string(),
+                    state: "running".to_string(),
+                    progress: Some(ControlJobProgress { done: 1, total: 10 }),
+                    message: Some("pruned the database up to milestone 42".to_string()),
+                })
+                .unwrap(),
+            ),
+            (
+                "TokensResponse",
+                serde_json::to_value(TokensResponse { tokens: vec![token()] }).unwrap(),
+            ),
+            (
+                "AddTokenResponse",
+                serde_json::to_value(AddTokenResponse {
+                    jwt: "eyJhbGciOiJIUzI1NiJ9.e30.c2lnbmF0dXJl".to_string(),
+                    token: token(),
+                })
+                .unwrap(),
+            ),
+            (
+                "AddPeerRequest",
+                json!({ "multiAddress": "/ip4/1.2.3.4/tcp/15600", "alias": "peer" }),
+            ),
+            (
+                "BanRequest",
+                json!({ "peerId": "12D3KooWJWEKvSFbben74C7H4YtKjhPMTDxd7gP6zxWSUEeRBdy1", "expiresAt": 1_660_000_000u64 }),
+            ),
+            ("PruneRequest", json!({ "depth": 1000 })),
+            (
+                "CreateSnapshotRequest",
+                json!({ "kind": "delta", "path": "delta_snapshot.bin" }),
+            ),
+            (
+                "AddTokenRequest",
+                json!({ "scopes": ["read", "peers"], "expiresIn": 3600 }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let documented = OPERATIONS
+            .iter()
+            .map(|operation| (operation.method.to_string(), normalize(operation.path)))
+            .collect::<BTreeSet<_>>();
+        let registered = registered_routes();
+
+        assert_eq!(
+            registered.difference(&documented).collect::<Vec<_>>(),
+            Vec::<&(String, String)>::new(),
+            "registered but not documented"
+        );
+        assert_eq!(
+            documented.difference(&registered).collect::<Vec<_>>(),
+            Vec::<&(String, String)>::new(),
+            "documented but not registered"
+        );
+    }
+
+    #[test]
+    fn every_path_parameter_is_described() {
+        for operation in OPERATIONS {
+            for segment in operation.path.split('/') {
+                if let Some(name) = segment.strip_prefix('{').and_then(|name| name.strip_suffix('}')) {
+                    assert!(
+                        operation.parameters.iter().any(|parameter| parameter.name == name),
+                        "{} {}: parameter {} is not described",
+                        operation.method,
+                        operation.path,
+                        name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_schema_reference_resolves() {
+        let schemas = schemas();
+        let mut names = OPERATIONS
+            .iter()
+            .flat_map(|operation| [&operation.request, &operation.response])
+            .filter_map(|content| match content {
+                Content::Json(name) | Content::JsonLines(name) => Some(name.to_string()),
+                Content::None | Content::Text => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        references(&schemas, &mut names);
+
+        for name in names {
+            assert!(schemas.get(&name).is_some(), "unknown schema {}", name);
+        }
+    }
+
+    #[test]
+    fn examples_validate_against_their_schemas() {
+        let schemas = schemas();
+
+        for (name, example) in examples() {
+            if let Err(e) = validate(&schemas, &schema_ref(name), &example, name) {
+                panic!("{}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn validation_rejects_mismatches() {
+        let schemas = schemas();
+        let job = |value| {
+            validate(
+                &schemas,
+                &schema_ref("ControlJobResponse"),
+                &value,
+                "ControlJobResponse",
+            )
+        };
+
+        assert!(job(json!({ "jobId": 1, "kind": "prune", "state": "running" })).is_ok());
+        assert!(job(json!({ "jobId": 1, "kind": "prune" })).is_err());
+        assert!(job(json!({ "jobId": "1", "kind": "prune", "state": "running" })).is_err());
+        assert!(job(json!({ "jobId": 1, "kind": "prune", "state": "paused" })).is_err());
+        assert!(job(json!({ "jobId": 1, "kind": "prune", "state": "running", "extra": true })).is_err());
+    }
+
+    #[test]
+    fn default_security_of_block_routes() {
+        let document = document(&RestApiConfig::build().finish(), "0.0.0");
+        let is_protected = |method: &str, path: &str| document["paths"][path][method].get("security").is_some();
+
+        assert!(!is_protected("post", "/api/v2/blocks"));
+        assert!(!is_protected("get", "/api/v2/blocks/{blockId}"));
+        assert!(!is_protected("get", "/api/v2/blocks/{blockId}/metadata"));
+        assert!(is_protected("get", "/api/v2/blocks"));
+        assert!(is_protected("get", "/api/v2/blocks/{blockId}/children"));
+        assert!(is_protected("get", "/api/v2/blocks/{blockId}/past-cone"));
+        assert!(is_protected("get", "/api/v2/blocks/{blockId}/future-cone"));
+        assert!(is_protected("get", "/api/v2/blocks/{blockId}/referenced-by"));
+    }
+}
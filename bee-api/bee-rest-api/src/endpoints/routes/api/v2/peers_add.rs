@@ -9,7 +9,7 @@ use serde_json::Value;
 use crate::{
     endpoints::{error::ApiError, extractors::json::CustomJson, storage::StorageBackend, ApiArgsFullNode},
     types::{
-        dtos::{PeerDto, RelationDto},
+        dtos::{PeerDto, ReconnectDto, RelationDto},
         responses::AddPeerResponse,
     },
 };
@@ -73,6 +73,7 @@ async fn peers_add<B: StorageBackend>(
                 relation: RelationDto::Known,
                 connected: false,
                 gossip: None,
+                reconnect: ReconnectDto::default(),
             }))
         })
 }
@@ -0,0 +1,41 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+
+use crate::{
+    endpoints::{storage::StorageBackend, ApiArgsFullNode},
+    types::{dtos::BanDto, responses::BansResponse},
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/peers/bans", get(peers_bans::<B>))
+}
+
+async fn peers_bans<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> BansResponse {
+    let peers = args
+        .ban_list
+        .peers()
+        .await
+        .into_iter()
+        .map(|(peer_id, info)| BanDto {
+            target: peer_id.to_string(),
+            expires_at: info.expires_at,
+            reason: info.reason,
+        })
+        .collect();
+
+    let addresses = args
+        .ban_list
+        .addresses()
+        .await
+        .into_iter()
+        .map(|(address, info)| BanDto {
+            target: address.to_string(),
+            expires_at: info.expires_at,
+            reason: info.reason,
+        })
+        .collect();
+
+    BansResponse { peers, addresses }
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, http::StatusCode, routing::post, Router};
+use bee_gossip::{BanInfo, Command, Multiaddr, PeerId};
+use log::error;
+use serde_json::Value;
+
+use crate::endpoints::{error::ApiError, extractors::json::CustomJson, storage::StorageBackend, ApiArgsFullNode};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/peers/bans", post(peers_bans_add::<B>))
+}
+
+async fn peers_bans_add<B: StorageBackend>(
+    CustomJson(value): CustomJson<Value>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<StatusCode, ApiError> {
+    let peer_id_json = &value["peerId"];
+    let multiaddress_json = &value["multiAddress"];
+    let expires_at_json = &value["expiresAt"];
+    let reason_json = &value["reason"];
+
+    let expires_at = if expires_at_json.is_null() {
+        None
+    } else {
+        Some(
+            expires_at_json
+                .as_u64()
+                .ok_or(ApiError::BadRequest("invalid expiration: expected a UNIX timestamp"))?,
+        )
+    };
+
+    let reason = if reason_json.is_null() {
+        None
+    } else {
+        Some(
+            reason_json
+                .as_str()
+                .ok_or(ApiError::BadRequest("invalid reason: expected a string"))?
+                .to_string(),
+        )
+    };
+
+    let info = BanInfo::new(expires_at, reason);
+
+    let command = match (peer_id_json.is_null(), multiaddress_json.is_null()) {
+        (false, true) => Command::BanPeer {
+            peer_id: peer_id_json
+                .as_str()
+                .ok_or(ApiError::BadRequest("invalid peer id"))?
+                .parse::<PeerId>()
+                .map_err(|_| ApiError::BadRequest("invalid peer id"))?,
+            info,
+        },
+        (true, false) => Command::BanAddress {
+            address: multiaddress_json
+                .as_str()
+                .ok_or(ApiError::BadRequest("invalid multiaddress"))?
+                .parse::<Multiaddr>()
+                .map_err(|_| ApiError::BadRequest("invalid multiaddress"))?,
+            info,
+        },
+        _ => {
+            return Err(ApiError::BadRequest(
+                "invalid ban: expected either a peer id or a multiaddress",
+            ));
+        }
+    };
+
+    if let Err(e) = args.network_command_sender.send(command) {
+        error!("cannot ban: {}", e);
+        return Err(ApiError::InternalServerError);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
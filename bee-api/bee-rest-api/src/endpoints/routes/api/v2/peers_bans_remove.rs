@@ -0,0 +1,59 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, http::StatusCode, routing::delete, Router};
+use bee_gossip::{Command, Multiaddr, PeerId};
+use log::error;
+use serde::Deserialize;
+
+use crate::endpoints::{
+    error::ApiError,
+    extractors::{path::CustomPath, query::CustomQuery},
+    storage::StorageBackend,
+    ApiArgsFullNode,
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    // Multiaddresses contain slashes and can't be matched by a path segment, they are passed as a query parameter.
+    Router::new()
+        .route("/peers/bans/peer/:peer_id", delete(peers_bans_remove_peer::<B>))
+        .route("/peers/bans/address", delete(peers_bans_remove_address::<B>))
+}
+
+#[derive(Deserialize)]
+struct AddressQuery {
+    #[serde(rename = "multiAddress")]
+    multi_address: String,
+}
+
+async fn peers_bans_remove_peer<B: StorageBackend>(
+    CustomPath(peer_id): CustomPath<String>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<StatusCode, ApiError> {
+    let peer_id = peer_id
+        .parse::<PeerId>()
+        .map_err(|_| ApiError::BadRequest("invalid peer id"))?;
+
+    send_unban(Command::UnbanPeer { peer_id }, &args)
+}
+
+async fn peers_bans_remove_address<B: StorageBackend>(
+    CustomQuery(query): CustomQuery<AddressQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<StatusCode, ApiError> {
+    let address = query
+        .multi_address
+        .parse::<Multiaddr>()
+        .map_err(|_| ApiError::BadRequest("invalid multiaddress"))?;
+
+    send_unban(Command::UnbanAddress { address }, &args)
+}
+
+fn send_unban<B: StorageBackend>(command: Command, args: &ApiArgsFullNode<B>) -> Result<StatusCode, ApiError> {
+    if let Err(e) = args.network_command_sender.send(command) {
+        error!("cannot unban: {}", e);
+        return Err(ApiError::InternalServerError);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,76 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use axum::{extract::Extension, routing::post, Router};
+use log::error;
+use serde_json::Value;
+
+use crate::{
+    endpoints::{
+        error::ApiError,
+        extractors::json::CustomJson,
+        storage::StorageBackend,
+        tokens::{self, Scope},
+        ApiArgsFullNode,
+    },
+    types::{dtos::TokenDto, responses::AddTokenResponse},
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/tokens", post(tokens_add::<B>))
+}
+
+async fn tokens_add<B: StorageBackend>(
+    CustomJson(value): CustomJson<Value>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<AddTokenResponse, ApiError> {
+    let scopes_json = &value["scopes"];
+    let expires_in_json = &value["expiresIn"];
+
+    let scopes = scopes_json
+        .as_array()
+        .ok_or(ApiError::BadRequest("invalid scopes: expected an array"))?
+        .iter()
+        .map(|scope| {
+            scope
+                .as_str()
+                .and_then(|scope| scope.parse::<Scope>().ok())
+                .ok_or(ApiError::BadRequest("invalid scope"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if scopes.is_empty() {
+        return Err(ApiError::BadRequest("invalid scopes: expected at least one scope"));
+    }
+
+    let expires_in = if expires_in_json.is_null() {
+        None
+    } else {
+        Some(Duration::from_secs(expires_in_json.as_u64().ok_or(
+            ApiError::BadRequest("invalid expiration: expected a number of seconds"),
+        )?))
+    };
+
+    let (record, jwt) = tokens::issue_token(
+        &args.node_id.to_string(),
+        args.rest_api_config.jwt_salt(),
+        args.node_keypair.secret().as_ref(),
+        &scopes,
+        expires_in,
+    )
+    .map_err(|e| {
+        error!("cannot issue token: {}", e);
+        ApiError::InternalServerError
+    })?;
+
+    let token = TokenDto::from(&record);
+
+    args.token_store.insert(record).map_err(|e| {
+        error!("cannot store token: {}", e);
+        ApiError::InternalServerError
+    })?;
+
+    Ok(AddTokenResponse { jwt, token })
+}
@@ -0,0 +1,27 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, routing::get, Router};
+use log::error;
+
+use crate::{
+    endpoints::{error::ApiError, storage::StorageBackend, ApiArgsFullNode},
+    types::{dtos::TokenDto, responses::TokensResponse},
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/tokens", get(tokens_all::<B>))
+}
+
+async fn tokens_all<B: StorageBackend>(
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<TokensResponse, ApiError> {
+    let tokens = args.token_store.tokens().map_err(|e| {
+        error!("cannot read tokens: {}", e);
+        ApiError::InternalServerError
+    })?;
+
+    Ok(TokensResponse {
+        tokens: tokens.iter().map(TokenDto::from).collect(),
+    })
+}
@@ -0,0 +1,27 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::Extension, http::StatusCode, routing::delete, Router};
+use log::error;
+
+use crate::endpoints::{
+    error::ApiError, extractors::path::CustomPath, storage::StorageBackend, tokens::TokenError, ApiArgsFullNode,
+};
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/tokens/:token_id", delete(tokens_revoke::<B>))
+}
+
+async fn tokens_revoke<B: StorageBackend>(
+    CustomPath(token_id): CustomPath<String>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<StatusCode, ApiError> {
+    match args.token_store.revoke(&token_id) {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(TokenError::UnknownToken(_)) => Err(ApiError::NotFound),
+        Err(e) => {
+            error!("cannot revoke token: {}", e);
+            Err(ApiError::InternalServerError)
+        }
+    }
+}
@@ -20,7 +20,7 @@ async fn utxo_changes_by_id<B: StorageBackend>(
     CustomPath(milestone_id): CustomPath<MilestoneId>,
     Extension(args): Extension<ApiArgsFullNode<B>>,
 ) -> Result<UtxoChangesResponse, ApiError> {
-    let milestone_index = match args.tangle.get_milestone(milestone_id) {
+    let milestone_index = match args.tangle.get_milestone(milestone_id)? {
         Some(milestone_payload) => milestone_payload.essence().index(),
         None => return Err(ApiError::NotFound),
     };
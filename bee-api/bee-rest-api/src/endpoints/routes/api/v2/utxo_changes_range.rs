@@ -0,0 +1,115 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    body::{Bytes, StreamBody},
+    extract::Extension,
+    http::header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::workers::{
+    error::Error as LedgerError,
+    storage::{check_milestone_utxo_diff_range, fetch_milestone_utxo_diff},
+};
+use futures::stream::{self, StreamExt};
+use log::error;
+use packable::PackableExt;
+use serde::Deserialize;
+
+use crate::{
+    endpoints::{
+        error::ApiError, extractors::query::CustomQuery, routes::api::v2::blocks::BYTE_CONTENT_HEADER,
+        storage::StorageBackend, ApiArgsFullNode,
+    },
+    types::dtos::MilestoneUtxoDiffDto,
+};
+
+const UTXO_CHANGES_RANGE_LENGTH_MAX: u32 = 1000;
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/milestones/utxo-changes", get(utxo_changes_range::<B>))
+}
+
+#[derive(Deserialize)]
+struct RangeQuery {
+    #[serde(rename = "startIndex")]
+    start_index: u32,
+    #[serde(rename = "endIndex")]
+    end_index: u32,
+}
+
+async fn utxo_changes_range<B: StorageBackend>(
+    headers: HeaderMap,
+    CustomQuery(query): CustomQuery<RangeQuery>,
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<Response, ApiError> {
+    check_milestone_utxo_diff_range(
+        &*args.storage,
+        MilestoneIndex(query.start_index),
+        MilestoneIndex(query.end_index),
+    )
+    .map_err(|e| match e {
+        LedgerError::InvalidMilestoneRange(..) => {
+            ApiError::BadRequest("invalid milestone range: the start index is greater than the end index")
+        }
+        LedgerError::MilestoneRangeBelowPruningIndex(..) => {
+            ApiError::BadRequest("invalid milestone range: the milestones at or below the pruning index are pruned")
+        }
+        LedgerError::MilestoneRangeAboveLedgerIndex(..) => {
+            ApiError::BadRequest("invalid milestone range: the milestones above the ledger index are not confirmed")
+        }
+        LedgerError::MilestoneRangeWithoutOutputDiff(..) => ApiError::BadRequest(
+            "invalid milestone range: the ledger changes of the milestones below the imported snapshot are not stored",
+        ),
+        LedgerError::UninitializedLedger => ApiError::ServiceUnavailable("the ledger is not initialized"),
+        e => {
+            error!("cannot fetch from storage: {}", e);
+            ApiError::InternalServerError
+        }
+    })?;
+
+    if query.end_index - query.start_index >= UTXO_CHANGES_RANGE_LENGTH_MAX {
+        return Err(ApiError::BadRequest(
+            "invalid milestone range: expected at most 1000 milestones",
+        ));
+    }
+
+    let raw = headers
+        .get(axum::http::header::ACCEPT)
+        .map_or(false, |value| value.eq(&*BYTE_CONTENT_HEADER));
+    let storage = args.storage.clone();
+
+    // The range was checked, but the milestones may still get pruned while they are streamed, in which case the stream
+    // is interrupted.
+    let body = StreamBody::new(stream::iter(query.start_index..=query.end_index).map(
+        move |index| -> Result<Bytes, std::io::Error> {
+            let index = MilestoneIndex(index);
+            let diff = fetch_milestone_utxo_diff(&*storage, index)
+                .and_then(|diff| diff.ok_or(LedgerError::MissingOutputDiff(index)))
+                .map_err(|e| {
+                    error!("cannot fetch from storage: {}", e);
+                    std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+                })?;
+
+            if raw {
+                Ok(Bytes::from(diff.pack_to_vec()))
+            } else {
+                let mut line = serde_json::to_vec(&MilestoneUtxoDiffDto::from(&diff))?;
+                line.push(b'\n');
+                Ok(Bytes::from(line))
+            }
+        },
+    ));
+
+    let content_type = if raw {
+        BYTE_CONTENT_HEADER.clone()
+    } else {
+        HeaderValue::from_static(NDJSON_CONTENT_TYPE)
+    };
+
+    Ok(([(CONTENT_TYPE, content_type)], body).into_response())
+}
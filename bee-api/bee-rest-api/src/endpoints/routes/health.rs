@@ -2,51 +2,203 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    convert::Infallible,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
-use axum::{extract::Extension, http::StatusCode, response::IntoResponse, routing::get, Router};
+use axum::{extract::Extension, routing::get, Router};
 use bee_protocol::workers::PeerManager;
+use bee_storage::system::StorageHealth;
 use bee_tangle::Tangle;
 
-use crate::endpoints::{storage::StorageBackend, ApiArgsFullNode};
-
-const HEALTH_CONFIRMED_THRESHOLD: u32 = 2; // in milestones
-const HEALTH_MILESTONE_AGE_MAX: Duration = Duration::from_secs(5 * 60);
+use crate::{
+    endpoints::{config::HealthConfig, storage::StorageBackend, ApiArgsFullNode},
+    types::responses::{HealthIssueResponse, HealthResponse},
+};
 
 pub(crate) fn filter<B: StorageBackend>() -> Router {
-    Router::new().route("/health", get(health::<B>))
+    Router::new()
+        .route("/health", get(ready::<B>))
+        .route("/health/live", get(live::<B>))
+        .route("/health/ready", get(ready::<B>))
+}
+
+async fn live<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> HealthResponse {
+    health_response(liveness_issues(&args))
+}
+
+async fn ready<B: StorageBackend>(Extension(args): Extension<ApiArgsFullNode<B>>) -> HealthResponse {
+    health_response(readiness_issues(&args))
 }
 
-async fn health<B: StorageBackend>(
-    Extension(args): Extension<ApiArgsFullNode<B>>,
-) -> Result<impl IntoResponse, Infallible> {
-    if is_healthy(&args.tangle, &args.peer_manager) {
-        Ok(StatusCode::OK)
-    } else {
-        Ok(StatusCode::SERVICE_UNAVAILABLE)
+fn health_response(issues: Vec<HealthIssue>) -> HealthResponse {
+    HealthResponse {
+        is_healthy: issues.is_empty(),
+        issues: issues
+            .iter()
+            .map(|issue| HealthIssueResponse {
+                reason: issue.reason().to_string(),
+                message: issue.to_string(),
+            })
+            .collect(),
     }
 }
 
-pub fn is_healthy<B: StorageBackend>(tangle: &Tangle<B>, peer_manager: &PeerManager) -> bool {
-    if !tangle.is_confirmed_threshold(HEALTH_CONFIRMED_THRESHOLD) {
-        return false;
+/// A failed health check.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HealthIssue {
+    /// The confirmed milestone lags behind the latest milestone by more than the threshold.
+    NotSynced {
+        /// The number of milestones the confirmed milestone lags behind.
+        milestones_behind: u32,
+        /// The configured threshold.
+        threshold: u32,
+    },
+    /// Fewer peers than required are connected.
+    NotEnoughPeers {
+        /// The number of connected peers.
+        connected: u8,
+        /// The configured minimum number of connected peers.
+        required: u8,
+    },
+    /// The latest milestone is unknown or older than allowed.
+    StaleMilestone {
+        /// The age of the latest milestone in seconds, if it is known.
+        age: Option<u64>,
+        /// The configured maximum age in seconds.
+        max_age: u64,
+    },
+    /// The storage reported that it is not healthy.
+    StorageUnhealthy,
+    /// A worker the node depends on has stopped.
+    WorkerDown(&'static str),
+}
+
+impl HealthIssue {
+    /// Returns a short machine-readable identifier of the failed check.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::NotSynced { .. } => "notSynced",
+            Self::NotEnoughPeers { .. } => "notEnoughPeers",
+            Self::StaleMilestone { .. } => "staleMilestone",
+            Self::StorageUnhealthy => "storageUnhealthy",
+            Self::WorkerDown(_) => "workerDown",
+        }
+    }
+}
+
+impl fmt::Display for HealthIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSynced {
+                milestones_behind,
+                threshold,
+            } => write!(
+                f,
+                "confirmed milestone is {} milestones behind the latest one, threshold is {}",
+                milestones_behind, threshold
+            ),
+            Self::NotEnoughPeers { connected, required } => {
+                write!(f, "{} connected peers, at least {} required", connected, required)
+            }
+            Self::StaleMilestone {
+                age: Some(age),
+                max_age,
+            } => {
+                write!(f, "latest milestone is {}s old, maximum is {}s", age, max_age)
+            }
+            Self::StaleMilestone { age: None, .. } => write!(f, "latest milestone is unknown"),
+            Self::StorageUnhealthy => write!(f, "storage is not healthy"),
+            Self::WorkerDown(worker) => write!(f, "{} worker is down", worker),
+        }
+    }
+}
+
+/// Returns the failed checks that can only be resolved by restarting the node.
+pub(crate) fn liveness_issues<B: StorageBackend>(args: &ApiArgsFullNode<B>) -> Vec<HealthIssue> {
+    let mut issues = Vec::new();
+
+    if args.tangle.has_storage_failed()
+        || !matches!(
+            args.storage.get_health(),
+            Ok(None | Some(StorageHealth::Healthy | StorageHealth::Idle))
+        )
+    {
+        issues.push(HealthIssue::StorageUnhealthy);
+    }
+
+    if args.consensus_worker.is_closed() {
+        issues.push(HealthIssue::WorkerDown("consensus"));
     }
 
-    if peer_manager.connected_peers() == 0 {
-        return false;
+    if args.block_submitter.is_closed() {
+        issues.push(HealthIssue::WorkerDown("block submitter"));
     }
 
-    match tangle.get_milestone_metadata(tangle.get_latest_milestone_index()) {
-        Some(milestone) => {
-            (SystemTime::now()
+    issues
+}
+
+/// Returns the failed checks that prevent the node from serving requests.
+pub(crate) fn readiness_issues<B: StorageBackend>(args: &ApiArgsFullNode<B>) -> Vec<HealthIssue> {
+    let mut issues = liveness_issues(args);
+
+    issues.extend(sync_issues(
+        &args.tangle,
+        &args.peer_manager,
+        args.rest_api_config.health(),
+    ));
+
+    issues
+}
+
+/// Returns the failed checks that indicate that the node is not in sync with the network.
+pub fn sync_issues<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    peer_manager: &PeerManager,
+    config: &HealthConfig,
+) -> Vec<HealthIssue> {
+    let mut issues = Vec::new();
+
+    let latest_milestone_index = tangle.get_latest_milestone_index();
+
+    if !tangle.is_confirmed_threshold(config.confirmed_threshold()) {
+        issues.push(HealthIssue::NotSynced {
+            milestones_behind: latest_milestone_index.saturating_sub(*tangle.get_confirmed_milestone_index()),
+            threshold: config.confirmed_threshold(),
+        });
+    }
+
+    let connected_peers = peer_manager.connected_peers();
+
+    if connected_peers < config.min_connected_peers() {
+        issues.push(HealthIssue::NotEnoughPeers {
+            connected: connected_peers,
+            required: config.min_connected_peers(),
+        });
+    }
+
+    let max_age = config.milestone_age_max().as_secs();
+    // A milestone that can not be read from the storage is reported as unknown.
+    let age = tangle
+        .get_milestone_metadata(latest_milestone_index)
+        .ok()
+        .flatten()
+        .map(|milestone| {
+            SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .expect("Clock may have gone backwards")
-                .as_secs() as u64)
+                .as_secs()
                 .saturating_sub(milestone.timestamp().into())
-                <= HEALTH_MILESTONE_AGE_MAX.as_secs()
-        }
-        None => false,
+        });
+
+    if !matches!(age, Some(age) if age <= max_age) {
+        issues.push(HealthIssue::StaleMilestone { age, max_age });
     }
+
+    issues
+}
+
+/// Returns whether the node is in sync with the network.
+pub fn is_healthy<B: StorageBackend>(tangle: &Tangle<B>, peer_manager: &PeerManager, config: &HealthConfig) -> bool {
+    sync_issues(tangle, peer_manager, config).is_empty()
 }
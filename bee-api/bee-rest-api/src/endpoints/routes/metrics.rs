@@ -0,0 +1,524 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exports the metrics of the node in the Prometheus text exposition format.
+
+use std::{
+    convert::Infallible,
+    fmt::{Display, Write},
+};
+
+use axum::{
+    extract::Extension,
+    http::header::{HeaderValue, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use bee_block::semantic::ConflictReason;
+use bee_protocol::types::peer::Peer;
+
+use crate::endpoints::{storage::StorageBackend, ApiArgsFullNode};
+
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+pub(crate) fn filter<B: StorageBackend>() -> Router {
+    Router::new().route("/metrics", get(metrics::<B>))
+}
+
+async fn metrics<B: StorageBackend>(
+    Extension(args): Extension<ApiArgsFullNode<B>>,
+) -> Result<impl IntoResponse, Infallible> {
+    let mut exporter = Exporter::default();
+
+    export_node_metrics(&mut exporter, &args);
+    export_tangle(&mut exporter, &args);
+    export_peers(&mut exporter, &args);
+    export_pruning(&mut exporter, &args);
+    export_storage(&mut exporter, &args);
+
+    let mut response = Response::new(exporter.finish());
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(METRICS_CONTENT_TYPE));
+
+    Ok(response)
+}
+
+fn export_node_metrics<B: StorageBackend>(exporter: &mut Exporter, args: &ApiArgsFullNode<B>) {
+    let metrics = &args.node_metrics;
+
+    exporter.family(
+        "bee_invalid_packets_total",
+        "counter",
+        "Number of invalid packets received.",
+    );
+    exporter.sample("bee_invalid_packets_total", &[], metrics.invalid_packets());
+
+    exporter.family(
+        "bee_gossip_received_total",
+        "counter",
+        "Number of gossip messages received, per message type.",
+    );
+    for (kind, value) in [
+        ("milestone_request", metrics.milestone_requests_received()),
+        ("block", metrics.blocks_received()),
+        ("block_request", metrics.block_requests_received()),
+        ("heartbeat", metrics.heartbeats_received()),
+        ("milestone_cone_request", metrics.milestone_cone_requests_received()),
+        ("milestone_cone_response", metrics.milestone_cone_responses_received()),
+    ] {
+        exporter.sample("bee_gossip_received_total", &[("type", kind)], value);
+    }
+
+    exporter.family(
+        "bee_gossip_sent_total",
+        "counter",
+        "Number of gossip messages sent, per message type.",
+    );
+    for (kind, value) in [
+        ("milestone_request", metrics.milestone_requests_sent()),
+        ("block", metrics.blocks_sent()),
+        ("block_request", metrics.block_requests_sent()),
+        ("heartbeat", metrics.heartbeats_sent()),
+        ("milestone_cone_request", metrics.milestone_cone_requests_sent()),
+        ("milestone_cone_response", metrics.milestone_cone_responses_sent()),
+    ] {
+        exporter.sample("bee_gossip_sent_total", &[("type", kind)], value);
+    }
+
+    for (name, help, value) in [
+        (
+            "bee_invalid_blocks_total",
+            "Number of invalid blocks.",
+            metrics.invalid_blocks(),
+        ),
+        ("bee_new_blocks_total", "Number of new blocks.", metrics.new_blocks()),
+        (
+            "bee_known_blocks_total",
+            "Number of already known blocks.",
+            metrics.known_blocks(),
+        ),
+        (
+            "bee_referenced_blocks_total",
+            "Number of blocks referenced by a milestone.",
+            metrics.referenced_blocks(),
+        ),
+        (
+            "bee_included_blocks_total",
+            "Number of blocks whose transaction was included in the ledger.",
+            metrics.included_blocks(),
+        ),
+        (
+            "bee_excluded_no_transaction_blocks_total",
+            "Number of blocks excluded from the ledger because they have no transaction.",
+            metrics.excluded_no_transaction_blocks(),
+        ),
+        (
+            "bee_created_outputs_total",
+            "Number of created outputs.",
+            metrics.created_outputs(),
+        ),
+        (
+            "bee_consumed_outputs_total",
+            "Number of consumed outputs.",
+            metrics.consumed_outputs(),
+        ),
+        ("bee_receipts_total", "Number of receipts.", metrics.receipts()),
+        ("bee_snapshots_total", "Number of snapshots.", metrics.snapshots()),
+        ("bee_prunings_total", "Number of prunings.", metrics.prunings()),
+    ] {
+        exporter.family(name, "counter", help);
+        exporter.sample(name, &[], value);
+    }
+
+    exporter.family(
+        "bee_excluded_conflicting_blocks_total",
+        "counter",
+        "Number of blocks excluded from the ledger because conflicting, per conflict reason.",
+    );
+    for (reason, value) in metrics.excluded_conflicting_blocks_by_reason() {
+        exporter.sample(
+            "bee_excluded_conflicting_blocks_total",
+            &[("reason", conflict_reason_label(reason))],
+            value,
+        );
+    }
+
+    exporter.family("bee_payloads_total", "counter", "Number of payloads, per payload kind.");
+    for (kind, value) in [
+        ("transaction", metrics.transaction_payloads()),
+        ("milestone", metrics.milestone_payloads()),
+        ("tagged_data", metrics.tagged_data_payloads()),
+    ] {
+        exporter.sample("bee_payloads_total", &[("kind", kind)], value);
+    }
+
+    exporter.family(
+        "bee_blocks_average_latency",
+        "gauge",
+        "Average latency of the processed blocks.",
+    );
+    exporter.sample("bee_blocks_average_latency", &[], metrics.blocks_average_latency());
+
+    let throughput = args.throughput_metrics.latest();
+
+    for (name, help, value) in [
+        (
+            "bee_blocks_per_second",
+            "Number of new blocks per second during the last milestone interval.",
+            throughput.as_ref().map_or(0.0, |t| t.blocks_per_second),
+        ),
+        (
+            "bee_referenced_blocks_per_second",
+            "Number of referenced blocks per second during the last milestone interval.",
+            throughput.as_ref().map_or(0.0, |t| t.referenced_blocks_per_second),
+        ),
+        (
+            "bee_referenced_rate",
+            "Percentage of new blocks referenced during the last milestone interval.",
+            throughput.as_ref().map_or(0.0, |t| t.referenced_rate),
+        ),
+    ] {
+        exporter.family(name, "gauge", help);
+        exporter.sample(name, &[], value);
+    }
+}
+
+fn export_tangle<B: StorageBackend>(exporter: &mut Exporter, args: &ApiArgsFullNode<B>) {
+    exporter.family(
+        "bee_milestone_index",
+        "gauge",
+        "Milestone indexes of the tangle, per kind.",
+    );
+    for (kind, index) in [
+        ("latest", args.tangle.get_latest_milestone_index()),
+        ("solid", args.tangle.get_solid_milestone_index()),
+        ("confirmed", args.tangle.get_confirmed_milestone_index()),
+        ("snapshot", args.tangle.get_snapshot_index()),
+        ("pruning", args.tangle.get_pruning_index()),
+        ("entry_point", args.tangle.get_entry_point_index()),
+    ] {
+        exporter.sample("bee_milestone_index", &[("kind", kind)], *index);
+    }
+
+    exporter.family("bee_synced", "gauge", "Whether the node is synced.");
+    exporter.sample("bee_synced", &[], args.tangle.is_synced() as u8);
+
+    exporter.family("bee_requested_blocks", "gauge", "Number of blocks currently requested.");
+    exporter.sample("bee_requested_blocks", &[], args.requested_blocks.len());
+
+    exporter.family(
+        "bee_requested_milestones",
+        "gauge",
+        "Number of milestones currently requested.",
+    );
+    exporter.sample("bee_requested_milestones", &[], args.requested_milestones.len());
+
+    let cache = args.tangle.cache_metrics();
+
+    exporter.family(
+        "bee_tangle_cache_hits_total",
+        "counter",
+        "Number of tangle lookups served by the cache, per kind.",
+    );
+    for (kind, hits) in [
+        ("block", cache.block_hits()),
+        ("metadata", cache.metadata_hits()),
+        ("children", cache.children_hits()),
+    ] {
+        exporter.sample("bee_tangle_cache_hits_total", &[("kind", kind)], hits);
+    }
+
+    exporter.family(
+        "bee_tangle_cache_misses_total",
+        "counter",
+        "Number of tangle lookups that went to the storage, per kind.",
+    );
+    for (kind, misses) in [
+        ("block", cache.block_misses()),
+        ("metadata", cache.metadata_misses()),
+        ("children", cache.children_misses()),
+    ] {
+        exporter.sample("bee_tangle_cache_misses_total", &[("kind", kind)], misses);
+    }
+}
+
+fn export_peers<B: StorageBackend>(exporter: &mut Exporter, args: &ApiArgsFullNode<B>) {
+    exporter.family("bee_peers", "gauge", "Number of peers, per state.");
+    exporter.sample("bee_peers", &[("state", "known")], args.peer_manager.len());
+    exporter.sample(
+        "bee_peers",
+        &[("state", "connected")],
+        args.peer_manager.connected_peers(),
+    );
+    exporter.sample("bee_peers", &[("state", "synced")], args.peer_manager.synced_peers());
+
+    let peers = args.peer_manager.get_all();
+
+    exporter.family("bee_peer_connected", "gauge", "Whether a peer is connected.");
+    for peer in peers.iter() {
+        let id = peer.id().to_string();
+        exporter.sample(
+            "bee_peer_connected",
+            &[("peer_id", &id), ("alias", peer.alias())],
+            peer.is_connected() as u8,
+        );
+    }
+
+    exporter.family(
+        "bee_peer_solid_milestone_index",
+        "gauge",
+        "Solid milestone index reported by a peer.",
+    );
+    for peer in peers.iter() {
+        let id = peer.id().to_string();
+        exporter.sample(
+            "bee_peer_solid_milestone_index",
+            &[("peer_id", &id), ("alias", peer.alias())],
+            *peer.solid_milestone_index(),
+        );
+    }
+
+    let counters: [(&str, &str, fn(&Peer) -> u64); 4] = [
+        (
+            "bee_peer_invalid_packets_total",
+            "Number of invalid packets received from a peer.",
+            |peer| peer.metrics().invalid_packets(),
+        ),
+        (
+            "bee_peer_invalid_blocks_total",
+            "Number of invalid blocks received from a peer.",
+            |peer| peer.metrics().invalid_blocks(),
+        ),
+        (
+            "bee_peer_new_blocks_total",
+            "Number of new blocks received from a peer.",
+            |peer| peer.metrics().new_blocks(),
+        ),
+        (
+            "bee_peer_known_blocks_total",
+            "Number of already known blocks received from a peer.",
+            |peer| peer.metrics().known_blocks(),
+        ),
+    ];
+
+    for (name, help, value) in counters {
+        exporter.family(name, "counter", help);
+        for peer in peers.iter() {
+            let id = peer.id().to_string();
+            exporter.sample(name, &[("peer_id", &id), ("alias", peer.alias())], value(peer));
+        }
+    }
+
+    exporter.family(
+        "bee_peer_gossip_received_total",
+        "counter",
+        "Number of gossip messages received from a peer, per message type.",
+    );
+    for peer in peers.iter() {
+        let id = peer.id().to_string();
+        let metrics = peer.metrics();
+        for (kind, value) in [
+            ("milestone_request", metrics.milestone_requests_received()),
+            ("block", metrics.blocks_received()),
+            ("block_request", metrics.block_requests_received()),
+            ("heartbeat", metrics.heartbeats_received()),
+            ("milestone_cone_request", metrics.milestone_cone_requests_received()),
+            ("milestone_cone_response", metrics.milestone_cone_responses_received()),
+        ] {
+            exporter.sample(
+                "bee_peer_gossip_received_total",
+                &[("peer_id", &id), ("alias", peer.alias()), ("type", kind)],
+                value,
+            );
+        }
+    }
+
+    exporter.family(
+        "bee_peer_gossip_sent_total",
+        "counter",
+        "Number of gossip messages sent to a peer, per message type.",
+    );
+    for peer in peers.iter() {
+        let id = peer.id().to_string();
+        let metrics = peer.metrics();
+        for (kind, value) in [
+            ("milestone_request", metrics.milestone_requests_sent()),
+            ("block", metrics.blocks_sent()),
+            ("block_request", metrics.block_requests_sent()),
+            ("heartbeat", metrics.heartbeats_sent()),
+            ("milestone_cone_request", metrics.milestone_cone_requests_sent()),
+            ("milestone_cone_response", metrics.milestone_cone_responses_sent()),
+        ] {
+            exporter.sample(
+                "bee_peer_gossip_sent_total",
+                &[("peer_id", &id), ("alias", peer.alias()), ("type", kind)],
+                value,
+            );
+        }
+    }
+}
+
+fn export_pruning<B: StorageBackend>(exporter: &mut Exporter, args: &ApiArgsFullNode<B>) {
+    let statistics = &args.pruning_statistics;
+
+    exporter.family("bee_pruned_total", "counter", "Number of pruned items, per kind.");
+    for (kind, value) in [
+        ("block", statistics.blocks()),
+        ("edge", statistics.edges()),
+        ("receipt", statistics.receipts()),
+    ] {
+        exporter.sample("bee_pruned_total", &[("kind", kind)], value);
+    }
+
+    exporter.family(
+        "bee_solid_entry_points",
+        "gauge",
+        "Number of solid entry points left by the latest pruning.",
+    );
+    exporter.sample("bee_solid_entry_points", &[], statistics.solid_entry_points());
+
+    // Nothing to report until a milestone was pruned.
+    if let Some(timings) = statistics.latest_timings() {
+        exporter.family(
+            "bee_pruning_step_duration_seconds",
+            "gauge",
+            "Time spent pruning the latest milestone, per step.",
+        );
+        for (step, duration) in timings {
+            exporter.sample(
+                "bee_pruning_step_duration_seconds",
+                &[("step", step)],
+                duration.as_secs_f64(),
+            );
+        }
+    }
+}
+
+fn export_storage<B: StorageBackend>(exporter: &mut Exporter, args: &ApiArgsFullNode<B>) {
+    // Not all backends are able to report their size.
+    if let Ok(Some(size)) = args.storage.size() {
+        exporter.family("bee_database_size_bytes", "gauge", "Size of the database.");
+        exporter.sample("bee_database_size_bytes", &[], size);
+    }
+}
+
+fn conflict_reason_label(reason: ConflictReason) -> &'static str {
+    match reason {
+        ConflictReason::None => "none",
+        ConflictReason::InputUtxoAlreadySpent => "input_utxo_already_spent",
+        ConflictReason::InputUtxoAlreadySpentInThisMilestone => "input_utxo_already_spent_in_this_milestone",
+        ConflictReason::InputUtxoNotFound => "input_utxo_not_found",
+        ConflictReason::CreatedConsumedAmountMismatch => "created_consumed_amount_mismatch",
+        ConflictReason::InvalidSignature => "invalid_signature",
+        ConflictReason::TimelockNotExpired => "timelock_not_expired",
+        ConflictReason::InvalidNativeTokens => "invalid_native_tokens",
+        ConflictReason::StorageDepositReturnUnfulfilled => "storage_deposit_return_unfulfilled",
+        ConflictReason::InvalidUnlock => "invalid_unlock",
+        ConflictReason::InputsCommitmentsMismatch => "inputs_commitments_mismatch",
+        ConflictReason::UnverifiedSender => "unverified_sender",
+        ConflictReason::InvalidChainStateTransition => "invalid_chain_state_transition",
+        ConflictReason::SemanticValidationFailed => "semantic_validation_failed",
+    }
+}
+
+/// Writes metric families in the Prometheus text exposition format.
+#[derive(Default)]
+struct Exporter {
+    buffer: String,
+}
+
+impl Exporter {
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        // Writing to a `String` can't fail.
+        let _ = writeln!(self.buffer, "# HELP {} {}", name, help);
+        let _ = writeln!(self.buffer, "# TYPE {} {}", name, kind);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.buffer.push_str(name);
+
+        if !labels.is_empty() {
+            self.buffer.push('{');
+            for (i, (label, value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(',');
+                }
+                self.buffer.push_str(label);
+                self.buffer.push_str("=\"");
+                escape_label_value(&mut self.buffer, value);
+                self.buffer.push('"');
+            }
+            self.buffer.push('}');
+        }
+
+        // Writing to a `String` can't fail.
+        let _ = writeln!(self.buffer, " {}", value);
+    }
+
+    fn finish(self) -> String {
+        self.buffer
+    }
+}
+
+fn escape_label_value(buffer: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\\' => buffer.push_str("\\\\"),
+            '"' => buffer.push_str("\\\""),
+            '\n' => buffer.push_str("\\n"),
+            c => buffer.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_families_and_samples() {
+        let mut exporter = Exporter::default();
+
+        exporter.family("bee_synced", "gauge", "Whether the node is synced.");
+        exporter.sample("bee_synced", &[], 1);
+        exporter.family("bee_pruned_total", "counter", "Number of pruned items, per kind.");
+        exporter.sample("bee_pruned_total", &[("kind", "block")], 42);
+        exporter.sample("bee_pruned_total", &[("kind", "edge"), ("step", "commit")], 0.5);
+
+        assert_eq!(
+            exporter.finish(),
+            "# HELP bee_synced Whether the node is synced.\n\
+             # TYPE bee_synced gauge\n\
+             bee_synced 1\n\
+             # HELP bee_pruned_total Number of pruned items, per kind.\n\
+             # TYPE bee_pruned_total counter\n\
+             bee_pruned_total{kind=\"block\"} 42\n\
+             bee_pruned_total{kind=\"edge\",step=\"commit\"} 0.5\n"
+        );
+    }
+
+    #[test]
+    fn escape_label_values() {
+        let mut exporter = Exporter::default();
+
+        exporter.sample("bee_peer_connected", &[("alias", "a \"quoted\"\\path\nnext")], 1);
+
+        assert_eq!(
+            exporter.finish(),
+            "bee_peer_connected{alias=\"a \\\"quoted\\\"\\\\path\\nnext\"} 1\n"
+        );
+    }
+
+    #[test]
+    fn conflict_reason_labels_are_unique() {
+        let labels = (0..=u8::MAX)
+            .filter_map(|reason| ConflictReason::try_from(reason).ok())
+            .map(conflict_reason_label)
+            .collect::<Vec<_>>();
+        let unique = labels.iter().collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(labels.len(), unique.len());
+    }
+}
@@ -3,11 +3,15 @@
 
 pub mod api;
 pub mod health;
+pub mod metrics;
 
 use axum::Router;
 
 use crate::endpoints::storage::StorageBackend;
 
 pub(crate) fn filter_all<B: StorageBackend>() -> Router {
-    Router::new().merge(api::filter::<B>()).merge(health::filter::<B>())
+    Router::new()
+        .merge(api::filter::<B>())
+        .merge(health::filter::<B>())
+        .merge(metrics::filter::<B>())
 }
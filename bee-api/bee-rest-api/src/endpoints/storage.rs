@@ -1,19 +1,30 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_block::{address::Ed25519Address, output::OutputId, payload::milestone::MilestoneIndex};
-use bee_ledger::types::{ConsumedOutput, OutputDiff, Receipt};
+use bee_block::{
+    address::Ed25519Address, output::OutputId, payload::milestone::MilestoneIndex, semantic::ConflictDiagnostic,
+    BlockId,
+};
+use bee_ledger::types::{
+    AddressHistoryEntry, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput, LedgerIndex, OutputDiff, Receipt,
+};
 use bee_storage::{
     access::{AsIterator, Fetch},
     backend,
 };
+use bee_tangle::tagged_block::{TaggedBlock, TaggedBlockPage};
 
 pub trait StorageBackend:
     backend::StorageBackend
+    + Fetch<(), AddressHistoryIndex>
+    + Fetch<(), LedgerIndex>
+    + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+    + Fetch<BlockId, ConflictDiagnostic>
     + Fetch<Ed25519Address, Vec<OutputId>>
     + Fetch<MilestoneIndex, OutputDiff>
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<OutputId, ConsumedOutput>
+    + Fetch<TaggedBlockPage, Vec<TaggedBlock>>
     + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
     + bee_protocol::workers::storage::StorageBackend
     + bee_ledger::workers::storage::StorageBackend
@@ -22,10 +33,15 @@ pub trait StorageBackend:
 
 impl<T> StorageBackend for T where
     T: backend::StorageBackend
+        + Fetch<(), AddressHistoryIndex>
+        + Fetch<(), LedgerIndex>
+        + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+        + Fetch<BlockId, ConflictDiagnostic>
         + Fetch<Ed25519Address, Vec<OutputId>>
         + Fetch<MilestoneIndex, OutputDiff>
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<OutputId, ConsumedOutput>
+        + Fetch<TaggedBlockPage, Vec<TaggedBlock>>
         + for<'a> AsIterator<'a, (MilestoneIndex, Receipt), ()>
         + bee_protocol::workers::storage::StorageBackend
         + bee_ledger::workers::storage::StorageBackend
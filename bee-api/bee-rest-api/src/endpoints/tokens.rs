@@ -0,0 +1,600 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Scoped API tokens and their persisted store.
+//!
+//! Scoped tokens are JWTs signed with the node secret that carry a `scopes` claim restricting the routes they can
+//! access. Tokens without that claim are legacy tokens issued by earlier versions of the `jwt-api` tool and keep full
+//! access to the protected routes of the scopes configured for them.
+
+use std::{
+    collections::HashSet,
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use axum::http::Method;
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::{endpoints::auth::API_AUDIENCE_CLAIM, types::dtos::TokenDto};
+
+/// Counter that makes token identifiers unique even if they are issued within the same nanosecond.
+static TOKEN_COUNTER: AtomicU64 = AtomicU64::new(0);
+/// Minimum time between two checks of the token store file for changes made by another process.
+const TOKEN_STORE_RELOAD_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Errors related to API tokens.
+#[derive(Debug, thiserror::Error)]
+pub enum TokenError {
+    #[error("unknown scope: {0}")]
+    UnknownScope(String),
+    #[error("a token requires at least one scope")]
+    NoScope,
+    #[error("token has expired")]
+    Expired,
+    #[error("invalid token: {0}")]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+    #[error("token store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid token store: {0}")]
+    Store(#[from] serde_json::Error),
+    #[error("unknown token: {0}")]
+    UnknownToken(String),
+}
+
+/// The scopes an API token can be granted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    /// Read access to the node, the tangle and the ledger.
+    Read,
+    /// Submission of blocks.
+    Submit,
+    /// Management of peers.
+    Peers,
+    /// Access to the debug plugin routes.
+    Debug,
+    /// Control of the node, including the management of API tokens.
+    Control,
+}
+
+impl Scope {
+    /// All the existing scopes.
+    pub const ALL: [Scope; 5] = [Scope::Read, Scope::Submit, Scope::Peers, Scope::Debug, Scope::Control];
+
+    /// Returns the name of the scope.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Submit => "submit",
+            Scope::Peers => "peers",
+            Scope::Debug => "debug",
+            Scope::Control => "control",
+        }
+    }
+
+    /// Returns the scope required to access a route.
+    pub fn of_route(method: &Method, path: &str) -> Scope {
+        if path.starts_with("/api/plugins/debug") {
+            Scope::Debug
+        } else if path.starts_with("/api/v2/peers") {
+            Scope::Peers
+        } else if path.starts_with("/api/v2/control") || path.starts_with("/api/v2/tokens") {
+            Scope::Control
+        } else if method == Method::GET || method == Method::HEAD {
+            Scope::Read
+        } else if method == Method::POST && path.trim_end_matches('/') == "/api/v2/blocks" {
+            Scope::Submit
+        } else {
+            Scope::Control
+        }
+    }
+
+    /// Parses a comma separated list of scopes.
+    pub fn parse_list(list: &str) -> Result<Vec<Scope>, TokenError> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(Scope::from_str)
+            .collect()
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scope::ALL
+            .iter()
+            .copied()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| TokenError::UnknownScope(s.to_owned()))
+    }
+}
+
+/// The claims of a scoped API token.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApiClaims {
+    /// Issuer, the node identifier.
+    pub iss: String,
+    /// Subject, the JWT salt of the node.
+    pub sub: String,
+    /// Audience, always `api`.
+    pub aud: String,
+    /// Time from which the token is valid, in seconds since the Unix epoch.
+    pub nbf: u64,
+    /// Time at which the token expires, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u64>,
+    /// Identifier of the token.
+    pub jti: String,
+    /// Scopes granted to the token.
+    pub scopes: Vec<Scope>,
+}
+
+impl ApiClaims {
+    /// Returns whether the token grants the given scope.
+    pub fn allows(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+/// The record of an issued API token, as kept in the token store.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenRecord {
+    /// Identifier of the token.
+    pub id: String,
+    /// Scopes granted to the token.
+    pub scopes: Vec<Scope>,
+    /// Time at which the token was issued, in seconds since the Unix epoch.
+    pub issued_at: u64,
+    /// Time at which the token expires, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Whether the token has been revoked.
+    #[serde(default)]
+    pub revoked: bool,
+}
+
+impl From<&TokenRecord> for TokenDto {
+    fn from(record: &TokenRecord) -> Self {
+        Self {
+            id: record.id.clone(),
+            scopes: record.scopes.iter().map(ToString::to_string).collect(),
+            issued_at: record.issued_at,
+            expires_at: record.expires_at,
+            revoked: record.revoked,
+        }
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the Unix epoch")
+}
+
+/// Issues a new scoped API token and returns its record together with the encoded JWT.
+pub fn issue_token(
+    issuer: &str,
+    jwt_salt: &str,
+    secret: &[u8],
+    scopes: &[Scope],
+    expires_in: Option<Duration>,
+) -> Result<(TokenRecord, String), TokenError> {
+    if scopes.is_empty() {
+        return Err(TokenError::NoScope);
+    }
+
+    let mut scopes = scopes.to_vec();
+    scopes.sort_unstable();
+    scopes.dedup();
+
+    let now = now();
+    let id = {
+        let mut hasher = Blake2b256::new();
+        hasher.update(issuer.as_bytes());
+        hasher.update(now.as_nanos().to_le_bytes());
+        hasher.update(TOKEN_COUNTER.fetch_add(1, Ordering::Relaxed).to_le_bytes());
+        hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+    };
+    let issued_at = now.as_secs();
+    let expires_at = expires_in.map(|expires_in| issued_at + expires_in.as_secs());
+
+    let claims = ApiClaims {
+        iss: issuer.to_owned(),
+        sub: jwt_salt.to_owned(),
+        aud: API_AUDIENCE_CLAIM.to_owned(),
+        nbf: issued_at,
+        exp: expires_at,
+        jti: id.clone(),
+        scopes: scopes.clone(),
+    };
+    let token = jsonwebtoken::encode(&Header::default(), &claims, &EncodingKey::from_secret(secret))?;
+
+    Ok((
+        TokenRecord {
+            id,
+            scopes,
+            issued_at,
+            expires_at,
+            revoked: false,
+        },
+        token,
+    ))
+}
+
+/// Decodes a scoped API token and checks its signature, issuer, subject, audience, validity start and expiry.
+pub fn decode_token(token: &str, issuer: &str, jwt_salt: &str, secret: &[u8]) -> Result<ApiClaims, TokenError> {
+    let mut validation = Validation {
+        // The expiry is optional, so it is checked below.
+        validate_exp: false,
+        validate_nbf: true,
+        iss: Some(issuer.to_owned()),
+        sub: Some(jwt_salt.to_owned()),
+        ..Validation::default()
+    };
+    validation.set_audience(&[API_AUDIENCE_CLAIM]);
+
+    let claims = jsonwebtoken::decode::<ApiClaims>(token, &DecodingKey::from_secret(secret), &validation)?.claims;
+
+    if matches!(claims.exp, Some(exp) if exp <= now().as_secs()) {
+        return Err(TokenError::Expired);
+    }
+
+    Ok(claims)
+}
+
+/// The revoked tokens of a store, as of a version of its file.
+struct Revocations {
+    ids: HashSet<String>,
+    version: Option<(SystemTime, u64)>,
+    checked_at: Instant,
+}
+
+impl Revocations {
+    fn load(path: &Path) -> Result<Self, TokenError> {
+        // The version is taken first so that a change made while reading is picked up by the next check.
+        let version = file_version(path);
+        let ids = read_records(path)?
+            .into_iter()
+            .filter(|record| record.revoked)
+            .map(|record| record.id)
+            .collect();
+
+        Ok(Self {
+            ids,
+            version,
+            checked_at: Instant::now(),
+        })
+    }
+}
+
+/// A persisted list of issued API tokens that keeps track of revocations.
+///
+/// Revocations made by another process, like the `jwt-api` tool, are picked up when the file changes.
+pub struct TokenStore {
+    path: PathBuf,
+    reload_interval: Duration,
+    revocations: RwLock<Revocations>,
+}
+
+impl TokenStore {
+    /// Opens the token store at the given path. A missing file is treated as an empty store.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, TokenError> {
+        let path = path.as_ref().to_path_buf();
+        let revocations = Revocations::load(&path)?;
+
+        Ok(Self {
+            path,
+            reload_interval: TOKEN_STORE_RELOAD_INTERVAL,
+            revocations: RwLock::new(revocations),
+        })
+    }
+
+    /// Returns all the tokens of the store.
+    pub fn tokens(&self) -> Result<Vec<TokenRecord>, TokenError> {
+        read_records(&self.path)
+    }
+
+    /// Adds the record of a newly issued token to the store.
+    pub fn insert(&self, record: TokenRecord) -> Result<(), TokenError> {
+        let mut revocations = self.revocations.write().expect("poisoned lock");
+        let mut records = read_records(&self.path)?;
+
+        records.push(record);
+        write_records(&self.path, &records)?;
+        *revocations = Revocations::load(&self.path)?;
+
+        Ok(())
+    }
+
+    /// Revokes a token of the store.
+    pub fn revoke(&self, id: &str) -> Result<TokenRecord, TokenError> {
+        let mut revocations = self.revocations.write().expect("poisoned lock");
+        let mut records = read_records(&self.path)?;
+
+        let record = records
+            .iter_mut()
+            .find(|record| record.id == id)
+            .ok_or_else(|| TokenError::UnknownToken(id.to_owned()))?;
+        record.revoked = true;
+        let record = record.clone();
+
+        write_records(&self.path, &records)?;
+        *revocations = Revocations::load(&self.path)?;
+
+        Ok(record)
+    }
+
+    /// Returns whether a token has been revoked, reloading the revocations if the file changed.
+    pub fn is_revoked(&self, id: &str) -> bool {
+        {
+            let revocations = self.revocations.read().expect("poisoned lock");
+
+            if revocations.checked_at.elapsed() < self.reload_interval {
+                return revocations.ids.contains(id);
+            }
+        }
+
+        let mut revocations = self.revocations.write().expect("poisoned lock");
+
+        if revocations.checked_at.elapsed() >= self.reload_interval {
+            if file_version(&self.path) == revocations.version {
+                revocations.checked_at = Instant::now();
+            } else {
+                match Revocations::load(&self.path) {
+                    Ok(reloaded) => *revocations = reloaded,
+                    // Known revocations are kept, the file is checked again later.
+                    Err(e) => {
+                        warn!("Reloading the token store failed: {}.", e);
+                        revocations.checked_at = Instant::now();
+                    }
+                }
+            }
+        }
+
+        revocations.ids.contains(id)
+    }
+}
+
+// Identifies a version of the file by its modification time and length, the latter changes with every revocation.
+fn file_version(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+fn read_records(path: &Path) -> Result<Vec<TokenRecord>, TokenError> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_records(path: &Path, records: &[TokenRecord]) -> Result<(), TokenError> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file first so that a crash can not leave a truncated store behind.
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, serde_json::to_vec_pretty(records)?)?;
+    fs::rename(&tmp, path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "12D3KooWJWEKvSFbben74C7H4YtKjhPMTDxd7gP6zxWSUEeRBdy1";
+    const SALT: &str = "Bee";
+    const SECRET: &[u8] = b"secret";
+
+    fn store_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "bee_tokens_{}_{}_{}.json",
+            name,
+            std::process::id(),
+            now().as_nanos()
+        ))
+    }
+
+    fn record(scopes: &[Scope]) -> TokenRecord {
+        issue_token(ISSUER, SALT, SECRET, scopes, None).unwrap().0
+    }
+
+    #[test]
+    fn issue_and_decode_token() {
+        let (record, token) = issue_token(
+            ISSUER,
+            SALT,
+            SECRET,
+            &[Scope::Control, Scope::Read, Scope::Control],
+            Some(Duration::from_secs(3600)),
+        )
+        .unwrap();
+
+        assert_eq!(record.scopes, vec![Scope::Read, Scope::Control]);
+        assert_eq!(record.expires_at, Some(record.issued_at + 3600));
+        assert!(!record.revoked);
+
+        let claims = decode_token(&token, ISSUER, SALT, SECRET).unwrap();
+
+        assert_eq!(claims.jti, record.id);
+        assert_eq!(claims.scopes, record.scopes);
+        assert_eq!(claims.nbf, record.issued_at);
+        assert_eq!(claims.exp, record.expires_at);
+        assert!(claims.allows(Scope::Read));
+        assert!(!claims.allows(Scope::Submit));
+    }
+
+    #[test]
+    fn issued_tokens_are_unique() {
+        assert_ne!(record(&[Scope::Read]).id, record(&[Scope::Read]).id);
+    }
+
+    #[test]
+    fn issue_token_without_scope() {
+        assert!(matches!(
+            issue_token(ISSUER, SALT, SECRET, &[], None),
+            Err(TokenError::NoScope)
+        ));
+    }
+
+    #[test]
+    fn decode_token_of_another_node() {
+        let (_, token) = issue_token(ISSUER, SALT, SECRET, &[Scope::Read], None).unwrap();
+
+        assert!(matches!(
+            decode_token(&token, ISSUER, SALT, b"another secret"),
+            Err(TokenError::Jwt(_))
+        ));
+        assert!(matches!(
+            decode_token(&token, "another issuer", SALT, SECRET),
+            Err(TokenError::Jwt(_))
+        ));
+        assert!(matches!(
+            decode_token(&token, ISSUER, "another salt", SECRET),
+            Err(TokenError::Jwt(_))
+        ));
+    }
+
+    #[test]
+    fn decode_expired_token() {
+        let (_, token) = issue_token(ISSUER, SALT, SECRET, &[Scope::Read], Some(Duration::ZERO)).unwrap();
+
+        assert!(matches!(
+            decode_token(&token, ISSUER, SALT, SECRET),
+            Err(TokenError::Expired)
+        ));
+    }
+
+    #[test]
+    fn decode_token_before_its_validity() {
+        let claims = ApiClaims {
+            iss: ISSUER.to_owned(),
+            sub: SALT.to_owned(),
+            aud: API_AUDIENCE_CLAIM.to_owned(),
+            nbf: now().as_secs() + 3600,
+            exp: None,
+            jti: "0".to_owned(),
+            scopes: vec![Scope::Read],
+        };
+        let token = jsonwebtoken::encode(&Header::default(), &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+
+        assert!(matches!(
+            decode_token(&token, ISSUER, SALT, SECRET),
+            Err(TokenError::Jwt(_))
+        ));
+    }
+
+    #[test]
+    fn scope_of_route() {
+        assert_eq!(Scope::of_route(&Method::GET, "/api/v2/info"), Scope::Read);
+        assert_eq!(Scope::of_route(&Method::HEAD, "/api/v2/blocks/0x00"), Scope::Read);
+        assert_eq!(Scope::of_route(&Method::GET, "/metrics"), Scope::Read);
+        assert_eq!(Scope::of_route(&Method::POST, "/api/v2/blocks"), Scope::Submit);
+        assert_eq!(Scope::of_route(&Method::POST, "/api/v2/blocks/"), Scope::Submit);
+        assert_eq!(Scope::of_route(&Method::GET, "/api/v2/peers"), Scope::Peers);
+        assert_eq!(Scope::of_route(&Method::DELETE, "/api/v2/peers/0x00"), Scope::Peers);
+        assert_eq!(
+            Scope::of_route(&Method::POST, "/api/plugins/debug/whiteflag"),
+            Scope::Debug
+        );
+        assert_eq!(Scope::of_route(&Method::GET, "/api/v2/control/jobs/1"), Scope::Control);
+        assert_eq!(Scope::of_route(&Method::GET, "/api/v2/tokens"), Scope::Control);
+        // Unknown writes need the broadest scope.
+        assert_eq!(Scope::of_route(&Method::POST, "/api/v2/blocks/0x00"), Scope::Control);
+        assert_eq!(Scope::of_route(&Method::PUT, "/api/v2/info"), Scope::Control);
+    }
+
+    #[test]
+    fn parse_scope_list() {
+        assert_eq!(
+            Scope::parse_list("read, peers,,control").unwrap(),
+            vec![Scope::Read, Scope::Peers, Scope::Control]
+        );
+        assert!(Scope::parse_list("").unwrap().is_empty());
+        assert!(matches!(
+            Scope::parse_list("read,admin"),
+            Err(TokenError::UnknownScope(scope)) if scope == "admin"
+        ));
+    }
+
+    #[test]
+    fn revoke_token() {
+        let path = store_path("revoke");
+        let store = TokenStore::open(&path).unwrap();
+        let first = record(&[Scope::Read]);
+        let second = record(&[Scope::Submit]);
+
+        store.insert(first.clone()).unwrap();
+        store.insert(second.clone()).unwrap();
+
+        assert!(!store.is_revoked(&first.id));
+        assert!(store.revoke(&first.id).unwrap().revoked);
+        assert!(store.is_revoked(&first.id));
+        assert!(!store.is_revoked(&second.id));
+        assert!(matches!(store.revoke("unknown"), Err(TokenError::UnknownToken(_))));
+
+        let tokens = store.tokens().unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens.iter().any(|token| token.id == first.id && token.revoked));
+
+        // Revocations are persisted.
+        let reopened = TokenStore::open(&path).unwrap();
+        assert!(reopened.is_revoked(&first.id));
+        assert!(!reopened.is_revoked(&second.id));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reload_revocations_of_another_process() {
+        let path = store_path("reload");
+        let mut store = TokenStore::open(&path).unwrap();
+        let record = record(&[Scope::Read]);
+
+        store.reload_interval = Duration::ZERO;
+        store.insert(record.clone()).unwrap();
+        assert!(!store.is_revoked(&record.id));
+
+        // The `jwt-api` tool opens its own store.
+        TokenStore::open(&path).unwrap().revoke(&record.id).unwrap();
+
+        assert!(store.is_revoked(&record.id));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_missing_store() {
+        let store = TokenStore::open(store_path("missing")).unwrap();
+
+        assert!(store.tokens().unwrap().is_empty());
+        assert!(!store.is_revoked("unknown"));
+    }
+}
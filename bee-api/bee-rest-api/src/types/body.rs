@@ -1,13 +1,13 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A marker trait to represent the data that can be included into `ErrorBody`.
 pub trait BodyInner {}
 
 /// Describes the response body of a unsuccessful HTTP request.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ErrorBody<T: BodyInner> {
     pub error: T,
 }
@@ -19,7 +19,7 @@ impl<T: BodyInner> ErrorBody<T> {
 }
 
 /// Describes the default error format.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DefaultErrorResponse {
     pub code: String,
     pub message: String,
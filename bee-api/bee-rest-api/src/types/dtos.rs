@@ -1,12 +1,20 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_block::payload::milestone::option::dto::ReceiptMilestoneOptionDto;
-use bee_ledger::types::Receipt;
+use bee_block::{
+    output::OutputId, payload::milestone::option::dto::ReceiptMilestoneOptionDto, semantic::dto::ConflictDiagnosticDto,
+};
+#[cfg(feature = "peer")]
+use bee_gossip::DialFailure;
+use bee_ledger::types::{
+    AddressHistoryEntry, AddressHistoryEntryKind, ConsumedOutput, CreatedOutput, MilestoneUtxoDiff, Receipt,
+};
 #[cfg(feature = "peer")]
 use bee_protocol::types::peer::Peer;
 use serde::{Deserialize, Serialize};
 
+use crate::types::responses::{OutputMetadataResponse, OutputResponse};
+
 /// Describes a peer.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PeerDto {
@@ -19,6 +27,7 @@ pub struct PeerDto {
     pub connected: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gossip: Option<GossipDto>,
+    pub reconnect: ReconnectDto,
 }
 
 #[cfg(feature = "peer")]
@@ -60,6 +69,11 @@ impl From<&Peer> for PeerDto {
                     dropped_packets: 0,
                 },
             }),
+            reconnect: ReconnectDto {
+                failed_dials: peer.failed_dials(),
+                last_failure: peer.last_failure().map(DialFailureDto::from),
+                next_dial_at: peer.next_dial_at(),
+            },
         }
     }
 }
@@ -71,6 +85,39 @@ pub struct GossipDto {
     pub metrics: MetricsDto,
 }
 
+/// Describes the attempts to reconnect to the peer.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReconnectDto {
+    #[serde(rename = "failedDials")]
+    pub failed_dials: u32,
+    #[serde(rename = "lastFailure", skip_serializing_if = "Option::is_none")]
+    pub last_failure: Option<DialFailureDto>,
+    #[serde(rename = "nextDialAt", skip_serializing_if = "Option::is_none")]
+    pub next_dial_at: Option<u64>,
+}
+
+/// Describes why dialing the peer failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DialFailureDto {
+    #[serde(rename = "refused")]
+    Refused,
+    #[serde(rename = "unreachable")]
+    Unreachable,
+    #[serde(rename = "handshakeFailed")]
+    HandshakeFailed,
+}
+
+#[cfg(feature = "peer")]
+impl From<DialFailure> for DialFailureDto {
+    fn from(failure: DialFailure) -> Self {
+        match failure {
+            DialFailure::Refused => Self::Refused,
+            DialFailure::Unreachable => Self::Unreachable,
+            DialFailure::HandshakeFailed => Self::HandshakeFailed,
+        }
+    }
+}
+
 /// Describes the relation with the peer.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum RelationDto {
@@ -124,6 +171,157 @@ pub struct MetricsDto {
     pub dropped_packets: u64,
 }
 
+/// Describes a ban of a peer or an address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BanDto {
+    pub target: String,
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Describes an API token issued by the node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenDto {
+    pub id: String,
+    pub scopes: Vec<String>,
+    #[serde(rename = "issuedAt")]
+    pub issued_at: u64,
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    pub revoked: bool,
+}
+
+/// Describes a block of the past or future cone of another block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConeBlockDto {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    /// Number of edges between the block and the block the cone was requested for.
+    pub depth: u32,
+}
+
+/// Describes a block carrying a tagged data payload.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TaggedBlockDto {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    /// Index of the latest milestone when the block was indexed.
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+}
+
+/// Describes an entry of the history of an address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressHistoryEntryDto {
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+    #[serde(rename = "milestoneTimestamp")]
+    pub milestone_timestamp: u32,
+    /// Whether the output was created or consumed at the milestone.
+    pub consumed: bool,
+    #[serde(rename = "outputId")]
+    pub output_id: String,
+    pub amount: String,
+}
+
+impl From<&AddressHistoryEntry> for AddressHistoryEntryDto {
+    fn from(entry: &AddressHistoryEntry) -> Self {
+        Self {
+            milestone_index: *entry.milestone_index(),
+            milestone_timestamp: entry.milestone_timestamp(),
+            consumed: entry.kind() == AddressHistoryEntryKind::Consumed,
+            output_id: entry.output_id().to_string(),
+            amount: entry.amount().to_string(),
+        }
+    }
+}
+
+/// Describes the ledger changes of a milestone, along with the outputs it created and consumed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneUtxoDiffDto {
+    pub index: u32,
+    /// Outputs created by the milestone, with their metadata as of the milestone.
+    #[serde(rename = "createdOutputs")]
+    pub created_outputs: Vec<OutputResponse>,
+    /// Outputs consumed by the milestone, with their metadata as of the milestone.
+    #[serde(rename = "consumedOutputs")]
+    pub consumed_outputs: Vec<OutputResponse>,
+    #[serde(rename = "treasuryDiff", skip_serializing_if = "Option::is_none")]
+    pub treasury_diff: Option<TreasuryDiffDto>,
+}
+
+/// Describes the treasury changes of a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TreasuryDiffDto {
+    /// Identifier of the milestone that created the new treasury output.
+    #[serde(rename = "createdMilestoneId")]
+    pub created_milestone_id: String,
+    /// Identifier of the milestone that created the consumed treasury output.
+    #[serde(rename = "consumedMilestoneId")]
+    pub consumed_milestone_id: String,
+}
+
+fn utxo_diff_output_response(
+    index: u32,
+    output_id: &OutputId,
+    created_output: &CreatedOutput,
+    consumed_output: Option<&ConsumedOutput>,
+) -> OutputResponse {
+    OutputResponse {
+        metadata: OutputMetadataResponse {
+            block_id: created_output.block_id().to_string(),
+            transaction_id: output_id.transaction_id().to_string(),
+            output_index: output_id.index(),
+            is_spent: consumed_output.is_some(),
+            milestone_index_spent: consumed_output.map(|o| *o.milestone_index()),
+            milestone_timestamp_spent: consumed_output.map(|o| o.milestone_timestamp()),
+            transaction_id_spent: consumed_output.map(|o| o.target().to_string()),
+            milestone_index_booked: *created_output.milestone_index(),
+            milestone_timestamp_booked: created_output.milestone_timestamp(),
+            ledger_index: index,
+        },
+        output: created_output.inner().into(),
+    }
+}
+
+impl From<&MilestoneUtxoDiff> for MilestoneUtxoDiffDto {
+    fn from(diff: &MilestoneUtxoDiff) -> Self {
+        let index = *diff.index();
+
+        Self {
+            index,
+            created_outputs: diff
+                .created_outputs()
+                .map(|(output_id, created_output)| utxo_diff_output_response(index, output_id, created_output, None))
+                .collect(),
+            consumed_outputs: diff
+                .consumed_outputs()
+                .map(|(output_id, (created_output, consumed_output))| {
+                    utxo_diff_output_response(index, output_id, created_output, Some(consumed_output))
+                })
+                .collect(),
+            treasury_diff: diff.output_diff().treasury_diff().map(|treasury_diff| TreasuryDiffDto {
+                created_milestone_id: treasury_diff.created().to_string(),
+                consumed_milestone_id: treasury_diff.consumed().to_string(),
+            }),
+        }
+    }
+}
+
+/// Describes a block that was excluded from the ledger because it was conflicting.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConflictingBlockDto {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    #[serde(rename = "conflictReason")]
+    pub conflict_reason: u8,
+    /// Details of the conflict, if it was found by the semantic validation of the transaction.
+    #[serde(rename = "conflictDiagnostic", skip_serializing_if = "Option::is_none")]
+    pub conflict_diagnostic: Option<ConflictDiagnosticDto>,
+}
+
 /// Describes a receipt.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ReceiptDto {
@@ -7,14 +7,49 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use bee_block::{output::dto::OutputDto, payload::dto::MilestonePayloadDto, BlockDto};
+use bee_block::{
+    output::dto::OutputDto, payload::dto::MilestonePayloadDto, semantic::dto::ConflictDiagnosticDto, BlockDto,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
     body::BodyInner,
-    dtos::{LedgerInclusionStateDto, PeerDto, ReceiptDto},
+    dtos::{
+        AddressHistoryEntryDto, BanDto, ConeBlockDto, ConflictingBlockDto, LedgerInclusionStateDto, PeerDto,
+        ReceiptDto, TaggedBlockDto, TokenDto,
+    },
 };
 
+/// Response of GET /health, /health/live and /health/ready.
+/// Returns whether the node is healthy and, if not, which checks failed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HealthResponse {
+    #[serde(rename = "isHealthy")]
+    pub is_healthy: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<HealthIssueResponse>,
+}
+
+impl IntoResponse for HealthResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        let status = if self.is_healthy {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+
+        (status, Json(self)).into_response()
+    }
+}
+
+/// Returned in [`HealthResponse`].
+/// A health check that failed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HealthIssueResponse {
+    pub reason: String,
+    pub message: String,
+}
+
 /// Response of GET /api/v2/info.
 /// Returns general information about the node.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -28,6 +63,10 @@ pub struct InfoResponse {
     pub metrics: MetricsResponse,
     pub features: Vec<String>,
     pub plugins: Vec<String>,
+    #[serde(rename = "externalAddresses", default, skip_serializing_if = "Vec::is_empty")]
+    pub external_addresses: Vec<String>,
+    #[serde(rename = "pendingProtocolParameters", default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_protocol_parameters: Vec<PendingProtocolParametersResponse>,
 }
 
 impl IntoResponse for InfoResponse {
@@ -93,6 +132,15 @@ pub struct ProtocolResponse {
     pub token_supply: String,
 }
 
+/// Returned in [`InfoResponse`].
+/// Protocol parameters that are scheduled to become active at a future milestone.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PendingProtocolParametersResponse {
+    #[serde(rename = "targetMilestoneIndex")]
+    pub target_milestone_index: u32,
+    pub params: ProtocolResponse,
+}
+
 /// Returned in [`InfoResponse`].
 /// Information about the base token.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -193,6 +241,9 @@ pub struct BlockMetadataResponse {
     pub ledger_inclusion_state: Option<LedgerInclusionStateDto>,
     #[serde(rename = "conflictReason", skip_serializing_if = "Option::is_none")]
     pub conflict_reason: Option<u8>,
+    /// Details of the conflict, if the node stores conflict diagnostics.
+    #[serde(rename = "conflictDiagnostic", skip_serializing_if = "Option::is_none")]
+    pub conflict_diagnostic: Option<ConflictDiagnosticDto>,
     #[serde(rename = "shouldPromote", skip_serializing_if = "Option::is_none")]
     pub should_promote: Option<bool>,
     #[serde(rename = "shouldReattach", skip_serializing_if = "Option::is_none")]
@@ -205,6 +256,124 @@ impl IntoResponse for BlockMetadataResponse {
     }
 }
 
+/// Response of GET /api/v2/blocks/{block_id}/children.
+/// Returns a page of the children of a block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BlockChildrenResponse {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    /// Maximum number of children the storage returns, if it is limited. If `count` reaches it, there may be more
+    /// children than listed.
+    #[serde(rename = "maxResults", skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<usize>,
+    /// Number of children, over all pages.
+    pub count: usize,
+    pub children: Vec<String>,
+    /// Cursor of the next page, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl IntoResponse for BlockChildrenResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of GET /api/v2/blocks?tag={tag}.
+/// Returns a page of the blocks carrying a tagged data payload with a tag, oldest first.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BlocksByTagResponse {
+    pub tag: String,
+    pub blocks: Vec<TaggedBlockDto>,
+    /// Cursor of the next page, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl IntoResponse for BlocksByTagResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of GET /api/v2/addresses/{bech32_address}/history.
+/// Returns a page of the history of an address, oldest first.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressHistoryResponse {
+    pub address: String,
+    /// Index of the oldest milestone at which the history is complete.
+    #[serde(rename = "historyIndex")]
+    pub history_index: u32,
+    pub entries: Vec<AddressHistoryEntryDto>,
+    /// Cursor of the next page, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl IntoResponse for AddressHistoryResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of GET /api/v2/addresses/{bech32_address}/balance.
+/// Returns the balance of an address at a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressBalanceResponse {
+    pub address: String,
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+    pub balance: String,
+}
+
+impl IntoResponse for AddressBalanceResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of:
+/// * GET /api/v2/blocks/{block_id}/past-cone
+/// * GET /api/v2/blocks/{block_id}/future-cone
+/// Returns the blocks of a cone of a block, up to a depth.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BlockConeResponse {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    pub depth: u32,
+    pub blocks: Vec<ConeBlockDto>,
+    /// Whether the cone was cut short because it contains too many blocks.
+    pub truncated: bool,
+}
+
+impl IntoResponse for BlockConeResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of GET /api/v2/blocks/{block_id}/referenced-by.
+/// Returns the milestone that referenced a block and a path of parent edges from the milestone block to the block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BlockReferencedByResponse {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+    #[serde(rename = "milestoneId")]
+    pub milestone_id: String,
+    #[serde(rename = "milestoneBlockId")]
+    pub milestone_block_id: String,
+    pub path: Vec<String>,
+}
+
+impl IntoResponse for BlockReferencedByResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
 /// Response of GET /api/v2/outputs/{output_id}.
 /// Returns an output and its metadata.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -347,12 +516,85 @@ impl IntoResponse for PeerResponse {
     }
 }
 
+/// Response of GET /api/v2/peers/bans.
+/// Returns all banned peers and addresses.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BansResponse {
+    pub peers: Vec<BanDto>,
+    pub addresses: Vec<BanDto>,
+}
+
+impl IntoResponse for BansResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of:
+/// * POST /api/v2/control/database/prune
+/// * POST /api/v2/control/snapshots/create
+/// * GET /api/v2/control/jobs/{job_id}
+/// Returns the state of a background control job.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ControlJobResponse {
+    #[serde(rename = "jobId")]
+    pub job_id: u64,
+    pub kind: String,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<ControlJobProgress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Progress of a running control job, in steps.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ControlJobProgress {
+    pub done: u64,
+    pub total: u64,
+}
+
+impl IntoResponse for ControlJobResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of POST /api/v2/tokens.
+/// Returns the newly issued API token.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddTokenResponse {
+    pub jwt: String,
+    pub token: TokenDto,
+}
+
+impl IntoResponse for AddTokenResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
+/// Response of GET /api/v2/tokens.
+/// Returns all API tokens issued by the node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokensResponse {
+    pub tokens: Vec<TokenDto>,
+}
+
+impl IntoResponse for TokensResponse {
+    fn into_response(self) -> Response<BoxBody> {
+        Json(self).into_response()
+    }
+}
+
 /// Response of GET /api/plugins/debug/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WhiteFlagResponse {
     #[serde(rename = "merkleTreeHash")]
     pub merkle_tree_hash: String,
+    #[serde(rename = "conflictingBlocks", default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicting_blocks: Vec<ConflictingBlockDto>,
 }
 
 impl IntoResponse for WhiteFlagResponse {
@@ -0,0 +1,72 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{
+    address::Ed25519Address,
+    signature::{Ed25519BatchVerifier, Ed25519Signature},
+};
+use bee_test::rand::bytes::rand_bytes_array;
+use criterion::*;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+
+const BATCH_SIZES: [usize; 4] = [1, 8, 64, 256];
+
+fn signed_messages(count: usize) -> Vec<([u8; 32], Ed25519Signature, Ed25519Address)> {
+    (0..count)
+        .map(|_| {
+            let secret_key = SecretKey::from_bytes(rand_bytes_array());
+            let public_key = secret_key.public_key().to_bytes();
+            let message = rand_bytes_array();
+            let signature = Ed25519Signature::new(public_key, secret_key.sign(&message).to_bytes());
+            let address = Ed25519Address::new(Blake2b256::digest(&public_key).into());
+
+            (message, signature, address)
+        })
+        .collect()
+}
+
+fn signature_verification_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signature_verification");
+
+    for size in BATCH_SIZES {
+        let signed_messages = signed_messages(size);
+
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("individual", size),
+            &signed_messages,
+            |b, signed_messages| {
+                b.iter(|| {
+                    signed_messages
+                        .iter()
+                        .all(|(message, signature, address)| signature.is_valid(message, address).is_ok())
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("batch", size),
+            &signed_messages,
+            |b, signed_messages| {
+                b.iter(|| {
+                    let mut verifier = Ed25519BatchVerifier::with_capacity(signed_messages.len());
+
+                    for (message, signature, _) in signed_messages {
+                        verifier.queue(*message, signature.clone());
+                    }
+
+                    verifier.verify().all_valid()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, signature_verification_bench);
+criterion_main!(benches);
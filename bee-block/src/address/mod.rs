@@ -101,7 +101,7 @@ impl Address {
 
                 let Signature::Ed25519(signature) = unlock.signature();
 
-                if signature.is_valid(&context.essence_hash, ed25519_address).is_err() {
+                if context.verify_signature(signature, ed25519_address).is_err() {
                     return Err(ConflictReason::InvalidSignature);
                 }
 
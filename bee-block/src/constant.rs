@@ -1,7 +1,9 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-/// The total number of tokens in circulation.
+/// The maximum number of tokens a network can have in circulation.
+///
+/// Amounts are bounded by it syntactically, the token supply a network is configured with is checked semantically.
 pub const TOKEN_SUPPLY: u64 = 2_779_530_283_277_761;
 
 /// Protocol version currently used by the network.
@@ -20,6 +20,7 @@ use crate::{
         milestone::BinaryParametersLength, InputCount, MigratedFundsAmount, MilestoneMetadataLength,
         MilestoneOptionCount, OutputCount, ReceiptFundsCount, SignatureCount, TagLength, TaggedDataLength,
     },
+    semantic::ConflictError,
     unlock::{UnlockCount, UnlockIndex},
 };
 
@@ -47,6 +48,9 @@ pub enum Error {
     InsufficientStorageDepositAmount { amount: u64, required: u64 },
     StorageDepositReturnExceedsOutputAmount { deposit: u64, amount: u64 },
     InsufficientStorageDepositReturnAmount { deposit: u64, required: u64 },
+    InvalidBech32Hrp,
+    InvalidConflictDiagnosticKind(u8),
+    InvalidConflictReason(u8),
     InvalidBinaryParametersLength(<BinaryParametersLength as TryFrom<usize>>::Error),
     InvalidEssenceKind(u8),
     InvalidFeatureCount(<FeatureCount as TryFrom<usize>>::Error),
@@ -64,6 +68,7 @@ pub enum Error {
     InvalidMilestoneOptionKind(u8),
     InvalidMigratedFundsEntryAmount(<MigratedFundsAmount as TryFrom<u64>>::Error),
     InvalidNativeTokenCount(<NativeTokenCount as TryFrom<usize>>::Error),
+    InvalidNetworkName,
     InvalidNftIndex(<UnlockIndex as TryFrom<u16>>::Error),
     InvalidOutputAmount(<OutputAmount as TryFrom<u64>>::Error),
     InvalidOutputCount(<OutputCount as TryFrom<usize>>::Error),
@@ -76,6 +81,7 @@ pub enum Error {
     InvalidReferenceIndex(<UnlockIndex as TryFrom<u16>>::Error),
     InvalidSignature,
     InvalidSignatureKind(u8),
+    InvalidStateTransitionErrorKind(u8),
     InvalidTaggedDataLength(<TaggedDataLength as TryFrom<usize>>::Error),
     InvalidTagFeatureLength(<TagFeatureLength as TryFrom<usize>>::Error),
     InvalidTagLength(<TagLength as TryFrom<usize>>::Error),
@@ -157,6 +163,9 @@ impl fmt::Display for Error {
             Error::InvalidAddress => write!(f, "invalid address provided"),
             Error::InvalidAddressKind(k) => write!(f, "invalid address kind: {}", k),
             Error::InvalidAliasIndex(index) => write!(f, "invalid alias index: {}", index),
+            Error::InvalidBech32Hrp => write!(f, "invalid bech32 hrp"),
+            Error::InvalidConflictDiagnosticKind(k) => write!(f, "invalid conflict diagnostic kind: {k}"),
+            Error::InvalidConflictReason(r) => write!(f, "invalid conflict reason: {r}"),
             Error::InvalidBinaryParametersLength(length) => {
                 write!(f, "invalid binary parameters length: {length}")
             }
@@ -205,6 +214,7 @@ impl fmt::Display for Error {
                 write!(f, "invalid migrated funds entry amount: {amount}")
             }
             Error::InvalidNativeTokenCount(count) => write!(f, "invalid native token count: {}", count),
+            Error::InvalidNetworkName => write!(f, "invalid network name"),
             Error::InvalidNftIndex(index) => write!(f, "invalid nft index: {}", index),
             Error::InvalidOutputAmount(amount) => write!(f, "invalid output amount: {}", amount),
             Error::InvalidOutputCount(count) => write!(f, "invalid output count: {}", count),
@@ -221,6 +231,7 @@ impl fmt::Display for Error {
             Error::InvalidReferenceIndex(index) => write!(f, "invalid reference index: {}", index),
             Error::InvalidSignature => write!(f, "invalid signature provided"),
             Error::InvalidSignatureKind(k) => write!(f, "invalid signature kind: {}", k),
+            Error::InvalidStateTransitionErrorKind(k) => write!(f, "invalid state transition error kind: {k}"),
             Error::InvalidTaggedDataLength(length) => {
                 write!(f, "invalid tagged data length {}", length)
             }
@@ -339,6 +350,14 @@ impl From<CryptoError> for Error {
     }
 }
 
+impl From<ConflictError> for Error {
+    fn from(error: ConflictError) -> Self {
+        match error {
+            ConflictError::InvalidConflict(byte) => Error::InvalidConflictReason(byte),
+        }
+    }
+}
+
 impl From<Infallible> for Error {
     fn from(err: Infallible) -> Self {
         match err {}
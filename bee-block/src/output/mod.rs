@@ -103,7 +103,8 @@ pub enum Output {
 }
 
 impl Output {
-    /// Valid amounts for an [`Output`].
+    /// Syntactically valid amounts for an [`Output`], semantic validation checks them against the configured token
+    /// supply.
     pub const AMOUNT_RANGE: RangeInclusive<u64> = 1..=TOKEN_SUPPLY;
 
     /// Return the output kind of an [`Output`].
@@ -266,7 +267,7 @@ impl ByteCost for Output {
 
 /// Computes the minimum amount that a storage deposit has to match to allow creating a return [`Output`] back to the
 /// sender [`Address`].
-fn minimum_storage_deposit(config: &ByteCostConfig, address: &Address) -> u64 {
+pub(crate) fn minimum_storage_deposit(config: &ByteCostConfig, address: &Address) -> u64 {
     let address_condition = UnlockCondition::Address(AddressUnlockCondition::new(*address));
     // PANIC: This can never fail because the amount will always be within the valid range. Also, the actual value is
     // not important, we are only interested in the storage requirements of the type.
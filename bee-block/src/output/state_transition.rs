@@ -1,29 +1,50 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::semantic::ValidationContext;
+use crate::{semantic::ValidationContext, Error};
 
 ///
 #[allow(missing_docs)]
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, packable::Packable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[packable(unpack_error = Error)]
+#[packable(tag_type = u8, with_error = Error::InvalidStateTransitionErrorKind)]
 pub enum StateTransitionError {
+    #[packable(tag = 0)]
     InconsistentCreatedFoundriesCount,
+    #[packable(tag = 1)]
     InconsistentFoundrySerialNumber,
+    #[packable(tag = 2)]
     InconsistentNativeTokensFoundryCreation,
+    #[packable(tag = 3)]
     InconsistentNativeTokensFoundryDestruction,
+    #[packable(tag = 4)]
     InconsistentNativeTokensMint,
+    #[packable(tag = 5)]
     InconsistentNativeTokensTransition,
+    #[packable(tag = 6)]
     InconsistentNativeTokensMeltBurn,
+    #[packable(tag = 7)]
     IssuerNotUnlocked,
+    #[packable(tag = 8)]
     MissingAliasForFoundry,
+    #[packable(tag = 9)]
     MutatedFieldWithoutRights,
+    #[packable(tag = 10)]
     MutatedImmutableField,
+    #[packable(tag = 11)]
     NonMonotonicallyIncreasingNativeTokens,
+    #[packable(tag = 12)]
     NonZeroCreatedId,
+    #[packable(tag = 13)]
     NonZeroCreatedFoundryCounter,
+    #[packable(tag = 14)]
     NonZeroCreatedStateIndex,
+    #[packable(tag = 15)]
     UnsortedCreatedFoundries,
+    #[packable(tag = 16)]
     UnsupportedStateIndexOperation { current_state: u32, next_state: u32 },
+    #[packable(tag = 17)]
     UnsupportedStateTransition,
 }
 
@@ -21,7 +21,8 @@ pub struct TreasuryOutput {
 impl TreasuryOutput {
     /// The [`Output`](crate::output::Output) kind of a [`TreasuryOutput`].
     pub const KIND: u8 = 2;
-    /// The allowed range of the amount of a [`TreasuryOutput`].
+    /// The allowed range of the amount of a [`TreasuryOutput`], further bounded by the configured token supply when
+    /// a receipt is applied.
     pub const AMOUNT_RANGE: RangeInclusive<u64> = 0..=TOKEN_SUPPLY;
 
     /// Creates a new [`TreasuryOutput`].
@@ -26,7 +26,8 @@ pub struct StorageDepositReturnUnlockCondition {
 impl StorageDepositReturnUnlockCondition {
     /// The [`UnlockCondition`](crate::output::UnlockCondition) kind of a [`StorageDepositReturnUnlockCondition`].
     pub const KIND: u8 = 1;
-    /// Valid amounts for a [`StorageDepositReturnUnlockCondition`].
+    /// Valid amounts for a [`StorageDepositReturnUnlockCondition`], never more than the amount of the output it
+    /// belongs to.
     pub const AMOUNT_RANGE: RangeInclusive<u64> = 1..=TOKEN_SUPPLY;
 
     /// Creates a new [`StorageDepositReturnUnlockCondition`].
@@ -27,6 +27,8 @@ pub use self::{
     option::{MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption},
 };
 pub(crate) use self::{essence::MilestoneMetadataLength, option::BinaryParametersLength};
+#[cfg(feature = "std")]
+use crate::signature::Ed25519BatchVerifier;
 use crate::{signature::Signature, Error};
 
 #[derive(Debug)]
@@ -117,6 +119,21 @@ impl MilestonePayload {
 
         let essence_hash = self.essence().hash();
 
+        // Signatures are first verified as a single batch; only if it fails are they verified one by one below, to
+        // find the invalid one.
+        #[cfg(feature = "std")]
+        let batch_verified = {
+            let mut verifier = Ed25519BatchVerifier::with_capacity(self.signatures.len());
+
+            for Signature::Ed25519(signature) in self.signatures().iter() {
+                verifier.queue(essence_hash, signature.clone());
+            }
+
+            verifier.verify_all()
+        };
+        #[cfg(not(feature = "std"))]
+        let batch_verified = false;
+
         for (index, signature) in self.signatures().iter().enumerate() {
             let Signature::Ed25519(signature) = signature;
 
@@ -126,6 +143,10 @@ impl MilestonePayload {
                 )));
             }
 
+            if batch_verified {
+                continue;
+            }
+
             let ed25519_public_key = ed25519::PublicKey::try_from_bytes(*signature.public_key())
                 .map_err(MilestoneValidationError::Crypto)?;
             let ed25519_signature = ed25519::Signature::from_bytes(*signature.signature());
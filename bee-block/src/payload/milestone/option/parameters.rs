@@ -5,9 +5,14 @@
 
 use core::ops::RangeInclusive;
 
-use packable::{bounded::BoundedU16, prefix::BoxedSlicePrefix, Packable};
+use packable::{
+    bounded::BoundedU16,
+    error::{UnexpectedEOF, UnpackError},
+    prefix::BoxedSlicePrefix,
+    Packable, PackableExt,
+};
 
-use crate::{payload::milestone::MilestoneIndex, Error};
+use crate::{payload::milestone::MilestoneIndex, protocol::ProtocolParameters, Error};
 
 pub(crate) type BinaryParametersLength = BoundedU16<
     { *ParametersMilestoneOption::BINARY_PARAMETERS_LENGTH_RANGE.start() },
@@ -64,6 +69,11 @@ impl ParametersMilestoneOption {
     pub fn binary_parameters(&self) -> &[u8] {
         &self.binary_parameters
     }
+
+    /// Decodes the binary parameters of a [`ParametersMilestoneOption`] into [`ProtocolParameters`].
+    pub fn protocol_parameters(&self) -> Result<ProtocolParameters, UnpackError<Error, UnexpectedEOF>> {
+        ProtocolParameters::unpack_verified(&mut &*self.binary_parameters)
+    }
 }
 
 #[cfg(feature = "dto")]
@@ -27,7 +27,9 @@ pub struct MigratedFundsEntry {
 }
 
 impl MigratedFundsEntry {
-    /// Range of valid amounts for a [`MigratedFundsEntry`].
+    /// Range of valid amounts for a [`MigratedFundsEntry`], see
+    /// [`ReceiptMilestoneOption::verify_token_supply`](super::ReceiptMilestoneOption::verify_token_supply) for the
+    /// configured token supply.
     pub const AMOUNT_RANGE: RangeInclusive<u64> = MIGRATED_FUNDS_ENTRY_AMOUNT_MIN..=TOKEN_SUPPLY;
 
     /// Creates a new [`MigratedFundsEntry`].
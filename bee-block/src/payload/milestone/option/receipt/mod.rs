@@ -11,7 +11,11 @@ use core::ops::RangeInclusive;
 
 use hashbrown::HashMap;
 use iterator_sorted::is_unique_sorted;
-use packable::{bounded::BoundedU16, prefix::VecPrefix, Packable, PackableExt};
+use packable::{
+    bounded::{BoundedU16, InvalidBoundedU64},
+    prefix::VecPrefix,
+    Packable, PackableExt,
+};
 
 pub(crate) use self::migrated_funds_entry::MigratedFundsAmount;
 pub use self::{migrated_funds_entry::MigratedFundsEntry, tail_transaction_hash::TailTransactionHash};
@@ -96,6 +100,20 @@ impl ReceiptMilestoneOption {
     pub fn amount(&self) -> u64 {
         self.funds.iter().map(|f| f.amount()).sum()
     }
+
+    /// Verifies that neither the migrated funds nor the created treasury output of a [`ReceiptMilestoneOption`]
+    /// exceed the given token supply.
+    pub fn verify_token_supply(&self, token_supply: u64) -> Result<(), Error> {
+        verify_funds_sum(&self.funds, token_supply)?;
+
+        let treasury_amount = self.transaction().output().amount();
+
+        if treasury_amount > token_supply {
+            return Err(Error::InvalidTreasuryOutputAmount(InvalidBoundedU64(treasury_amount)));
+        }
+
+        Ok(())
+    }
 }
 
 fn verify_funds<const VERIFY: bool>(funds: &[MigratedFundsEntry]) -> Result<(), Error> {
@@ -105,7 +123,6 @@ fn verify_funds<const VERIFY: bool>(funds: &[MigratedFundsEntry]) -> Result<(),
     }
 
     let mut tail_transaction_hashes = HashMap::with_capacity(funds.len());
-    let mut funds_sum: u64 = 0;
 
     for (index, funds) in funds.iter().enumerate() {
         if let Some(previous) = tail_transaction_hashes.insert(funds.tail_transaction_hash().as_ref(), index) {
@@ -114,12 +131,20 @@ fn verify_funds<const VERIFY: bool>(funds: &[MigratedFundsEntry]) -> Result<(),
                 current: index,
             });
         }
+    }
+
+    verify_funds_sum(funds, TOKEN_SUPPLY)
+}
+
+fn verify_funds_sum(funds: &[MigratedFundsEntry], token_supply: u64) -> Result<(), Error> {
+    let mut funds_sum: u64 = 0;
 
+    for funds in funds.iter() {
         funds_sum = funds_sum
             .checked_add(funds.amount())
             .ok_or_else(|| Error::InvalidReceiptFundsSum(funds_sum as u128 + funds.amount() as u128))?;
 
-        if funds_sum > TOKEN_SUPPLY {
+        if funds_sum > token_supply {
             return Err(Error::InvalidReceiptFundsSum(funds_sum as u128));
         }
     }
@@ -154,6 +154,11 @@ impl RegularTransactionEssence {
     pub fn payload(&self) -> Option<&Payload> {
         self.payload.as_ref()
     }
+
+    /// Verifies that the outputs of a [`RegularTransactionEssence`] don't create more than the given token supply.
+    pub fn verify_token_supply(&self, token_supply: u64) -> Result<(), Error> {
+        verify_amount_sum(self.outputs.iter().map(Output::amount), token_supply)
+    }
 }
 
 fn verify_inputs<const VERIFY: bool>(inputs: &[Input]) -> Result<(), Error> {
@@ -173,27 +178,34 @@ fn verify_inputs<const VERIFY: bool>(inputs: &[Input]) -> Result<(), Error> {
     Ok(())
 }
 
-fn verify_outputs<const VERIFY: bool>(outputs: &[Output]) -> Result<(), Error> {
+fn verify_amount_sum(amounts: impl Iterator<Item = u64>, token_supply: u64) -> Result<(), Error> {
     let mut amount_sum: u64 = 0;
-    let mut native_tokens_count: u8 = 0;
-
-    for output in outputs.iter() {
-        let (amount, native_tokens) = match output {
-            Output::Basic(output) => (output.amount(), output.native_tokens()),
-            Output::Alias(output) => (output.amount(), output.native_tokens()),
-            Output::Foundry(output) => (output.amount(), output.native_tokens()),
-            Output::Nft(output) => (output.amount(), output.native_tokens()),
-            _ => return Err(Error::InvalidOutputKind(output.kind())),
-        };
 
+    for amount in amounts {
         amount_sum = amount_sum
             .checked_add(amount)
             .ok_or(Error::InvalidTransactionAmountSum(amount_sum as u128 + amount as u128))?;
 
         // Accumulated output balance must not exceed the total supply of tokens.
-        if amount_sum > TOKEN_SUPPLY {
+        if amount_sum > token_supply {
             return Err(Error::InvalidTransactionAmountSum(amount_sum as u128));
         }
+    }
+
+    Ok(())
+}
+
+fn verify_outputs<const VERIFY: bool>(outputs: &[Output]) -> Result<(), Error> {
+    let mut native_tokens_count: u8 = 0;
+
+    for output in outputs.iter() {
+        let native_tokens = match output {
+            Output::Basic(output) => output.native_tokens(),
+            Output::Alias(output) => output.native_tokens(),
+            Output::Foundry(output) => output.native_tokens(),
+            Output::Nft(output) => output.native_tokens(),
+            _ => return Err(Error::InvalidOutputKind(output.kind())),
+        };
 
         native_tokens_count = native_tokens_count.checked_add(native_tokens.len() as u8).ok_or(
             Error::InvalidTransactionNativeTokensCount(native_tokens_count as u16 + native_tokens.len() as u16),
@@ -204,7 +216,7 @@ fn verify_outputs<const VERIFY: bool>(outputs: &[Output]) -> Result<(), Error> {
         }
     }
 
-    Ok(())
+    verify_amount_sum(outputs.iter().map(Output::amount), TOKEN_SUPPLY)
 }
 
 fn verify_payload<const VERIFY: bool>(payload: &OptionalPayload) -> Result<(), Error> {
@@ -0,0 +1,173 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::string::String;
+
+use packable::{
+    bounded::BoundedU8,
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    prefix::BoxedSlicePrefix,
+    unpacker::Unpacker,
+    Packable,
+};
+
+use crate::{
+    output::{ByteCostConfig, ByteCostConfigBuilder},
+    Error,
+};
+
+type StringLength = BoundedU8<1, { u8::MAX }>;
+
+/// The parameters of the protocol, as carried in binary form by a parameters milestone option.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolParameters {
+    // The version of the protocol.
+    protocol_version: u8,
+    // The human friendly name of the network.
+    network_name: String,
+    // The HRP prefix used for Bech32 addresses in the network.
+    bech32_hrp: String,
+    // The minimum PoW score required for a block to be processed.
+    min_pow_score: u32,
+    // The below max depth parameter of the network.
+    below_max_depth: u8,
+    // The cost in tokens per virtual byte.
+    v_byte_cost: u32,
+    // The weight factor used for data fields in the outputs.
+    v_byte_factor_data: u8,
+    // The weight factor used for key fields in the outputs.
+    v_byte_factor_key: u8,
+    // The total amount of tokens of the network.
+    token_supply: u64,
+}
+
+impl ProtocolParameters {
+    /// Creates a new [`ProtocolParameters`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        protocol_version: u8,
+        network_name: String,
+        bech32_hrp: String,
+        min_pow_score: u32,
+        below_max_depth: u8,
+        v_byte_cost: u32,
+        v_byte_factor_data: u8,
+        v_byte_factor_key: u8,
+        token_supply: u64,
+    ) -> Result<Self, Error> {
+        StringLength::try_from(network_name.len()).map_err(|_| Error::InvalidNetworkName)?;
+        StringLength::try_from(bech32_hrp.len()).map_err(|_| Error::InvalidBech32Hrp)?;
+
+        Ok(Self {
+            protocol_version,
+            network_name,
+            bech32_hrp,
+            min_pow_score,
+            below_max_depth,
+            v_byte_cost,
+            v_byte_factor_data,
+            v_byte_factor_key,
+            token_supply,
+        })
+    }
+
+    /// Returns the protocol version of the [`ProtocolParameters`].
+    pub fn protocol_version(&self) -> u8 {
+        self.protocol_version
+    }
+
+    /// Returns the network name of the [`ProtocolParameters`].
+    pub fn network_name(&self) -> &str {
+        &self.network_name
+    }
+
+    /// Returns the bech32 HRP of the [`ProtocolParameters`].
+    pub fn bech32_hrp(&self) -> &str {
+        &self.bech32_hrp
+    }
+
+    /// Returns the minimum PoW score of the [`ProtocolParameters`].
+    pub fn min_pow_score(&self) -> u32 {
+        self.min_pow_score
+    }
+
+    /// Returns the below max depth of the [`ProtocolParameters`].
+    pub fn below_max_depth(&self) -> u8 {
+        self.below_max_depth
+    }
+
+    /// Returns the token supply of the [`ProtocolParameters`].
+    pub fn token_supply(&self) -> u64 {
+        self.token_supply
+    }
+
+    /// Returns the byte cost configuration (rent structure) of the [`ProtocolParameters`].
+    pub fn byte_cost(&self) -> ByteCostConfig {
+        ByteCostConfigBuilder::new()
+            .byte_cost(self.v_byte_cost.into())
+            .data_factor(self.v_byte_factor_data.into())
+            .key_factor(self.v_byte_factor_key.into())
+            .finish()
+    }
+}
+
+fn pack_string<P: Packer>(string: &str, packer: &mut P) -> Result<(), P::Error> {
+    // The length was checked when the parameters were created.
+    (string.len() as u8).pack(packer)?;
+    packer.pack_bytes(string.as_bytes())
+}
+
+fn unpack_string<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+    error: fn() -> Error,
+) -> Result<String, UnpackError<Error, U::Error>> {
+    let bytes = BoxedSlicePrefix::<u8, StringLength>::unpack::<_, VERIFY>(unpacker).map_packable_err(|_| error())?;
+
+    String::from_utf8(bytes.to_vec()).map_err(|_| UnpackError::Packable(error()))
+}
+
+impl Packable for ProtocolParameters {
+    type UnpackError = Error;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.protocol_version.pack(packer)?;
+        pack_string(&self.network_name, packer)?;
+        pack_string(&self.bech32_hrp, packer)?;
+        self.min_pow_score.pack(packer)?;
+        self.below_max_depth.pack(packer)?;
+        self.v_byte_cost.pack(packer)?;
+        self.v_byte_factor_data.pack(packer)?;
+        self.v_byte_factor_key.pack(packer)?;
+        self.token_supply.pack(packer)?;
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let protocol_version = u8::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let network_name = unpack_string::<_, VERIFY>(unpacker, || Error::InvalidNetworkName)?;
+        let bech32_hrp = unpack_string::<_, VERIFY>(unpacker, || Error::InvalidBech32Hrp)?;
+        let min_pow_score = u32::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let below_max_depth = u8::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let v_byte_cost = u32::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let v_byte_factor_data = u8::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let v_byte_factor_key = u8::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let token_supply = u64::unpack::<_, VERIFY>(unpacker).coerce()?;
+
+        Ok(Self {
+            protocol_version,
+            network_name,
+            bech32_hrp,
+            min_pow_score,
+            below_max_depth,
+            v_byte_cost,
+            v_byte_factor_data,
+            v_byte_factor_key,
+            token_supply,
+        })
+    }
+}
@@ -6,14 +6,22 @@ use core::{convert::Infallible, fmt};
 use hashbrown::{HashMap, HashSet};
 use primitive_types::U256;
 
+#[cfg(feature = "std")]
+use crate::signature::VerifiedSignatures;
 use crate::{
-    address::Address,
+    address::{Address, Ed25519Address},
+    constant::TOKEN_SUPPLY,
     error::Error,
-    output::{ChainId, FoundryId, InputsCommitment, NativeTokens, Output, OutputId, TokenId, UnlockCondition},
+    output::{
+        minimum_storage_deposit, ByteCost, ByteCostConfig, ChainId, FoundryId, InputsCommitment, NativeTokens, Output,
+        OutputId, StateTransitionError, TokenId, UnlockCondition,
+    },
     payload::{
         milestone::MilestoneIndex,
         transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
     },
+    protocol::ProtocolParameters,
+    signature::Ed25519Signature,
     unlock::Unlocks,
 };
 
@@ -108,8 +116,168 @@ impl TryFrom<u8> for ConflictReason {
     }
 }
 
+/// A detailed report of the first conflict found by the semantic validation of a transaction.
+///
+/// Indexes refer to the inputs and outputs of the transaction essence.
+#[derive(Clone, Debug, Eq, PartialEq, packable::Packable)]
+#[packable(unpack_error = Error)]
+#[packable(tag_type = u8, with_error = Error::InvalidConflictDiagnosticKind)]
+pub enum ConflictDiagnostic {
+    /// The inputs commitment of the essence doesn't match the consumed outputs.
+    #[packable(tag = 0)]
+    InputsCommitmentsMismatch {
+        /// The commitment to the consumed outputs.
+        expected: InputsCommitment,
+        /// The commitment of the essence.
+        actual: InputsCommitment,
+    },
+    /// An input could not be unlocked.
+    #[packable(tag = 1)]
+    UnlockFailed {
+        /// Index of the input.
+        input_index: u16,
+        /// Reason of the failure.
+        reason: ConflictReason,
+    },
+    /// The timelock unlock condition of an input is not expired.
+    #[packable(tag = 2)]
+    TimelockNotExpired {
+        /// Index of the input.
+        input_index: u16,
+        /// Milestone index of the timelock.
+        milestone_index: MilestoneIndex,
+        /// Timestamp of the timelock.
+        timestamp: u32,
+    },
+    /// The storage deposit returned to an address is less than required by the storage deposit return unlock
+    /// conditions of the inputs.
+    #[packable(tag = 3)]
+    StorageDepositReturnUnfulfilled {
+        /// The return address.
+        return_address: Address,
+        /// The amount to be returned.
+        expected: u64,
+        /// The amount returned.
+        actual: u64,
+    },
+    /// The sender feature of an output refers to an address that is not unlocked by the inputs.
+    #[packable(tag = 4)]
+    UnverifiedSender {
+        /// Index of the output.
+        output_index: u16,
+        /// The sender address.
+        sender: Address,
+    },
+    /// The created amount does not match the consumed amount.
+    #[packable(tag = 5)]
+    CreatedConsumedAmountMismatch {
+        /// The consumed amount.
+        consumed: u64,
+        /// The created amount.
+        created: u64,
+    },
+    /// More native tokens are created than consumed without a foundry transition.
+    #[packable(tag = 6)]
+    NativeTokensAmountMismatch {
+        /// Identifier of the native token.
+        token_id: TokenId,
+        /// The consumed amount.
+        consumed: U256,
+        /// The created amount.
+        created: U256,
+    },
+    /// The transaction holds too many distinct native tokens.
+    #[packable(tag = 7)]
+    NativeTokensCountExceeded {
+        /// The number of distinct native tokens.
+        count: u16,
+    },
+    /// The creation of a chain by an output is invalid.
+    #[packable(tag = 8)]
+    InvalidChainStateCreation {
+        /// Index of the output.
+        output_index: u16,
+        /// The state transition error.
+        error: StateTransitionError,
+    },
+    /// The transition of a chain from an input to an output is invalid.
+    #[packable(tag = 9)]
+    InvalidChainStateTransition {
+        /// Index of the input.
+        input_index: u16,
+        /// Index of the output.
+        output_index: u16,
+        /// The state transition error.
+        error: StateTransitionError,
+    },
+    /// The destruction of a chain by an input is invalid.
+    #[packable(tag = 10)]
+    InvalidChainStateDestruction {
+        /// Index of the input.
+        input_index: u16,
+        /// The state transition error.
+        error: StateTransitionError,
+    },
+    /// The amount of an output doesn't cover its byte cost.
+    #[packable(tag = 11)]
+    InsufficientStorageDeposit {
+        /// Index of the output.
+        output_index: u16,
+        /// The amount of the output.
+        amount: u64,
+        /// The byte cost of the output.
+        required: u64,
+    },
+    /// The amount of the storage deposit return unlock condition of an output is less than the minimum storage deposit
+    /// of its return address, or more than the amount of the output.
+    #[packable(tag = 12)]
+    InvalidStorageDepositReturn {
+        /// Index of the output.
+        output_index: u16,
+        /// The amount to be returned.
+        deposit: u64,
+        /// The minimum storage deposit of the return address.
+        minimum: u64,
+        /// The amount of the output.
+        maximum: u64,
+    },
+    /// The created amount exceeds the token supply.
+    #[packable(tag = 13)]
+    CreatedAmountExceedsTokenSupply {
+        /// The created amount.
+        created: u64,
+        /// The token supply.
+        token_supply: u64,
+    },
+}
+
+impl ConflictDiagnostic {
+    /// Returns the conflict reason the diagnostic details.
+    pub fn reason(&self) -> ConflictReason {
+        match self {
+            Self::InputsCommitmentsMismatch { .. } => ConflictReason::InputsCommitmentsMismatch,
+            Self::UnlockFailed { reason, .. } => *reason,
+            Self::TimelockNotExpired { .. } => ConflictReason::TimelockNotExpired,
+            Self::StorageDepositReturnUnfulfilled { .. } => ConflictReason::StorageDepositReturnUnfulfilled,
+            Self::UnverifiedSender { .. } => ConflictReason::UnverifiedSender,
+            Self::CreatedConsumedAmountMismatch { .. } => ConflictReason::CreatedConsumedAmountMismatch,
+            Self::NativeTokensAmountMismatch { .. } | Self::NativeTokensCountExceeded { .. } => {
+                ConflictReason::InvalidNativeTokens
+            }
+            Self::InvalidChainStateCreation { .. }
+            | Self::InvalidChainStateTransition { .. }
+            | Self::InvalidChainStateDestruction { .. } => ConflictReason::InvalidChainStateTransition,
+            Self::InsufficientStorageDeposit { .. }
+            | Self::InvalidStorageDepositReturn { .. }
+            | Self::CreatedAmountExceedsTokenSupply { .. } => ConflictReason::SemanticValidationFailed,
+        }
+    }
+}
+
 ///
 pub struct ValidationContext<'a> {
+    ///
+    pub transaction_id: TransactionId,
     ///
     pub essence: &'a RegularTransactionEssence,
     ///
@@ -140,6 +308,13 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    /// The token supply the created amount can't exceed.
+    pub token_supply: u64,
+    /// The byte cost configuration the storage deposits of the outputs are checked against, if any.
+    pub byte_cost: Option<ByteCostConfig>,
+    /// Signatures that have already been verified, e.g. as part of a batch.
+    #[cfg(feature = "std")]
+    pub verified_signatures: Option<&'a VerifiedSignatures>,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -153,6 +328,7 @@ impl<'a> ValidationContext<'a> {
         milestone_timestamp: u32,
     ) -> Self {
         Self {
+            transaction_id: *transaction_id,
             essence,
             unlocks,
             essence_hash: TransactionEssence::from(essence.clone()).hash(),
@@ -186,23 +362,80 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            token_supply: TOKEN_SUPPLY,
+            byte_cost: None,
+            #[cfg(feature = "std")]
+            verified_signatures: None,
         }
     }
+
+    /// Checks the created amount against the given token supply instead of the protocol-wide maximum.
+    #[must_use]
+    pub fn with_token_supply(mut self, token_supply: u64) -> Self {
+        self.token_supply = token_supply;
+        self
+    }
+
+    /// Checks the created amount and the storage deposits of the outputs against the given protocol parameters.
+    #[must_use]
+    pub fn with_protocol_parameters(mut self, parameters: &ProtocolParameters) -> Self {
+        self.token_supply = parameters.token_supply();
+        self.byte_cost = Some(parameters.byte_cost());
+        self
+    }
+
+    /// Uses already verified signatures instead of verifying them again.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_verified_signatures(mut self, verified_signatures: &'a VerifiedSignatures) -> Self {
+        self.verified_signatures = Some(verified_signatures);
+        self
+    }
+
+    /// Verifies an [`Ed25519Signature`] of the transaction essence against an [`Ed25519Address`], relying on the
+    /// already verified signatures when available.
+    pub(crate) fn verify_signature(&self, signature: &Ed25519Signature, address: &Ed25519Address) -> Result<(), Error> {
+        #[cfg(feature = "std")]
+        if let Some(valid) = self
+            .verified_signatures
+            .and_then(|verified_signatures| verified_signatures.get(&self.essence_hash, signature))
+        {
+            signature.matches_address(address)?;
+
+            return if valid { Ok(()) } else { Err(Error::InvalidSignature) };
+        }
+
+        signature.is_valid(&self.essence_hash, address)
+    }
 }
 
-///
+/// Validates a transaction against the ledger state and returns the reason of the first conflict found.
 pub fn semantic_validation(
-    mut context: ValidationContext,
+    context: ValidationContext,
     inputs: &[(OutputId, &Output)],
     unlocks: &Unlocks,
 ) -> Result<ConflictReason, Error> {
+    Ok(semantic_validation_with_diagnostic(context, inputs, unlocks)?
+        .map_or(ConflictReason::None, |diagnostic| diagnostic.reason()))
+}
+
+/// Validates a transaction against the ledger state and returns a diagnostic of the first conflict found, if any.
+pub fn semantic_validation_with_diagnostic(
+    mut context: ValidationContext,
+    inputs: &[(OutputId, &Output)],
+    unlocks: &Unlocks,
+) -> Result<Option<ConflictDiagnostic>, Error> {
     // Validation of the inputs commitment.
     if context.essence.inputs_commitment() != &context.inputs_commitment {
-        return Ok(ConflictReason::InputsCommitmentsMismatch);
+        return Ok(Some(ConflictDiagnostic::InputsCommitmentsMismatch {
+            expected: context.inputs_commitment,
+            actual: *context.essence.inputs_commitment(),
+        }));
     }
 
     // Validation of inputs.
-    for ((output_id, consumed_output), unlock) in inputs.iter().zip(unlocks.iter()) {
+    for (input_index, ((output_id, consumed_output), unlock)) in inputs.iter().zip(unlocks.iter()).enumerate() {
+        let input_index = input_index as u16;
         let (conflict, amount, consumed_native_tokens, unlock_conditions) = match consumed_output {
             Output::Basic(output) => (
                 output.unlock(output_id, unlock, inputs, &mut context),
@@ -231,15 +464,19 @@ pub fn semantic_validation(
             _ => return Err(Error::UnsupportedOutputKind(consumed_output.kind())),
         };
 
-        if let Err(conflict) = conflict {
-            return Ok(conflict);
+        if let Err(reason) = conflict {
+            return Ok(Some(ConflictDiagnostic::UnlockFailed { input_index, reason }));
         }
 
         if let Some(timelock) = unlock_conditions.timelock() {
             if (*timelock.milestone_index() != 0 && context.milestone_index < timelock.milestone_index())
                 || (timelock.timestamp() != 0 && context.milestone_timestamp < timelock.timestamp())
             {
-                return Ok(ConflictReason::TimelockNotExpired);
+                return Ok(Some(ConflictDiagnostic::TimelockNotExpired {
+                    input_index,
+                    milestone_index: timelock.milestone_index(),
+                    timestamp: timelock.timestamp(),
+                }));
             }
         }
 
@@ -269,7 +506,7 @@ pub fn semantic_validation(
     }
 
     // Validation of outputs.
-    for created_output in context.essence.outputs() {
+    for (output_index, created_output) in context.essence.outputs().iter().enumerate() {
         let (amount, created_native_tokens, features) = match created_output {
             Output::Basic(output) => {
                 if let [UnlockCondition::Address(address)] = output.unlock_conditions().as_ref() {
@@ -289,9 +526,18 @@ pub fn semantic_validation(
             _ => return Err(Error::UnsupportedOutputKind(created_output.kind())),
         };
 
+        if let Some(byte_cost) = &context.byte_cost {
+            if let Some(diagnostic) = storage_deposit_diagnostic(output_index as u16, created_output, byte_cost) {
+                return Ok(Some(diagnostic));
+            }
+        }
+
         if let Some(sender) = features.sender() {
             if !context.unlocked_addresses.contains(sender.address()) {
-                return Ok(ConflictReason::UnverifiedSender);
+                return Ok(Some(ConflictDiagnostic::UnverifiedSender {
+                    output_index: output_index as u16,
+                    sender: *sender.address(),
+                }));
             }
         }
 
@@ -314,18 +560,31 @@ pub fn semantic_validation(
 
     // Validation of storage deposit returns.
     for (return_address, return_amount) in context.storage_deposit_returns.iter() {
-        if let Some(deposit_amount) = context.simple_deposits.get(return_address) {
-            if deposit_amount < return_amount {
-                return Ok(ConflictReason::StorageDepositReturnUnfulfilled);
+        match context.simple_deposits.get(return_address) {
+            Some(deposit_amount) if deposit_amount >= return_amount => {}
+            deposit_amount => {
+                return Ok(Some(ConflictDiagnostic::StorageDepositReturnUnfulfilled {
+                    return_address: *return_address,
+                    expected: *return_amount,
+                    actual: deposit_amount.copied().unwrap_or_default(),
+                }));
             }
-        } else {
-            return Ok(ConflictReason::StorageDepositReturnUnfulfilled);
         }
     }
 
     // Validation of amounts.
+    if context.output_amount > context.token_supply {
+        return Ok(Some(ConflictDiagnostic::CreatedAmountExceedsTokenSupply {
+            created: context.output_amount,
+            token_supply: context.token_supply,
+        }));
+    }
+
     if context.input_amount != context.output_amount {
-        return Ok(ConflictReason::CreatedConsumedAmountMismatch);
+        return Ok(Some(ConflictDiagnostic::CreatedConsumedAmountMismatch {
+            consumed: context.input_amount,
+            created: context.output_amount,
+        }));
     }
 
     let mut native_token_ids = HashSet::new();
@@ -344,37 +603,288 @@ pub fn semantic_validation(
                 .output_chains
                 .contains_key(&ChainId::from(FoundryId::from(*token_id)))
         {
-            return Ok(ConflictReason::InvalidNativeTokens);
+            return Ok(Some(ConflictDiagnostic::NativeTokensAmountMismatch {
+                token_id: *token_id,
+                consumed: input_amount,
+                created: *output_amount,
+            }));
         }
 
         native_token_ids.insert(token_id);
     }
 
     if native_token_ids.len() > NativeTokens::COUNT_MAX as usize {
-        return Ok(ConflictReason::InvalidNativeTokens);
+        return Ok(Some(ConflictDiagnostic::NativeTokensCountExceeded {
+            count: native_token_ids.len() as u16,
+        }));
     }
 
     // Validation of state transitions and destructions.
     for (chain_id, current_state) in context.input_chains.iter() {
-        if Output::verify_state_transition(
-            Some(current_state),
-            context.output_chains.get(chain_id).map(core::ops::Deref::deref),
-            &context,
-        )
-        .is_err()
-        {
-            return Ok(ConflictReason::InvalidChainStateTransition);
+        let next_state = context.output_chains.get(chain_id).map(core::ops::Deref::deref);
+
+        if let Err(error) = Output::verify_state_transition(Some(current_state), next_state, &context) {
+            let input_index = input_chain_index(inputs, chain_id);
+
+            return Ok(Some(match next_state {
+                Some(_) => ConflictDiagnostic::InvalidChainStateTransition {
+                    input_index,
+                    output_index: output_chain_index(&context, chain_id),
+                    error,
+                },
+                None => ConflictDiagnostic::InvalidChainStateDestruction { input_index, error },
+            }));
         }
     }
 
     // Validation of state creations.
     for (chain_id, next_state) in context.output_chains.iter() {
-        if context.input_chains.get(chain_id).is_none()
-            && Output::verify_state_transition(None, Some(next_state), &context).is_err()
-        {
-            return Ok(ConflictReason::InvalidChainStateTransition);
+        if context.input_chains.get(chain_id).is_none() {
+            if let Err(error) = Output::verify_state_transition(None, Some(next_state), &context) {
+                return Ok(Some(ConflictDiagnostic::InvalidChainStateCreation {
+                    output_index: output_chain_index(&context, chain_id),
+                    error,
+                }));
+            }
         }
     }
 
-    Ok(ConflictReason::None)
+    Ok(None)
+}
+
+// Returns a diagnostic if the storage deposits of an output don't match the byte cost configuration.
+fn storage_deposit_diagnostic(
+    output_index: u16,
+    output: &Output,
+    byte_cost: &ByteCostConfig,
+) -> Option<ConflictDiagnostic> {
+    let required = output.byte_cost(byte_cost);
+
+    if output.amount() < required {
+        return Some(ConflictDiagnostic::InsufficientStorageDeposit {
+            output_index,
+            amount: output.amount(),
+            required,
+        });
+    }
+
+    let storage_deposit_return = output.unlock_conditions()?.storage_deposit_return()?;
+    let minimum = minimum_storage_deposit(byte_cost, storage_deposit_return.return_address());
+
+    if storage_deposit_return.amount() < minimum || storage_deposit_return.amount() > output.amount() {
+        return Some(ConflictDiagnostic::InvalidStorageDepositReturn {
+            output_index,
+            deposit: storage_deposit_return.amount(),
+            minimum,
+            maximum: output.amount(),
+        });
+    }
+
+    None
+}
+
+// Returns the index of the input of a chain, which is only called for chains of the inputs.
+fn input_chain_index(inputs: &[(OutputId, &Output)], chain_id: &ChainId) -> u16 {
+    inputs
+        .iter()
+        .position(|(output_id, output)| {
+            output
+                .chain_id()
+                .map_or(false, |id| &id.or_from_output_id(*output_id) == chain_id)
+        })
+        .unwrap_or_default() as u16
+}
+
+// Returns the index of the output of a chain, which is only called for chains of the outputs.
+fn output_chain_index(context: &ValidationContext, chain_id: &ChainId) -> u16 {
+    context
+        .essence
+        .outputs()
+        .iter()
+        .enumerate()
+        .position(|(index, output)| {
+            output.chain_id().map_or(false, |id| {
+                OutputId::new(context.transaction_id, index as u16)
+                    .map_or(false, |output_id| &id.or_from_output_id(output_id) == chain_id)
+            })
+        })
+        .unwrap_or_default() as u16
+}
+
+#[cfg(feature = "dto")]
+#[allow(missing_docs)]
+pub mod dto {
+    use alloc::string::{String, ToString};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{address::dto::AddressDto, dto::U256Dto, output::dto::TokenIdDto};
+
+    /// Describes the first conflict found by the semantic validation of a transaction.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "kind", rename_all = "camelCase")]
+    pub enum ConflictDiagnosticDto {
+        #[serde(rename_all = "camelCase")]
+        InputsCommitmentsMismatch { expected: String, actual: String },
+        #[serde(rename_all = "camelCase")]
+        UnlockFailed { input_index: u16, reason: u8 },
+        #[serde(rename_all = "camelCase")]
+        TimelockNotExpired {
+            input_index: u16,
+            milestone_index: u32,
+            timestamp: u32,
+        },
+        #[serde(rename_all = "camelCase")]
+        StorageDepositReturnUnfulfilled {
+            return_address: AddressDto,
+            expected: String,
+            actual: String,
+        },
+        #[serde(rename_all = "camelCase")]
+        UnverifiedSender { output_index: u16, sender: AddressDto },
+        #[serde(rename_all = "camelCase")]
+        CreatedConsumedAmountMismatch { consumed: String, created: String },
+        #[serde(rename_all = "camelCase")]
+        NativeTokensAmountMismatch {
+            token_id: TokenIdDto,
+            consumed: U256Dto,
+            created: U256Dto,
+        },
+        #[serde(rename_all = "camelCase")]
+        NativeTokensCountExceeded { count: u16 },
+        #[serde(rename_all = "camelCase")]
+        InvalidChainStateCreation {
+            output_index: u16,
+            error: StateTransitionError,
+        },
+        #[serde(rename_all = "camelCase")]
+        InvalidChainStateTransition {
+            input_index: u16,
+            output_index: u16,
+            error: StateTransitionError,
+        },
+        #[serde(rename_all = "camelCase")]
+        InvalidChainStateDestruction {
+            input_index: u16,
+            error: StateTransitionError,
+        },
+        #[serde(rename_all = "camelCase")]
+        InsufficientStorageDeposit {
+            output_index: u16,
+            amount: String,
+            required: String,
+        },
+        #[serde(rename_all = "camelCase")]
+        InvalidStorageDepositReturn {
+            output_index: u16,
+            deposit: String,
+            minimum: String,
+            maximum: String,
+        },
+        #[serde(rename_all = "camelCase")]
+        CreatedAmountExceedsTokenSupply { created: String, token_supply: String },
+    }
+
+    impl From<&ConflictDiagnostic> for ConflictDiagnosticDto {
+        fn from(value: &ConflictDiagnostic) -> Self {
+            match value {
+                ConflictDiagnostic::InputsCommitmentsMismatch { expected, actual } => Self::InputsCommitmentsMismatch {
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                },
+                ConflictDiagnostic::UnlockFailed { input_index, reason } => Self::UnlockFailed {
+                    input_index: *input_index,
+                    reason: *reason as u8,
+                },
+                ConflictDiagnostic::TimelockNotExpired {
+                    input_index,
+                    milestone_index,
+                    timestamp,
+                } => Self::TimelockNotExpired {
+                    input_index: *input_index,
+                    milestone_index: **milestone_index,
+                    timestamp: *timestamp,
+                },
+                ConflictDiagnostic::StorageDepositReturnUnfulfilled {
+                    return_address,
+                    expected,
+                    actual,
+                } => Self::StorageDepositReturnUnfulfilled {
+                    return_address: return_address.into(),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                },
+                ConflictDiagnostic::UnverifiedSender { output_index, sender } => Self::UnverifiedSender {
+                    output_index: *output_index,
+                    sender: sender.into(),
+                },
+                ConflictDiagnostic::CreatedConsumedAmountMismatch { consumed, created } => {
+                    Self::CreatedConsumedAmountMismatch {
+                        consumed: consumed.to_string(),
+                        created: created.to_string(),
+                    }
+                }
+                ConflictDiagnostic::NativeTokensAmountMismatch {
+                    token_id,
+                    consumed,
+                    created,
+                } => Self::NativeTokensAmountMismatch {
+                    token_id: TokenIdDto(token_id.to_string()),
+                    consumed: consumed.into(),
+                    created: created.into(),
+                },
+                ConflictDiagnostic::NativeTokensCountExceeded { count } => {
+                    Self::NativeTokensCountExceeded { count: *count }
+                }
+                ConflictDiagnostic::InvalidChainStateCreation { output_index, error } => {
+                    Self::InvalidChainStateCreation {
+                        output_index: *output_index,
+                        error: *error,
+                    }
+                }
+                ConflictDiagnostic::InvalidChainStateTransition {
+                    input_index,
+                    output_index,
+                    error,
+                } => Self::InvalidChainStateTransition {
+                    input_index: *input_index,
+                    output_index: *output_index,
+                    error: *error,
+                },
+                ConflictDiagnostic::InvalidChainStateDestruction { input_index, error } => {
+                    Self::InvalidChainStateDestruction {
+                        input_index: *input_index,
+                        error: *error,
+                    }
+                }
+                ConflictDiagnostic::InsufficientStorageDeposit {
+                    output_index,
+                    amount,
+                    required,
+                } => Self::InsufficientStorageDeposit {
+                    output_index: *output_index,
+                    amount: amount.to_string(),
+                    required: required.to_string(),
+                },
+                ConflictDiagnostic::InvalidStorageDepositReturn {
+                    output_index,
+                    deposit,
+                    minimum,
+                    maximum,
+                } => Self::InvalidStorageDepositReturn {
+                    output_index: *output_index,
+                    deposit: deposit.to_string(),
+                    minimum: minimum.to_string(),
+                    maximum: maximum.to_string(),
+                },
+                ConflictDiagnostic::CreatedAmountExceedsTokenSupply { created, token_supply } => {
+                    Self::CreatedAmountExceedsTokenSupply {
+                        created: created.to_string(),
+                        token_supply: token_supply.to_string(),
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,114 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+
+use ed25519_zebra::{batch, Signature, VerificationKeyBytes};
+use hashbrown::HashMap;
+
+use crate::signature::Ed25519Signature;
+
+/// Collects [`Ed25519Signature`]s over 32-byte messages to verify them all at once.
+///
+/// Verifying a batch is noticeably cheaper than verifying each signature on its own, but a failing batch doesn't tell
+/// which signature is invalid; [`Ed25519BatchVerifier::verify`] then falls back to individual checks.
+#[derive(Clone, Debug, Default)]
+pub struct Ed25519BatchVerifier {
+    items: Vec<([u8; 32], Ed25519Signature)>,
+}
+
+impl Ed25519BatchVerifier {
+    /// Creates a new, empty [`Ed25519BatchVerifier`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, empty [`Ed25519BatchVerifier`] with room for `capacity` signatures.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Queues a signature of a message for verification.
+    pub fn queue(&mut self, message: [u8; 32], signature: Ed25519Signature) {
+        self.items.push((message, signature));
+    }
+
+    /// Returns the number of queued signatures.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether no signature has been queued.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verifies all queued signatures as a single batch and returns whether they are all valid.
+    pub fn verify_all(&self) -> bool {
+        let mut verifier = batch::Verifier::new();
+
+        for (message, signature) in self.items.iter() {
+            verifier.queue((
+                VerificationKeyBytes::from(*signature.public_key()),
+                Signature::from(*signature.signature()),
+                message,
+            ));
+        }
+
+        verifier.verify(rand::thread_rng()).is_ok()
+    }
+
+    /// Verifies all queued signatures and returns the validity of each of them.
+    ///
+    /// Signatures are verified as a single batch; if the batch fails, each signature is verified individually to find
+    /// the invalid ones.
+    pub fn verify(self) -> VerifiedSignatures {
+        let valid = self.verify_all();
+
+        VerifiedSignatures(
+            self.items
+                .into_iter()
+                .map(|(message, signature)| {
+                    let is_valid = valid || signature.verify(&message).is_ok();
+                    ((message, signature), is_valid)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The results of an [`Ed25519BatchVerifier`], indexed by message and signature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerifiedSignatures(HashMap<([u8; 32], Ed25519Signature), bool>);
+
+impl VerifiedSignatures {
+    /// Returns whether a signature of a message is valid, or `None` if it was not verified.
+    pub fn get(&self, message: &[u8; 32], signature: &Ed25519Signature) -> Option<bool> {
+        self.0.get(&(*message, signature.clone())).copied()
+    }
+
+    /// Returns the number of verified signatures.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether no signature was verified.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns whether all verified signatures are valid.
+    pub fn all_valid(&self) -> bool {
+        self.0.values().all(|valid| *valid)
+    }
+
+    /// Returns an iterator over the invalid signatures and the messages they were verified against.
+    pub fn invalid(&self) -> impl Iterator<Item = (&[u8; 32], &Ed25519Signature)> + '_ {
+        self.0
+            .iter()
+            .filter(|(_, valid)| !**valid)
+            .map(|((message, signature), _)| (message, signature))
+    }
+}
@@ -44,6 +44,12 @@ impl Ed25519Signature {
 
     /// Verifies the [`Ed25519Signature`] for a message against an [`Ed25519Address`].
     pub fn is_valid(&self, message: &[u8], address: &Ed25519Address) -> Result<(), Error> {
+        self.matches_address(address)?;
+        self.verify(message)
+    }
+
+    /// Checks that the public key of the [`Ed25519Signature`] hashes to an [`Ed25519Address`].
+    pub(crate) fn matches_address(&self, address: &Ed25519Address) -> Result<(), Error> {
         let signature_address: [u8; PUBLIC_KEY_LENGTH] = Blake2b256::digest(&self.public_key).into();
 
         if address.deref() != &signature_address {
@@ -53,6 +59,11 @@ impl Ed25519Signature {
             });
         }
 
+        Ok(())
+    }
+
+    /// Cryptographically verifies the [`Ed25519Signature`] for a message.
+    pub(crate) fn verify(&self, message: &[u8]) -> Result<(), Error> {
         if !PublicKey::try_from_bytes(self.public_key)?.verify(&Signature::from_bytes(self.signature), message) {
             return Err(Error::InvalidSignature);
         }
@@ -1,10 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "std")]
+mod batch;
 mod ed25519;
 
 use derive_more::From;
 
+#[cfg(feature = "std")]
+pub use self::batch::{Ed25519BatchVerifier, VerifiedSignatures};
 pub use self::ed25519::Ed25519Signature;
 use crate::Error;
 
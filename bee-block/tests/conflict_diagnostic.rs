@@ -0,0 +1,118 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{
+    output::{StateTransitionError, TokenId},
+    payload::milestone::MilestoneIndex,
+    semantic::{ConflictDiagnostic, ConflictReason},
+    Error,
+};
+use bee_test::rand::address::rand_address;
+use packable::{error::UnpackError, PackableExt};
+use primitive_types::U256;
+
+#[test]
+fn reason() {
+    assert_eq!(
+        ConflictDiagnostic::UnlockFailed {
+            input_index: 2,
+            reason: ConflictReason::InvalidSignature,
+        }
+        .reason(),
+        ConflictReason::InvalidSignature
+    );
+    assert_eq!(
+        ConflictDiagnostic::NativeTokensCountExceeded { count: 65 }.reason(),
+        ConflictReason::InvalidNativeTokens
+    );
+    assert_eq!(
+        ConflictDiagnostic::InvalidChainStateDestruction {
+            input_index: 0,
+            error: StateTransitionError::UnsupportedStateTransition,
+        }
+        .reason(),
+        ConflictReason::InvalidChainStateTransition
+    );
+    assert_eq!(
+        ConflictDiagnostic::InsufficientStorageDeposit {
+            output_index: 0,
+            amount: 100,
+            required: 42_600,
+        }
+        .reason(),
+        ConflictReason::SemanticValidationFailed
+    );
+    assert_eq!(
+        ConflictDiagnostic::CreatedAmountExceedsTokenSupply {
+            created: 1_001,
+            token_supply: 1_000,
+        }
+        .reason(),
+        ConflictReason::SemanticValidationFailed
+    );
+}
+
+#[test]
+fn packable_round_trip() {
+    let diagnostics = [
+        ConflictDiagnostic::TimelockNotExpired {
+            input_index: 1,
+            milestone_index: MilestoneIndex(42),
+            timestamp: 1_660_000_000,
+        },
+        ConflictDiagnostic::StorageDepositReturnUnfulfilled {
+            return_address: rand_address(),
+            expected: 1_000_000,
+            actual: 500,
+        },
+        ConflictDiagnostic::NativeTokensAmountMismatch {
+            token_id: TokenId::null(),
+            consumed: U256::from(10),
+            created: U256::from(11),
+        },
+        ConflictDiagnostic::InvalidChainStateTransition {
+            input_index: 3,
+            output_index: 4,
+            error: StateTransitionError::UnsupportedStateIndexOperation {
+                current_state: 5,
+                next_state: 7,
+            },
+        },
+        ConflictDiagnostic::InvalidStorageDepositReturn {
+            output_index: 2,
+            deposit: 100,
+            minimum: 42_600,
+            maximum: 1_000_000,
+        },
+        ConflictDiagnostic::CreatedAmountExceedsTokenSupply {
+            created: 1_001,
+            token_supply: 1_000,
+        },
+    ];
+
+    for diagnostic in diagnostics {
+        let packed = diagnostic.pack_to_vec();
+
+        assert_eq!(packed.len(), diagnostic.packed_len());
+        assert_eq!(
+            diagnostic,
+            ConflictDiagnostic::unpack_verified(&mut packed.as_slice()).unwrap()
+        );
+    }
+}
+
+#[test]
+fn unpack_invalid_kind() {
+    assert!(matches!(
+        ConflictDiagnostic::unpack_verified(&mut [42u8].as_slice()),
+        Err(UnpackError::Packable(Error::InvalidConflictDiagnosticKind(42)))
+    ));
+}
+
+#[test]
+fn unpack_invalid_reason() {
+    assert!(matches!(
+        ConflictDiagnostic::unpack_verified(&mut [1u8, 0, 0, 13].as_slice()),
+        Err(UnpackError::Packable(Error::InvalidConflictReason(13)))
+    ));
+}
@@ -0,0 +1,178 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "std")]
+
+use bee_block::{
+    payload::milestone::{
+        MilestoneEssence, MilestoneIndex, MilestoneOptions, MilestonePayload, MilestoneValidationError,
+    },
+    signature::{Ed25519BatchVerifier, Ed25519Signature, Signature},
+};
+use bee_test::rand::{
+    bytes::rand_bytes_array,
+    milestone::{rand_merkle_root, rand_milestone_id},
+    parents::rand_parents,
+};
+use crypto::signatures::ed25519::SecretKey;
+
+fn rand_secret_key() -> SecretKey {
+    SecretKey::from_bytes(rand_bytes_array())
+}
+
+fn sign(secret_key: &SecretKey, message: &[u8; 32]) -> Ed25519Signature {
+    Ed25519Signature::new(secret_key.public_key().to_bytes(), secret_key.sign(message).to_bytes())
+}
+
+fn tamper(signature: &Ed25519Signature) -> Ed25519Signature {
+    let mut bytes = *signature.signature();
+    bytes[0] ^= 1;
+
+    Ed25519Signature::new(*signature.public_key(), bytes)
+}
+
+#[test]
+fn empty() {
+    let verifier = Ed25519BatchVerifier::new();
+
+    assert!(verifier.is_empty());
+    assert!(verifier.verify_all());
+
+    let verified = verifier.verify();
+
+    assert!(verified.is_empty());
+    assert!(verified.all_valid());
+}
+
+#[test]
+fn all_valid() {
+    let mut verifier = Ed25519BatchVerifier::new();
+    let signed = (0..16)
+        .map(|_| {
+            let message = rand_bytes_array();
+            (message, sign(&rand_secret_key(), &message))
+        })
+        .collect::<Vec<_>>();
+
+    for (message, signature) in signed.iter() {
+        verifier.queue(*message, signature.clone());
+    }
+
+    assert_eq!(verifier.len(), 16);
+    assert!(verifier.verify_all());
+
+    let verified = verifier.verify();
+
+    assert_eq!(verified.len(), 16);
+    assert!(verified.all_valid());
+    assert_eq!(verified.invalid().count(), 0);
+
+    for (message, signature) in signed.iter() {
+        assert_eq!(verified.get(message, signature), Some(true));
+    }
+}
+
+#[test]
+fn invalid_falls_back_to_individual_checks() {
+    let mut verifier = Ed25519BatchVerifier::new();
+    let signed = (0..16)
+        .map(|index| {
+            let message = rand_bytes_array();
+            let signature = sign(&rand_secret_key(), &message);
+
+            (message, if index == 7 { tamper(&signature) } else { signature })
+        })
+        .collect::<Vec<_>>();
+
+    for (message, signature) in signed.iter() {
+        verifier.queue(*message, signature.clone());
+    }
+
+    assert!(!verifier.verify_all());
+
+    let verified = verifier.verify();
+
+    assert!(!verified.all_valid());
+    assert_eq!(
+        verified.invalid().collect::<Vec<_>>(),
+        vec![(&signed[7].0, &signed[7].1)]
+    );
+
+    for (index, (message, signature)) in signed.iter().enumerate() {
+        assert_eq!(verified.get(message, signature), Some(index != 7));
+    }
+}
+
+#[test]
+fn wrong_message() {
+    let mut verifier = Ed25519BatchVerifier::new();
+    let message = rand_bytes_array();
+    let other_message = rand_bytes_array();
+    let signature = sign(&rand_secret_key(), &message);
+
+    verifier.queue(other_message, signature.clone());
+
+    let verified = verifier.verify();
+
+    assert_eq!(verified.get(&other_message, &signature), Some(false));
+    assert_eq!(verified.get(&message, &signature), None);
+}
+
+fn rand_milestone_essence() -> MilestoneEssence {
+    MilestoneEssence::new(
+        MilestoneIndex(1),
+        0,
+        rand_milestone_id(),
+        rand_parents(),
+        rand_merkle_root(),
+        rand_merkle_root(),
+        vec![],
+        MilestoneOptions::new(vec![]).unwrap(),
+    )
+    .unwrap()
+}
+
+fn signed_milestone(tampered: Option<usize>) -> (MilestonePayload, Vec<String>) {
+    let essence = rand_milestone_essence();
+    let essence_hash = essence.hash();
+    let mut secret_keys = (0..3).map(|_| rand_secret_key()).collect::<Vec<_>>();
+
+    secret_keys.sort_by_key(|secret_key| secret_key.public_key().to_bytes());
+
+    let signatures = secret_keys
+        .iter()
+        .enumerate()
+        .map(|(index, secret_key)| {
+            let signature = sign(secret_key, &essence_hash);
+
+            Signature::from(if tampered == Some(index) {
+                tamper(&signature)
+            } else {
+                signature
+            })
+        })
+        .collect();
+    let public_keys = secret_keys
+        .iter()
+        .map(|secret_key| hex::encode(secret_key.public_key().to_bytes()))
+        .collect();
+
+    (MilestonePayload::new(essence, signatures).unwrap(), public_keys)
+}
+
+#[test]
+fn milestone_signatures_valid() {
+    let (milestone, public_keys) = signed_milestone(None);
+
+    assert!(milestone.validate(&public_keys, 3).is_ok());
+}
+
+#[test]
+fn milestone_signatures_invalid() {
+    let (milestone, public_keys) = signed_milestone(Some(1));
+
+    assert!(matches!(
+        milestone.validate(&public_keys, 3),
+        Err(MilestoneValidationError::InvalidSignature(1, _))
+    ));
+}
@@ -0,0 +1,104 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{
+    payload::milestone::{MilestoneIndex, ParametersMilestoneOption},
+    protocol::ProtocolParameters,
+    Error,
+};
+use bee_test::rand::protocol::rand_protocol_parameters;
+use packable::{error::UnpackError, PackableExt};
+
+fn protocol_parameters() -> ProtocolParameters {
+    ProtocolParameters::new(
+        2,
+        "testnet".to_string(),
+        "rms".to_string(),
+        1500,
+        15,
+        100,
+        1,
+        10,
+        1_000_000,
+    )
+    .unwrap()
+}
+
+#[test]
+fn new_valid() {
+    let parameters = protocol_parameters();
+
+    assert_eq!(parameters.protocol_version(), 2);
+    assert_eq!(parameters.network_name(), "testnet");
+    assert_eq!(parameters.bech32_hrp(), "rms");
+    assert_eq!(parameters.min_pow_score(), 1500);
+    assert_eq!(parameters.below_max_depth(), 15);
+    assert_eq!(parameters.byte_cost().v_byte_cost, 100);
+    assert_eq!(parameters.byte_cost().v_byte_factor_data, 1);
+    assert_eq!(parameters.byte_cost().v_byte_factor_key, 10);
+    assert_eq!(parameters.token_supply(), 1_000_000);
+}
+
+#[test]
+fn new_invalid_network_name() {
+    assert!(matches!(
+        ProtocolParameters::new(2, String::new(), "rms".to_string(), 1500, 15, 100, 1, 10, 1_000_000),
+        Err(Error::InvalidNetworkName)
+    ));
+}
+
+#[test]
+fn new_invalid_bech32_hrp() {
+    assert!(matches!(
+        ProtocolParameters::new(
+            2,
+            "testnet".to_string(),
+            "a".repeat(256),
+            1500,
+            15,
+            100,
+            1,
+            10,
+            1_000_000
+        ),
+        Err(Error::InvalidBech32Hrp)
+    ));
+}
+
+#[test]
+fn packed_len() {
+    let parameters = protocol_parameters();
+
+    assert_eq!(parameters.packed_len(), 1 + 1 + 7 + 1 + 3 + 4 + 1 + 4 + 1 + 1 + 8);
+    assert_eq!(parameters.pack_to_vec().len(), parameters.packed_len());
+}
+
+#[test]
+fn pack_unpack_valid() {
+    let parameters = rand_protocol_parameters();
+    let packed = parameters.pack_to_vec();
+
+    assert_eq!(
+        ProtocolParameters::unpack_verified(&mut packed.as_slice()).unwrap(),
+        parameters
+    );
+}
+
+#[test]
+fn unpack_invalid_utf8() {
+    let mut packed = protocol_parameters().pack_to_vec();
+    packed[2] = 0xff;
+
+    assert!(matches!(
+        ProtocolParameters::unpack_verified(&mut packed.as_slice()),
+        Err(UnpackError::Packable(Error::InvalidNetworkName))
+    ));
+}
+
+#[test]
+fn decode_from_milestone_option() {
+    let parameters = rand_protocol_parameters();
+    let option = ParametersMilestoneOption::new(MilestoneIndex(42), 2, parameters.pack_to_vec()).unwrap();
+
+    assert_eq!(option.protocol_parameters().unwrap(), parameters);
+}
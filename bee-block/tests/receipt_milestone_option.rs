@@ -5,6 +5,7 @@ use core::str::FromStr;
 
 use bee_block::{
     address::{Address, Ed25519Address},
+    constant::TOKEN_SUPPLY,
     input::TreasuryInput,
     output::TreasuryOutput,
     payload::{
@@ -17,7 +18,10 @@ use bee_block::{
     Error,
 };
 use bee_test::rand::number::rand_number;
-use packable::{bounded::TryIntoBoundedU16Error, PackableExt};
+use packable::{
+    bounded::{InvalidBoundedU64, TryIntoBoundedU16Error},
+    PackableExt,
+};
 
 const AMOUNT: u64 = 1_000_000;
 const ED25519_ADDRESS: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
@@ -215,3 +219,36 @@ fn getters() {
     assert_eq!(receipt.transaction(), &transaction);
     assert_eq!(receipt.amount(), AMOUNT);
 }
+
+#[test]
+fn verify_token_supply() {
+    let receipt = ReceiptMilestoneOption::new(
+        MilestoneIndex::new(0),
+        true,
+        vec![
+            MigratedFundsEntry::new(
+                TailTransactionHash::new(TAIL_TRANSACTION_HASH_BYTES).unwrap(),
+                Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()),
+                AMOUNT,
+            )
+            .unwrap(),
+        ],
+        TreasuryTransactionPayload::new(
+            TreasuryInput::new(MilestoneId::from_str(MILESTONE_ID).unwrap()),
+            TreasuryOutput::new(2 * AMOUNT).unwrap(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert!(receipt.verify_token_supply(TOKEN_SUPPLY).is_ok());
+    assert!(receipt.verify_token_supply(2 * AMOUNT).is_ok());
+    assert!(matches!(
+        receipt.verify_token_supply(2 * AMOUNT - 1),
+        Err(Error::InvalidTreasuryOutputAmount(InvalidBoundedU64(amount))) if amount == 2 * AMOUNT
+    ));
+    assert!(matches!(
+        receipt.verify_token_supply(AMOUNT - 1),
+        Err(Error::InvalidReceiptFundsSum(sum)) if sum == AMOUNT as u128
+    ));
+}
@@ -314,6 +314,38 @@ fn build_invalid_accumulated_output() {
     assert!(matches!(essence, Err(Error::InvalidTransactionAmountSum(_))));
 }
 
+#[test]
+fn verify_token_supply() {
+    let txid = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let input = Input::Utxo(UtxoInput::new(txid, 0).unwrap());
+    let bytes: [u8; 32] = prefix_hex::decode(ED25519_ADDRESS_1).unwrap();
+    let address = Address::from(Ed25519Address::new(bytes));
+    let outputs = (0..2)
+        .map(|_| {
+            Output::Basic(
+                BasicOutput::build_with_amount(1_000_000)
+                    .unwrap()
+                    .add_unlock_condition(AddressUnlockCondition::new(address).into())
+                    .finish()
+                    .unwrap(),
+            )
+        })
+        .collect();
+
+    let essence = RegularTransactionEssence::builder(0, rand_inputs_commitment())
+        .add_input(input)
+        .with_outputs(outputs)
+        .finish()
+        .unwrap();
+
+    assert!(essence.verify_token_supply(TOKEN_SUPPLY).is_ok());
+    assert!(essence.verify_token_supply(2_000_000).is_ok());
+    assert!(matches!(
+        essence.verify_token_supply(1_999_999),
+        Err(Error::InvalidTransactionAmountSum(2_000_000))
+    ));
+}
+
 #[test]
 fn getters() {
     let txid = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
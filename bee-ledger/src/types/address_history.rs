@@ -0,0 +1,292 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Deref;
+
+use bee_block::{
+    address::{Address, Ed25519Address},
+    output::OutputId,
+    payload::milestone::MilestoneIndex,
+};
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+use crate::types::Error;
+
+/// The length of a packed address, which is the same for all kinds of addresses.
+///
+/// The fixed length allows storage backends to use a packed address as the prefix of a key.
+pub const PACKED_ADDRESS_LENGTH: usize = 1 + Ed25519Address::LENGTH;
+
+/// The kind of an [`AddressHistoryEntry`].
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, packable::Packable)]
+#[packable(unpack_error = Error)]
+#[packable(tag_type = u8, with_error = Error::InvalidAddressHistoryEntryKind)]
+pub enum AddressHistoryEntryKind {
+    /// The output was created.
+    Created = 0,
+    /// The output was consumed.
+    Consumed = 1,
+}
+
+/// An entry of the history of an address, recording that an output owned by the address was created or consumed.
+///
+/// Entries are ordered by milestone index first, so that the history of an address can be listed oldest first.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AddressHistoryEntry {
+    milestone_index: MilestoneIndex,
+    kind: AddressHistoryEntryKind,
+    output_id: OutputId,
+    milestone_timestamp: u32,
+    amount: u64,
+    created_milestone_index: MilestoneIndex,
+    created_milestone_timestamp: u32,
+}
+
+impl AddressHistoryEntry {
+    /// Creates a new [`AddressHistoryEntry`] recording the creation of an output.
+    pub fn created(
+        output_id: OutputId,
+        milestone_index: MilestoneIndex,
+        milestone_timestamp: u32,
+        amount: u64,
+    ) -> Self {
+        Self {
+            milestone_index,
+            kind: AddressHistoryEntryKind::Created,
+            output_id,
+            milestone_timestamp,
+            amount,
+            created_milestone_index: milestone_index,
+            created_milestone_timestamp: milestone_timestamp,
+        }
+    }
+
+    /// Creates a new [`AddressHistoryEntry`] recording the consumption of an output, which also records when the
+    /// output was created.
+    pub fn consumed(
+        output_id: OutputId,
+        milestone_index: MilestoneIndex,
+        milestone_timestamp: u32,
+        amount: u64,
+        created_milestone_index: MilestoneIndex,
+        created_milestone_timestamp: u32,
+    ) -> Self {
+        Self {
+            milestone_index,
+            kind: AddressHistoryEntryKind::Consumed,
+            output_id,
+            milestone_timestamp,
+            amount,
+            created_milestone_index,
+            created_milestone_timestamp,
+        }
+    }
+
+    /// Returns the least [`AddressHistoryEntry`] with a milestone index, a kind and an output ID, which a page of
+    /// the history can start from.
+    pub fn lower_bound(milestone_index: MilestoneIndex, kind: AddressHistoryEntryKind, output_id: OutputId) -> Self {
+        Self {
+            milestone_index,
+            kind,
+            output_id,
+            milestone_timestamp: 0,
+            amount: 0,
+            created_milestone_index: MilestoneIndex(0),
+            created_milestone_timestamp: 0,
+        }
+    }
+
+    /// Returns the index of the milestone that created or consumed the output.
+    pub fn milestone_index(&self) -> MilestoneIndex {
+        self.milestone_index
+    }
+
+    /// Returns whether the output was created or consumed.
+    pub fn kind(&self) -> AddressHistoryEntryKind {
+        self.kind
+    }
+
+    /// Returns the ID of the output.
+    pub fn output_id(&self) -> &OutputId {
+        &self.output_id
+    }
+
+    /// Returns the timestamp of the milestone that created or consumed the output.
+    pub fn milestone_timestamp(&self) -> u32 {
+        self.milestone_timestamp
+    }
+
+    /// Returns the amount of the output.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the entry recording the creation of the output.
+    pub fn creation(&self) -> Self {
+        Self::created(
+            self.output_id,
+            self.created_milestone_index,
+            self.created_milestone_timestamp,
+            self.amount,
+        )
+    }
+}
+
+// The milestone index is packed big-endian so that packed entries sort by milestone index, which allows storage
+// backends to seek to an entry within the history of an address.
+impl Packable for AddressHistoryEntry {
+    type UnpackError = Error;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.milestone_index.to_be_bytes().pack(packer)?;
+        self.kind.pack(packer)?;
+        self.output_id.pack(packer)?;
+        self.milestone_timestamp.pack(packer)?;
+        self.amount.pack(packer)?;
+        self.created_milestone_index.pack(packer)?;
+        self.created_milestone_timestamp.pack(packer)
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let milestone_index = MilestoneIndex(u32::from_be_bytes(<[u8; 4]>::unpack::<_, VERIFY>(unpacker).coerce()?));
+        let kind = AddressHistoryEntryKind::unpack::<_, VERIFY>(unpacker)?;
+        let output_id = OutputId::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let milestone_timestamp = u32::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let amount = u64::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let created_milestone_index = MilestoneIndex::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let created_milestone_timestamp = u32::unpack::<_, VERIFY>(unpacker).coerce()?;
+
+        Ok(Self {
+            milestone_index,
+            kind,
+            output_id,
+            milestone_timestamp,
+            amount,
+            created_milestone_index,
+            created_milestone_timestamp,
+        })
+    }
+}
+
+/// A page of the history of an address, in the order of [`AddressHistoryEntry`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AddressHistoryPage {
+    address: Address,
+    start: Option<AddressHistoryEntry>,
+    limit: usize,
+}
+
+impl AddressHistoryPage {
+    /// Creates a new [`AddressHistoryPage`] of at most `limit` entries, starting from the first entry that is not less
+    /// than `start`, or from the first entry of the history if there is no `start`.
+    pub fn new(address: Address, start: Option<AddressHistoryEntry>, limit: usize) -> Self {
+        Self { address, start, limit }
+    }
+
+    /// Returns the address of the page.
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Returns the entry the page starts from, if any.
+    pub fn start(&self) -> Option<&AddressHistoryEntry> {
+        self.start.as_ref()
+    }
+
+    /// Returns the maximum number of entries of the page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// A wrapper type to represent the index of the oldest milestone at which the address history is complete.
+///
+/// Balances can only be computed from the address history at this milestone or later ones.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd, packable::Packable)]
+pub struct AddressHistoryIndex(pub MilestoneIndex);
+
+impl AddressHistoryIndex {
+    /// Creates a new `AddressHistoryIndex`.
+    pub fn new(index: MilestoneIndex) -> Self {
+        index.into()
+    }
+}
+
+impl From<MilestoneIndex> for AddressHistoryIndex {
+    fn from(index: MilestoneIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl Deref for AddressHistoryIndex {
+    type Target = <MilestoneIndex as Deref>::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_block::payload::transaction::TransactionId;
+    use packable::PackableExt;
+
+    use super::*;
+
+    #[test]
+    fn packed_entries_sort_like_entries() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; TransactionId::LENGTH]), 0).unwrap();
+        let mut entries = [0u32, 1, 255, 256, 65_536, u32::MAX - 1]
+            .iter()
+            .flat_map(|index| {
+                [
+                    AddressHistoryEntry::created(output_id, MilestoneIndex(*index), u32::MAX, 1),
+                    AddressHistoryEntry::consumed(output_id, MilestoneIndex(*index), 0, 1, MilestoneIndex(0), 0),
+                ]
+            })
+            .collect::<Vec<_>>();
+        entries.reverse();
+
+        let mut packed = entries.iter().map(PackableExt::pack_to_vec).collect::<Vec<_>>();
+
+        entries.sort();
+        packed.sort();
+
+        assert_eq!(
+            packed
+                .iter()
+                .map(|bytes| AddressHistoryEntry::unpack_verified(bytes).unwrap())
+                .collect::<Vec<_>>(),
+            entries
+        );
+    }
+
+    #[test]
+    fn lower_bound_precedes_its_entries() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; TransactionId::LENGTH]), 1).unwrap();
+        let entry = AddressHistoryEntry::consumed(output_id, MilestoneIndex(2), 0, 0, MilestoneIndex(0), 0);
+        let lower_bound =
+            AddressHistoryEntry::lower_bound(MilestoneIndex(2), AddressHistoryEntryKind::Consumed, output_id);
+
+        assert!(lower_bound <= entry);
+        assert!(lower_bound.pack_to_vec() <= entry.pack_to_vec());
+    }
+
+    #[test]
+    fn creation_of_a_consumed_entry() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; TransactionId::LENGTH]), 0).unwrap();
+        let created = AddressHistoryEntry::created(output_id, MilestoneIndex(1), 10, 100);
+        let consumed = AddressHistoryEntry::consumed(output_id, MilestoneIndex(2), 20, 100, MilestoneIndex(1), 10);
+
+        assert_eq!(consumed.creation(), created);
+        assert_eq!(created.creation(), created);
+    }
+}
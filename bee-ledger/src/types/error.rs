@@ -68,6 +68,12 @@ pub enum Error {
     /// Invalid snapshot kind.
     #[error("invalid snapshot kind: {0}")]
     InvalidSnapshotKind(u8),
+    /// Invalid address history entry kind.
+    #[error("invalid address history entry kind: {0}")]
+    InvalidAddressHistoryEntryKind(u8),
+    /// Output count mismatch.
+    #[error("output count mismatch: {0} != {1}")]
+    OutputCountMismatch(usize, usize),
     /// Unsupported snapshot version.
     #[error("unsupported snapshot version: supports {0}, read {1}")]
     UnsupportedVersion(u8, u8),
@@ -0,0 +1,131 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{output::OutputId, payload::milestone::MilestoneIndex};
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+use crate::types::{ConsumedOutput, CreatedOutput, Error, OutputDiff};
+
+/// The ledger changes of a milestone, made of its [`OutputDiff`] along with the bodies of the outputs it created and
+/// consumed.
+///
+/// The bodies follow the order of the output identifiers of the [`OutputDiff`], which is why they are packed without
+/// their identifiers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneUtxoDiff {
+    index: MilestoneIndex,
+    output_diff: OutputDiff,
+    created_outputs: Vec<CreatedOutput>,
+    consumed_outputs: Vec<(CreatedOutput, ConsumedOutput)>,
+}
+
+impl MilestoneUtxoDiff {
+    /// Creates a new [`MilestoneUtxoDiff`].
+    pub fn new(
+        index: MilestoneIndex,
+        output_diff: OutputDiff,
+        created_outputs: Vec<CreatedOutput>,
+        consumed_outputs: Vec<(CreatedOutput, ConsumedOutput)>,
+    ) -> Result<Self, Error> {
+        if created_outputs.len() != output_diff.created_outputs().len() {
+            return Err(Error::OutputCountMismatch(
+                created_outputs.len(),
+                output_diff.created_outputs().len(),
+            ));
+        }
+
+        if consumed_outputs.len() != output_diff.consumed_outputs().len() {
+            return Err(Error::OutputCountMismatch(
+                consumed_outputs.len(),
+                output_diff.consumed_outputs().len(),
+            ));
+        }
+
+        Ok(Self {
+            index,
+            output_diff,
+            created_outputs,
+            consumed_outputs,
+        })
+    }
+
+    /// Returns the index of the milestone of the [`MilestoneUtxoDiff`].
+    pub fn index(&self) -> MilestoneIndex {
+        self.index
+    }
+
+    /// Returns the [`OutputDiff`] of the [`MilestoneUtxoDiff`].
+    pub fn output_diff(&self) -> &OutputDiff {
+        &self.output_diff
+    }
+
+    /// Returns the outputs created by the milestone, along with their identifiers.
+    pub fn created_outputs(&self) -> impl Iterator<Item = (&OutputId, &CreatedOutput)> {
+        self.output_diff
+            .created_outputs()
+            .iter()
+            .zip(self.created_outputs.iter())
+    }
+
+    /// Returns the outputs consumed by the milestone, along with their identifiers.
+    pub fn consumed_outputs(&self) -> impl Iterator<Item = (&OutputId, &(CreatedOutput, ConsumedOutput))> {
+        self.output_diff
+            .consumed_outputs()
+            .iter()
+            .zip(self.consumed_outputs.iter())
+    }
+}
+
+impl Packable for MilestoneUtxoDiff {
+    type UnpackError = Error;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.index.pack(packer)?;
+        self.output_diff.pack(packer)?;
+
+        for created_output in self.created_outputs.iter() {
+            created_output.pack(packer)?;
+        }
+
+        for (created_output, consumed_output) in self.consumed_outputs.iter() {
+            created_output.pack(packer)?;
+            consumed_output.pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let index = MilestoneIndex::unpack::<_, VERIFY>(unpacker).coerce()?;
+        let output_diff = OutputDiff::unpack::<_, VERIFY>(unpacker)?;
+
+        let mut created_outputs = Vec::with_capacity(output_diff.created_outputs().len());
+
+        for _ in 0..output_diff.created_outputs().len() {
+            created_outputs.push(CreatedOutput::unpack::<_, VERIFY>(unpacker)?);
+        }
+
+        let mut consumed_outputs = Vec::with_capacity(output_diff.consumed_outputs().len());
+
+        for _ in 0..output_diff.consumed_outputs().len() {
+            let created_output = CreatedOutput::unpack::<_, VERIFY>(unpacker)?;
+            let consumed_output = ConsumedOutput::unpack::<_, VERIFY>(unpacker).coerce()?;
+
+            consumed_outputs.push((created_output, consumed_output));
+        }
+
+        Ok(Self {
+            index,
+            output_diff,
+            created_outputs,
+            consumed_outputs,
+        })
+    }
+}
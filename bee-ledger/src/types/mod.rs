@@ -5,11 +5,13 @@
 
 pub mod snapshot;
 
+mod address_history;
 mod consumed_output;
 mod created_output;
 mod error;
 mod ledger_index;
 mod migration;
+mod milestone_utxo_diff;
 mod output_diff;
 mod receipt;
 mod treasury_diff;
@@ -17,7 +19,18 @@ mod treasury_output;
 mod unspent;
 
 pub use self::{
-    consumed_output::ConsumedOutput, created_output::CreatedOutput, error::Error, ledger_index::LedgerIndex,
-    migration::Migration, output_diff::OutputDiff, receipt::Receipt, treasury_diff::TreasuryDiff,
-    treasury_output::TreasuryOutput, unspent::Unspent,
+    address_history::{
+        AddressHistoryEntry, AddressHistoryEntryKind, AddressHistoryIndex, AddressHistoryPage, PACKED_ADDRESS_LENGTH,
+    },
+    consumed_output::ConsumedOutput,
+    created_output::CreatedOutput,
+    error::Error,
+    ledger_index::LedgerIndex,
+    migration::Migration,
+    milestone_utxo_diff::MilestoneUtxoDiff,
+    output_diff::OutputDiff,
+    receipt::Receipt,
+    treasury_diff::TreasuryDiff,
+    treasury_output::TreasuryOutput,
+    unspent::Unspent,
 };
@@ -1,10 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_block::{
-    constant::TOKEN_SUPPLY,
-    payload::milestone::{MilestoneIndex, ReceiptMilestoneOption},
-};
+use bee_block::payload::milestone::{MilestoneIndex, ReceiptMilestoneOption};
 
 use crate::types::{error::Error, TreasuryOutput};
 
@@ -31,8 +28,10 @@ impl Receipt {
         &self.included_in
     }
 
-    /// Semantically validates the `Receipt`.
-    pub fn validate(&self, consumed_treasury_output: &TreasuryOutput) -> Result<(), Error> {
+    /// Semantically validates the `Receipt` against the consumed treasury output and the token supply.
+    pub fn validate(&self, consumed_treasury_output: &TreasuryOutput, token_supply: u64) -> Result<(), Error> {
+        self.inner().verify_token_supply(token_supply)?;
+
         let mut migrated_amount: u64 = 0;
         let transaction = self.inner().transaction();
 
@@ -42,10 +41,6 @@ impl Receipt {
                 .ok_or_else(|| Error::MigratedFundsAmountOverflow(migrated_amount as u128 + funds.amount() as u128))?;
         }
 
-        if migrated_amount > TOKEN_SUPPLY {
-            return Err(Error::InvalidMigratedFundsAmount(migrated_amount));
-        }
-
         let input = transaction.input();
 
         if input.milestone_id() != consumed_treasury_output.milestone_id() {
@@ -29,6 +29,25 @@ pub struct MilestoneDiff {
 }
 
 impl MilestoneDiff {
+    /// Creates a new `MilestoneDiff`.
+    pub fn new(
+        milestone: MilestonePayload,
+        consumed_treasury: Option<(TreasuryOutput, MilestoneId)>,
+        created_outputs: HashMap<OutputId, CreatedOutput>,
+        consumed_outputs: HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+    ) -> Result<Self, Error> {
+        if milestone.essence().options().receipt().is_some() != consumed_treasury.is_some() {
+            return Err(Error::MissingConsumedTreasury);
+        }
+
+        Ok(Self {
+            milestone,
+            consumed_treasury,
+            created_outputs,
+            consumed_outputs,
+        })
+    }
+
     /// Returns the milestone of a `MilestoneDiff`.
     pub fn milestone(&self) -> &MilestonePayload {
         &self.milestone
@@ -63,8 +82,8 @@ impl Packable for MilestoneDiff {
                 milestone_id.pack(packer)?;
                 treasury_output.pack(packer)?;
             } else {
-                // This never happens because `MilestoneDiff` values can only be created by unpacking them and the
-                // `unpack` implementation guarantees that the `consumed_treasury` field is some if the receipt is some.
+                // This never happens because both `new` and `unpack` guarantee that the `consumed_treasury` field is
+                // some if the receipt is some.
                 unreachable!()
             }
         }
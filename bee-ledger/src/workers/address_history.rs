@@ -0,0 +1,447 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the address history operations.
+
+use std::collections::HashMap;
+
+use bee_block::{
+    address::Address,
+    output::{Output, OutputId},
+    payload::{milestone::MilestoneIndex, transaction::TransactionId},
+};
+use bee_storage::access::{AsIterator, Batch, BatchBuilder, Fetch, Truncate};
+use log::info;
+
+use crate::{
+    types::{
+        AddressHistoryEntry, AddressHistoryEntryKind, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput,
+        CreatedOutput, LedgerIndex, Unspent,
+    },
+    workers::{
+        error::Error,
+        storage::{self, StorageBackend},
+    },
+};
+
+// Number of unspent outputs recorded per batch when the address history is initialized.
+const INITIALIZATION_BATCH_SIZE: usize = 10_000;
+// Number of entries fetched at once when walking the history of an address.
+const HISTORY_PAGE_SIZE: usize = 1_000;
+
+/// Returns the address an output is recorded in the history of, which is the address owning it: the address of basic
+/// and NFT outputs, the state controller of alias outputs and the alias of foundry outputs.
+///
+/// Governors and expiration return addresses can unlock outputs as well, but recording the outputs in their history
+/// too would count them in the balances of several addresses.
+fn output_address(output: &Output) -> Option<Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    unlock_conditions
+        .address()
+        .map(|address| *address.address())
+        .or_else(|| {
+            unlock_conditions
+                .state_controller_address()
+                .map(|state_controller_address| *state_controller_address.address())
+        })
+        .or_else(|| {
+            unlock_conditions
+                .immutable_alias_address()
+                .map(|immutable_alias_address| *immutable_alias_address.address())
+        })
+}
+
+/// Visits the entries of the history of an address in order, starting from `start`, as long as `visit` returns true.
+fn visit_history<B: StorageBackend>(
+    storage: &B,
+    address: &Address,
+    mut start: Option<AddressHistoryEntry>,
+    mut visit: impl FnMut(&AddressHistoryEntry) -> Result<bool, Error>,
+) -> Result<(), Error> {
+    loop {
+        let mut entries = Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+            storage,
+            &AddressHistoryPage::new(*address, start, HISTORY_PAGE_SIZE + 1),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default();
+
+        // The extra entry starts the next page.
+        start = if entries.len() > HISTORY_PAGE_SIZE {
+            entries.pop()
+        } else {
+            None
+        };
+
+        for entry in entries.iter() {
+            if !visit(entry)? {
+                return Ok(());
+            }
+        }
+
+        if start.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+fn created_output_entry(output_id: &OutputId, output: &CreatedOutput) -> AddressHistoryEntry {
+    AddressHistoryEntry::created(
+        *output_id,
+        output.milestone_index(),
+        output.milestone_timestamp(),
+        output.inner().amount(),
+    )
+}
+
+fn consumed_output_entry(
+    output_id: &OutputId,
+    created_output: &CreatedOutput,
+    consumed_output: &ConsumedOutput,
+) -> AddressHistoryEntry {
+    AddressHistoryEntry::consumed(
+        *output_id,
+        consumed_output.milestone_index(),
+        consumed_output.milestone_timestamp(),
+        created_output.inner().amount(),
+        created_output.milestone_index(),
+        created_output.milestone_timestamp(),
+    )
+}
+
+fn insert_created_output_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &CreatedOutput,
+) -> Result<(), Error> {
+    if let Some(address) = output_address(output.inner()) {
+        Batch::<(Address, AddressHistoryEntry), ()>::batch_insert(
+            storage,
+            batch,
+            &(address, created_output_entry(output_id, output)),
+            &(),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+fn insert_consumed_output_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    created_output: &CreatedOutput,
+    consumed_output: &ConsumedOutput,
+) -> Result<(), Error> {
+    if let Some(address) = output_address(created_output.inner()) {
+        Batch::<(Address, AddressHistoryEntry), ()>::batch_insert(
+            storage,
+            batch,
+            &(
+                address,
+                consumed_output_entry(output_id, created_output, consumed_output),
+            ),
+            &(),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+        // Keeps track of the addresses that consumed outputs at this milestone, for the retention to find them.
+        Batch::<(MilestoneIndex, Address), ()>::batch_insert(
+            storage,
+            batch,
+            &(consumed_output.milestone_index(), address),
+            &(),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+fn delete_created_output_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    output: &CreatedOutput,
+) -> Result<(), Error> {
+    if let Some(address) = output_address(output.inner()) {
+        Batch::<(Address, AddressHistoryEntry), ()>::batch_delete(
+            storage,
+            batch,
+            &(address, created_output_entry(output_id, output)),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+fn delete_consumed_output_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    output_id: &OutputId,
+    created_output: &CreatedOutput,
+    consumed_output: &ConsumedOutput,
+) -> Result<(), Error> {
+    if let Some(address) = output_address(created_output.inner()) {
+        Batch::<(Address, AddressHistoryEntry), ()>::batch_delete(
+            storage,
+            batch,
+            &(
+                address,
+                consumed_output_entry(output_id, created_output, consumed_output),
+            ),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+        Batch::<(MilestoneIndex, Address), ()>::batch_delete(
+            storage,
+            batch,
+            &(consumed_output.milestone_index(), address),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+/// Records the outputs created and consumed by a milestone in the address history.
+pub fn insert_milestone_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    created_outputs: &HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+) -> Result<(), Error> {
+    for (output_id, created_output) in created_outputs.iter() {
+        insert_created_output_batch(storage, batch, output_id, created_output)?;
+    }
+
+    for (output_id, (created_output, consumed_output)) in consumed_outputs.iter() {
+        insert_consumed_output_batch(storage, batch, output_id, created_output, consumed_output)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the outputs created and consumed by a rolled back milestone from the address history.
+pub fn delete_milestone_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    created_outputs: &HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+) -> Result<(), Error> {
+    for (output_id, created_output) in created_outputs.iter() {
+        delete_created_output_batch(storage, batch, output_id, created_output)?;
+    }
+
+    for (output_id, (created_output, consumed_output)) in consumed_outputs.iter() {
+        delete_consumed_output_batch(storage, batch, output_id, created_output, consumed_output)?;
+    }
+
+    Ok(())
+}
+
+/// Fetches the index of the oldest milestone at which the address history is complete, if it is recorded.
+pub fn fetch_address_history_index<B: StorageBackend>(storage: &B) -> Result<Option<AddressHistoryIndex>, Error> {
+    Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// Discards the address history, it is initialized again from the unspent outputs at the next start if enabled.
+pub fn discard<B: StorageBackend>(storage: &B) -> Result<(), Error> {
+    Truncate::<(Address, AddressHistoryEntry), ()>::truncate(storage).map_err(|e| Error::Storage(Box::new(e)))?;
+    Truncate::<(MilestoneIndex, Address), ()>::truncate(storage).map_err(|e| Error::Storage(Box::new(e)))?;
+    Truncate::<(), AddressHistoryIndex>::truncate(storage).map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// Initializes the address history from the unspent outputs when it gets enabled, or discards it when it gets
+/// disabled, since it would not be complete anymore.
+pub fn initialize<B: StorageBackend>(storage: &B, enabled: bool, ledger_index: LedgerIndex) -> Result<(), Error> {
+    match (enabled, fetch_address_history_index(storage)?) {
+        (true, None) => {
+            info!(
+                "Initializing the address history from the unspent outputs at milestone {}...",
+                *ledger_index
+            );
+
+            let iterator = AsIterator::<Unspent, ()>::iter(storage).map_err(|e| Error::Storage(Box::new(e)))?;
+            let mut batch = B::batch_begin();
+            let mut batch_len = 0;
+
+            for result in iterator {
+                let (output_id, _) = result.map_err(|e| Error::Storage(Box::new(e)))?;
+                let output =
+                    storage::fetch_output(storage, &*output_id)?.ok_or(Error::MissingUnspentOutput(output_id))?;
+
+                insert_created_output_batch(storage, &mut batch, &*output_id, &output)?;
+                batch_len += 1;
+
+                if batch_len == INITIALIZATION_BATCH_SIZE {
+                    storage
+                        .batch_commit(std::mem::replace(&mut batch, B::batch_begin()), true)
+                        .map_err(|e| Error::Storage(Box::new(e)))?;
+                    batch_len = 0;
+                }
+            }
+
+            // The index is only written once all unspent outputs are recorded, an interrupted initialization is
+            // therefore started over at the next start.
+            Batch::<(), AddressHistoryIndex>::batch_insert(storage, &mut batch, &(), &ledger_index.0.into())
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+
+            storage
+                .batch_commit(batch, true)
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+
+            info!("Initialized the address history.");
+        }
+        (false, Some(_)) => {
+            info!("Address history disabled, discarding it.");
+
+            discard(storage)?;
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+fn prune_milestone_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    index: MilestoneIndex,
+) -> Result<(), Error> {
+    let addresses = Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, &index)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .unwrap_or_default();
+    // The consumptions of a milestone are the last entries of the milestone.
+    let start = AddressHistoryEntry::lower_bound(
+        index,
+        AddressHistoryEntryKind::Consumed,
+        OutputId::new(TransactionId::null(), 0)?,
+    );
+
+    for address in addresses {
+        // Both the creation and the consumption of an output are removed, so that balances can still be computed from
+        // the remaining entries.
+        visit_history(storage, &address, Some(start), |entry| {
+            if entry.milestone_index() != index {
+                return Ok(false);
+            }
+
+            Batch::<(Address, AddressHistoryEntry), ()>::batch_delete(storage, batch, &(address, entry.creation()))
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+            Batch::<(Address, AddressHistoryEntry), ()>::batch_delete(storage, batch, &(address, *entry))
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+
+            Ok(true)
+        })?;
+
+        Batch::<(MilestoneIndex, Address), ()>::batch_delete(storage, batch, &(index, address))
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+/// Removes the history of the outputs consumed up to a target milestone, making it the oldest milestone at which the
+/// address history is complete.
+pub fn prune<B: StorageBackend>(storage: &B, target_index: MilestoneIndex) -> Result<(), Error> {
+    let start_index = match fetch_address_history_index(storage)? {
+        Some(index) if index.0 < target_index => index.0 + 1,
+        _ => return Ok(()),
+    };
+
+    let mut batch = B::batch_begin();
+
+    for index in *start_index..=*target_index {
+        prune_milestone_batch(storage, &mut batch, MilestoneIndex(index))?;
+    }
+
+    Batch::<(), AddressHistoryIndex>::batch_insert(storage, &mut batch, &(), &target_index.into())
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    storage
+        .batch_commit(batch, true)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// Computes the balance of an address at a milestone from its history, which must be complete at that milestone.
+pub fn fetch_address_balance<B: StorageBackend>(
+    storage: &B,
+    address: &Address,
+    milestone_index: MilestoneIndex,
+) -> Result<u64, Error> {
+    let (mut created, mut consumed) = (0u64, 0u64);
+
+    visit_history(storage, address, None, |entry| {
+        if entry.milestone_index() > milestone_index {
+            return Ok(false);
+        }
+
+        match entry.kind() {
+            AddressHistoryEntryKind::Created => created += entry.amount(),
+            AddressHistoryEntryKind::Consumed => consumed += entry.amount(),
+        }
+
+        Ok(true)
+    })?;
+
+    // The outputs consumed up to a milestone were all created up to it, so the subtraction can't underflow.
+    Ok(created - consumed)
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_block::{
+        address::{AliasAddress, Ed25519Address},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutput, BasicOutput, TreasuryOutput,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn basic_output_address() {
+        let owner = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let return_address = Address::from(AliasAddress::new(AliasId::new([2; AliasId::LENGTH])));
+        let output = BasicOutput::build_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(owner).into())
+            .add_unlock_condition(
+                ExpirationUnlockCondition::new(return_address, MilestoneIndex(10), 0)
+                    .unwrap()
+                    .into(),
+            )
+            .finish_output()
+            .unwrap();
+
+        assert_eq!(output_address(&output), Some(owner));
+    }
+
+    #[test]
+    fn alias_output_address() {
+        let controller = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let governor = Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        let output = AliasOutput::build_with_amount(1_000_000, AliasId::null())
+            .unwrap()
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(controller).into())
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(governor).into())
+            .finish_output()
+            .unwrap();
+
+        assert_eq!(output_address(&output), Some(controller));
+    }
+
+    #[test]
+    fn treasury_output_address() {
+        let output = Output::from(TreasuryOutput::new(1_000_000).unwrap());
+
+        assert_eq!(output_address(&output), None);
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the base token configuration.
+
+use bee_block::constant::TOKEN_SUPPLY;
+use serde::Deserialize;
+
+const DEFAULT_NAME: &str = "Shimmer";
+const DEFAULT_TICKER_SYMBOL: &str = "SMR";
+const DEFAULT_UNIT: &str = "SMR";
+const DEFAULT_SUBUNIT: &str = "glow";
+const DEFAULT_DECIMALS: u8 = 6;
+const DEFAULT_USE_METRIC_PREFIX: bool = false;
+const DEFAULT_TOKEN_SUPPLY: u64 = TOKEN_SUPPLY;
+
+/// Errors occurring when building a [`BaseTokenConfig`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The configured token supply is either zero or larger than the maximum token supply.
+    #[error("invalid token supply {0}: expected a value in 1..={}", TOKEN_SUPPLY)]
+    InvalidTokenSupply(u64),
+}
+
+/// Builder for a [`BaseTokenConfig`].
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct BaseTokenConfigBuilder {
+    name: Option<String>,
+    #[serde(alias = "tickerSymbol")]
+    ticker_symbol: Option<String>,
+    unit: Option<String>,
+    subunit: Option<String>,
+    decimals: Option<u8>,
+    #[serde(alias = "useMetricPrefix")]
+    use_metric_prefix: Option<bool>,
+    #[serde(alias = "tokenSupply")]
+    token_supply: Option<u64>,
+}
+
+impl BaseTokenConfigBuilder {
+    /// Creates a new [`BaseTokenConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the base token.
+    pub fn name(mut self, name: String) -> Self {
+        self.name.replace(name);
+        self
+    }
+
+    /// Sets the ticker symbol of the base token.
+    pub fn ticker_symbol(mut self, ticker_symbol: String) -> Self {
+        self.ticker_symbol.replace(ticker_symbol);
+        self
+    }
+
+    /// Sets the unit of the base token.
+    pub fn unit(mut self, unit: String) -> Self {
+        self.unit.replace(unit);
+        self
+    }
+
+    /// Sets the subunit of the base token.
+    pub fn subunit(mut self, subunit: String) -> Self {
+        self.subunit.replace(subunit);
+        self
+    }
+
+    /// Sets the number of decimals of the base token.
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals.replace(decimals);
+        self
+    }
+
+    /// Sets whether the base token uses metric prefixes.
+    pub fn use_metric_prefix(mut self, use_metric_prefix: bool) -> Self {
+        self.use_metric_prefix.replace(use_metric_prefix);
+        self
+    }
+
+    /// Sets the total supply of the base token.
+    pub fn token_supply(mut self, token_supply: u64) -> Self {
+        self.token_supply.replace(token_supply);
+        self
+    }
+
+    /// Finishes the builder into a [`BaseTokenConfig`].
+    pub fn finish(self) -> Result<BaseTokenConfig, Error> {
+        let token_supply = self.token_supply.unwrap_or(DEFAULT_TOKEN_SUPPLY);
+
+        if !(1..=TOKEN_SUPPLY).contains(&token_supply) {
+            return Err(Error::InvalidTokenSupply(token_supply));
+        }
+
+        // A subunit is only defaulted alongside the default unit, a custom token may not have one.
+        let subunit = match (self.subunit, &self.unit) {
+            (Some(subunit), _) => Some(subunit),
+            (None, None) => Some(DEFAULT_SUBUNIT.to_string()),
+            (None, Some(_)) => None,
+        };
+
+        Ok(BaseTokenConfig {
+            name: self.name.unwrap_or_else(|| DEFAULT_NAME.to_string()),
+            ticker_symbol: self.ticker_symbol.unwrap_or_else(|| DEFAULT_TICKER_SYMBOL.to_string()),
+            unit: self.unit.unwrap_or_else(|| DEFAULT_UNIT.to_string()),
+            subunit,
+            decimals: self.decimals.unwrap_or(DEFAULT_DECIMALS),
+            use_metric_prefix: self.use_metric_prefix.unwrap_or(DEFAULT_USE_METRIC_PREFIX),
+            token_supply,
+        })
+    }
+}
+
+/// The base token configuration.
+#[derive(Clone, Debug)]
+pub struct BaseTokenConfig {
+    name: String,
+    ticker_symbol: String,
+    unit: String,
+    subunit: Option<String>,
+    decimals: u8,
+    use_metric_prefix: bool,
+    token_supply: u64,
+}
+
+impl BaseTokenConfig {
+    /// Returns a builder to create a [`BaseTokenConfig`].
+    pub fn build() -> BaseTokenConfigBuilder {
+        BaseTokenConfigBuilder::new()
+    }
+
+    /// Returns the name of the base token.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the ticker symbol of the base token.
+    pub fn ticker_symbol(&self) -> &str {
+        &self.ticker_symbol
+    }
+
+    /// Returns the unit of the base token.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns the subunit of the base token, if any.
+    pub fn subunit(&self) -> Option<&str> {
+        self.subunit.as_deref()
+    }
+
+    /// Returns the number of decimals of the base token.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns whether the base token uses metric prefixes.
+    pub fn use_metric_prefix(&self) -> bool {
+        self.use_metric_prefix
+    }
+
+    /// Returns the total supply of the base token.
+    pub fn token_supply(&self) -> u64 {
+        self.token_supply
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_supply_defaults_to_the_maximum() {
+        assert_eq!(BaseTokenConfig::build().finish().unwrap().token_supply(), TOKEN_SUPPLY);
+    }
+
+    #[test]
+    fn token_supply_up_to_the_maximum() {
+        assert_eq!(
+            BaseTokenConfig::build()
+                .token_supply(1_000)
+                .finish()
+                .unwrap()
+                .token_supply(),
+            1_000
+        );
+        assert!(BaseTokenConfig::build().token_supply(TOKEN_SUPPLY).finish().is_ok());
+    }
+
+    #[test]
+    fn invalid_token_supply() {
+        assert!(matches!(
+            BaseTokenConfig::build().token_supply(0).finish(),
+            Err(Error::InvalidTokenSupply(0))
+        ));
+        assert!(matches!(
+            BaseTokenConfig::build().token_supply(TOKEN_SUPPLY + 1).finish(),
+            Err(Error::InvalidTokenSupply(supply)) if supply == TOKEN_SUPPLY + 1
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the ledger configuration.
+
+use serde::Deserialize;
+
+const DEFAULT_STORE_CONFLICT_DIAGNOSTICS: bool = false;
+const DEFAULT_ADDRESS_HISTORY: bool = false;
+const DEFAULT_ADDRESS_HISTORY_RETENTION: u32 = 0;
+
+/// Builder for a [`LedgerConfig`].
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct LedgerConfigBuilder {
+    #[serde(alias = "storeConflictDiagnostics")]
+    store_conflict_diagnostics: Option<bool>,
+    #[serde(alias = "addressHistory")]
+    address_history: Option<bool>,
+    #[serde(alias = "addressHistoryRetention")]
+    address_history_retention: Option<u32>,
+}
+
+impl LedgerConfigBuilder {
+    /// Creates a new [`LedgerConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether detailed conflict diagnostics of conflicting blocks should be stored.
+    pub fn store_conflict_diagnostics(mut self, store_conflict_diagnostics: bool) -> Self {
+        self.store_conflict_diagnostics.replace(store_conflict_diagnostics);
+        self
+    }
+
+    /// Sets whether the history of the outputs created and consumed by each address should be recorded.
+    pub fn address_history(mut self, address_history: bool) -> Self {
+        self.address_history.replace(address_history);
+        self
+    }
+
+    /// Sets the number of milestones for which the history of consumed outputs is retained, `0` retaining it forever.
+    pub fn address_history_retention(mut self, address_history_retention: u32) -> Self {
+        self.address_history_retention.replace(address_history_retention);
+        self
+    }
+
+    /// Finishes the builder into a [`LedgerConfig`].
+    #[must_use]
+    pub fn finish(self) -> LedgerConfig {
+        LedgerConfig {
+            store_conflict_diagnostics: self
+                .store_conflict_diagnostics
+                .unwrap_or(DEFAULT_STORE_CONFLICT_DIAGNOSTICS),
+            address_history: self.address_history.unwrap_or(DEFAULT_ADDRESS_HISTORY),
+            address_history_retention: self
+                .address_history_retention
+                .unwrap_or(DEFAULT_ADDRESS_HISTORY_RETENTION),
+        }
+    }
+}
+
+/// The ledger configuration.
+#[derive(Clone, Debug)]
+pub struct LedgerConfig {
+    store_conflict_diagnostics: bool,
+    address_history: bool,
+    address_history_retention: u32,
+}
+
+impl LedgerConfig {
+    /// Returns a builder to create a [`LedgerConfig`].
+    pub fn build() -> LedgerConfigBuilder {
+        LedgerConfigBuilder::new()
+    }
+
+    /// Returns whether detailed conflict diagnostics of conflicting blocks are stored.
+    pub fn store_conflict_diagnostics(&self) -> bool {
+        self.store_conflict_diagnostics
+    }
+
+    /// Returns whether the history of the outputs created and consumed by each address is recorded.
+    pub fn address_history(&self) -> bool {
+        self.address_history
+    }
+
+    /// Returns the number of milestones for which the history of consumed outputs is retained, `0` retaining it
+    /// forever.
+    pub fn address_history_retention(&self) -> u32 {
+        self.address_history_retention
+    }
+}
@@ -4,15 +4,18 @@
 use std::collections::HashMap;
 
 use bee_block::{
+    constant::TOKEN_SUPPLY,
     output::OutputId,
     payload::milestone::{MerkleRoot, MilestoneId, MilestoneIndex},
-    semantic::ConflictReason,
+    protocol::ProtocolParameters,
+    semantic::{ConflictDiagnostic, ConflictReason, ValidationContext},
     BlockId,
 };
 
 use crate::types::{ConsumedOutput, CreatedOutput};
 
 /// White flag metadata of a milestone confirmation.
+#[derive(Debug, Eq, PartialEq)]
 pub struct WhiteFlagMetadata {
     /// Index of the confirmed milestone.
     pub(crate) milestone_index: MilestoneIndex,
@@ -20,6 +23,10 @@ pub struct WhiteFlagMetadata {
     pub(crate) milestone_timestamp: u32,
     /// The id of the previous milestone.
     pub(crate) previous_milestone_id: Option<MilestoneId>,
+    /// The token supply the transactions are validated against if no protocol parameters are active.
+    pub(crate) token_supply: u64,
+    /// The protocol parameters active at the confirmed milestone, if any were announced.
+    pub(crate) protocol_parameters: Option<ProtocolParameters>,
     /// Whether the previous milestone has been found in the past cone of the current milestone.
     pub(crate) found_previous_milestone: bool,
     /// The blocks which were referenced by the confirmed milestone.
@@ -28,6 +35,8 @@ pub struct WhiteFlagMetadata {
     pub(crate) excluded_no_transaction_blocks: Vec<BlockId>,
     /// The blocks which were excluded because they were conflicting with the ledger state.
     pub(crate) excluded_conflicting_blocks: Vec<(BlockId, ConflictReason)>,
+    /// The detailed semantic validation diagnostics of the excluded conflicting blocks, when available.
+    pub(crate) conflict_diagnostics: Vec<(BlockId, ConflictDiagnostic)>,
     // The blocks which mutate the ledger in the order in which they were applied.
     pub(crate) included_blocks: Vec<BlockId>,
     /// The outputs created within the confirmed milestone.
@@ -51,10 +60,13 @@ impl WhiteFlagMetadata {
             milestone_index,
             milestone_timestamp,
             previous_milestone_id,
+            token_supply: TOKEN_SUPPLY,
+            protocol_parameters: None,
             found_previous_milestone: false,
             referenced_blocks: Vec::new(),
             excluded_no_transaction_blocks: Vec::new(),
             excluded_conflicting_blocks: Vec::new(),
+            conflict_diagnostics: Vec::new(),
             included_blocks: Vec::new(),
             created_outputs: HashMap::new(),
             consumed_outputs: HashMap::new(),
@@ -63,6 +75,28 @@ impl WhiteFlagMetadata {
         }
     }
 
+    /// Validates the transactions against the given token supply if no protocol parameters are active.
+    #[must_use]
+    pub fn with_token_supply(mut self, token_supply: u64) -> Self {
+        self.token_supply = token_supply;
+        self
+    }
+
+    /// Validates the transactions against the protocol parameters active at the confirmed milestone.
+    #[must_use]
+    pub fn with_protocol_parameters(mut self, protocol_parameters: Option<ProtocolParameters>) -> Self {
+        self.protocol_parameters = protocol_parameters;
+        self
+    }
+
+    /// Applies the milestone-dependent parameters to the semantic validation of a transaction.
+    pub(crate) fn parameterize<'a>(&self, context: ValidationContext<'a>) -> ValidationContext<'a> {
+        match &self.protocol_parameters {
+            Some(parameters) => context.with_protocol_parameters(parameters),
+            None => context.with_token_supply(self.token_supply),
+        }
+    }
+
     /// Returns the inclusion merkle root of a [`WhiteFlagMetadata`].
     pub fn inclusion_merkle_root(&self) -> &MerkleRoot {
         &self.inclusion_merkle_root
@@ -72,4 +106,14 @@ impl WhiteFlagMetadata {
     pub fn applied_merkle_root(&self) -> &MerkleRoot {
         &self.applied_merkle_root
     }
+
+    /// Returns the excluded conflicting blocks of a [`WhiteFlagMetadata`].
+    pub fn excluded_conflicting_blocks(&self) -> &[(BlockId, ConflictReason)] {
+        &self.excluded_conflicting_blocks
+    }
+
+    /// Returns the conflict diagnostics of a [`WhiteFlagMetadata`].
+    pub fn conflict_diagnostics(&self) -> &[(BlockId, ConflictDiagnostic)] {
+        &self.conflict_diagnostics
+    }
 }
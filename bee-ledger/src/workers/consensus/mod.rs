@@ -11,6 +11,6 @@ pub(crate) mod worker;
 
 pub use self::{
     metadata::WhiteFlagMetadata,
-    white_flag::white_flag,
-    worker::{ConsensusWorker, ConsensusWorkerCommand},
+    white_flag::{white_flag, white_flag_sequential},
+    worker::{ConsensusWorker, ConsensusWorkerCommand, JobProgress},
 };
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use bee_block::{
     input::Input,
@@ -11,12 +11,17 @@ use bee_block::{
         transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
         Payload,
     },
-    semantic::{semantic_validation, ConflictReason, ValidationContext},
-    unlock::Unlocks,
+    semantic::{semantic_validation_with_diagnostic, ConflictDiagnostic, ConflictReason, ValidationContext},
+    signature::{Ed25519BatchVerifier, Signature, VerifiedSignatures},
+    unlock::{Unlock, Unlocks},
     Block, BlockId,
 };
-use bee_tangle::Tangle;
+use bee_tangle::{
+    walker::{WalkItem, WalkOrder},
+    Tangle,
+};
 use crypto::hashes::blake2b::Blake2b256;
+use rayon::prelude::*;
 
 use crate::{
     types::{ConsumedOutput, CreatedOutput},
@@ -27,12 +32,43 @@ use crate::{
     },
 };
 
+/// Number of walked blocks validated and applied at once, which bounds the number of blocks held in memory while a past
+/// cone is traversed.
+const CONE_BATCH_SIZE: usize = 256;
+
+/// The result of the semantic validation of a transaction performed ahead of its application, along with the inputs it
+/// was performed against.
+struct Prevalidation {
+    inputs: Vec<(OutputId, Output)>,
+    diagnostic: Option<ConflictDiagnostic>,
+}
+
+impl Prevalidation {
+    /// Returns whether the prevalidation was performed against the given inputs.
+    fn matches(&self, inputs: &[(OutputId, &Output)]) -> bool {
+        self.inputs.len() == inputs.len()
+            && self
+                .inputs
+                .iter()
+                .zip(inputs)
+                .all(|((output_id, output), (input_id, input))| output_id == input_id && output == *input)
+    }
+}
+
+/// The validation work shared by all the transactions of a batch of a past cone.
+#[derive(Default)]
+struct ConeValidation {
+    verified_signatures: VerifiedSignatures,
+    prevalidations: HashMap<BlockId, Prevalidation>,
+}
+
 fn apply_regular_essence<B: StorageBackend>(
     storage: &B,
     block_id: &BlockId,
     transaction_id: &TransactionId,
     essence: &RegularTransactionEssence,
     unlocks: &Unlocks,
+    validation: &ConeValidation,
     metadata: &mut WhiteFlagMetadata,
 ) -> Result<ConflictReason, Error> {
     let mut consumed_outputs = Vec::<(OutputId, CreatedOutput)>::new();
@@ -71,18 +107,31 @@ fn apply_regular_essence<B: StorageBackend>(
         .map(|(output_id, created_output)| (*output_id, created_output.inner()))
         .collect();
 
-    let context = ValidationContext::new(
-        transaction_id,
-        essence,
-        inputs.iter().map(|(output_id, input)| (output_id, *input)),
-        unlocks,
-        metadata.milestone_index,
-        metadata.milestone_timestamp,
-    );
+    // A prevalidation can only be reused if it was performed against the very same inputs.
+    let diagnostic = match validation.prevalidations.get(block_id) {
+        Some(prevalidation) if prevalidation.matches(&inputs) => prevalidation.diagnostic.clone(),
+        _ => {
+            let context = metadata.parameterize(
+                ValidationContext::new(
+                    transaction_id,
+                    essence,
+                    inputs.iter().map(|(output_id, input)| (output_id, *input)),
+                    unlocks,
+                    metadata.milestone_index,
+                    metadata.milestone_timestamp,
+                )
+                .with_verified_signatures(&validation.verified_signatures),
+            );
+
+            semantic_validation_with_diagnostic(context, &inputs, unlocks)?
+        }
+    };
+
+    if let Some(diagnostic) = diagnostic {
+        let conflict = diagnostic.reason();
 
-    let conflict = semantic_validation(context, &inputs, unlocks)?;
+        metadata.conflict_diagnostics.push((*block_id, diagnostic));
 
-    if conflict != ConflictReason::None {
         return Ok(conflict);
     }
 
@@ -115,6 +164,7 @@ fn apply_transaction<B: StorageBackend>(
     storage: &B,
     block_id: &BlockId,
     transaction: &TransactionPayload,
+    validation: &ConeValidation,
     metadata: &mut WhiteFlagMetadata,
 ) -> Result<ConflictReason, Error> {
     match transaction.essence() {
@@ -124,6 +174,7 @@ fn apply_transaction<B: StorageBackend>(
             &transaction.id(),
             essence,
             transaction.unlocks(),
+            validation,
             metadata,
         ),
     }
@@ -133,15 +184,18 @@ fn apply_block<B: StorageBackend>(
     storage: &B,
     block_id: &BlockId,
     block: &Block,
+    validation: &ConeValidation,
     metadata: &mut WhiteFlagMetadata,
 ) -> Result<(), Error> {
     metadata.referenced_blocks.push(*block_id);
 
     match block.payload() {
-        Some(Payload::Transaction(transaction)) => match apply_transaction(storage, block_id, transaction, metadata)? {
-            ConflictReason::None => metadata.included_blocks.push(*block_id),
-            conflict => metadata.excluded_conflicting_blocks.push((*block_id, conflict)),
-        },
+        Some(Payload::Transaction(transaction)) => {
+            match apply_transaction(storage, block_id, transaction, validation, metadata)? {
+                ConflictReason::None => metadata.included_blocks.push(*block_id),
+                conflict => metadata.excluded_conflicting_blocks.push((*block_id, conflict)),
+            }
+        }
         Some(Payload::Milestone(milestone)) => {
             if let Some(previous_milestone_id) = metadata.previous_milestone_id {
                 if previous_milestone_id == milestone.id() {
@@ -156,36 +210,215 @@ fn apply_block<B: StorageBackend>(
     Ok(())
 }
 
+/// Verifies the signature unlocks of all the transactions of the walked blocks as a single batch.
+fn verify_signature_unlocks(items: &[WalkItem]) -> VerifiedSignatures {
+    let mut verifier = Ed25519BatchVerifier::new();
+
+    for item in items {
+        if let WalkItem::Matched { block, .. } = item {
+            if let Some(Payload::Transaction(transaction)) = block.payload() {
+                let essence_hash = transaction.essence().hash();
+
+                for unlock in transaction.unlocks().iter() {
+                    if let Unlock::Signature(unlock) = unlock {
+                        let Signature::Ed25519(signature) = unlock.signature();
+
+                        verifier.queue(essence_hash, signature.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    verifier.verify()
+}
+
+/// Returns the transactions of the walked blocks, in walk order.
+fn cone_transactions(items: &[WalkItem]) -> Vec<(BlockId, TransactionId, &TransactionPayload)> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            WalkItem::Matched { block_id, block, .. } => match block.payload() {
+                Some(Payload::Transaction(transaction)) => Some((*block_id, transaction.id(), &**transaction)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Semantically validates a transaction against the inputs it would consume if none of them were spent yet.
+///
+/// Returns `None` if the transaction can't be prevalidated, in which case it is validated when it is applied.
+#[allow(clippy::too_many_arguments)]
+fn prevalidate_regular_essence<B: StorageBackend>(
+    storage: &B,
+    transaction_id: &TransactionId,
+    essence: &RegularTransactionEssence,
+    unlocks: &Unlocks,
+    created_outputs: &HashMap<OutputId, &Output>,
+    input_uses: &HashMap<OutputId, usize>,
+    verified_signatures: &VerifiedSignatures,
+    metadata: &WhiteFlagMetadata,
+) -> Option<Prevalidation> {
+    let mut inputs = Vec::with_capacity(essence.inputs().len());
+
+    for input in essence.inputs().iter() {
+        let output_id = match input {
+            Input::Utxo(input) => input.output_id(),
+            _ => return None,
+        };
+
+        // Inputs shared with other transactions of the batch are only resolved by the order of application.
+        if input_uses.get(output_id).copied().unwrap_or_default() > 1 {
+            return None;
+        }
+
+        let output = match (created_outputs.get(output_id), metadata.created_outputs.get(output_id)) {
+            (Some(output), _) => (*output).clone(),
+            // Created by a previous batch of the cone.
+            (None, Some(output)) => output.inner().clone(),
+            // Storage errors are surfaced when the transaction is applied.
+            (None, None) => storage::fetch_output(storage, output_id).ok()??.inner().clone(),
+        };
+
+        inputs.push((*output_id, output));
+    }
+
+    let input_refs: Vec<(OutputId, &Output)> = inputs.iter().map(|(output_id, output)| (*output_id, output)).collect();
+
+    let context = metadata.parameterize(
+        ValidationContext::new(
+            transaction_id,
+            essence,
+            input_refs.iter().map(|(output_id, input)| (output_id, *input)),
+            unlocks,
+            metadata.milestone_index,
+            metadata.milestone_timestamp,
+        )
+        .with_verified_signatures(verified_signatures),
+    );
+
+    // Validation errors are surfaced when the transaction is applied.
+    let diagnostic = semantic_validation_with_diagnostic(context, &input_refs, unlocks).ok()?;
+
+    Some(Prevalidation { inputs, diagnostic })
+}
+
+/// Semantically validates the transactions of the walked blocks in parallel, ahead of their sequential application.
+///
+/// The semantic validation of a transaction only depends on the content of its inputs, which is the same whether they
+/// are created within the cone or fetched from the storage; only whether they can still be consumed depends on the
+/// order of application. Transactions sharing an input with another transaction of the batch are not prevalidated as
+/// at most one of them can be applied.
+fn prevalidate_transactions<B: StorageBackend>(
+    storage: &B,
+    items: &[WalkItem],
+    verified_signatures: &VerifiedSignatures,
+    metadata: &WhiteFlagMetadata,
+) -> Result<HashMap<BlockId, Prevalidation>, Error> {
+    let transactions = cone_transactions(items);
+    let mut created_outputs = HashMap::new();
+    let mut input_uses = HashMap::<OutputId, usize>::new();
+
+    for (_, transaction_id, transaction) in transactions.iter() {
+        let TransactionEssence::Regular(essence) = transaction.essence();
+
+        for (index, output) in essence.outputs().iter().enumerate() {
+            created_outputs.insert(OutputId::new(*transaction_id, index as u16)?, output);
+        }
+
+        for input in essence.inputs().iter() {
+            if let Input::Utxo(input) = input {
+                *input_uses.entry(*input.output_id()).or_default() += 1;
+            }
+        }
+    }
+
+    Ok(transactions
+        .par_iter()
+        .filter_map(|(block_id, transaction_id, transaction)| {
+            let TransactionEssence::Regular(essence) = transaction.essence();
+
+            prevalidate_regular_essence(
+                storage,
+                transaction_id,
+                essence,
+                transaction.unlocks(),
+                &created_outputs,
+                &input_uses,
+                verified_signatures,
+                metadata,
+            )
+            .map(|prevalidation| (*block_id, prevalidation))
+        })
+        .collect())
+}
+
+/// Validates and applies a batch of walked blocks, recording the blocks missing from the tangle.
+fn apply_batch<B: StorageBackend>(
+    storage: &B,
+    items: Vec<WalkItem>,
+    metadata: &mut WhiteFlagMetadata,
+    parallel: bool,
+    missing: &mut Vec<BlockId>,
+) -> Result<(), Error> {
+    let mut validation = ConeValidation {
+        verified_signatures: verify_signature_unlocks(&items),
+        ..Default::default()
+    };
+
+    if parallel {
+        validation.prevalidations =
+            prevalidate_transactions(storage, &items, &validation.verified_signatures, metadata)?;
+    }
+
+    for item in items {
+        match item {
+            WalkItem::Matched { block_id, block, .. } => {
+                apply_block(storage, &block_id, &block, &validation, metadata)?
+            }
+            WalkItem::Missing { block_id, .. } => missing.push(block_id),
+            WalkItem::Unmatched { .. } | WalkItem::SolidEntryPoint { .. } => {}
+        }
+    }
+
+    Ok(())
+}
+
 async fn traverse_past_cone<B: StorageBackend>(
     tangle: &Tangle<B>,
     storage: &B,
-    mut block_ids: Vec<BlockId>,
+    block_ids: Vec<BlockId>,
     metadata: &mut WhiteFlagMetadata,
+    parallel: bool,
 ) -> Result<(), Error> {
-    let mut visited = HashSet::new();
-
-    while let Some(block_id) = block_ids.last() {
-        if let Some((block, meta)) = tangle.get_block_and_metadata(block_id) {
-            if meta.flags().is_referenced() {
-                visited.insert(*block_id);
-                block_ids.pop();
-                continue;
-            }
+    let mut missing = Vec::new();
 
-            if let Some(unvisited) = block.parents().iter().find(|p| !visited.contains(p)) {
-                block_ids.push(*unvisited);
-            } else {
-                if !visited.contains(block_id) {
-                    apply_block(storage, block_id, &block, metadata)?;
-                    visited.insert(*block_id);
-                }
-                block_ids.pop();
+    {
+        // Blocks are applied once all their parents have been, starting from the last of the given blocks. The cone is
+        // walked in batches, the transactions of a batch are validated ahead of their application.
+        let mut walk = tangle
+            .walk_past_cone(block_ids.into_iter().rev())
+            .with_order(WalkOrder::DepthFirstPostOrder)
+            .with_predicate(|_, _, meta| !meta.flags().is_referenced())
+            .finish();
+
+        loop {
+            let items = walk.by_ref().take(CONE_BATCH_SIZE).collect::<Result<Vec<_>, _>>()?;
+
+            if items.is_empty() {
+                break;
             }
-        } else if !tangle.is_solid_entry_point(block_id).await {
-            return Err(Error::MissingBlock(*block_id));
-        } else {
-            visited.insert(*block_id);
-            block_ids.pop();
+
+            apply_batch(storage, items, metadata, parallel, &mut missing)?;
+        }
+    }
+
+    // Only solid entry points may be missing from the tangle, the first block that isn't one fails the traversal.
+    for block_id in missing {
+        if !tangle.is_solid_entry_point(&block_id).await {
+            return Err(Error::MissingBlock(block_id));
         }
     }
 
@@ -194,13 +427,43 @@ async fn traverse_past_cone<B: StorageBackend>(
 
 /// Computes the ledger state according to the White Flag method.
 /// TIP: <https://github.com/iotaledger/tips/blob/main/tips/TIP-0002/tip-0002.md>
+///
+/// Transactions are semantically validated in parallel and then applied in order, which yields the same metadata as
+/// [`white_flag_sequential`].
 pub async fn white_flag<B: StorageBackend>(
     tangle: &Tangle<B>,
     storage: &B,
     block_ids: &[BlockId],
     metadata: &mut WhiteFlagMetadata,
 ) -> Result<(), Error> {
-    traverse_past_cone(tangle, storage, block_ids.iter().rev().copied().collect(), metadata).await?;
+    compute_white_flag(tangle, storage, block_ids, metadata, true).await
+}
+
+/// Computes the ledger state according to the White Flag method, validating and applying transactions one by one.
+pub async fn white_flag_sequential<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    block_ids: &[BlockId],
+    metadata: &mut WhiteFlagMetadata,
+) -> Result<(), Error> {
+    compute_white_flag(tangle, storage, block_ids, metadata, false).await
+}
+
+async fn compute_white_flag<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    block_ids: &[BlockId],
+    metadata: &mut WhiteFlagMetadata,
+    parallel: bool,
+) -> Result<(), Error> {
+    traverse_past_cone(
+        tangle,
+        storage,
+        block_ids.iter().rev().copied().collect(),
+        metadata,
+        parallel,
+    )
+    .await?;
 
     // PANIC: unwrap is fine as Blake2b256 returns a hash of length MerkleRoot::LENGTH.
     metadata.inclusion_merkle_root = MerkleRoot::from(
@@ -1,16 +1,25 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::any::TypeId;
+use std::{
+    any::TypeId,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 use async_trait::async_trait;
 use bee_block::{
     output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
     payload::{
-        milestone::{MilestoneId, MilestoneIndex, ReceiptMilestoneOption},
+        milestone::{MilestoneEssence, MilestoneId, MilestoneIndex, ReceiptMilestoneOption},
         transaction::TransactionId,
         Payload,
     },
+    protocol::ProtocolParameters,
     semantic::ConflictReason,
     BlockId,
 };
@@ -22,13 +31,29 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::{
-    types::{CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
+    types::{snapshot::SnapshotKind, CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
     workers::{
+        address_history,
+        config::LedgerConfig,
         consensus::{metadata::WhiteFlagMetadata, state::validate_ledger_state, white_flag},
         error::Error,
-        event::{BlockReferenced, LedgerUpdated, MilestoneConfirmed, OutputConsumed, OutputCreated, ReceiptCreated},
-        pruning::{condition::should_prune, config::PruningConfig, prune},
-        snapshot::{condition::should_snapshot, config::SnapshotConfig, worker::SnapshotWorker},
+        event::{
+            BlockReferenced, LedgerUpdated, MilestoneConfirmed, OutputConsumed, OutputCreated, ReceiptCreated,
+            SnapshottedIndex,
+        },
+        protocol_parameters::ProtocolParametersSchedule,
+        pruning::{
+            condition::{pruning_range, should_prune},
+            config::PruningConfig,
+            prune, PruningReason, PruningStatistics, PruningTarget,
+        },
+        snapshot::{
+            condition::should_snapshot,
+            config::SnapshotConfig,
+            error::Error as SnapshotError,
+            export::{self, resolve_snapshot_path, SnapshotState},
+            worker::SnapshotWorker,
+        },
         storage::{self, StorageBackend},
     },
 };
@@ -46,6 +71,43 @@ pub enum ConsensusWorkerCommand {
         OutputId,
         oneshot::Sender<(Result<Option<CreatedOutput>, Error>, LedgerIndex)>,
     ),
+    /// Command to prune the database up to a target, replying with the last pruned milestone index.
+    Prune(
+        PruningTarget,
+        Arc<JobProgress>,
+        oneshot::Sender<Result<MilestoneIndex, Error>>,
+    ),
+    /// Command to write a snapshot to a path relative to the snapshot directory, replying with its solid entry point
+    /// index and ledger index.
+    CreateSnapshot(
+        SnapshotKind,
+        PathBuf,
+        Arc<JobProgress>,
+        oneshot::Sender<Result<(MilestoneIndex, MilestoneIndex), Error>>,
+    ),
+}
+
+/// Progress of a pruning or snapshot job of the consensus worker, in steps.
+#[derive(Debug, Default)]
+pub struct JobProgress {
+    done: AtomicU64,
+    total: AtomicU64,
+}
+
+impl JobProgress {
+    pub(crate) fn start(&self, total: u64) {
+        self.done.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    pub(crate) fn advance(&self) {
+        self.done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of finished steps and the total number of steps, which is zero until the job started.
+    pub fn get(&self) -> (u64, u64) {
+        (self.done.load(Ordering::Relaxed), self.total.load(Ordering::Relaxed))
+    }
 }
 
 /// The consensus worker.
@@ -59,20 +121,85 @@ pub(crate) fn migration_from_milestone(
     milestone_id: MilestoneId,
     receipt: &ReceiptMilestoneOption,
     consumed_treasury: TreasuryOutput,
+    token_supply: u64,
 ) -> Result<Migration, Error> {
     let receipt = Receipt::new(receipt.clone(), milestone_index);
 
-    receipt.validate(&consumed_treasury)?;
+    receipt.validate(&consumed_treasury, token_supply)?;
 
     let created_treasury = TreasuryOutput::new(receipt.inner().transaction().output().clone(), milestone_id);
 
     Ok(Migration::new(receipt, consumed_treasury, created_treasury))
 }
 
+pub(crate) fn protocol_parameters_from_milestone(
+    essence: &MilestoneEssence,
+) -> Result<Option<(MilestoneIndex, ProtocolParameters)>, Error> {
+    let option = match essence.options().parameters() {
+        Some(option) => option,
+        None => return Ok(None),
+    };
+
+    if option.target_milestone_index() <= essence.index() {
+        return Err(Error::PastProtocolParametersTarget(
+            essence.index(),
+            option.target_milestone_index(),
+        ));
+    }
+
+    let parameters = option
+        .protocol_parameters()
+        .map_err(|_| Error::InvalidProtocolParameters(essence.index()))?;
+
+    if parameters.protocol_version() != option.protocol_version() {
+        return Err(Error::InvalidProtocolParameters(essence.index()));
+    }
+
+    Ok(Some((option.target_milestone_index(), parameters)))
+}
+
+// Captures the ledger state a snapshot is written from, milestones are not confirmed meanwhile.
+async fn snapshot_state<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    snapshot_config: &SnapshotConfig,
+    kind: SnapshotKind,
+    sep_index: MilestoneIndex,
+) -> Result<SnapshotState, Error> {
+    Ok(match kind {
+        SnapshotKind::Full => SnapshotState::Full(export::full_snapshot_state(tangle, storage).await?),
+        SnapshotKind::Delta => SnapshotState::Delta(
+            export::delta_snapshot_state(tangle, storage, snapshot_config.full_path(), sep_index).await?,
+        ),
+    })
+}
+
+// Writes an ad-hoc snapshot, the snapshot and solid entry point indexes of the node are left untouched.
+fn create_snapshot<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    bus: &Bus<'static>,
+    state: SnapshotState,
+    path: &Path,
+    progress: &JobProgress,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    let (sep_index, ledger_index) = match state {
+        SnapshotState::Full(state) => export::write_full_snapshot(tangle, storage, state, path, Some(progress))?,
+        SnapshotState::Delta(state) => export::write_delta_snapshot(tangle, storage, state, path, Some(progress))?,
+    };
+
+    bus.dispatch(SnapshottedIndex { index: sep_index });
+
+    Ok((sep_index, ledger_index))
+}
+
 async fn confirm<N: Node>(
     tangle: &Tangle<N::Backend>,
     storage: &N::Backend,
+    schedule: &ProtocolParametersSchedule,
     bus: &Bus<'static>,
+    token_supply: u64,
+    ledger_config: &LedgerConfig,
     block_id: BlockId,
     ledger_index: &mut LedgerIndex,
     receipt_migrated_at: &mut MilestoneIndex,
@@ -80,7 +207,7 @@ async fn confirm<N: Node>(
 where
     N::Backend: StorageBackend,
 {
-    let block = tangle.get(&block_id).ok_or(Error::MilestoneBlockNotFound(block_id))?;
+    let block = tangle.get(&block_id)?.ok_or(Error::MilestoneBlockNotFound(block_id))?;
 
     let milestone = match block.payload() {
         Some(Payload::Milestone(milestone)) => milestone,
@@ -94,11 +221,32 @@ where
         ));
     }
 
+    let active_parameters = schedule.active_at(milestone.essence().index());
+
+    if let Some(active) = &active_parameters {
+        if active.protocol_version() != milestone.essence().protocol_version() {
+            return Err(Error::ProtocolVersionMismatch(
+                milestone.essence().index(),
+                active.protocol_version(),
+                milestone.essence().protocol_version(),
+            ));
+        }
+    }
+
+    let parameters = protocol_parameters_from_milestone(milestone.essence())?;
+
+    // The token supply and the byte cost may be changed by a protocol parameters upgrade.
+    let token_supply = active_parameters
+        .as_ref()
+        .map_or(token_supply, ProtocolParameters::token_supply);
+
     let mut metadata = WhiteFlagMetadata::new(
         milestone.essence().index(),
         milestone.essence().timestamp(),
         Some(*milestone.essence().previous_milestone_id()),
-    );
+    )
+    .with_token_supply(token_supply)
+    .with_protocol_parameters(active_parameters);
 
     white_flag(tangle, storage, block.parents(), &mut metadata).await?;
 
@@ -160,6 +308,7 @@ where
             milestone_id,
             receipt,
             storage::fetch_unspent_treasury_output(storage)?,
+            token_supply,
         )?)
     } else {
         None
@@ -171,8 +320,36 @@ where
         &metadata.created_outputs,
         &metadata.consumed_outputs,
         &migration,
+        &parameters,
+        ledger_config.address_history(),
     )?;
 
+    if ledger_config.store_conflict_diagnostics() {
+        storage::insert_conflict_diagnostics(&*storage, &metadata.conflict_diagnostics)?;
+    }
+
+    if ledger_config.address_history() && ledger_config.address_history_retention() != 0 {
+        if let Some(target_index) = (*metadata.milestone_index).checked_sub(ledger_config.address_history_retention()) {
+            address_history::prune(&*storage, MilestoneIndex(target_index))?;
+        }
+    }
+
+    if let Some((target_index, parameters)) = parameters {
+        info!(
+            "Protocol parameters (version {}) scheduled to become active at milestone {}.",
+            parameters.protocol_version(),
+            target_index
+        );
+        schedule.schedule(target_index, parameters);
+    }
+
+    if schedule.is_upgrade_at(milestone.essence().index()) {
+        info!(
+            "Protocol parameters activated at milestone {}.",
+            milestone.essence().index()
+        );
+    }
+
     *ledger_index = LedgerIndex(milestone.essence().index());
     tangle.update_confirmed_milestone_index(milestone.essence().index());
 
@@ -180,7 +357,7 @@ where
         tangle.update_metadata(block_id, |block_metadata| {
             block_metadata.set_conflict(ConflictReason::None);
             block_metadata.reference(milestone.essence().timestamp());
-        });
+        })?;
         bus.dispatch(BlockReferenced { block_id: *block_id });
     }
 
@@ -188,7 +365,7 @@ where
         tangle.update_metadata(block_id, |block_metadata| {
             block_metadata.set_conflict(*conflict);
             block_metadata.reference(milestone.essence().timestamp());
-        });
+        })?;
         bus.dispatch(BlockReferenced { block_id: *block_id });
     }
 
@@ -196,7 +373,7 @@ where
         tangle.update_metadata(block_id, |block_metadata| {
             block_metadata.set_conflict(ConflictReason::None);
             block_metadata.reference(milestone.essence().timestamp());
-        });
+        })?;
         bus.dispatch(BlockReferenced { block_id: *block_id });
     }
 
@@ -258,7 +435,7 @@ impl<N: Node> Worker<N> for ConsensusWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (SnapshotConfig, PruningConfig);
+    type Config = (u64, LedgerConfig, SnapshotConfig, PruningConfig);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -266,13 +443,22 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (snapshot_config, pruning_config) = config;
+        let (token_supply, ledger_config, snapshot_config, pruning_config) = config;
         let (tx, rx) = mpsc::unbounded_channel();
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
         let bus = node.bus();
+        let schedule = node.resource::<ProtocolParametersSchedule>();
+        let pruning_statistics = node.resource::<PruningStatistics>();
+
+        schedule.reset(storage::fetch_protocol_parameters(&*storage)?);
 
-        validate_ledger_state(&*storage)?;
+        // The token supply may have been changed by a protocol parameters upgrade.
+        let active_token_supply = storage::fetch_ledger_index(&*storage)?
+            .and_then(|ledger_index| schedule.active_at(ledger_index.0))
+            .map_or(token_supply, |parameters| parameters.token_supply());
+
+        validate_ledger_state(&*storage, active_token_supply)?;
 
         let bmd = tangle.config().below_max_depth();
 
@@ -288,6 +474,12 @@ where
             snapshot_config.depth()
         };
 
+        // Snapshots created on demand are written next to the full snapshot the node was started from.
+        let snapshot_directory = snapshot_config
+            .full_path()
+            .parent()
+            .map_or_else(PathBuf::new, Path::to_path_buf);
+
         let snapshot_pruning_delta = bmd + EXTRA_PRUNING_DEPTH;
         let pruning_delay_min = snapshot_depth + snapshot_pruning_delta;
         let pruning_delay = if pruning_config.delay() < pruning_delay_min {
@@ -303,7 +495,11 @@ where
 
         // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
         let mut ledger_index = storage::fetch_ledger_index(&*storage)?.unwrap();
+
+        address_history::initialize(&*storage, ledger_config.address_history(), ledger_index)?;
+
         let mut receipt_migrated_at = MilestoneIndex(0);
+        let mut last_size_pruning = None;
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
@@ -316,7 +512,10 @@ where
                         if let Err(e) = confirm::<N>(
                             &tangle,
                             &storage,
+                            &schedule,
                             &bus,
+                            token_supply,
+                            &ledger_config,
                             block_id,
                             &mut ledger_index,
                             &mut receipt_migrated_at,
@@ -324,6 +523,12 @@ where
                         .await
                         {
                             error!("Confirmation error on {}: {}.", block_id, e);
+
+                            // The tangle already requested the node to stop, so the ledger is left untouched.
+                            if let Error::Tangle(_) = e {
+                                break;
+                            }
+
                             panic!("Aborting due to unexpected ledger error.");
                         }
 
@@ -343,11 +548,32 @@ where
                             }
                         }
 
-                        match should_prune(&tangle, ledger_index, pruning_delay, &pruning_config) {
-                            Ok((start_index, target_index)) => {
-                                if let Err(e) =
-                                    prune::prune(&tangle, &storage, &bus, start_index, target_index, &pruning_config)
-                                        .await
+                        match should_prune(
+                            &tangle,
+                            &storage,
+                            ledger_index,
+                            pruning_delay,
+                            pruning_delay_min,
+                            last_size_pruning,
+                            &pruning_config,
+                        ) {
+                            Ok((start_index, target_index, reason)) => {
+                                if reason == PruningReason::Size {
+                                    last_size_pruning.replace(Instant::now());
+                                }
+
+                                if let Err(e) = prune::prune(
+                                    &tangle,
+                                    &storage,
+                                    &bus,
+                                    start_index,
+                                    target_index,
+                                    reason,
+                                    &pruning_config,
+                                    &pruning_statistics,
+                                    None,
+                                )
+                                .await
                                 {
                                     error!("Pruning failed: {:?}.", e);
                                 }
@@ -362,6 +588,64 @@ where
                             error!("Error while sending output: {:?}", e);
                         }
                     }
+                    ConsensusWorkerCommand::Prune(target, progress, sender) => {
+                        // The job runs in the worker loop, no milestone is confirmed until it finishes; on demand
+                        // pruning has to leave as many milestones as the automatic one at least.
+                        let result =
+                            match pruning_range(tangle.get_pruning_index(), ledger_index, pruning_delay_min, target) {
+                                Ok((start_index, target_index)) => prune::prune(
+                                    &tangle,
+                                    &storage,
+                                    &bus,
+                                    start_index,
+                                    target_index,
+                                    PruningReason::Manual,
+                                    &pruning_config,
+                                    &pruning_statistics,
+                                    Some(&progress),
+                                )
+                                .await
+                                .map(|_| target_index)
+                                .map_err(Error::from),
+                                Err(e) => Err(Error::from(e)),
+                            };
+
+                        if let Err(e) = &result {
+                            error!("Pruning failed: {:?}.", e);
+                        }
+
+                        if sender.send(result).is_err() {
+                            error!("Error while sending pruning result.");
+                        }
+                    }
+                    ConsensusWorkerCommand::CreateSnapshot(kind, path, progress, sender) => {
+                        // The job runs in the worker loop, no milestone is confirmed until it finishes.
+                        let state = match resolve_snapshot_path(&snapshot_directory, &path) {
+                            Some(path) => snapshot_state(
+                                &tangle,
+                                &storage,
+                                &snapshot_config,
+                                kind,
+                                MilestoneIndex(ledger_index.saturating_sub(snapshot_depth)),
+                            )
+                            .await
+                            .map(|state| (state, path)),
+                            None => Err(Error::Snapshot(SnapshotError::InvalidFilePath(
+                                path.to_string_lossy().into_owned(),
+                            ))),
+                        };
+                        let result = state.and_then(|(state, path)| {
+                            create_snapshot(&tangle, &storage, &bus, state, &path, &progress)
+                        });
+
+                        if let Err(e) = &result {
+                            error!("Failed to create snapshot: {:?}.", e);
+                        }
+
+                        if sender.send(result).is_err() {
+                            error!("Error while sending snapshot result.");
+                        }
+                    }
                 }
             }
 
@@ -6,14 +6,16 @@
 use std::convert::Infallible;
 
 use bee_block::{
+    output::OutputId,
     payload::milestone::{MerkleRoot, MilestoneIndex},
     BlockId, Error as BlockError,
 };
+use bee_tangle::TangleError;
 use packable::error::UnpackError;
 
 use crate::{
     types::{Error as TypesError, Unspent},
-    workers::snapshot::error::Error as SnapshotError,
+    workers::{pruning::error::Error as PruningError, snapshot::error::Error as SnapshotError},
 };
 
 /// Errors occurring during ledger workers operations.
@@ -24,6 +26,8 @@ pub enum Error {
     Io(#[from] std::io::Error),
     #[error("snapshot error: {0}")]
     Snapshot(#[from] SnapshotError),
+    #[error("pruning error: {0}")]
+    Pruning(#[from] PruningError),
     #[error("types error: {0}")]
     Types(#[from] TypesError),
     #[error("block error: {0}")]
@@ -48,8 +52,8 @@ pub enum Error {
     AppliedMerkleRootMismatch(MilestoneIndex, MerkleRoot, MerkleRoot),
     #[error("invalid blocks count: referenced ({0}) != no transaction ({1}) + conflicting ({2}) + included ({3})")]
     InvalidBlocksCount(usize, usize, usize, usize),
-    #[error("invalid ledger unspent state: {0}")]
-    InvalidLedgerUnspentState(u64),
+    #[error("invalid ledger unspent state: outputs and treasury sum up to {0} instead of the token supply {1}")]
+    InvalidLedgerUnspentState(u64, u64),
     #[error("consumed amount overflow")]
     ConsumedAmountOverflow,
     #[error("created amount overflow")]
@@ -64,12 +68,36 @@ pub enum Error {
     DecreasingReceiptMigratedAtIndex(MilestoneIndex, MilestoneIndex),
     #[error("missing unspent output {0}")]
     MissingUnspentOutput(Unspent),
+    #[error("missing output {0}")]
+    MissingOutput(OutputId),
+    #[error("missing consumed output {0}")]
+    MissingConsumedOutput(OutputId),
+    #[error("missing output diff of milestone {0}")]
+    MissingOutputDiff(MilestoneIndex),
+    #[error("the ledger is not initialized")]
+    UninitializedLedger,
+    #[error("invalid milestone range: {0} > {1}")]
+    InvalidMilestoneRange(MilestoneIndex, MilestoneIndex),
+    #[error("milestone range starts at {0}, at or below the pruning index {1}")]
+    MilestoneRangeBelowPruningIndex(MilestoneIndex, MilestoneIndex),
+    #[error("milestone range ends at {0}, above the ledger index {1}")]
+    MilestoneRangeAboveLedgerIndex(MilestoneIndex, MilestoneIndex),
+    #[error("milestone range includes milestone {0}, whose output diff is not stored")]
+    MilestoneRangeWithoutOutputDiff(MilestoneIndex),
     #[error("storage backend error: {0}")]
     Storage(Box<dyn std::error::Error + Send>),
+    #[error("tangle error: {0}")]
+    Tangle(#[from] TangleError),
     #[error("storage deposit return overflow")]
     StorageDepositReturnOverflow,
     #[error("previous milestone not found in the past cone")]
     PreviousMilestoneNotFound,
+    #[error("invalid protocol parameters in milestone {0}")]
+    InvalidProtocolParameters(MilestoneIndex),
+    #[error("protocol parameters of milestone {0} target a past milestone index: {1}")]
+    PastProtocolParametersTarget(MilestoneIndex, MilestoneIndex),
+    #[error("protocol version mismatch on milestone {0}: expected {1} != actual {2}")]
+    ProtocolVersionMismatch(MilestoneIndex, u8, u8),
 }
 
 impl<E: Into<Error>> From<UnpackError<E, std::io::Error>> for Error {
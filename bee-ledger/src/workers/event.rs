@@ -12,7 +12,10 @@ use bee_block::{
     BlockId,
 };
 
-use crate::types::{ConsumedOutput, CreatedOutput, Receipt};
+use crate::{
+    types::{ConsumedOutput, CreatedOutput, Receipt},
+    workers::pruning::PruningReason,
+};
 
 /// An event that indicates that a milestone was confirmed.
 #[derive(Clone)]
@@ -80,6 +83,21 @@ pub struct PrunedIndex {
     pub index: MilestoneIndex,
 }
 
+/// An event that indicates that a pruning run finished.
+#[derive(Clone)]
+pub struct DatabasePruned {
+    /// The first pruned index.
+    pub start_index: MilestoneIndex,
+    /// The last pruned index.
+    pub target_index: MilestoneIndex,
+    /// The reason that triggered the pruning.
+    pub reason: PruningReason,
+    /// The UNIX timestamp in seconds at which the pruning started.
+    pub start_timestamp: u64,
+    /// The UNIX timestamp in seconds at which the pruning ended.
+    pub end_timestamp: u64,
+}
+
 /// An event that indicates that a receipt was created.
 #[derive(Clone)]
 pub struct ReceiptCreated(pub Receipt);
@@ -3,9 +3,13 @@
 
 //! Module containing workers required to create and maintain the ledger state.
 
+pub mod address_history;
+pub mod base_token;
+pub mod config;
 pub mod consensus;
 pub mod error;
 pub mod event;
+pub mod protocol_parameters;
 pub mod pruning;
 pub mod snapshot;
 pub mod storage;
@@ -14,8 +18,10 @@ use bee_runtime::node::{Node, NodeBuilder};
 
 pub use self::storage::StorageBackend;
 use self::{
+    config::LedgerConfig,
     consensus::ConsensusWorker,
-    pruning::config::PruningConfig,
+    protocol_parameters::ProtocolParametersSchedule,
+    pruning::{config::PruningConfig, PruningStatistics},
     snapshot::{config::SnapshotConfig, worker::SnapshotWorker},
 };
 
@@ -23,6 +29,8 @@ use self::{
 pub fn init<N>(
     node_builder: N::Builder,
     network_id: u64,
+    token_supply: u64,
+    ledger_config: LedgerConfig,
     snapshot_config: SnapshotConfig,
     pruning_config: PruningConfig,
 ) -> N::Builder
@@ -31,6 +39,8 @@ where
     N::Backend: StorageBackend,
 {
     node_builder
-        .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone()))
-        .with_worker_cfg::<ConsensusWorker>((snapshot_config, pruning_config))
+        .with_resource(ProtocolParametersSchedule::default())
+        .with_resource(PruningStatistics::default())
+        .with_worker_cfg::<SnapshotWorker>((network_id, token_supply, snapshot_config.clone()))
+        .with_worker_cfg::<ConsensusWorker>((token_supply, ledger_config, snapshot_config, pruning_config))
 }
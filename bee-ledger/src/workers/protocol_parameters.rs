@@ -0,0 +1,119 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the schedule of protocol parameters upgrades.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use bee_block::{payload::milestone::MilestoneIndex, protocol::ProtocolParameters};
+
+/// Keeps track of the protocol parameters that are active or scheduled to become active at a milestone index.
+///
+/// Protocol parameters are announced by a parameters milestone option and become active at its target milestone index,
+/// i.e. they apply to the milestone with that index and all following ones until the next upgrade.
+#[derive(Clone, Default)]
+pub struct ProtocolParametersSchedule {
+    inner: Arc<RwLock<BTreeMap<MilestoneIndex, ProtocolParameters>>>,
+}
+
+impl ProtocolParametersSchedule {
+    /// Creates a new [`ProtocolParametersSchedule`] from a history of protocol parameters.
+    pub fn new(history: impl IntoIterator<Item = (MilestoneIndex, ProtocolParameters)>) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(history.into_iter().collect())),
+        }
+    }
+
+    /// Replaces the whole schedule with a history of protocol parameters.
+    pub(crate) fn reset(&self, history: impl IntoIterator<Item = (MilestoneIndex, ProtocolParameters)>) {
+        // Panic: the lock is never held across a panic.
+        *self.inner.write().unwrap() = history.into_iter().collect();
+    }
+
+    /// Schedules protocol parameters to become active at a target milestone index.
+    pub(crate) fn schedule(&self, target_index: MilestoneIndex, parameters: ProtocolParameters) {
+        // Panic: the lock is never held across a panic.
+        self.inner.write().unwrap().insert(target_index, parameters);
+    }
+
+    /// Removes the protocol parameters scheduled at a target milestone index.
+    #[cfg(test)]
+    pub(crate) fn unschedule(&self, target_index: MilestoneIndex) {
+        // Panic: the lock is never held across a panic.
+        self.inner.write().unwrap().remove(&target_index);
+    }
+
+    /// Returns the protocol parameters that are active at a milestone index, if any were announced.
+    pub fn active_at(&self, index: MilestoneIndex) -> Option<ProtocolParameters> {
+        // Panic: the lock is never held across a panic.
+        self.inner
+            .read()
+            .unwrap()
+            .range(..=index)
+            .next_back()
+            .map(|(_, parameters)| parameters.clone())
+    }
+
+    /// Returns the protocol parameters that are scheduled to become active after a milestone index.
+    pub fn pending_after(&self, index: MilestoneIndex) -> Vec<(MilestoneIndex, ProtocolParameters)> {
+        // Panic: the lock is never held across a panic.
+        self.inner
+            .read()
+            .unwrap()
+            .range(index + 1..)
+            .map(|(target_index, parameters)| (*target_index, parameters.clone()))
+            .collect()
+    }
+
+    /// Returns whether protocol parameters become active exactly at a milestone index.
+    pub fn is_upgrade_at(&self, index: MilestoneIndex) -> bool {
+        // Panic: the lock is never held across a panic.
+        self.inner.read().unwrap().contains_key(&index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(min_pow_score: u32) -> ProtocolParameters {
+        ProtocolParameters::new(
+            2,
+            "testnet".to_string(),
+            "rms".to_string(),
+            min_pow_score,
+            15,
+            100,
+            1,
+            10,
+            1_000,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn active_and_pending() {
+        let schedule = ProtocolParametersSchedule::new([(MilestoneIndex(1), parameters(1000))]);
+
+        schedule.schedule(MilestoneIndex(10), parameters(2000));
+
+        assert!(schedule.active_at(MilestoneIndex(0)).is_none());
+        assert_eq!(schedule.active_at(MilestoneIndex(9)), Some(parameters(1000)));
+        assert_eq!(schedule.active_at(MilestoneIndex(10)), Some(parameters(2000)));
+        assert_eq!(schedule.active_at(MilestoneIndex(42)), Some(parameters(2000)));
+
+        assert_eq!(
+            schedule.pending_after(MilestoneIndex(5)),
+            vec![(MilestoneIndex(10), parameters(2000))]
+        );
+        assert!(schedule.pending_after(MilestoneIndex(10)).is_empty());
+        assert!(schedule.is_upgrade_at(MilestoneIndex(10)));
+
+        schedule.unschedule(MilestoneIndex(10));
+
+        assert_eq!(schedule.active_at(MilestoneIndex(42)), Some(parameters(1000)));
+    }
+}
@@ -3,11 +3,15 @@
 
 use std::collections::VecDeque;
 
-use bee_block::{output::OutputId, payload::milestone::MilestoneIndex, Block, BlockId};
+use bee_block::{output::OutputId, payload::milestone::MilestoneIndex, semantic::ConflictDiagnostic, Block, BlockId};
 use bee_storage::access::{Batch, Fetch};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
-    unreferenced_block::UnreferencedBlock, Tangle,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
+    unreferenced_block::UnreferencedBlock,
+    Tangle,
 };
 use hashbrown::{HashMap, HashSet};
 use ref_cast::RefCast;
@@ -40,6 +44,7 @@ pub fn prune_confirmed_data<S: StorageBackend>(
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
     current_seps: &Seps,
+    pruned_blocks: &mut Vec<BlockId>,
 ) -> Result<(Seps, ConfirmedDataPruningMetrics), Error> {
     // We keep a list of already visited blocks.
     let mut visited = Blocks::with_capacity(512);
@@ -103,6 +108,9 @@ pub fn prune_confirmed_data<S: StorageBackend>(
             metrics.prunable_edges += 1;
         }
 
+        // Delete its tag index entry, if any.
+        prune_tagged_block(storage, batch, prune_index, block_id, &block)?;
+
         // Add its parents to the queue of yet to traverse blocks.
         to_visit.extend(block.into_parents().iter());
 
@@ -201,6 +209,8 @@ pub fn prune_confirmed_data<S: StorageBackend>(
     metrics.prunable_blocks = visited.len();
     metrics.new_seps = new_seps.len();
 
+    pruned_blocks.extend(visited);
+
     Ok((new_seps, metrics))
 }
 
@@ -208,6 +218,7 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
     storage: &S,
     batch: &mut S::Batch,
     prune_index: MilestoneIndex,
+    pruned_blocks: &mut Vec<BlockId>,
 ) -> Result<UnconfirmedDataPruningMetrics, Error> {
     let mut metrics = UnconfirmedDataPruningMetrics::default();
 
@@ -295,6 +306,10 @@ pub fn prune_unconfirmed_data<S: StorageBackend>(
 
                     metrics.prunable_edges += 1;
                 }
+
+                // The parents may outlive the block, their children are changed.
+                pruned_blocks.push(*unconf_block_id);
+                pruned_blocks.extend(parents.iter());
             }
             None => {
                 metrics.already_pruned += 1;
@@ -341,6 +356,28 @@ fn prune_block_and_metadata<S: StorageBackend>(
 ) -> Result<(), Error> {
     Batch::<BlockId, Block>::batch_delete(storage, batch, block_id).map_err(|e| Error::Storage(Box::new(e)))?;
     Batch::<BlockId, BlockMetadata>::batch_delete(storage, batch, block_id).map_err(|e| Error::Storage(Box::new(e)))?;
+    Batch::<BlockId, ConflictDiagnostic>::batch_delete(storage, batch, block_id)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    Ok(())
+}
+
+fn prune_tagged_block<S: StorageBackend>(
+    storage: &S,
+    batch: &mut S::Batch,
+    index: MilestoneIndex,
+    block_id: BlockId,
+    block: &Block,
+) -> Result<(), Error> {
+    // Only confirmed blocks are indexed, at the index of the milestone that confirmed them.
+    if let Some(padded_tag) = PaddedTag::from_block(block) {
+        Batch::<(PaddedTag, TaggedBlock), ()>::batch_delete(
+            storage,
+            batch,
+            &(padded_tag, TaggedBlock::new(index, block_id)),
+        )
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
 
     Ok(())
 }
@@ -1,10 +1,18 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
 use bee_block::payload::milestone::MilestoneIndex;
 use bee_tangle::{storage::StorageBackend, Tangle};
 
-use crate::{types::LedgerIndex, workers::pruning::config::PruningConfig};
+use crate::{
+    types::LedgerIndex,
+    workers::pruning::{
+        config::{PruningAgeConfig, PruningConfig, PruningSizeConfig},
+        error::Error,
+    },
+};
 
 const PRUNING_BATCH_SIZE_MAX: u32 = 200;
 
@@ -17,33 +25,466 @@ pub enum PruningSkipReason {
     BelowThreshold { reached_in: u32 },
 }
 
+/// Reasons for pruning.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PruningReason {
+    /// The ledger index exceeded the configured pruning delay.
+    Milestones,
+    /// The database exceeded the configured target size.
+    Size,
+    /// Milestones exceeded the configured maximum age.
+    Age,
+    /// Pruning was requested on demand.
+    Manual,
+}
+
+/// The milestone up to which an on-demand pruning removes data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PruningTarget {
+    /// Prunes up to the given milestone index.
+    Index(MilestoneIndex),
+    /// Prunes up to the milestone that lies the given number of milestones below the ledger index.
+    Depth(u32),
+}
+
+/// Returns the range of milestones to prune from `pruning_index` to reach `target`, keeping at least `depth_min`
+/// milestones below the ledger index.
+pub(crate) fn pruning_range(
+    pruning_index: MilestoneIndex,
+    ledger_index: LedgerIndex,
+    depth_min: u32,
+    target: PruningTarget,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    let start_index = MilestoneIndex(*pruning_index + 1);
+    let target_index = match target {
+        PruningTarget::Index(index) => index,
+        PruningTarget::Depth(depth) => MilestoneIndex(ledger_index.saturating_sub(depth)),
+    };
+    let maximum = MilestoneIndex(ledger_index.saturating_sub(depth_min));
+
+    if target_index > maximum {
+        Err(Error::TargetIndexTooHigh {
+            selected: target_index,
+            maximum,
+        })
+    } else if target_index < start_index {
+        Err(Error::InvalidTargetIndex {
+            selected: target_index,
+            minimum: start_index,
+        })
+    } else {
+        Ok((start_index, target_index))
+    }
+}
+
 pub(crate) fn should_prune<B: StorageBackend>(
     tangle: &Tangle<B>,
+    storage: &B,
     ledger_index: LedgerIndex,
     pruning_delay: u32,
+    pruning_delay_min: u32,
+    last_size_pruning: Option<Instant>,
     config: &PruningConfig,
-) -> Result<(MilestoneIndex, MilestoneIndex), PruningSkipReason> {
+) -> Result<(MilestoneIndex, MilestoneIndex, PruningReason), PruningSkipReason> {
     if config.disabled() {
         return Err(PruningSkipReason::Disabled);
     }
 
-    let pruning_index = *tangle.get_pruning_index() + 1;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("error creating timestamp")
+        .as_secs();
+
+    pruning_target(
+        *tangle.get_pruning_index() + 1,
+        *ledger_index,
+        pruning_delay,
+        pruning_delay_min,
+        last_size_pruning,
+        config,
+        || match storage.size() {
+            Ok(Some(size)) => Some(size as u64),
+            _ => None,
+        },
+        |index| {
+            tangle
+                .get_milestone_metadata(MilestoneIndex(index))
+                .ok()
+                .flatten()
+                .map(|milestone| milestone.timestamp())
+        },
+        now,
+    )
+}
+
+// Decides whether and up to which milestone to prune, given the database size and the milestone timestamps.
+#[allow(clippy::too_many_arguments)]
+fn pruning_target(
+    pruning_index: u32,
+    ledger_index: u32,
+    pruning_delay: u32,
+    pruning_delay_min: u32,
+    last_size_pruning: Option<Instant>,
+    config: &PruningConfig,
+    size: impl FnOnce() -> Option<u64>,
+    milestone_timestamp: impl Fn(u32) -> Option<u32>,
+    now: u64,
+) -> Result<(MilestoneIndex, MilestoneIndex, PruningReason), PruningSkipReason> {
     let pruning_threshold = pruning_index + pruning_delay;
 
-    if *ledger_index < pruning_threshold {
-        Err(PruningSkipReason::BelowThreshold {
-            reached_in: pruning_threshold - *ledger_index,
-        })
-    } else {
-        let target_pruning_index = *ledger_index - pruning_delay;
-
-        Ok((
-            pruning_index.into(),
-            if target_pruning_index > pruning_index + PRUNING_BATCH_SIZE_MAX {
-                (pruning_index + PRUNING_BATCH_SIZE_MAX).into()
-            } else {
-                target_pruning_index.into()
-            },
-        ))
+    if ledger_index >= pruning_threshold {
+        return Ok(batch_range(
+            pruning_index,
+            ledger_index - pruning_delay,
+            PruningReason::Milestones,
+        ));
+    }
+
+    // Size-based and age-based pruning may go beyond the configured delay, but never below the minimum one.
+    if ledger_index >= pruning_index + pruning_delay_min {
+        let target_index_max = ledger_index - pruning_delay_min;
+
+        if config.size().enabled()
+            && last_size_pruning.map_or(true, |last| last.elapsed() >= config.size().cooldown_time())
+        {
+            if let Some(target_index) =
+                size_target_index(size(), ledger_index, pruning_index, target_index_max, config.size())
+            {
+                return Ok(batch_range(pruning_index, target_index, PruningReason::Size));
+            }
+        }
+
+        if config.age().enabled() {
+            if let Some(target_index) =
+                age_target_index(milestone_timestamp, now, pruning_index, target_index_max, config.age())
+            {
+                return Ok(batch_range(pruning_index, target_index, PruningReason::Age));
+            }
+        }
+    }
+
+    Err(PruningSkipReason::BelowThreshold {
+        reached_in: pruning_threshold - ledger_index,
+    })
+}
+
+fn batch_range(
+    pruning_index: u32,
+    target_pruning_index: u32,
+    reason: PruningReason,
+) -> (MilestoneIndex, MilestoneIndex, PruningReason) {
+    (
+        pruning_index.into(),
+        if target_pruning_index > pruning_index + PRUNING_BATCH_SIZE_MAX {
+            (pruning_index + PRUNING_BATCH_SIZE_MAX).into()
+        } else {
+            target_pruning_index.into()
+        },
+        reason,
+    )
+}
+
+// Returns the index up to which milestones have to be pruned to shrink the database below the target size, assuming
+// that all milestones take up the same space.
+fn size_target_index(
+    size: Option<u64>,
+    ledger_index: u32,
+    pruning_index: u32,
+    target_index_max: u32,
+    config: &PruningSizeConfig,
+) -> Option<u32> {
+    let size = size?;
+
+    if size <= config.target_size() {
+        return None;
+    }
+
+    let reduced_size = config.target_size() as f64 * (100.0 - config.threshold_percentage()) / 100.0;
+    let milestones = (ledger_index - pruning_index + 1) as f64;
+    let prunable_milestones = (milestones * (1.0 - reduced_size / size as f64)).ceil().max(1.0) as u32;
+
+    Some((pruning_index + prunable_milestones - 1).min(target_index_max))
+}
+
+// Returns the index of the most recent milestone that is older than the maximum age, if any.
+fn age_target_index(
+    milestone_timestamp: impl Fn(u32) -> Option<u32>,
+    now: u64,
+    pruning_index: u32,
+    target_index_max: u32,
+    config: &PruningAgeConfig,
+) -> Option<u32> {
+    let mut target_index = None;
+
+    for index in pruning_index..=target_index_max.min(pruning_index + PRUNING_BATCH_SIZE_MAX) {
+        match milestone_timestamp(index) {
+            Some(timestamp) if now.saturating_sub(timestamp.into()) > config.max_age().as_secs() => {
+                target_index = Some(index);
+            }
+            _ => break,
+        }
+    }
+
+    target_index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workers::pruning::config::{PruningAgeConfigBuilder, PruningSizeConfigBuilder};
+
+    #[test]
+    fn pruning_range_by_index() {
+        assert_eq!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Index(MilestoneIndex(40))
+            )
+            .unwrap(),
+            (MilestoneIndex(11), MilestoneIndex(40))
+        );
+        assert_eq!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Index(MilestoneIndex(11))
+            )
+            .unwrap(),
+            (MilestoneIndex(11), MilestoneIndex(11))
+        );
+    }
+
+    #[test]
+    fn pruning_range_by_depth() {
+        assert_eq!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Depth(60)
+            )
+            .unwrap(),
+            (MilestoneIndex(11), MilestoneIndex(40))
+        );
+        assert_eq!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Depth(50)
+            )
+            .unwrap(),
+            (MilestoneIndex(11), MilestoneIndex(50))
+        );
+    }
+
+    #[test]
+    fn pruning_range_above_minimum_depth() {
+        assert!(matches!(
+            pruning_range(MilestoneIndex(10), LedgerIndex(MilestoneIndex(100)), 50, PruningTarget::Index(MilestoneIndex(51))),
+            Err(Error::TargetIndexTooHigh { selected, maximum })
+                if selected == MilestoneIndex(51) && maximum == MilestoneIndex(50)
+        ));
+        assert!(matches!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Depth(49)
+            ),
+            Err(Error::TargetIndexTooHigh { .. })
+        ));
+        // A depth beyond the ledger index saturates to the genesis.
+        assert!(matches!(
+            pruning_range(
+                MilestoneIndex(0),
+                LedgerIndex(MilestoneIndex(10)),
+                50,
+                PruningTarget::Depth(5)
+            ),
+            Err(Error::TargetIndexTooHigh { .. })
+        ));
+    }
+
+    #[test]
+    fn pruning_range_below_pruning_index() {
+        assert!(matches!(
+            pruning_range(MilestoneIndex(10), LedgerIndex(MilestoneIndex(100)), 50, PruningTarget::Index(MilestoneIndex(10))),
+            Err(Error::InvalidTargetIndex { selected, minimum })
+                if selected == MilestoneIndex(10) && minimum == MilestoneIndex(11)
+        ));
+        assert!(matches!(
+            pruning_range(
+                MilestoneIndex(10),
+                LedgerIndex(MilestoneIndex(100)),
+                50,
+                PruningTarget::Depth(95)
+            ),
+            Err(Error::InvalidTargetIndex { .. })
+        ));
+    }
+
+    fn size_config(target_size: u64, cooldown_time: u64) -> PruningConfig {
+        PruningConfig::build()
+            .size(
+                PruningSizeConfigBuilder::new()
+                    .enabled(true)
+                    .target_size(target_size)
+                    .threshold_percentage(50.0)
+                    .cooldown_time(cooldown_time),
+            )
+            .finish()
+    }
+
+    #[test]
+    fn batch_range_is_capped() {
+        assert_eq!(
+            batch_range(10, 50, PruningReason::Milestones),
+            (MilestoneIndex(10), MilestoneIndex(50), PruningReason::Milestones)
+        );
+        assert_eq!(
+            batch_range(10, 10 + PRUNING_BATCH_SIZE_MAX, PruningReason::Size),
+            (MilestoneIndex(10), MilestoneIndex(210), PruningReason::Size)
+        );
+        assert_eq!(
+            batch_range(10, 10_000, PruningReason::Age),
+            (MilestoneIndex(10), MilestoneIndex(210), PruningReason::Age)
+        );
+    }
+
+    #[test]
+    fn pruning_target_by_delay() {
+        let config = PruningConfig::build().delay(100).finish();
+
+        assert_eq!(
+            pruning_target(1, 150, 100, 10, None, &config, || None, |_| None, 0).unwrap(),
+            (MilestoneIndex(1), MilestoneIndex(50), PruningReason::Milestones)
+        );
+        // The batch is capped, the rest is pruned with the next milestone.
+        assert_eq!(
+            pruning_target(1, 1_000, 100, 10, None, &config, || None, |_| None, 0).unwrap(),
+            (MilestoneIndex(1), MilestoneIndex(201), PruningReason::Milestones)
+        );
+        assert!(matches!(
+            pruning_target(1, 60, 100, 10, None, &config, || None, |_| None, 0),
+            Err(PruningSkipReason::BelowThreshold { reached_in: 41 })
+        ));
+    }
+
+    #[test]
+    fn pruning_target_without_size_or_age() {
+        let config = PruningConfig::build().delay(100).finish();
+
+        // Neither size-based nor age-based pruning is enabled.
+        assert!(matches!(
+            pruning_target(1, 60, 100, 10, None, &config, || Some(u64::MAX), |_| Some(0), 10_000),
+            Err(PruningSkipReason::BelowThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn pruning_target_by_size_respects_cooldown() {
+        let config = size_config(1_000, 300);
+
+        assert_eq!(
+            pruning_target(1, 100, 1_000, 10, None, &config, || Some(2_000), |_| None, 0).unwrap(),
+            (MilestoneIndex(1), MilestoneIndex(75), PruningReason::Size)
+        );
+        // A size-based pruning just happened.
+        assert!(matches!(
+            pruning_target(
+                1,
+                100,
+                1_000,
+                10,
+                Some(Instant::now()),
+                &config,
+                || panic!("size queried during cooldown"),
+                |_| None,
+                0
+            ),
+            Err(PruningSkipReason::BelowThreshold { .. })
+        ));
+
+        let config = size_config(1_000, 0);
+
+        assert_eq!(
+            pruning_target(
+                1,
+                100,
+                1_000,
+                10,
+                Some(Instant::now()),
+                &config,
+                || Some(2_000),
+                |_| None,
+                0
+            )
+            .unwrap(),
+            (MilestoneIndex(1), MilestoneIndex(75), PruningReason::Size)
+        );
+    }
+
+    #[test]
+    fn pruning_target_respects_minimum_delay() {
+        let config = size_config(1_000, 0);
+
+        // Not enough milestones above the minimum delay.
+        assert!(matches!(
+            pruning_target(1, 10, 1_000, 10, None, &config, || Some(u64::MAX), |_| None, 0),
+            Err(PruningSkipReason::BelowThreshold { .. })
+        ));
+        assert_eq!(
+            pruning_target(1, 100, 1_000, 10, None, &config, || Some(u64::MAX), |_| None, 0).unwrap(),
+            (MilestoneIndex(1), MilestoneIndex(90), PruningReason::Size)
+        );
+    }
+
+    #[test]
+    fn size_target_index_frees_threshold() {
+        let config = PruningSizeConfigBuilder::new()
+            .enabled(true)
+            .target_size(1_000)
+            .threshold_percentage(50.0)
+            .finish();
+
+        // Unknown size or below the target size.
+        assert_eq!(size_target_index(None, 100, 1, 90, &config), None);
+        assert_eq!(size_target_index(Some(1_000), 100, 1, 90, &config), None);
+        // 75% of the 100 milestones have to go to shrink 2000 bytes down to 500 bytes.
+        assert_eq!(size_target_index(Some(2_000), 100, 1, 90, &config), Some(75));
+        assert_eq!(size_target_index(Some(2_000), 110, 11, 100, &config), Some(85));
+        // Rounded up, and at least one milestone is pruned.
+        assert_eq!(size_target_index(Some(1_001), 100, 1, 90, &config), Some(51));
+        assert_eq!(size_target_index(Some(1_001), 1, 1, 1, &config), Some(1));
+        // Never beyond the maximum target index.
+        assert_eq!(size_target_index(Some(u64::MAX), 100, 1, 90, &config), Some(90));
+    }
+
+    #[test]
+    fn age_target_index_stops_at_recent_milestones() {
+        let config = PruningAgeConfigBuilder::new().enabled(true).max_age(100).finish();
+        let now = 10_000;
+
+        // Milestones up to 20 are older than the maximum age.
+        let timestamp = |index: u32| Some(if index <= 20 { 0 } else { now as u32 });
+        assert_eq!(age_target_index(timestamp, now, 1, 90, &config), Some(20));
+        assert_eq!(age_target_index(timestamp, now, 1, 10, &config), Some(10));
+        assert_eq!(age_target_index(timestamp, now, 21, 90, &config), None);
+
+        // Exactly the maximum age is not old enough.
+        let timestamp = |_: u32| Some(now as u32 - 100);
+        assert_eq!(age_target_index(timestamp, now, 1, 90, &config), None);
+
+        // Missing milestones end the search.
+        let timestamp = |index: u32| if index == 5 { None } else { Some(0) };
+        assert_eq!(age_target_index(timestamp, now, 1, 90, &config), Some(4));
+        assert_eq!(age_target_index(timestamp, now, 5, 90, &config), None);
+
+        // At most one batch is looked at.
+        assert_eq!(age_target_index(|_| Some(0), now, 1, 10_000, &config), Some(201));
     }
 }
@@ -3,11 +3,116 @@
 
 //! Module containing pruning configuration.
 
+use std::time::Duration;
+
 use serde::Deserialize;
 
 const DEFAULT_ENABLED: bool = true;
 const DEFAULT_DELAY: u32 = 60480;
 const DEFAULT_PRUNE_RECEIPTS: bool = false;
+const DEFAULT_SIZE_ENABLED: bool = false;
+const DEFAULT_SIZE_TARGET_SIZE: u64 = 30 * 1024 * 1024 * 1024;
+const DEFAULT_SIZE_THRESHOLD_PERCENTAGE: f64 = 10.0;
+const DEFAULT_SIZE_COOLDOWN_TIME: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_AGE_ENABLED: bool = false;
+const DEFAULT_AGE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Builder for a [`PruningSizeConfig`].
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct PruningSizeConfigBuilder {
+    enabled: Option<bool>,
+    #[serde(alias = "targetSize")]
+    target_size: Option<u64>,
+    #[serde(alias = "thresholdPercentage")]
+    threshold_percentage: Option<f64>,
+    #[serde(alias = "cooldownTime")]
+    cooldown_time: Option<u64>,
+}
+
+impl PruningSizeConfigBuilder {
+    /// Creates a new [`PruningSizeConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables size-based pruning.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled.replace(enabled);
+        self
+    }
+
+    /// Sets the database size in bytes above which pruning is triggered.
+    pub fn target_size(mut self, target_size: u64) -> Self {
+        self.target_size.replace(target_size);
+        self
+    }
+
+    /// Sets the percentage of the target size that is freed up by a size-based pruning.
+    pub fn threshold_percentage(mut self, threshold_percentage: f64) -> Self {
+        self.threshold_percentage.replace(threshold_percentage);
+        self
+    }
+
+    /// Sets the minimum time in seconds between two size-based prunings.
+    pub fn cooldown_time(mut self, cooldown_time: u64) -> Self {
+        self.cooldown_time.replace(cooldown_time);
+        self
+    }
+
+    /// Finishes the builder into a [`PruningSizeConfig`].
+    #[must_use]
+    pub fn finish(self) -> PruningSizeConfig {
+        PruningSizeConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_SIZE_ENABLED),
+            target_size: self.target_size.unwrap_or(DEFAULT_SIZE_TARGET_SIZE),
+            threshold_percentage: self
+                .threshold_percentage
+                .unwrap_or(DEFAULT_SIZE_THRESHOLD_PERCENTAGE)
+                .clamp(0.0, 100.0),
+            cooldown_time: self
+                .cooldown_time
+                .map_or(DEFAULT_SIZE_COOLDOWN_TIME, Duration::from_secs),
+        }
+    }
+}
+
+/// Builder for a [`PruningAgeConfig`].
+#[derive(Default, Deserialize, PartialEq)]
+#[must_use]
+pub struct PruningAgeConfigBuilder {
+    enabled: Option<bool>,
+    #[serde(alias = "maxAge")]
+    max_age: Option<u64>,
+}
+
+impl PruningAgeConfigBuilder {
+    /// Creates a new [`PruningAgeConfigBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables age-based pruning.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled.replace(enabled);
+        self
+    }
+
+    /// Sets the age in seconds above which milestones are pruned.
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age.replace(max_age);
+        self
+    }
+
+    /// Finishes the builder into a [`PruningAgeConfig`].
+    #[must_use]
+    pub fn finish(self) -> PruningAgeConfig {
+        PruningAgeConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_AGE_ENABLED),
+            max_age: self.max_age.map_or(DEFAULT_AGE_MAX_AGE, Duration::from_secs),
+        }
+    }
+}
 
 /// Builder for a [`PruningConfig`].
 #[derive(Default, Deserialize, PartialEq)]
@@ -17,6 +122,8 @@ pub struct PruningConfigBuilder {
     delay: Option<u32>,
     #[serde(alias = "pruneReceipts")]
     prune_receipts: Option<bool>,
+    size: Option<PruningSizeConfigBuilder>,
+    age: Option<PruningAgeConfigBuilder>,
 }
 
 impl PruningConfigBuilder {
@@ -43,6 +150,18 @@ impl PruningConfigBuilder {
         self
     }
 
+    /// Sets the size-based pruning configuration.
+    pub fn size(mut self, size: PruningSizeConfigBuilder) -> Self {
+        self.size.replace(size);
+        self
+    }
+
+    /// Sets the age-based pruning configuration.
+    pub fn age(mut self, age: PruningAgeConfigBuilder) -> Self {
+        self.age.replace(age);
+        self
+    }
+
     /// Finishes the builder into a [`PruningConfig`].
     #[must_use]
     pub fn finish(self) -> PruningConfig {
@@ -50,16 +169,70 @@ impl PruningConfigBuilder {
             enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
             delay: self.delay.unwrap_or(DEFAULT_DELAY),
             prune_receipts: self.prune_receipts.unwrap_or(DEFAULT_PRUNE_RECEIPTS),
+            size: self.size.unwrap_or_default().finish(),
+            age: self.age.unwrap_or_default().finish(),
         }
     }
 }
 
+/// The size-based pruning configuration.
+#[derive(Clone)]
+pub struct PruningSizeConfig {
+    enabled: bool,
+    target_size: u64,
+    threshold_percentage: f64,
+    cooldown_time: Duration,
+}
+
+impl PruningSizeConfig {
+    /// Returns whether size-based pruning is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the database size in bytes above which pruning is triggered.
+    pub fn target_size(&self) -> u64 {
+        self.target_size
+    }
+
+    /// Returns the percentage of the target size that is freed up by a size-based pruning.
+    pub fn threshold_percentage(&self) -> f64 {
+        self.threshold_percentage
+    }
+
+    /// Returns the minimum time between two size-based prunings.
+    pub fn cooldown_time(&self) -> Duration {
+        self.cooldown_time
+    }
+}
+
+/// The age-based pruning configuration.
+#[derive(Clone)]
+pub struct PruningAgeConfig {
+    enabled: bool,
+    max_age: Duration,
+}
+
+impl PruningAgeConfig {
+    /// Returns whether age-based pruning is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the age above which milestones are pruned.
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+}
+
 /// The pruning configuration.
 #[derive(Clone)]
 pub struct PruningConfig {
     enabled: bool,
     delay: u32,
     prune_receipts: bool,
+    size: PruningSizeConfig,
+    age: PruningAgeConfig,
 }
 
 impl PruningConfig {
@@ -87,4 +260,14 @@ impl PruningConfig {
     pub fn prune_receipts(&self) -> bool {
         self.prune_receipts
     }
+
+    /// Returns the size-based pruning configuration.
+    pub fn size(&self) -> &PruningSizeConfig {
+        &self.size
+    }
+
+    /// Returns the age-based pruning configuration.
+    pub fn age(&self) -> &PruningAgeConfig {
+        &self.age
+    }
 }
@@ -3,13 +3,20 @@
 
 use bee_block::{payload::milestone::MilestoneIndex, BlockId};
 
+/// Errors occurring during pruning.
 #[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
 pub enum Error {
     #[error("pruning target index {selected} below minimum {minimum}")]
     InvalidTargetIndex {
         selected: MilestoneIndex,
         minimum: MilestoneIndex,
     },
+    #[error("pruning target index {selected} above maximum {maximum}")]
+    TargetIndexTooHigh {
+        selected: MilestoneIndex,
+        maximum: MilestoneIndex,
+    },
     #[error("missing snapshot info")]
     MissingSnapshotInfo,
     #[error("missing milestone {0}")]
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{sync::Mutex, time::Duration};
 
 #[derive(Debug, Default)]
 pub struct PruningMetrics {
@@ -43,7 +43,7 @@ pub struct MilestoneDataPruningMetrics {
     pub receipts: usize,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub full_prune: Duration,
     pub get_curr_seps: Duration,
@@ -56,3 +56,120 @@ pub struct Timings {
     pub truncate_curr_seps: Duration,
     pub batch_commit: Duration,
 }
+
+impl Timings {
+    fn steps(&self) -> [(&'static str, Duration); 10] {
+        [
+            ("full_prune", self.full_prune),
+            ("get_curr_seps", self.get_curr_seps),
+            ("filter_curr_seps", self.filter_curr_seps),
+            ("replace_seps", self.replace_seps),
+            ("batch_confirmed_data", self.batch_confirmed_data),
+            ("batch_unconfirmed_data", self.batch_unconfirmed_data),
+            ("batch_milestone_data", self.batch_milestone_data),
+            ("batch_new_seps", self.batch_new_seps),
+            ("truncate_curr_seps", self.truncate_curr_seps),
+            ("batch_commit", self.batch_commit),
+        ]
+    }
+}
+
+#[derive(Default)]
+struct PruningStatisticsInner {
+    blocks: u64,
+    edges: u64,
+    receipts: u64,
+    solid_entry_points: usize,
+    timings: Option<Timings>,
+}
+
+/// Accumulates the metrics of the pruned milestones so that they can be reported by other workers.
+#[derive(Default)]
+pub struct PruningStatistics {
+    inner: Mutex<PruningStatisticsInner>,
+}
+
+impl PruningStatistics {
+    pub(crate) fn record(&self, metrics: &PruningMetrics, timings: &Timings) {
+        // Panic: the lock is never held across a panic.
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.blocks += metrics.blocks as u64;
+        inner.edges += metrics.edges as u64;
+        inner.receipts += metrics.receipts as u64;
+        inner.solid_entry_points = metrics.next_seps;
+        inner.timings = Some(timings.clone());
+    }
+
+    /// Returns the number of pruned blocks.
+    pub fn blocks(&self) -> u64 {
+        self.inner.lock().unwrap().blocks
+    }
+
+    /// Returns the number of pruned edges between blocks and their parents.
+    pub fn edges(&self) -> u64 {
+        self.inner.lock().unwrap().edges
+    }
+
+    /// Returns the number of pruned receipts.
+    pub fn receipts(&self) -> u64 {
+        self.inner.lock().unwrap().receipts
+    }
+
+    /// Returns the number of solid entry points left by the latest pruned milestone.
+    pub fn solid_entry_points(&self) -> usize {
+        self.inner.lock().unwrap().solid_entry_points
+    }
+
+    /// Returns the time spent by each step of the latest pruned milestone, if any milestone was pruned.
+    pub fn latest_timings(&self) -> Option<[(&'static str, Duration); 10]> {
+        self.inner.lock().unwrap().timings.as_ref().map(Timings::steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_accumulates_counts_and_keeps_latest_timings() {
+        let statistics = PruningStatistics::default();
+
+        assert!(statistics.latest_timings().is_none());
+
+        statistics.record(
+            &PruningMetrics {
+                blocks: 3,
+                edges: 5,
+                receipts: 1,
+                next_seps: 7,
+                ..Default::default()
+            },
+            &Timings {
+                full_prune: Duration::from_millis(10),
+                ..Default::default()
+            },
+        );
+        statistics.record(
+            &PruningMetrics {
+                blocks: 2,
+                edges: 4,
+                next_seps: 6,
+                ..Default::default()
+            },
+            &Timings {
+                full_prune: Duration::from_millis(20),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(statistics.blocks(), 5);
+        assert_eq!(statistics.edges(), 9);
+        assert_eq!(statistics.receipts(), 1);
+        assert_eq!(statistics.solid_entry_points(), 6);
+        assert_eq!(
+            statistics.latest_timings().unwrap()[0],
+            ("full_prune", Duration::from_millis(20))
+        );
+    }
+}
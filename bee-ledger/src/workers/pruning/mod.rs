@@ -4,10 +4,15 @@
 //! Module that contains the pruning logic.
 
 mod batch;
-mod error;
 mod metrics;
 
 pub(crate) mod condition;
 pub(crate) mod prune;
 
 pub mod config;
+pub mod error;
+
+pub use self::{
+    condition::{PruningReason, PruningTarget},
+    metrics::PruningStatistics,
+};
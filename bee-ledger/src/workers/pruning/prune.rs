@@ -13,12 +13,14 @@ use bee_tangle::{solid_entry_point::SolidEntryPoint, Tangle};
 use log::{debug, info};
 
 use crate::workers::{
-    event::PrunedIndex,
+    consensus::JobProgress,
+    event::{DatabasePruned, PrunedIndex},
     pruning::{
-        batch,
+        batch::{self, Seps},
+        condition::PruningReason,
         config::PruningConfig,
         error::Error,
-        metrics::{PruningMetrics, Timings},
+        metrics::{PruningMetrics, PruningStatistics, Timings},
     },
     storage::{self, StorageBackend},
 };
@@ -34,7 +36,10 @@ pub async fn prune<S: StorageBackend>(
     bus: &Bus<'_>,
     start_index: MilestoneIndex,
     target_index: MilestoneIndex,
+    reason: PruningReason,
     config: &PruningConfig,
+    statistics: &PruningStatistics,
+    progress: Option<&JobProgress>,
 ) -> Result<(), Error> {
     let mut timings = Timings::default();
     let mut metrics = PruningMetrics::default();
@@ -46,10 +51,19 @@ pub async fn prune<S: StorageBackend>(
         });
     }
 
+    let start_timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("error creating timestamp")
+        .as_secs();
+
+    if let Some(progress) = progress {
+        progress.start((*target_index - *start_index + 1) as u64);
+    }
+
     if start_index != target_index {
         info!(
-            "Pruning from milestone {} to milestone {}...",
-            start_index, target_index
+            "Pruning from milestone {} to milestone {} ({:?})...",
+            start_index, target_index, reason
         );
     }
 
@@ -72,12 +86,14 @@ pub async fn prune<S: StorageBackend>(
 
         // Start a batch to make changes to the storage in a single atomic step.
         let mut batch = S::batch_begin();
+        // Blocks whose data are changed by the batch and must be removed from the tangle cache once it is committed.
+        let mut pruned_blocks = Vec::new();
 
         // Add confirmed data to the delete batch.
         // NOTE: This is the most costly thing during pruning, because it has to perform a past-cone traversal.
         let batch_confirmed_data = Instant::now();
         let (mut new_seps, confirmed_data_metrics) =
-            batch::prune_confirmed_data(tangle, storage, &mut batch, index, &curr_seps)?;
+            batch::prune_confirmed_data(tangle, storage, &mut batch, index, &curr_seps, &mut pruned_blocks)?;
         timings.batch_confirmed_data = batch_confirmed_data.elapsed();
 
         metrics.new_seps = new_seps.len();
@@ -134,7 +150,7 @@ pub async fn prune<S: StorageBackend>(
 
         // Add unconfirmed data to the delete batch.
         let batch_unconfirmed_data = Instant::now();
-        let unconfirmed_data_metrics = batch::prune_unconfirmed_data(storage, &mut batch, index)?;
+        let unconfirmed_data_metrics = batch::prune_unconfirmed_data(storage, &mut batch, index, &mut pruned_blocks)?;
         timings.batch_unconfirmed_data = batch_unconfirmed_data.elapsed();
 
         metrics.blocks += unconfirmed_data_metrics.prunable_blocks;
@@ -157,6 +173,8 @@ pub async fn prune<S: StorageBackend>(
             .map_err(|e| Error::Storage(Box::new(e)))?;
         timings.batch_commit = batch_commit.elapsed();
 
+        tangle.remove_from_cache(&pruned_blocks);
+
         // Update the pruning index.
         tangle.update_pruning_index(index);
 
@@ -174,6 +192,8 @@ pub async fn prune<S: StorageBackend>(
 
         timings.full_prune = full_prune.elapsed();
 
+        statistics.record(&metrics, &timings);
+
         debug!("{:?}.", metrics);
         debug!("{:?}", confirmed_data_metrics);
         debug!("{:?}", unconfirmed_data_metrics);
@@ -185,6 +205,10 @@ pub async fn prune<S: StorageBackend>(
         debug!("Pruned milestone {}.", index);
 
         bus.dispatch(PrunedIndex { index });
+
+        if let Some(progress) = progress {
+            progress.advance();
+        }
     }
 
     if start_index == target_index {
@@ -193,5 +217,49 @@ pub async fn prune<S: StorageBackend>(
         info!("Pruned from milestone {} to milestone {}.", start_index, target_index);
     }
 
+    bus.dispatch(DatabasePruned {
+        start_index,
+        target_index,
+        reason,
+        start_timestamp,
+        end_timestamp: SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("error creating timestamp")
+            .as_secs(),
+    });
+
     Ok(())
 }
+
+/// Returns the solid entry points that pruning from `index`, with the solid entry points `seps`, up to `target_index`
+/// would leave, without changing the storage.
+pub(crate) fn solid_entry_points_at<S: StorageBackend>(
+    tangle: &Tangle<S>,
+    storage: &S,
+    index: MilestoneIndex,
+    mut seps: Seps,
+    target_index: MilestoneIndex,
+    progress: Option<&JobProgress>,
+) -> Result<Seps, Error> {
+    let mut visited_blocks = Vec::new();
+
+    for index in *index + 1..=*target_index {
+        let index = MilestoneIndex(index);
+        // The batch only collects what pruning would delete and is dropped without being committed.
+        let mut batch = S::batch_begin();
+
+        let (mut new_seps, _) =
+            batch::prune_confirmed_data(tangle, storage, &mut batch, index, &seps, &mut visited_blocks)?;
+
+        seps.retain(|_, v| **v > *index);
+        new_seps.extend(seps);
+        seps = new_seps;
+        visited_blocks.clear();
+
+        if let Some(progress) = progress {
+            progress.advance();
+        }
+    }
+
+    Ok(seps)
+}
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_block::payload::milestone::MilestoneIndex;
+use bee_block::{output::OutputId, payload::milestone::MilestoneIndex};
 use thiserror::Error;
 
 use crate::types::{snapshot::SnapshotKind, Error as TypesError};
@@ -21,6 +21,16 @@ pub enum Error {
     LedgerSepIndexesInconsistency(MilestoneIndex, MilestoneIndex),
     #[error("missing consumed treasury")]
     MissingConsumedTreasury,
+    #[error("missing milestone {0}")]
+    MissingMilestone(MilestoneIndex),
+    #[error("missing milestone diff {0}")]
+    MissingMilestoneDiff(MilestoneIndex),
+    #[error("milestone diffs from {0} to {1} were pruned")]
+    MilestoneDiffsPruned(MilestoneIndex, MilestoneIndex),
+    #[error("missing output {0}")]
+    MissingOutput(OutputId),
+    #[error("missing snapshot info")]
+    MissingSnapshotInfo,
     #[error("network id mismatch between configuration and snapshot: {0} != {1}")]
     NetworkIdMismatch(u64, u64),
     #[error("no snapshot download source available")]
@@ -0,0 +1,493 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module containing the export of snapshot files.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter, Write},
+    path::{Component, Path, PathBuf},
+    time::SystemTime,
+};
+
+use bee_block::{
+    output::OutputId,
+    payload::{milestone::MilestoneIndex, Payload},
+};
+use bee_storage::access::{AsIterator, Fetch};
+use bee_tangle::{solid_entry_point::SolidEntryPoint, Tangle};
+use log::info;
+use packable::{
+    packer::{IoPacker, Packer},
+    unpacker::IoUnpacker,
+    Packable,
+};
+
+use crate::{
+    types::{
+        snapshot::{DeltaSnapshotHeader, FullSnapshotHeader, MilestoneDiff, SnapshotHeader, SnapshotKind},
+        ConsumedOutput, OutputDiff, TreasuryOutput, Unspent,
+    },
+    workers::{
+        consensus::JobProgress,
+        error::Error,
+        pruning::prune::solid_entry_points_at,
+        snapshot::error::Error as SnapshotError,
+        storage::{self, StorageBackend},
+    },
+};
+
+/// Resolves a snapshot file `path` relative to the snapshot `directory`.
+///
+/// Returns `None` for absolute paths and for paths that could leave the directory.
+pub fn resolve_snapshot_path(directory: &Path, path: &Path) -> Option<PathBuf> {
+    if path.as_os_str().is_empty() || !path.components().all(|c| matches!(c, Component::Normal(_))) {
+        return None;
+    }
+
+    Some(directory.join(path))
+}
+
+/// The state of the ledger a snapshot is written from.
+pub(crate) enum SnapshotState {
+    Full(FullSnapshotState),
+    Delta(DeltaSnapshotState),
+}
+
+/// The state of the ledger a full snapshot is written from.
+pub(crate) struct FullSnapshotState {
+    network_id: u64,
+    sep_index: MilestoneIndex,
+    ledger_index: MilestoneIndex,
+    seps: hashbrown::HashMap<SolidEntryPoint, MilestoneIndex>,
+    treasury_output: TreasuryOutput,
+    unspent: Vec<Unspent>,
+}
+
+/// The state of the ledger a delta snapshot is written from.
+pub(crate) struct DeltaSnapshotState {
+    network_id: u64,
+    full_ledger_index: MilestoneIndex,
+    entry_point_index: MilestoneIndex,
+    seps: hashbrown::HashMap<SolidEntryPoint, MilestoneIndex>,
+    sep_index: MilestoneIndex,
+}
+
+fn snapshot_writer(path: &Path) -> Result<BufWriter<File>, Error> {
+    Ok(BufWriter::new(
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?,
+    ))
+}
+
+fn export_outputs<P: Packer<Error = std::io::Error>, B: StorageBackend>(
+    packer: &mut P,
+    storage: &B,
+    unspent: &[Unspent],
+    progress: Option<&JobProgress>,
+) -> Result<(), Error> {
+    for output_id in unspent {
+        let created_output = storage::fetch_output(storage, output_id.id())?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id.id())))?;
+
+        output_id.id().pack(packer)?;
+        created_output.pack(packer)?;
+
+        if let Some(progress) = progress {
+            progress.advance();
+        }
+    }
+
+    Ok(())
+}
+
+fn milestone_diff<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<MilestoneDiff, Error> {
+    let milestone = match tangle
+        .get_milestone_block(index)?
+        .as_ref()
+        .and_then(|block| block.payload())
+    {
+        Some(Payload::Milestone(milestone)) => milestone.as_ref().clone(),
+        _ => return Err(Error::Snapshot(SnapshotError::MissingMilestone(index))),
+    };
+    let output_diff = Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .ok_or(Error::Snapshot(SnapshotError::MissingMilestoneDiff(index)))?;
+
+    let mut created_outputs = HashMap::with_capacity(output_diff.created_outputs().len());
+
+    for output_id in output_diff.created_outputs() {
+        let created_output = storage::fetch_output(storage, output_id)?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+
+        created_outputs.insert(*output_id, created_output);
+    }
+
+    let mut consumed_outputs = HashMap::with_capacity(output_diff.consumed_outputs().len());
+
+    for output_id in output_diff.consumed_outputs() {
+        let created_output = storage::fetch_output(storage, output_id)?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+        let consumed_output = Fetch::<OutputId, ConsumedOutput>::fetch(storage, output_id)
+            .map_err(|e| Error::Storage(Box::new(e)))?
+            .ok_or(Error::Snapshot(SnapshotError::MissingOutput(*output_id)))?;
+
+        consumed_outputs.insert(*output_id, (created_output, consumed_output));
+    }
+
+    let consumed_treasury = match output_diff.treasury_diff() {
+        Some(treasury_diff) => {
+            let spent = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &true)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .unwrap_or_default();
+            let consumed = spent
+                .into_iter()
+                .find(|treasury| treasury.milestone_id() == treasury_diff.consumed())
+                .ok_or(Error::Snapshot(SnapshotError::MissingConsumedTreasury))?;
+
+            Some((consumed.inner().clone(), *consumed.milestone_id()))
+        }
+        None => None,
+    };
+
+    Ok(MilestoneDiff::new(
+        milestone,
+        consumed_treasury,
+        created_outputs,
+        consumed_outputs,
+    )?)
+}
+
+fn timestamp() -> u32 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("error creating timestamp")
+        .as_secs() as u32
+}
+
+// The file is written next to its destination first so that an existing snapshot is only replaced by a complete one.
+fn write_snapshot_file(
+    path: &Path,
+    write: impl FnOnce(&mut IoPacker<BufWriter<File>>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut tmp_path = PathBuf::from(path);
+    tmp_path.set_extension("tmp");
+
+    let mut packer = IoPacker::new(snapshot_writer(&tmp_path)?);
+
+    write(&mut packer)?;
+
+    packer
+        .into_inner()
+        .flush()
+        .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?;
+    fs::rename(&tmp_path, path).map_err(|e| Error::Snapshot(SnapshotError::Io(e)))
+}
+
+fn network_id<B: StorageBackend>(storage: &B) -> Result<u64, Error> {
+    Ok(storage::fetch_snapshot_info(storage)?
+        .ok_or(Error::Snapshot(SnapshotError::MissingSnapshotInfo))?
+        .network_id())
+}
+
+/// Captures the state a full snapshot is written from.
+///
+/// The snapshot uses the current solid entry points, its ledger index is the current ledger index and it contains the
+/// milestone diffs needed to roll the ledger back to the entry point index. Milestones must not be confirmed meanwhile.
+pub(crate) async fn full_snapshot_state<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+) -> Result<FullSnapshotState, Error> {
+    let network_id = network_id(storage)?;
+    // Unwrap is fine because ledger index was already in storage or just added by the snapshot worker.
+    let ledger_index = MilestoneIndex(*storage::fetch_ledger_index(storage)?.unwrap());
+    let sep_index = tangle.get_entry_point_index();
+
+    if ledger_index < sep_index {
+        return Err(Error::Snapshot(SnapshotError::LedgerSepIndexesInconsistency(
+            ledger_index,
+            sep_index,
+        )));
+    }
+
+    let seps = tangle.get_solid_entry_points().await;
+    let treasury_output = storage::fetch_unspent_treasury_output(storage)?;
+    let unspent = AsIterator::<Unspent, ()>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .map(|result| {
+            result
+                .map(|(unspent, _)| unspent)
+                .map_err(|e| Error::Storage(Box::new(e)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(FullSnapshotState {
+        network_id,
+        sep_index,
+        ledger_index,
+        seps,
+        treasury_output,
+        unspent,
+    })
+}
+
+/// Writes a full snapshot of a captured ledger state to `path` and returns its solid entry point index and ledger
+/// index.
+pub(crate) fn write_full_snapshot<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    state: FullSnapshotState,
+    path: &Path,
+    progress: Option<&JobProgress>,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    info!("Creating full snapshot file {}...", &path.to_string_lossy());
+
+    let FullSnapshotState {
+        network_id,
+        sep_index,
+        ledger_index,
+        seps,
+        treasury_output,
+        unspent,
+    } = state;
+    let milestone_diff_count = *ledger_index - *sep_index;
+
+    if let Some(progress) = progress {
+        progress.start(unspent.len() as u64 + milestone_diff_count as u64);
+    }
+
+    write_snapshot_file(path, |packer| {
+        SnapshotHeader::new(SnapshotKind::Full, timestamp(), network_id, sep_index, ledger_index).pack(packer)?;
+        FullSnapshotHeader::new(
+            seps.len() as u64,
+            unspent.len() as u64,
+            milestone_diff_count as u64,
+            *treasury_output.milestone_id(),
+            treasury_output.inner().amount(),
+        )
+        .pack(packer)?;
+
+        for sep in seps.keys() {
+            sep.pack(packer)?;
+        }
+
+        export_outputs(packer, storage, &unspent, progress)?;
+
+        // Milestone diffs of a full snapshot are rolled back on import, from the ledger index down to the entry point
+        // index.
+        for index in (*sep_index + 1..=*ledger_index).rev() {
+            milestone_diff(tangle, storage, MilestoneIndex(index))?.pack(packer)?;
+
+            if let Some(progress) = progress {
+                progress.advance();
+            }
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        "Created full snapshot file with sep index {}, ledger index {}, {} solid entry points, {} outputs and {} milestone diffs.",
+        *sep_index,
+        *ledger_index,
+        seps.len(),
+        unspent.len(),
+        milestone_diff_count
+    );
+
+    Ok((sep_index, ledger_index))
+}
+
+/// Writes a full snapshot of the current ledger state to `path` and returns its solid entry point index and ledger
+/// index.
+pub async fn export_full_snapshot<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    path: &Path,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    let state = full_snapshot_state(tangle, storage).await?;
+
+    write_full_snapshot(tangle, storage, state, path, None)
+}
+
+/// Captures the state a delta snapshot on top of the full snapshot at `full_path` is written from.
+///
+/// The delta snapshot reaches up to `sep_index` and contains the milestone diffs applied since the ledger index of the
+/// full snapshot, which must not have been pruned yet. Milestones must not be pruned meanwhile.
+pub(crate) async fn delta_snapshot_state<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    full_path: &Path,
+    sep_index: MilestoneIndex,
+) -> Result<DeltaSnapshotState, Error> {
+    let network_id = network_id(storage)?;
+    let full_header = SnapshotHeader::unpack::<_, true>(&mut IoUnpacker::new(BufReader::new(
+        OpenOptions::new()
+            .read(true)
+            .open(full_path)
+            .map_err(|e| Error::Snapshot(SnapshotError::Io(e)))?,
+    )))?;
+
+    if full_header.kind() != SnapshotKind::Full {
+        return Err(Error::Snapshot(SnapshotError::UnexpectedSnapshotKind(
+            SnapshotKind::Full,
+            full_header.kind(),
+        )));
+    }
+    if full_header.network_id() != network_id {
+        return Err(Error::Snapshot(SnapshotError::NetworkIdMismatch(
+            network_id,
+            full_header.network_id(),
+        )));
+    }
+
+    let full_ledger_index = full_header.ledger_index();
+    let entry_point_index = tangle.get_entry_point_index();
+
+    if sep_index < full_ledger_index {
+        return Err(Error::Snapshot(SnapshotError::LedgerSepIndexesInconsistency(
+            full_ledger_index,
+            sep_index,
+        )));
+    }
+    // Pruning a milestone removes its diff, the ones applied since the full snapshot have to be left.
+    if entry_point_index > full_ledger_index {
+        return Err(Error::Snapshot(SnapshotError::MilestoneDiffsPruned(
+            full_ledger_index + 1,
+            entry_point_index,
+        )));
+    }
+
+    Ok(DeltaSnapshotState {
+        network_id,
+        full_ledger_index,
+        entry_point_index,
+        seps: tangle.get_solid_entry_points().await,
+        sep_index,
+    })
+}
+
+/// Writes a delta snapshot of a captured ledger state to `path` and returns its solid entry point index and ledger
+/// index.
+pub(crate) fn write_delta_snapshot<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    state: DeltaSnapshotState,
+    path: &Path,
+    progress: Option<&JobProgress>,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    info!("Creating delta snapshot file {}...", &path.to_string_lossy());
+
+    let DeltaSnapshotState {
+        network_id,
+        full_ledger_index,
+        entry_point_index,
+        seps,
+        sep_index,
+    } = state;
+    let milestone_diff_count = *sep_index - *full_ledger_index;
+
+    if let Some(progress) = progress {
+        progress.start((*sep_index - *entry_point_index) as u64 + milestone_diff_count as u64);
+    }
+
+    // The solid entry points of the snapshot are the ones pruning up to its index would leave.
+    let seps = solid_entry_points_at(tangle, storage, entry_point_index, seps, sep_index, progress)?;
+
+    write_snapshot_file(path, |packer| {
+        SnapshotHeader::new(
+            SnapshotKind::Delta,
+            timestamp(),
+            network_id,
+            sep_index,
+            full_ledger_index,
+        )
+        .pack(packer)?;
+        DeltaSnapshotHeader::new(seps.len() as u64, milestone_diff_count as u64).pack(packer)?;
+
+        for sep in seps.keys() {
+            sep.pack(packer)?;
+        }
+
+        // Milestone diffs of a delta snapshot are applied on import, from the ledger index of the full snapshot up to
+        // the entry point index.
+        for index in *full_ledger_index + 1..=*sep_index {
+            milestone_diff(tangle, storage, MilestoneIndex(index))?.pack(packer)?;
+
+            if let Some(progress) = progress {
+                progress.advance();
+            }
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        "Created delta snapshot file with sep index {}, ledger index {}, {} solid entry points and {} milestone diffs.",
+        *sep_index,
+        *full_ledger_index,
+        seps.len(),
+        milestone_diff_count
+    );
+
+    Ok((sep_index, full_ledger_index))
+}
+
+/// Writes a delta snapshot reaching up to `sep_index` on top of the full snapshot at `full_path` to `path` and returns
+/// its solid entry point index and ledger index.
+pub async fn export_delta_snapshot<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    storage: &B,
+    full_path: &Path,
+    sep_index: MilestoneIndex,
+    path: &Path,
+) -> Result<(MilestoneIndex, MilestoneIndex), Error> {
+    let state = delta_snapshot_state(tangle, storage, full_path, sep_index).await?;
+
+    write_delta_snapshot(tangle, storage, state, path, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_relative_snapshot_path() {
+        let directory = Path::new("snapshots/mainnet");
+
+        assert_eq!(
+            resolve_snapshot_path(directory, Path::new("full_snapshot.bin")),
+            Some(PathBuf::from("snapshots/mainnet/full_snapshot.bin"))
+        );
+        assert_eq!(
+            resolve_snapshot_path(directory, Path::new("manual/delta_snapshot.bin")),
+            Some(PathBuf::from("snapshots/mainnet/manual/delta_snapshot.bin"))
+        );
+    }
+
+    #[test]
+    fn reject_escaping_snapshot_path() {
+        let directory = Path::new("snapshots/mainnet");
+
+        assert_eq!(resolve_snapshot_path(directory, Path::new("")), None);
+        assert_eq!(resolve_snapshot_path(directory, Path::new("/etc/passwd")), None);
+        assert_eq!(
+            resolve_snapshot_path(directory, Path::new("../full_snapshot.bin")),
+            None
+        );
+        assert_eq!(
+            resolve_snapshot_path(directory, Path::new("manual/../../full_snapshot.bin")),
+            None
+        );
+        assert_eq!(resolve_snapshot_path(directory, Path::new("./full_snapshot.bin")), None);
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+//! Module containing the import of snapshot files.
+
 use std::{
     collections::HashMap,
     fs::{File, OpenOptions},
@@ -29,7 +31,8 @@ use crate::{
         CreatedOutput, TreasuryOutput,
     },
     workers::{
-        consensus::worker::migration_from_milestone,
+        address_history::{self, fetch_address_history_index},
+        consensus::worker::{migration_from_milestone, protocol_parameters_from_milestone},
         error::Error,
         snapshot::{config::SnapshotConfig, download::download_latest_snapshot_files, error::Error as SnapshotError},
         storage::{self, apply_milestone, create_output, rollback_milestone, StorageBackend},
@@ -83,7 +86,12 @@ fn import_milestone_diffs<U: Unpacker<Error = std::io::Error>, B: StorageBackend
     unpacker: &mut U,
     storage: &B,
     milestone_diff_count: u64,
+    token_supply: u64,
 ) -> Result<(), Error> {
+    // The address history is only kept up to date if it exists, it is otherwise initialized from the unspent outputs
+    // once the snapshots are imported.
+    let address_history = fetch_address_history_index(storage)?.is_some();
+
     for _ in 0..milestone_diff_count {
         let diff = MilestoneDiff::unpack::<_, true>(unpacker)?;
         let index = diff.milestone().essence().index();
@@ -109,15 +117,34 @@ fn import_milestone_diffs<U: Unpacker<Error = std::io::Error>, B: StorageBackend
                 diff.milestone().id(),
                 receipt,
                 TreasuryOutput::new(consumed_treasury.0, consumed_treasury.1),
+                token_supply,
             )?)
         } else {
             None
         };
 
+        let parameters = protocol_parameters_from_milestone(diff.milestone().essence())?;
+
         if index == MilestoneIndex(ledger_index + 1) {
-            apply_milestone(&*storage, index, diff.created(), &consumed, &migration)?;
+            apply_milestone(
+                &*storage,
+                index,
+                diff.created(),
+                &consumed,
+                &migration,
+                &parameters,
+                address_history,
+            )?;
         } else if index == MilestoneIndex(ledger_index) {
-            rollback_milestone(&*storage, index, diff.created(), &consumed, &migration)?;
+            rollback_milestone(
+                &*storage,
+                index,
+                diff.created(),
+                &consumed,
+                &migration,
+                &parameters,
+                address_history,
+            )?;
         } else {
             return Err(Error::Snapshot(SnapshotError::UnexpectedMilestoneDiffIndex(index)));
         }
@@ -142,7 +169,13 @@ fn check_header(header: &SnapshotHeader, kind: SnapshotKind, network_id: u64) ->
     }
 }
 
-fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+/// Imports the full snapshot file at `path` into an empty storage.
+pub fn import_full_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    token_supply: u64,
+) -> Result<(), Error> {
     info!("Importing full snapshot file {}...", &path.to_string_lossy());
 
     let mut unpacker = IoUnpacker::new(snapshot_reader(path)?);
@@ -173,6 +206,9 @@ fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id:
         ),
     )?;
 
+    // The imported outputs replace the ledger the address history was recorded from.
+    address_history::discard(storage)?;
+
     storage::insert_ledger_index(storage, &header.ledger_index().into())?;
     storage::insert_snapshot_info(
         storage,
@@ -187,7 +223,7 @@ fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id:
 
     import_solid_entry_points(&mut unpacker, storage, full_header.sep_count(), header.sep_index())?;
     import_outputs(&mut unpacker, storage, full_header.output_count())?;
-    import_milestone_diffs(&mut unpacker, storage, full_header.milestone_diff_count())?;
+    import_milestone_diffs(&mut unpacker, storage, full_header.milestone_diff_count(), token_supply)?;
 
     if unpacker.into_inner().bytes().next().is_some() {
         return Err(Error::Snapshot(SnapshotError::RemainingBytes));
@@ -206,7 +242,13 @@ fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id:
     Ok(())
 }
 
-fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+/// Imports the delta snapshot file at `path` on top of the full snapshot it was created from.
+pub fn import_delta_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    token_supply: u64,
+) -> Result<(), Error> {
     info!("Importing delta snapshot file {}...", &path.to_string_lossy());
 
     let mut unpacker = IoUnpacker::new(snapshot_reader(path)?);
@@ -242,7 +284,12 @@ fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id
     )?;
 
     import_solid_entry_points(&mut unpacker, storage, delta_header.sep_count(), header.sep_index())?;
-    import_milestone_diffs(&mut unpacker, storage, delta_header.milestone_diff_count())?;
+    import_milestone_diffs(
+        &mut unpacker,
+        storage,
+        delta_header.milestone_diff_count(),
+        token_supply,
+    )?;
 
     if unpacker.into_inner().bytes().next().is_some() {
         return Err(Error::Snapshot(SnapshotError::RemainingBytes));
@@ -263,6 +310,7 @@ fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id
 pub(crate) async fn import_snapshots<B: StorageBackend>(
     storage: &B,
     network_id: u64,
+    token_supply: u64,
     config: &SnapshotConfig,
 ) -> Result<(), Error> {
     let full_exists = config.full_path().exists();
@@ -280,11 +328,11 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
         .await?;
     }
 
-    import_full_snapshot(storage, config.full_path(), network_id)?;
+    import_full_snapshot(storage, config.full_path(), network_id, token_supply)?;
 
     if let Some(delta_path) = config.delta_path() {
         if delta_path.exists() {
-            import_delta_snapshot(storage, delta_path, network_id)?;
+            import_delta_snapshot(storage, delta_path, network_id, token_supply)?;
         }
     }
 
@@ -6,7 +6,8 @@
 pub(crate) mod condition;
 pub(crate) mod download;
 pub(crate) mod error;
-pub(crate) mod import;
 pub(crate) mod worker;
 
 pub mod config;
+pub mod export;
+pub mod import;
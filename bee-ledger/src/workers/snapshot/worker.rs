@@ -24,7 +24,7 @@ impl<N: Node> Worker<N> for SnapshotWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (u64, SnapshotConfig);
+    type Config = (u64, u64, SnapshotConfig);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -32,7 +32,7 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (network_id, snapshot_config) = config;
+        let (network_id, token_supply, snapshot_config) = config;
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
 
@@ -51,7 +51,7 @@ where
                 *info.entry_point_index(),
                 *info.pruning_index(),
             );
-        } else if let Err(e) = import_snapshots(&*storage, network_id, &snapshot_config).await {
+        } else if let Err(e) = import_snapshots(&*storage, network_id, token_supply, &snapshot_config).await {
             (*storage)
                 .set_health(StorageHealth::Corrupted)
                 .map_err(|e| Error::Storage(Box::new(e)))?;
@@ -5,22 +5,29 @@
 
 use std::collections::HashMap;
 
-use bee_block::{output::OutputId, payload::milestone::MilestoneIndex, Block, BlockId};
+use bee_block::{
+    address::Address, output::OutputId, payload::milestone::MilestoneIndex, protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic, Block, BlockId,
+};
 use bee_storage::{
     access::{AsIterator, Batch, BatchBuilder, Exist, Fetch, Insert, Truncate},
     backend,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
 use crate::{
     types::{
-        snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, Migration, OutputDiff, Receipt,
-        TreasuryDiff, TreasuryOutput, Unspent,
+        snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput,
+        CreatedOutput, LedgerIndex, Migration, MilestoneUtxoDiff, OutputDiff, Receipt, TreasuryDiff, TreasuryOutput,
+        Unspent,
     },
-    workers::error::Error,
+    workers::{address_history, error::Error},
 };
 
 /// A blanket-implemented helper trait for the storage layer.
@@ -40,21 +47,36 @@ pub trait StorageBackend:
     + Batch<BlockId, Block>
     + Batch<BlockId, BlockMetadata>
     + Batch<MilestoneIndex, MilestoneMetadata>
+    + Batch<MilestoneIndex, ProtocolParameters>
+    + Batch<BlockId, ConflictDiagnostic>
+    + Batch<(PaddedTag, TaggedBlock), ()>
+    + Batch<(Address, AddressHistoryEntry), ()>
+    + Batch<(MilestoneIndex, Address), ()>
+    + Batch<(), AddressHistoryIndex>
+    + Exist<MilestoneIndex, OutputDiff>
     + Exist<Unspent, ()>
     + Fetch<(), SnapshotInfo>
     + Fetch<OutputId, CreatedOutput>
+    + Fetch<OutputId, ConsumedOutput>
     + Fetch<(), LedgerIndex>
     + Fetch<bool, Vec<TreasuryOutput>>
     + Fetch<MilestoneIndex, MilestoneMetadata>
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<MilestoneIndex, Vec<UnreferencedBlock>>
     + Fetch<MilestoneIndex, OutputDiff>
+    + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+    + Fetch<MilestoneIndex, Vec<Address>>
+    + Fetch<(), AddressHistoryIndex>
     + Insert<(), SnapshotInfo>
     + Insert<(), LedgerIndex>
     + Insert<(bool, TreasuryOutput), ()>
     + Truncate<SolidEntryPoint, MilestoneIndex>
+    + Truncate<(Address, AddressHistoryEntry), ()>
+    + Truncate<(MilestoneIndex, Address), ()>
+    + Truncate<(), AddressHistoryIndex>
     + for<'a> AsIterator<'a, Unspent, ()>
     + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+    + for<'a> AsIterator<'a, MilestoneIndex, ProtocolParameters>
     + bee_tangle::storage::StorageBackend
 {
 }
@@ -75,25 +97,56 @@ impl<T> StorageBackend for T where
         + Batch<BlockId, Block>
         + Batch<BlockId, BlockMetadata>
         + Batch<MilestoneIndex, MilestoneMetadata>
+        + Batch<MilestoneIndex, ProtocolParameters>
+        + Batch<BlockId, ConflictDiagnostic>
+        + Batch<(PaddedTag, TaggedBlock), ()>
+        + Batch<(Address, AddressHistoryEntry), ()>
+        + Batch<(MilestoneIndex, Address), ()>
+        + Batch<(), AddressHistoryIndex>
+        + Exist<MilestoneIndex, OutputDiff>
         + Exist<Unspent, ()>
         + Fetch<(), SnapshotInfo>
         + Fetch<OutputId, CreatedOutput>
+        + Fetch<OutputId, ConsumedOutput>
         + Fetch<(), LedgerIndex>
         + Fetch<bool, Vec<TreasuryOutput>>
         + Fetch<MilestoneIndex, MilestoneMetadata>
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<MilestoneIndex, Vec<UnreferencedBlock>>
         + Fetch<MilestoneIndex, OutputDiff>
+        + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+        + Fetch<MilestoneIndex, Vec<Address>>
+        + Fetch<(), AddressHistoryIndex>
         + Insert<(), SnapshotInfo>
         + Insert<(), LedgerIndex>
         + Insert<(bool, TreasuryOutput), ()>
         + Truncate<SolidEntryPoint, MilestoneIndex>
+        + Truncate<(Address, AddressHistoryEntry), ()>
+        + Truncate<(MilestoneIndex, Address), ()>
+        + Truncate<(), AddressHistoryIndex>
         + for<'a> AsIterator<'a, Unspent, ()>
         + for<'a> AsIterator<'a, SolidEntryPoint, MilestoneIndex>
+        + for<'a> AsIterator<'a, MilestoneIndex, ProtocolParameters>
         + bee_tangle::storage::StorageBackend
 {
 }
 
+pub(crate) fn insert_conflict_diagnostics<B: StorageBackend>(
+    storage: &B,
+    diagnostics: &[(BlockId, ConflictDiagnostic)],
+) -> Result<(), Error> {
+    let mut batch = B::batch_begin();
+
+    for (block_id, diagnostic) in diagnostics {
+        Batch::<BlockId, ConflictDiagnostic>::batch_insert(storage, &mut batch, block_id, diagnostic)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    storage
+        .batch_commit(batch, true)
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
 pub(crate) fn insert_created_output_batch<B: StorageBackend>(
     storage: &B,
     batch: &mut <B as BatchBuilder>::Batch,
@@ -159,6 +212,8 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
     created_outputs: &HashMap<OutputId, CreatedOutput>,
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     migration: &Option<Migration>,
+    parameters: &Option<(MilestoneIndex, ProtocolParameters)>,
+    address_history: bool,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
@@ -180,6 +235,10 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    if address_history {
+        address_history::insert_milestone_batch(storage, &mut batch, created_outputs, consumed_outputs)?;
+    }
+
     let treasury_diff = if let Some(migration) = migration {
         insert_receipt_batch(storage, &mut batch, migration.receipt())?;
         insert_treasury_output_batch(storage, &mut batch, migration.created_treasury())?;
@@ -193,6 +252,11 @@ pub(crate) fn apply_milestone<B: StorageBackend>(
         None
     };
 
+    if let Some((target_index, parameters)) = parameters {
+        Batch::<MilestoneIndex, ProtocolParameters>::batch_insert(storage, &mut batch, target_index, parameters)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
     Batch::<MilestoneIndex, OutputDiff>::batch_insert(
         storage,
         &mut batch,
@@ -213,6 +277,8 @@ pub(crate) fn rollback_milestone<B: StorageBackend>(
     created_outputs: &HashMap<OutputId, CreatedOutput>,
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     migration: &Option<Migration>,
+    parameters: &Option<(MilestoneIndex, ProtocolParameters)>,
+    address_history: bool,
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
@@ -227,12 +293,21 @@ pub(crate) fn rollback_milestone<B: StorageBackend>(
         delete_consumed_output_batch(storage, &mut batch, output_id)?;
     }
 
+    if address_history {
+        address_history::delete_milestone_batch(storage, &mut batch, created_outputs, consumed_outputs)?;
+    }
+
     if let Some(migration) = migration {
         delete_receipt_batch(storage, &mut batch, migration.receipt())?;
         delete_treasury_output_batch(storage, &mut batch, migration.created_treasury())?;
         unspend_treasury_output_batch(storage, &mut batch, migration.consumed_treasury())?;
     }
 
+    if let Some((target_index, _)) = parameters {
+        Batch::<MilestoneIndex, ProtocolParameters>::batch_delete(storage, &mut batch, target_index)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
     Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, &mut batch, &index)
         .map_err(|e| Error::Storage(Box::new(e)))?;
 
@@ -292,6 +367,16 @@ pub(crate) fn fetch_snapshot_info<B: StorageBackend>(storage: &B) -> Result<Opti
     Fetch::<(), SnapshotInfo>::fetch(storage, &()).map_err(|e| Error::Storage(Box::new(e)))
 }
 
+/// Fetches the history of protocol parameters, keyed by the milestone index at which they become active.
+pub fn fetch_protocol_parameters<B: StorageBackend>(
+    storage: &B,
+) -> Result<Vec<(MilestoneIndex, ProtocolParameters)>, Error> {
+    AsIterator::<MilestoneIndex, ProtocolParameters>::iter(storage)
+        .map_err(|e| Error::Storage(Box::new(e)))?
+        .map(|result| result.map_err(|e| Error::Storage(Box::new(e))))
+        .collect()
+}
+
 pub(crate) fn fetch_output<B: StorageBackend>(
     storage: &B,
     output_id: &OutputId,
@@ -351,6 +436,79 @@ pub(crate) fn unspend_treasury_output_batch<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+/// Checks that the ledger changes of a range of milestones are stored, which is the case for the milestones after the
+/// pruning index and up to the ledger index, except those below the ledger index of the full snapshot the ledger was
+/// imported from.
+pub fn check_milestone_utxo_diff_range<B: StorageBackend>(
+    storage: &B,
+    start: MilestoneIndex,
+    end: MilestoneIndex,
+) -> Result<(), Error> {
+    if start > end {
+        return Err(Error::InvalidMilestoneRange(start, end));
+    }
+
+    let pruning_index = fetch_snapshot_info(storage)?
+        .ok_or(Error::UninitializedLedger)?
+        .pruning_index();
+    let ledger_index = fetch_ledger_index(storage)?.ok_or(Error::UninitializedLedger)?;
+
+    if start <= pruning_index {
+        return Err(Error::MilestoneRangeBelowPruningIndex(start, pruning_index));
+    }
+
+    if end > ledger_index.0 {
+        return Err(Error::MilestoneRangeAboveLedgerIndex(end, ledger_index.0));
+    }
+
+    // The stored ledger changes are contiguous, so the range is stored if both of its ends are.
+    for index in [start, end] {
+        if !Exist::<MilestoneIndex, OutputDiff>::exist(storage, &index).map_err(|e| Error::Storage(Box::new(e)))? {
+            return Err(Error::MilestoneRangeWithoutOutputDiff(index));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches the ledger changes of a milestone, along with the bodies of the outputs it created and consumed.
+pub fn fetch_milestone_utxo_diff<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Option<MilestoneUtxoDiff>, Error> {
+    let output_diff =
+        match Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index).map_err(|e| Error::Storage(Box::new(e)))? {
+            Some(output_diff) => output_diff,
+            None => return Ok(None),
+        };
+
+    let created_outputs = output_diff
+        .created_outputs()
+        .iter()
+        .map(|output_id| fetch_output(storage, output_id)?.ok_or(Error::MissingOutput(*output_id)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let consumed_outputs = output_diff
+        .consumed_outputs()
+        .iter()
+        .map(|output_id| {
+            let created_output = fetch_output(storage, output_id)?.ok_or(Error::MissingOutput(*output_id))?;
+            let consumed_output = Fetch::<OutputId, ConsumedOutput>::fetch(storage, output_id)
+                .map_err(|e| Error::Storage(Box::new(e)))?
+                .ok_or(Error::MissingConsumedOutput(*output_id))?;
+
+            Ok((created_output, consumed_output))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Some(MilestoneUtxoDiff::new(
+        index,
+        output_diff,
+        created_outputs,
+        consumed_outputs,
+    )?))
+}
+
 /// Fetches the unspent treasury output from the storage.
 pub fn fetch_unspent_treasury_output<B: StorageBackend>(storage: &B) -> Result<TreasuryOutput, Error> {
     if let Some(outputs) =
@@ -546,7 +546,8 @@ fn validate_verification_request(
         // NOTE: the validity of the transmitted source and target addresses is ensured through the
         // `VerificationRequest` type.
         // TODO: maybe add check whether the peer sent the correct source address in the packet.
-        // TODO: store own external IP address as perceived by the peer
+        // NOTE: the own external IP address is only learned from verification responses, which - other than
+        // requests - can't be sent unsolicited.
         Ok(())
     }
 }
@@ -679,6 +680,16 @@ fn handle_verification_response(verif_res: VerificationResponse, verif_reqval: R
         }
     }
 
+    // Collect the external address of the local peer as perceived by the remote peer.
+    if let Some(address) = ctx.local.record_external_addr(*ctx.peer_id, verif_res.target_addr()) {
+        log::info!("External address updated: {}.", address);
+
+        // Panic: we don't allow channel send errors.
+        ctx.event_tx
+            .send(Event::ExternalAddressUpdated { address })
+            .expect("error publishing external-address-updated event");
+    }
+
     // Send the response notification.
     if let Some(tx) = verif_reqval.response_tx {
         // Panic: we don't allow channel send errors.
@@ -132,6 +132,10 @@ impl VerificationResponse {
         &self.services
     }
 
+    pub(crate) fn target_addr(&self) -> IpAddr {
+        self.target_addr
+    }
+
     pub(crate) fn from_protobuf(bytes: &[u8]) -> Result<Self, Error> {
         let proto::Pong {
             req_hash,
@@ -0,0 +1,137 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, VecDeque},
+    net::IpAddr,
+};
+
+use crate::peer::PeerId;
+
+// The number of distinct peers that need to report the same external address before it's accepted.
+pub(crate) const MIN_CONFIRMATIONS: usize = 3;
+// The maximum number of (most recent) reports that are taken into account.
+const MAX_REPORTS: usize = 16;
+
+/// Reaches a consensus about the external addresses of the local peer from the addresses reported by other peers.
+///
+/// IPv4 and IPv6 addresses are treated independently, so a dual-stack node can end up with one of each.
+#[derive(Debug, Default)]
+pub(crate) struct ExternalAddrTracker {
+    reports: VecDeque<(PeerId, IpAddr)>,
+    consensus_v4: Option<IpAddr>,
+    consensus_v6: Option<IpAddr>,
+}
+
+impl ExternalAddrTracker {
+    /// Returns the external addresses that were agreed upon.
+    pub(crate) fn consensus(&self) -> impl Iterator<Item = IpAddr> {
+        self.consensus_v4.into_iter().chain(self.consensus_v6)
+    }
+
+    /// Records the external address reported by a peer.
+    ///
+    /// Returns the new consensus address if the report changed it.
+    pub(crate) fn record(&mut self, reporter: PeerId, addr: IpAddr) -> Option<IpAddr> {
+        let is_ipv4 = addr.is_ipv4();
+
+        // Only the most recent report of a peer counts.
+        self.reports
+            .retain(|(peer_id, reported)| *peer_id != reporter || reported.is_ipv4() != is_ipv4);
+
+        if self.reports.len() >= MAX_REPORTS {
+            self.reports.pop_front();
+        }
+        self.reports.push_back((reporter, addr));
+
+        let mut counts = HashMap::<IpAddr, usize>::new();
+
+        for (_, reported) in &self.reports {
+            if reported.is_ipv4() == is_ipv4 {
+                *counts.entry(*reported).or_default() += 1;
+            }
+        }
+
+        let total = counts.values().sum::<usize>();
+
+        let (candidate, count) = counts.into_iter().max_by_key(|(_, count)| *count)?;
+
+        let consensus = if is_ipv4 {
+            &mut self.consensus_v4
+        } else {
+            &mut self.consensus_v6
+        };
+
+        // The address needs enough confirmations, and a strict majority of the recent reports.
+        if count < MIN_CONFIRMATIONS || 2 * count <= total || *consensus == Some(candidate) {
+            None
+        } else {
+            consensus.replace(candidate);
+            Some(candidate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consensus_requires_confirmations() {
+        let mut tracker = ExternalAddrTracker::default();
+        let addr: IpAddr = "203.0.113.1".parse().unwrap();
+
+        for _ in 1..MIN_CONFIRMATIONS {
+            assert!(tracker.record(PeerId::generate(), addr).is_none());
+        }
+
+        assert_eq!(tracker.record(PeerId::generate(), addr), Some(addr));
+        // The consensus didn't change.
+        assert!(tracker.record(PeerId::generate(), addr).is_none());
+        assert_eq!(tracker.consensus().collect::<Vec<_>>(), vec![addr]);
+    }
+
+    #[test]
+    fn repeated_reports_count_once() {
+        let mut tracker = ExternalAddrTracker::default();
+        let reporter = PeerId::generate();
+        let addr: IpAddr = "203.0.113.1".parse().unwrap();
+
+        for _ in 0..MIN_CONFIRMATIONS {
+            assert!(tracker.record(reporter, addr).is_none());
+        }
+
+        assert_eq!(tracker.consensus().count(), 0);
+    }
+
+    #[test]
+    fn consensus_changes_with_majority() {
+        let mut tracker = ExternalAddrTracker::default();
+        let old: IpAddr = "203.0.113.1".parse().unwrap();
+        let new: IpAddr = "203.0.113.2".parse().unwrap();
+        let ipv6: IpAddr = "2001:db8::1".parse().unwrap();
+
+        let reporters = (0..2 * MIN_CONFIRMATIONS + 1)
+            .map(|_| PeerId::generate())
+            .collect::<Vec<_>>();
+
+        for reporter in &reporters[..MIN_CONFIRMATIONS] {
+            tracker.record(*reporter, old);
+        }
+        assert_eq!(tracker.consensus().collect::<Vec<_>>(), vec![old]);
+
+        // IPv6 reports don't interfere with the IPv4 consensus.
+        for reporter in &reporters[..MIN_CONFIRMATIONS] {
+            tracker.record(*reporter, ipv6);
+        }
+        assert_eq!(tracker.consensus().collect::<Vec<_>>(), vec![old, ipv6]);
+
+        let changed = reporters[MIN_CONFIRMATIONS..]
+            .iter()
+            .filter_map(|reporter| tracker.record(*reporter, new))
+            .collect::<Vec<_>>();
+
+        assert_eq!(changed, vec![new]);
+        assert_eq!(tracker.consensus().collect::<Vec<_>>(), vec![new, ipv6]);
+    }
+}
@@ -1,18 +1,21 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod external_addr;
 pub(crate) mod salt;
 pub mod services;
 
 use std::{
     fmt,
+    net::IpAddr,
     sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use crypto::signatures::ed25519::{PublicKey, SecretKey as PrivateKey, Signature, SECRET_KEY_LENGTH};
-use libp2p_core::identity::ed25519::Keypair;
+use libp2p_core::{identity::ed25519::Keypair, multiaddr::Protocol, Multiaddr};
 
 use self::{
+    external_addr::ExternalAddrTracker,
     salt::{Salt, SALT_LIFETIME_SECS},
     services::{ServiceMap, ServiceProtocol},
 };
@@ -47,6 +50,7 @@ pub struct LocalInner {
     private_key: PrivateKey,
     private_salt: Salt,
     services: ServiceMap,
+    external_addrs: ExternalAddrTracker,
 }
 
 impl Local {
@@ -62,6 +66,7 @@ impl Local {
             private_key,
             private_salt: Salt::default(),
             services: ServiceMap::default(),
+            external_addrs: ExternalAddrTracker::default(),
         };
 
         Self {
@@ -113,6 +118,7 @@ impl Local {
                 private_salt: Salt::new(SALT_LIFETIME_SECS),
                 public_salt: Salt::new(SALT_LIFETIME_SECS),
                 services: ServiceMap::default(),
+                external_addrs: ExternalAddrTracker::default(),
             })),
         }
     }
@@ -162,6 +168,50 @@ impl Local {
         self.read().services().clone()
     }
 
+    /// Returns the external addresses of this identity, as perceived by the majority of its peers.
+    ///
+    /// Returns an empty list until enough peers agreed on an address.
+    pub fn external_addrs(&self) -> Vec<IpAddr> {
+        self.read().external_addrs.consensus().collect()
+    }
+
+    /// Returns the externally reachable [`Multiaddr`]s associated with the given service name.
+    ///
+    /// Example: "peering" => `/ip4/203.0.113.1/udp/14627`.
+    pub fn service_multiaddrs(&self, service_name: impl AsRef<str>) -> Vec<Multiaddr> {
+        let inner = self.read();
+
+        inner
+            .services()
+            .get(service_name)
+            .map(|endpoint| {
+                inner
+                    .external_addrs
+                    .consensus()
+                    .map(|ip_address| {
+                        let mut multiaddr = Multiaddr::empty();
+
+                        match ip_address {
+                            IpAddr::V4(ipv4_addr) => multiaddr.push(Protocol::Ip4(ipv4_addr)),
+                            IpAddr::V6(ipv6_addr) => multiaddr.push(Protocol::Ip6(ipv6_addr)),
+                        };
+
+                        multiaddr.push(endpoint.to_libp2p_protocol());
+
+                        multiaddr
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Records the external address of this identity as reported by a peer.
+    ///
+    /// Returns the new external address if the peers reached a (new) consensus about it.
+    pub(crate) fn record_external_addr(&self, reporter: PeerId, addr: IpAddr) -> Option<IpAddr> {
+        self.write().external_addrs.record(reporter, addr)
+    }
+
     fn read(&self) -> RwLockReadGuard<LocalInner> {
         // Panic: we do not allow the lock to be poisened.
         self.inner.read().expect("error getting read access")
@@ -238,3 +288,29 @@ impl PartialEq for LocalInner {
         self.peer_id == other.peer_id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{external_addr::MIN_CONFIRMATIONS, *};
+
+    #[test]
+    fn service_multiaddrs_use_the_external_addrs() {
+        let local = Local::generate();
+        local.add_service("gossip", ServiceProtocol::Tcp, 15600);
+
+        let addr: IpAddr = "203.0.113.1".parse().unwrap();
+
+        // No consensus about the external address yet.
+        assert!(local.service_multiaddrs("gossip").is_empty());
+
+        for _ in 0..MIN_CONFIRMATIONS {
+            local.record_external_addr(PeerId::generate(), addr);
+        }
+
+        assert_eq!(
+            local.service_multiaddrs("gossip"),
+            vec!["/ip4/203.0.113.1/tcp/15600".parse::<Multiaddr>().unwrap()]
+        );
+        assert!(local.service_multiaddrs("unknown").is_empty());
+    }
+}
@@ -32,7 +32,7 @@ async fn main() {
         thread,
     };
 
-    use bee_gossip::{alias, standalone::init, Event, Multiaddr, NetworkConfig, Protocol};
+    use bee_gossip::{alias, standalone::init, Event, InMemoryBanStore, Multiaddr, NetworkConfig, Protocol};
     use tokio::signal::ctrl_c;
     use tokio_stream::StreamExt;
 
@@ -74,7 +74,9 @@ async fn main() {
     }));
 
     let mut _my_local_id = None;
-    let (_tx, mut rx) = init(config, keys, network_id, shutdown).await.expect("init failed");
+    let (_tx, mut rx) = init::<InMemoryBanStore>(config, keys, network_id, (), shutdown)
+        .await
+        .expect("init failed");
 
     loop {
         if let Some(event) = rx.recv().await {
@@ -3,7 +3,11 @@
 
 #![cfg(feature = "full")]
 
-use std::{borrow::Cow, collections::HashSet};
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
 use serde::Deserialize;
@@ -18,6 +22,10 @@ const MIN_RECONNECT_INTERVAL_SECS: u64 = 1;
 pub const DEFAULT_MAX_UNKNOWN_PEERS: usize = 4;
 pub const DEFAULT_MAX_DISCOVERED_PEERS: usize = 4;
 
+pub const DEFAULT_MDNS: bool = false;
+
+const DEFAULT_BAN_STORAGE_PATH: &str = "./storage/mainnet/bans.json";
+
 /// [`NetworkConfigBuilder`] errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -65,6 +73,8 @@ pub struct NetworkConfig {
     pub(crate) reconnect_interval_secs: u64,
     pub(crate) max_unknown_peers: usize,
     pub(crate) max_discovered_peers: usize,
+    pub(crate) mdns: bool,
+    pub(crate) ban_storage_path: PathBuf,
     pub(crate) static_peers: HashSet<Peer>,
 }
 
@@ -80,7 +90,6 @@ impl NetworkConfig {
     }
 
     /// Returns an in-memory config builder to construct a [`NetworkConfig`] iteratively.
-    #[cfg(test)]
     pub fn build_in_memory() -> InMemoryNetworkConfigBuilder {
         InMemoryNetworkConfigBuilder::new()
     }
@@ -161,6 +170,16 @@ impl NetworkConfig {
         self.max_discovered_peers
     }
 
+    /// Returns whether peers are discovered in the local network via mDNS.
+    pub fn mdns(&self) -> bool {
+        self.mdns
+    }
+
+    /// Returns the path of the storage of banned peers and addresses.
+    pub fn ban_storage_path(&self) -> &Path {
+        &self.ban_storage_path
+    }
+
     /// Returns the statically configured peers.
     pub fn static_peers(&self) -> &HashSet<Peer> {
         &self.static_peers
@@ -191,6 +210,8 @@ impl Default for NetworkConfig {
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            mdns: DEFAULT_MDNS,
+            ban_storage_path: DEFAULT_BAN_STORAGE_PATH.into(),
             static_peers: Default::default(),
         }
     }
@@ -208,6 +229,9 @@ pub struct NetworkConfigBuilder {
     max_unknown_peers: Option<usize>,
     #[serde(alias = "maxDiscoveredPeers")]
     max_discovered_peers: Option<usize>,
+    mdns: Option<bool>,
+    #[serde(alias = "banStoragePath")]
+    ban_storage_path: Option<PathBuf>,
     peering: ManualPeeringConfigBuilder,
 }
 
@@ -298,6 +322,20 @@ impl NetworkConfigBuilder {
         self
     }
 
+    /// Specifies whether peers are discovered in the local network via mDNS.
+    ///
+    /// Only peers of the same network are kept, and they count towards the maximum number of discovered peers.
+    pub fn with_mdns(mut self, mdns: bool) -> Self {
+        self.mdns.replace(mdns);
+        self
+    }
+
+    /// Specifies the path of the storage of banned peers and addresses.
+    pub fn with_ban_storage_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.ban_storage_path.replace(path.as_ref().to_owned());
+        self
+    }
+
     /// Builds the network config.
     pub fn finish(self) -> Result<NetworkConfig, Error> {
         Ok(NetworkConfig {
@@ -309,20 +347,20 @@ impl NetworkConfigBuilder {
             reconnect_interval_secs: self.reconnect_interval_secs.unwrap_or(DEFAULT_RECONNECT_INTERVAL_SECS),
             max_unknown_peers: self.max_unknown_peers.unwrap_or(DEFAULT_MAX_UNKNOWN_PEERS),
             max_discovered_peers: self.max_discovered_peers.unwrap_or(DEFAULT_MAX_DISCOVERED_PEERS),
+            mdns: self.mdns.unwrap_or(DEFAULT_MDNS),
+            ban_storage_path: self.ban_storage_path.unwrap_or_else(|| DEFAULT_BAN_STORAGE_PATH.into()),
             static_peers: self.peering.finish()?.peers,
         })
     }
 }
 
 /// An in-memory network config builder, that becomes useful as part of integration testing.
-#[cfg(test)]
 #[derive(Default)]
 #[must_use]
 pub struct InMemoryNetworkConfigBuilder {
     bind_multiaddr: Option<Multiaddr>,
 }
 
-#[cfg(test)]
 impl InMemoryNetworkConfigBuilder {
     /// Creates a new default builder.
     pub fn new() -> Self {
@@ -357,6 +395,8 @@ impl InMemoryNetworkConfigBuilder {
             reconnect_interval_secs: DEFAULT_RECONNECT_INTERVAL_SECS,
             max_unknown_peers: DEFAULT_MAX_UNKNOWN_PEERS,
             max_discovered_peers: DEFAULT_MAX_DISCOVERED_PEERS,
+            mdns: DEFAULT_MDNS,
+            ban_storage_path: DEFAULT_BAN_STORAGE_PATH.into(),
             static_peers: Default::default(),
         }
     }
@@ -495,6 +535,12 @@ mod tests {
             .finish();
     }
 
+    #[test]
+    fn create_with_builder_and_mdns() {
+        assert!(!NetworkConfig::build().finish().unwrap().mdns());
+        assert!(NetworkConfig::build().with_mdns(true).finish().unwrap().mdns());
+    }
+
     #[test]
     #[should_panic]
     fn create_with_mem_builder_and_non_mem_multiaddr() {
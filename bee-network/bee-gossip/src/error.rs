@@ -25,4 +25,8 @@ pub enum Error {
     /// An error occurred in the host event loop.
     #[error("failed to process an item in the host processor event loop")]
     HostEventLoopError,
+
+    /// Creating or loading the ban store failed.
+    #[error("failed to initialize the ban store: {0}")]
+    BanStoreInitFailed(String),
 }
@@ -10,18 +10,20 @@ use super::{
     config::NetworkConfig,
     error::Error,
     peer::{
-        info::{PeerInfo, PeerRelation},
+        ban::BanList,
+        info::{PeerInfo, PeerRelation, ReconnectInfo},
         list::{PeerList, PeerListWrapper},
+        stores::{BanStore, BanStoreHandle},
     },
     service::{
         command::{command_channel, NetworkCommandSender},
         event::{event_channel, Event, InternalEvent, NetworkEventReceiver},
     },
-    Keypair, PeerId,
+    Keypair, PeerId, Protocol,
 };
 use crate::{
     alias,
-    network::host::NetworkHostConfig,
+    network::{host::NetworkHostConfig, mdns::Mdns},
     service::host::{self, ServiceHostConfig},
     swarm::builder::build_swarm,
 };
@@ -34,26 +36,26 @@ pub mod global {
     static MAX_UNKNOWN_PEERS: OnceCell<usize> = OnceCell::new();
     static MAX_DISCOVERED_PEERS: OnceCell<usize> = OnceCell::new();
 
-    pub fn set_reconnect_interval_secs(reconnect_interval_secs: u64) {
-        if cfg!(test) {
-            let _ = RECONNECT_INTERVAL_SECS.set(reconnect_interval_secs);
-        } else {
-            RECONNECT_INTERVAL_SECS
-                .set(reconnect_interval_secs)
-                .expect("oncecell set");
+    // Several in-process nodes (e.g. in tests or simulations) share these globals, so setting them again is only
+    // allowed with the same value.
+    fn set<T: PartialEq>(cell: &OnceCell<T>, value: T) {
+        if let Err(value) = cell.set(value) {
+            if !cfg!(test) && cell.get() != Some(&value) {
+                panic!("oncecell set");
+            }
         }
     }
 
+    pub fn set_reconnect_interval_secs(reconnect_interval_secs: u64) {
+        set(&RECONNECT_INTERVAL_SECS, reconnect_interval_secs);
+    }
+
     pub fn reconnect_interval_secs() -> u64 {
         *RECONNECT_INTERVAL_SECS.get().expect("oncecell get")
     }
 
     pub fn set_network_id(network_id: u64) {
-        if cfg!(test) {
-            let _ = NETWORK_ID.set(network_id);
-        } else {
-            NETWORK_ID.set(network_id).expect("oncecell set");
-        }
+        set(&NETWORK_ID, network_id);
     }
 
     pub fn network_id() -> u64 {
@@ -61,11 +63,7 @@ pub mod global {
     }
 
     pub fn set_max_unknown_peers(max_unknown_peers: usize) {
-        if cfg!(test) {
-            let _ = MAX_UNKNOWN_PEERS.set(max_unknown_peers);
-        } else {
-            MAX_UNKNOWN_PEERS.set(max_unknown_peers).expect("oncecell set");
-        }
+        set(&MAX_UNKNOWN_PEERS, max_unknown_peers);
     }
 
     pub fn max_unknown_peers() -> usize {
@@ -73,11 +71,7 @@ pub mod global {
     }
 
     pub fn set_max_discovered_peers(max_discovered_peers: usize) {
-        if cfg!(test) {
-            let _ = MAX_DISCOVERED_PEERS.set(max_discovered_peers);
-        } else {
-            MAX_DISCOVERED_PEERS.set(max_discovered_peers).expect("oncecell set");
-        }
+        set(&MAX_DISCOVERED_PEERS, max_discovered_peers);
     }
 
     pub fn max_discovered_peers() -> usize {
@@ -95,14 +89,15 @@ pub mod standalone {
     use crate::{network::host::standalone::NetworkHost, service::host::standalone::ServiceHost};
 
     /// Initializes the network.
-    pub async fn init(
+    pub async fn init<S: BanStore>(
         config: NetworkConfig,
         keys: Keypair,
         network_id: u64,
+        ban_store_config: S::Config,
         shutdown: impl Future + Send + Unpin + 'static,
     ) -> Result<(NetworkCommandSender, NetworkEventReceiver), Error> {
         let (network_config, service_config, network_command_sender, network_event_receiver) =
-            super::init(config, keys, network_id)?;
+            super::init::<S>(config, keys, network_id, ban_store_config).await?;
 
         let (shutdown_signal_tx1, shutdown_signal_rx1) = oneshot::channel::<()>();
         let (shutdown_signal_tx2, shutdown_signal_rx2) = oneshot::channel::<()>();
@@ -129,28 +124,33 @@ pub mod integrated {
     use crate::{network::host::integrated::NetworkHost, service::host::integrated::ServiceHost};
 
     /// Initializes the network.
-    pub async fn init<N: Node>(
+    pub async fn init<N: Node, S: BanStore>(
         config: NetworkConfig,
         keys: Keypair,
         network_id: u64,
+        ban_store_config: S::Config,
         mut node_builder: N::Builder,
     ) -> Result<(N::Builder, NetworkEventReceiver), Error> {
         let (host_config, service_config, network_command_sender, network_event_receiver) =
-            super::init(config, keys, network_id)?;
+            super::init::<S>(config, keys, network_id, ban_store_config).await?;
+
+        let ban_list = BanList::new(service_config.peerlist.clone());
 
         node_builder = node_builder
             .with_worker_cfg::<NetworkHost>(host_config)
             .with_worker_cfg::<ServiceHost>(service_config)
-            .with_resource(network_command_sender);
+            .with_resource(network_command_sender)
+            .with_resource(ban_list);
 
         Ok((node_builder, network_event_receiver))
     }
 }
 
-fn init(
+async fn init<S: BanStore>(
     config: NetworkConfig,
     keys: Keypair,
     network_id: u64,
+    ban_store_config: S::Config,
 ) -> Result<
     (
         NetworkHostConfig,
@@ -165,6 +165,7 @@ fn init(
         reconnect_interval_secs,
         max_unknown_peers,
         max_discovered_peers,
+        mdns,
         static_peers: peers,
     } = config;
 
@@ -186,8 +187,14 @@ fn init(
         .send(Event::LocalIdCreated { local_id })
         .map_err(|_| Error::LocalIdAnnouncementFailed)?;
 
+    let ban_store = S::new(ban_store_config).map_err(|e| Error::BanStoreInitFailed(e.to_string()))?;
+
     // TODO: rename to PeerStateMap.
-    let peerlist = PeerListWrapper::new(PeerList::from_peers(local_id, peers.iter().cloned().collect()));
+    let peerlist = PeerListWrapper::new(
+        PeerList::from_peers(local_id, peers.iter().cloned().collect())
+            .with_ban_store(BanStoreHandle::new(ban_store))
+            .map_err(|e| Error::BanStoreInitFailed(e.to_string()))?,
+    );
 
     // Publish which known peers were added initially.
     for peer in peers.into_iter() {
@@ -199,13 +206,29 @@ fn init(
                     address: peer.multiaddr,
                     alias: peer.alias.unwrap_or_else(|| alias!(peer_id).into()),
                     relation: PeerRelation::Known,
+                    reconnect: ReconnectInfo::default(),
                 },
             })
             .map_err(|_| Error::StaticPeersAnnouncementFailed)?;
     }
 
     // Create the transport layer.
-    let swarm = build_swarm(&local_keys, internal_event_sender.clone()).map_err(|_| Error::CreatingTransportFailed)?;
+    let in_memory = matches!(bind_multiaddr.iter().next(), Some(Protocol::Memory(_)));
+    let swarm = build_swarm(&local_keys, internal_event_sender.clone(), in_memory)
+        .map_err(|_| Error::CreatingTransportFailed)?;
+
+    // Peers can't be discovered via mDNS in memory.
+    let mdns = if mdns && !in_memory {
+        match Mdns::new(local_id, network_id) {
+            Ok(mdns) => Some(mdns),
+            Err(e) => {
+                log::warn!("Failed to start mDNS discovery. Cause: {:?}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
 
     let network_host_config = NetworkHostConfig {
         internal_event_sender: internal_event_sender.clone(),
@@ -213,6 +236,7 @@ fn init(
         peerlist: peerlist.clone(),
         swarm,
         bind_multiaddr,
+        mdns,
     };
 
     let service_host_config = ServiceHostConfig {
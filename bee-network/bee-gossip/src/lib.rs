@@ -32,17 +32,25 @@ pub use libp2p_core::{
     PeerId,
 };
 
-pub use self::peer::info::{PeerInfo, PeerRelation};
+pub use self::peer::info::{DialFailure, PeerInfo, PeerRelation, ReconnectInfo};
+#[cfg(all(feature = "full", feature = "rocksdb"))]
+pub use self::peer::stores::{Options as RocksDbBanStoreConfigOptions, RocksDbBanStore, RocksDbBanStoreConfig};
+#[cfg(all(feature = "full", feature = "sled"))]
+pub use self::peer::stores::{SledBanStore, SledBanStoreConfig};
 #[cfg(feature = "full")]
 pub use self::{
-    config::{NetworkConfig, NetworkConfigBuilder},
+    config::{InMemoryNetworkConfigBuilder, NetworkConfig, NetworkConfigBuilder},
     error::Error,
     init::{integrated, standalone},
     network::{host::integrated::NetworkHost, origin::Origin},
+    peer::{
+        ban::{BanInfo, BanList},
+        stores::{BanStore, FileBanStore, FileBanStoreConfig, FileBanStoreError, InMemoryBanStore},
+    },
     service::{
         command::{Command, NetworkCommandSender},
         event::{Event, NetworkEventReceiver},
         host::integrated::ServiceHost,
     },
-    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender},
+    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender, GossipVersion},
 };
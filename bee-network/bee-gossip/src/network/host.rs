@@ -1,14 +1,23 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use futures::{channel::oneshot, StreamExt};
-use libp2p::{swarm::SwarmEvent, Multiaddr, PeerId, Swarm};
+use std::{error::Error as StdError, io};
+
+use futures::{channel::oneshot, future, StreamExt};
+use libp2p::{
+    core::transport::TransportError,
+    swarm::{DialError, SwarmEvent},
+    Multiaddr, PeerId, Swarm,
+};
 use log::*;
 
-use super::error::Error;
+use super::{error::Error, mdns::Mdns};
 use crate::{
     alias,
-    peer::{info::PeerInfo, list::PeerListWrapper as PeerList},
+    peer::{
+        info::{DialFailure, PeerInfo},
+        list::PeerListWrapper as PeerList,
+    },
     service::{
         command::{Command, CommandReceiver},
         event::{InternalEvent, InternalEventSender},
@@ -22,6 +31,7 @@ pub struct NetworkHostConfig {
     pub peerlist: PeerList,
     pub swarm: Swarm<SwarmBehaviour>,
     pub bind_multiaddr: Multiaddr,
+    pub mdns: Option<Mdns>,
 }
 
 pub mod integrated {
@@ -102,6 +112,7 @@ async fn network_host_processor(
         peerlist,
         mut swarm,
         bind_multiaddr,
+        mut mdns,
     } = config;
 
     // Try binding to the configured bind address.
@@ -114,27 +125,52 @@ async fn network_host_processor(
             _ = &mut shutdown => break,
             event = (&mut swarm).next() => {
                 let event = event.ok_or(crate::Error::HostEventLoopError)?;
-                process_swarm_event(event, &internal_event_sender, &peerlist).await;
+                process_swarm_event(event, &internal_event_sender, &peerlist, mdns.as_mut()).await;
             }
             command = (&mut internal_command_receiver).recv() => {
                 let command = command.ok_or(crate::Error::HostEventLoopError)?;
                 process_internal_command(command, &mut swarm, &peerlist).await;
             },
+            event = next_mdns_event(&mut mdns) => {
+                match event {
+                    Some(event) => internal_event_sender.send(event).expect("send error"),
+                    None => {
+                        warn!("mDNS discovery stopped.");
+                        mdns = None;
+                    }
+                }
+            },
         }
     }
 
+    if let Some(mdns) = mdns {
+        mdns.shutdown();
+    }
+
     Ok(())
 }
 
+async fn next_mdns_event(mdns: &mut Option<Mdns>) -> Option<InternalEvent> {
+    match mdns {
+        Some(mdns) => mdns.next_event().await,
+        None => future::pending().await,
+    }
+}
+
 async fn process_swarm_event(
     event: SwarmEvent<(), impl std::error::Error>,
     internal_event_sender: &InternalEventSender,
     peerlist: &PeerList,
+    mdns: Option<&mut Mdns>,
 ) {
     match event {
         SwarmEvent::NewListenAddr { address, .. } => {
             debug!("Swarm event: new listen address {}.", address);
 
+            if let Some(mdns) = mdns {
+                mdns.announce(&address);
+            }
+
             internal_event_sender
                 .send(InternalEvent::AddressBound {
                     address: address.clone(),
@@ -160,6 +196,23 @@ async fn process_swarm_event(
         SwarmEvent::IncomingConnection { send_back_addr, .. } => {
             debug!("Swarm event: being dialed from {}.", send_back_addr);
         }
+        SwarmEvent::OutgoingConnectionError {
+            peer_id: Some(peer_id),
+            error,
+        } => {
+            let failure = dial_failure(&error);
+
+            debug!(
+                "Swarm event: dialing {} failed ({}). Cause: {}",
+                alias!(peer_id),
+                failure,
+                error
+            );
+
+            internal_event_sender
+                .send(InternalEvent::PeerDialFailed { peer_id, failure })
+                .expect("send error");
+        }
         _ => {}
     }
 }
@@ -232,6 +285,61 @@ async fn dial_peer(swarm: &mut Swarm<SwarmBehaviour>, peer_id: PeerId, peerlist:
     Ok(())
 }
 
+/// Tells apart the different reasons why dialing a peer failed.
+fn dial_failure(error: &DialError) -> DialFailure {
+    match error {
+        DialError::ConnectionIo(e) => io_failure(e).unwrap_or(DialFailure::Unreachable),
+        DialError::Transport(errors) => {
+            // If any of the peer's addresses refused the connection, the peer is most likely online.
+            let failures = errors.iter().map(|(_, e)| match e {
+                TransportError::MultiaddrNotSupported(_) => DialFailure::Unreachable,
+                // Transport errors that don't originate from the underlying socket happened during the upgrade of the
+                // connection, i.e. while negotiating the security or multiplexing protocols.
+                TransportError::Other(e) => io_failure(e).unwrap_or(DialFailure::HandshakeFailed),
+            });
+
+            failures
+                .min_by_key(|failure| match failure {
+                    DialFailure::Refused => 0,
+                    DialFailure::HandshakeFailed => 1,
+                    DialFailure::Unreachable => 2,
+                })
+                .unwrap_or(DialFailure::Unreachable)
+        }
+        DialError::WrongPeerId { .. } | DialError::InvalidPeerId(_) => DialFailure::HandshakeFailed,
+        _ => DialFailure::Unreachable,
+    }
+}
+
+/// Walks the chain of errors to find the socket error that caused a dial to fail.
+fn io_failure(error: &io::Error) -> Option<DialFailure> {
+    let mut current: Option<&(dyn StdError + 'static)> = Some(error);
+
+    while let Some(e) = current {
+        if let Some(e) = e.downcast_ref::<io::Error>() {
+            match e.kind() {
+                io::ErrorKind::ConnectionRefused => return Some(DialFailure::Refused),
+                io::ErrorKind::TimedOut
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::NotConnected
+                | io::ErrorKind::AddrNotAvailable => return Some(DialFailure::Unreachable),
+                _ => {}
+            }
+
+            // Transports wrap their errors into `io::Error`s, whose `source` skips the wrapped error.
+            if let Some(inner) = e.get_ref() {
+                current = Some(inner);
+                continue;
+            }
+        }
+
+        current = e.source();
+    }
+
+    None
+}
+
 async fn hang_up(swarm: &mut Swarm<SwarmBehaviour>, peer_id: PeerId) {
     debug!("Hanging up on: {}.", alias!(peer_id));
 
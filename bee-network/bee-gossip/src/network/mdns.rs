@@ -0,0 +1,139 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    net::Ipv4Addr,
+};
+
+use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
+use log::*;
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+
+use crate::{alias, service::event::InternalEvent};
+
+const SERVICE_TYPE: &str = "_iota-gossip._tcp.local.";
+const NETWORK_ID_PROPERTY: &str = "network_id";
+const PEER_ID_PROPERTY: &str = "peer_id";
+
+/// Announces the local peer in the local network via mDNS, and discovers the peers of the same network.
+///
+/// The network id is announced in a TXT record, so that peers of other networks are filtered out before they are
+/// reported, and hence never dialed.
+pub struct Mdns {
+    daemon: ServiceDaemon,
+    events: flume::Receiver<ServiceEvent>,
+    local_id: PeerId,
+    network_id: String,
+    local_addrs: HashSet<Ipv4Addr>,
+    discovered: HashMap<String, PeerId>,
+}
+
+impl Mdns {
+    pub fn new(local_id: PeerId, network_id: u64) -> Result<Self, mdns_sd::Error> {
+        let daemon = ServiceDaemon::new()?;
+        let events = daemon.browse(SERVICE_TYPE)?;
+
+        Ok(Self {
+            daemon,
+            events,
+            local_id,
+            network_id: network_id.to_string(),
+            local_addrs: HashSet::new(),
+            discovered: HashMap::new(),
+        })
+    }
+
+    /// Announces a new listen address of the local peer.
+    pub fn announce(&mut self, address: &Multiaddr) {
+        let mut ip = None;
+        let mut port = None;
+
+        for protocol in address.iter() {
+            match protocol {
+                Protocol::Ip4(addr) => ip = Some(addr),
+                Protocol::Tcp(p) => port = Some(p),
+                _ => {}
+            }
+        }
+
+        // Peers in the local network can't dial a loopback address.
+        let (ip, port) = match (ip, port) {
+            (Some(ip), Some(port)) if !ip.is_loopback() => (ip, port),
+            _ => return,
+        };
+
+        if !self.local_addrs.insert(ip) {
+            return;
+        }
+
+        let addrs = self.local_addrs.iter().copied().collect::<Vec<_>>();
+        let instance_name = self.local_id.to_string();
+        let host_name = format!("{}.local.", instance_name);
+        let properties = HashMap::from([
+            (NETWORK_ID_PROPERTY.to_string(), self.network_id.clone()),
+            (PEER_ID_PROPERTY.to_string(), instance_name.clone()),
+        ]);
+
+        // Registering the service again replaces its previous record.
+        let registered = ServiceInfo::new(SERVICE_TYPE, &instance_name, &host_name, &addrs[..], port, properties)
+            .and_then(|info| self.daemon.register(info));
+
+        match registered {
+            Ok(()) => debug!("Announcing {} via mDNS.", address),
+            Err(e) => warn!("Announcing {} via mDNS failed. Cause: {:?}", address, e),
+        }
+    }
+
+    /// Waits for a peer of the same network to be discovered, or for one to expire.
+    ///
+    /// Returns `None` once the mDNS daemon stopped.
+    pub async fn next_event(&mut self) -> Option<InternalEvent> {
+        loop {
+            match self.events.recv_async().await.ok()? {
+                ServiceEvent::ServiceResolved(info) => {
+                    if let Some(event) = self.process_resolved(&info) {
+                        return Some(event);
+                    }
+                }
+                ServiceEvent::ServiceRemoved(_, fullname) => {
+                    if let Some(peer_id) = self.discovered.remove(&fullname) {
+                        trace!("mDNS record of {} expired.", alias!(peer_id));
+
+                        return Some(InternalEvent::PeerExpired { peer_id });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn shutdown(self) {
+        if let Err(e) = self.daemon.shutdown() {
+            warn!("Stopping mDNS discovery failed. Cause: {:?}", e);
+        }
+    }
+
+    fn process_resolved(&mut self, info: &ServiceInfo) -> Option<InternalEvent> {
+        let peer_id = info.get_property_val_str(PEER_ID_PROPERTY)?.parse::<PeerId>().ok()?;
+
+        if peer_id == self.local_id {
+            return None;
+        }
+
+        if info.get_property_val_str(NETWORK_ID_PROPERTY) != Some(self.network_id.as_str()) {
+            trace!("Ignoring {} of another network discovered via mDNS.", alias!(peer_id));
+            return None;
+        }
+
+        // The lowest address is picked so that the same one is dialed whenever the record is refreshed.
+        let ip = info.get_addresses().iter().min()?;
+        let address = Multiaddr::from(Protocol::Ip4(*ip)).with(Protocol::Tcp(info.get_port()));
+
+        trace!("Discovered {} at {} via mDNS.", alias!(peer_id), address);
+
+        self.discovered.insert(info.get_fullname().to_string(), peer_id);
+
+        Some(InternalEvent::PeerDiscovered { peer_id, address })
+    }
+}
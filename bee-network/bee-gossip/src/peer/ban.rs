@@ -0,0 +1,106 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "full")]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use libp2p::{Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+
+use super::list::PeerListWrapper;
+
+/// Additional information about a ban of a peer or an address.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BanInfo {
+    /// The UNIX timestamp (in seconds) at which the ban expires, or `None` if it never expires.
+    pub expires_at: Option<u64>,
+    /// The reason of the ban.
+    pub reason: Option<String>,
+}
+
+impl BanInfo {
+    /// Creates a new [`BanInfo`].
+    pub fn new(expires_at: Option<u64>, reason: Option<String>) -> Self {
+        Self { expires_at, reason }
+    }
+
+    /// Creates a new [`BanInfo`] of a ban that never expires.
+    pub fn permanent(reason: Option<String>) -> Self {
+        Self::new(None, reason)
+    }
+
+    /// Returns whether the ban is expired at the given UNIX timestamp (in seconds).
+    pub fn is_expired_at(&self, timestamp: u64) -> bool {
+        self.expires_at.map_or(false, |expires_at| expires_at <= timestamp)
+    }
+
+    /// Returns whether the ban is expired.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(unix_time_secs())
+    }
+
+    #[cfg(any(feature = "rocksdb", feature = "sled"))]
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        // Panic: serializing this type to JSON can't fail.
+        serde_json::to_vec(self).expect("serialize ban info")
+    }
+
+    #[cfg(any(feature = "rocksdb", feature = "sled"))]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+/// Allows to inspect the currently banned peers and addresses.
+#[derive(Clone)]
+pub struct BanList(PeerListWrapper);
+
+impl BanList {
+    pub(crate) fn new(peerlist: PeerListWrapper) -> Self {
+        Self(peerlist)
+    }
+
+    /// Returns all banned peers.
+    pub async fn peers(&self) -> Vec<(PeerId, BanInfo)> {
+        self.0.0.read().await.banned_peers()
+    }
+
+    /// Returns all banned addresses.
+    pub async fn addresses(&self) -> Vec<(Multiaddr, BanInfo)> {
+        self.0.0.read().await.banned_addrs()
+    }
+
+    /// Returns whether a peer is banned.
+    pub async fn is_peer_banned(&self, peer_id: &PeerId) -> bool {
+        self.0.0.read().await.is_peer_banned(peer_id)
+    }
+
+    /// Returns whether an address is banned.
+    pub async fn is_address_banned(&self, address: &Multiaddr) -> bool {
+        self.0.0.read().await.is_addr_banned(address)
+    }
+}
+
+pub(crate) fn unix_time_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expiry() {
+        assert!(!BanInfo::permanent(None).is_expired_at(u64::MAX));
+
+        let info = BanInfo::new(Some(100), Some("spam".into()));
+        assert!(!info.is_expired_at(99));
+        assert!(info.is_expired_at(100));
+        assert!(info.is_expired_at(101));
+    }
+}
@@ -40,6 +40,10 @@ pub enum Error {
     #[error("already banned that peer: {0}")]
     PeerIsBanned(PeerId),
 
+    /// A failure due to a discovered peer being part of another network.
+    #[error("peer is part of another network: {0}")]
+    PeerIsForeign(PeerId),
+
     /// A failure due to attempting to connect a peer twice.
     #[error("already connected that peer: {0}")]
     PeerIsConnected(PeerId),
@@ -59,4 +63,8 @@ pub enum Error {
     /// A failure due to hitting the maximum number of allowed discovered peers.
     #[error("tried to add more discovered peers than defined in the config ({0})")]
     ExceedsDiscoveredPeerLimit(usize),
+
+    /// A failure due to the ban store.
+    #[error("ban store operation failed: {0}")]
+    BanStoreFailed(String),
 }
@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::fmt;
+
 use libp2p_core::Multiaddr;
 
 /// Additional information about a peer.
@@ -12,6 +14,8 @@ pub struct PeerInfo {
     pub alias: String,
     /// The type of relation regarding this peer.
     pub relation: PeerRelation,
+    /// The state of the attempts to reconnect to this peer.
+    pub reconnect: ReconnectInfo,
 }
 
 /// Describes the relation with a peer.
@@ -63,6 +67,54 @@ impl PeerRelation {
     }
 }
 
+/// Describes why dialing a peer failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DialFailure {
+    /// The peer actively refused the connection.
+    Refused,
+    /// The peer couldn't be reached, e.g. because it's offline or the connection timed out.
+    Unreachable,
+    /// A connection was established, but negotiating the protocols with the peer failed.
+    HandshakeFailed,
+}
+
+impl fmt::Display for DialFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Refused => write!(f, "refused"),
+            Self::Unreachable => write!(f, "unreachable"),
+            Self::HandshakeFailed => write!(f, "handshake failed"),
+        }
+    }
+}
+
+/// Describes the state of the attempts to reconnect to a peer.
+///
+/// It is reset once a connection with the peer has been established.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReconnectInfo {
+    /// The number of consecutive failed attempts to dial the peer.
+    pub failed_dials: u32,
+    /// The reason why the last attempt to dial the peer failed.
+    pub last_failure: Option<DialFailure>,
+    /// The UNIX timestamp (in seconds) of the next attempt to dial the peer, if one is scheduled.
+    pub next_dial_at: Option<u64>,
+}
+
+impl ReconnectInfo {
+    /// Returns whether the peer may be dialed at the given UNIX timestamp (in seconds).
+    pub fn allows_dialing_at(&self, timestamp: u64) -> bool {
+        self.next_dial_at.map_or(true, |next_dial_at| next_dial_at <= timestamp)
+    }
+
+    /// Records a failed attempt to dial the peer, and schedules the next attempt after the given delay.
+    pub fn record_failure(&mut self, failure: DialFailure, now: u64, delay_secs: u64) {
+        self.failed_dials = self.failed_dials.saturating_add(1);
+        self.last_failure = Some(failure);
+        self.next_dial_at = Some(now.saturating_add(delay_secs));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,4 +133,20 @@ mod tests {
         pr.set_discovered();
         assert!(pr.is_discovered())
     }
+
+    #[test]
+    fn reconnect_schedule() {
+        let mut reconnect = ReconnectInfo::default();
+        assert!(reconnect.allows_dialing_at(0));
+
+        reconnect.record_failure(DialFailure::Refused, 100, 30);
+        assert_eq!(reconnect.failed_dials, 1);
+        assert_eq!(reconnect.last_failure, Some(DialFailure::Refused));
+        assert!(!reconnect.allows_dialing_at(129));
+        assert!(reconnect.allows_dialing_at(130));
+
+        reconnect.record_failure(DialFailure::Unreachable, 130, 60);
+        assert_eq!(reconnect.failed_dials, 2);
+        assert_eq!(reconnect.next_dial_at, Some(190));
+    }
 }
@@ -10,8 +10,10 @@ use libp2p::{Multiaddr, PeerId};
 use tokio::sync::RwLock;
 
 use super::{
+    ban::{unix_time_secs, BanInfo},
     error::Error,
-    info::{PeerInfo, PeerRelation},
+    info::{PeerInfo, PeerRelation, ReconnectInfo},
+    stores::BanStoreHandle,
 };
 use crate::{alias, config::Peer, init::global, swarm::protocols::iota_gossip::GossipSender};
 
@@ -33,8 +35,11 @@ pub struct PeerList {
     local_id: PeerId,
     local_addrs: HashSet<Multiaddr>,
     peers: HashMap<PeerId, (PeerInfo, PeerState, PeerMetrics)>,
-    banned_peers: HashSet<PeerId>,
-    banned_addrs: HashSet<Multiaddr>,
+    banned_peers: HashMap<PeerId, BanInfo>,
+    banned_addrs: HashMap<Multiaddr, BanInfo>,
+    ban_store: Option<BanStoreHandle>,
+    /// Discovered peers that identified as part of another network, ignored until their mDNS record expires.
+    foreign_peers: HashSet<PeerId>,
 }
 
 impl PeerList {
@@ -43,8 +48,10 @@ impl PeerList {
             local_id,
             local_addrs: HashSet::with_capacity(LOCAL_ADDRS_INITIAL_CAP),
             peers: HashMap::with_capacity(REMOTE_PEERS_INITIAL_CAP),
-            banned_peers: HashSet::default(),
-            banned_addrs: HashSet::default(),
+            banned_peers: HashMap::default(),
+            banned_addrs: HashMap::default(),
+            ban_store: None,
+            foreign_peers: HashSet::default(),
         }
     }
 
@@ -61,6 +68,7 @@ impl PeerList {
                         address: peer.multiaddr,
                         alias: peer.alias.unwrap_or_else(|| alias!(peer_id).to_owned()),
                         relation: PeerRelation::Known,
+                        reconnect: ReconnectInfo::default(),
                     },
                     PeerState::default(),
                     PeerMetrics::default(),
@@ -72,11 +80,38 @@ impl PeerList {
             local_id,
             local_addrs: HashSet::with_capacity(LOCAL_ADDRS_INITIAL_CAP),
             peers: p,
-            banned_peers: HashSet::default(),
-            banned_addrs: HashSet::default(),
+            banned_peers: HashMap::default(),
+            banned_addrs: HashMap::default(),
+            ban_store: None,
+            foreign_peers: HashSet::default(),
         }
     }
 
+    /// Persists all bans in the given store, and restores the bans that didn't expire yet from it.
+    pub(crate) fn with_ban_store(mut self, ban_store: BanStoreHandle) -> Result<Self, Error> {
+        let now = unix_time_secs();
+
+        for (peer_id, info) in ban_store.fetch_all_peer_bans()? {
+            if info.is_expired_at(now) {
+                ban_store.delete_peer_ban(&peer_id)?;
+            } else {
+                self.banned_peers.insert(peer_id, info);
+            }
+        }
+
+        for (address, info) in ban_store.fetch_all_address_bans()? {
+            if info.is_expired_at(now) {
+                ban_store.delete_address_ban(&address)?;
+            } else {
+                self.banned_addrs.insert(address, info);
+            }
+        }
+
+        self.ban_store.replace(ban_store);
+
+        Ok(self)
+    }
+
     pub fn add(&mut self, peer_id: PeerId, peer_info: PeerInfo) -> Result<(), (PeerId, PeerInfo, Error)> {
         if self.contains(&peer_id) {
             return Err((peer_id, peer_info, Error::PeerIsDuplicate(peer_id)));
@@ -221,46 +256,122 @@ impl PeerList {
         self.banned_addrs.clear();
     }
 
-    pub fn ban_peer(&mut self, peer_id: PeerId) -> Result<(), Error> {
-        // TODO: use storage to persist banned peers
-        if self.banned_peers.insert(peer_id) {
-            Ok(())
-        } else {
-            Err(Error::PeerIsBanned(peer_id))
+    pub fn ban_peer(&mut self, peer_id: PeerId, info: BanInfo) -> Result<(), Error> {
+        if self.is_peer_banned(&peer_id) {
+            return Err(Error::PeerIsBanned(peer_id));
         }
+
+        if let Some(ban_store) = &self.ban_store {
+            ban_store.store_peer_ban(&peer_id, &info)?;
+        }
+
+        self.banned_peers.insert(peer_id, info);
+
+        Ok(())
     }
 
-    pub fn ban_address(&mut self, address: Multiaddr) -> Result<(), Error> {
-        // TODO: use storage to persist banned addrs
-        if self.banned_addrs.insert(address.clone()) {
-            Ok(())
-        } else {
-            Err(Error::AddressIsBanned(address))
+    pub fn ban_address(&mut self, address: Multiaddr, info: BanInfo) -> Result<(), Error> {
+        if self.is_addr_banned(&address) {
+            return Err(Error::AddressIsBanned(address));
         }
+
+        if let Some(ban_store) = &self.ban_store {
+            ban_store.store_address_ban(&address, &info)?;
+        }
+
+        self.banned_addrs.insert(address, info);
+
+        Ok(())
     }
 
     pub fn unban_peer(&mut self, peer_id: &PeerId) -> Result<(), Error> {
-        if self.banned_peers.remove(peer_id) {
-            Ok(())
-        } else {
-            Err(Error::PeerIsUnbanned(*peer_id))
+        if !self.is_peer_banned(peer_id) {
+            return Err(Error::PeerIsUnbanned(*peer_id));
         }
+
+        if let Some(ban_store) = &self.ban_store {
+            ban_store.delete_peer_ban(peer_id)?;
+        }
+
+        self.banned_peers.remove(peer_id);
+
+        Ok(())
     }
 
     pub fn unban_address(&mut self, addr: &Multiaddr) -> Result<(), Error> {
-        if self.banned_addrs.remove(addr) {
-            Ok(())
-        } else {
-            Err(Error::AddressIsUnbanned(addr.clone()))
+        if !self.is_addr_banned(addr) {
+            return Err(Error::AddressIsUnbanned(addr.clone()));
         }
+
+        if let Some(ban_store) = &self.ban_store {
+            ban_store.delete_address_ban(addr)?;
+        }
+
+        self.banned_addrs.remove(addr);
+
+        Ok(())
     }
 
     pub fn is_peer_banned(&self, peer_id: &PeerId) -> bool {
-        self.banned_peers.contains(peer_id)
+        self.banned_peers.get(peer_id).map_or(false, |info| !info.is_expired())
     }
 
     pub fn is_addr_banned(&self, addr: &Multiaddr) -> bool {
-        self.banned_addrs.contains(addr)
+        self.banned_addrs.get(addr).map_or(false, |info| !info.is_expired())
+    }
+
+    pub fn banned_peers(&self) -> Vec<(PeerId, BanInfo)> {
+        let now = unix_time_secs();
+
+        self.banned_peers
+            .iter()
+            .filter(|(_, info)| !info.is_expired_at(now))
+            .map(|(peer_id, info)| (*peer_id, info.clone()))
+            .collect()
+    }
+
+    pub fn banned_addrs(&self) -> Vec<(Multiaddr, BanInfo)> {
+        let now = unix_time_secs();
+
+        self.banned_addrs
+            .iter()
+            .filter(|(_, info)| !info.is_expired_at(now))
+            .map(|(addr, info)| (addr.clone(), info.clone()))
+            .collect()
+    }
+
+    /// Removes all expired bans, and returns the peers and addresses that are no longer banned.
+    pub fn remove_expired_bans(&mut self) -> Result<(Vec<PeerId>, Vec<Multiaddr>), Error> {
+        let now = unix_time_secs();
+
+        let peers = self
+            .banned_peers
+            .iter()
+            .filter(|(_, info)| info.is_expired_at(now))
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        let addrs = self
+            .banned_addrs
+            .iter()
+            .filter(|(_, info)| info.is_expired_at(now))
+            .map(|(addr, _)| addr.clone())
+            .collect::<Vec<_>>();
+
+        for peer_id in peers.iter() {
+            if let Some(ban_store) = &self.ban_store {
+                ban_store.delete_peer_ban(peer_id)?;
+            }
+            self.banned_peers.remove(peer_id);
+        }
+
+        for addr in addrs.iter() {
+            if let Some(ban_store) = &self.ban_store {
+                ban_store.delete_address_ban(addr)?;
+            }
+            self.banned_addrs.remove(addr);
+        }
+
+        Ok((peers, addrs))
     }
 
     pub fn accepts_incoming_peer(&self, peer_id: &PeerId, peer_addr: &Multiaddr) -> Result<(), Error> {
@@ -276,9 +387,9 @@ impl PeerList {
             Err(Error::PeerIsLocal(*peer_id))
         } else if self.local_addrs.contains(peer_addr) {
             Err(Error::AddressIsLocal(peer_addr.clone()))
-        } else if self.banned_peers.contains(peer_id) {
+        } else if self.is_peer_banned(peer_id) {
             Err(Error::PeerIsBanned(*peer_id))
-        } else if self.banned_addrs.contains(peer_addr) {
+        } else if self.is_addr_banned(peer_addr) {
             Err(Error::AddressIsBanned(peer_addr.clone()))
         } else if self
             .satisfies(peer_id, |_, state, _| state.is_connected())
@@ -299,6 +410,49 @@ impl PeerList {
         }
     }
 
+    /// Marks a peer as part of another network, so that it isn't added again when it gets rediscovered.
+    pub fn mark_foreign(&mut self, peer_id: PeerId) {
+        self.foreign_peers.insert(peer_id);
+    }
+
+    /// Forgets that a peer is part of another network, returns whether it was marked.
+    pub fn unmark_foreign(&mut self, peer_id: &PeerId) -> bool {
+        self.foreign_peers.remove(peer_id)
+    }
+
+    pub fn is_foreign(&self, peer_id: &PeerId) -> bool {
+        self.foreign_peers.contains(peer_id)
+    }
+
+    pub fn accepts_discovered_peer(&self, peer_id: &PeerId, peer_addr: &Multiaddr) -> Result<(), Error> {
+        // Checks performed are:
+        // - Deny ourself as peer.
+        // - Deny one of our own addresses.
+        // - Deny banned peers.
+        // - Deny banned addresses.
+        // - Deny peers of another network.
+        // - Deny already added peers.
+        // - Deny more than the configured discovered peers.
+        if peer_id == &self.local_id {
+            Err(Error::PeerIsLocal(*peer_id))
+        } else if self.local_addrs.contains(peer_addr) {
+            Err(Error::AddressIsLocal(peer_addr.clone()))
+        } else if self.is_peer_banned(peer_id) {
+            Err(Error::PeerIsBanned(*peer_id))
+        } else if self.is_addr_banned(peer_addr) {
+            Err(Error::AddressIsBanned(peer_addr.clone()))
+        } else if self.is_foreign(peer_id) {
+            Err(Error::PeerIsForeign(*peer_id))
+        } else if self.contains(peer_id) {
+            Err(Error::PeerIsDuplicate(*peer_id))
+        } else if self.filter_count(|info, _, _| info.relation.is_discovered()) >= global::max_discovered_peers() {
+            Err(Error::ExceedsDiscoveredPeerLimit(global::max_discovered_peers()))
+        } else {
+            // All checks passed! Accept that peer.
+            Ok(())
+        }
+    }
+
     pub fn allows_dialing_peer(&self, peer_id: &PeerId) -> Result<(), Error> {
         // Checks performed are:
         // - Deny dialing ourself as peer.
@@ -313,7 +467,7 @@ impl PeerList {
             Err(Error::PeerIsLocal(*peer_id))
         } else if !self.contains(peer_id) {
             Err(Error::PeerNotPresent(*peer_id))
-        } else if self.banned_peers.contains(peer_id) {
+        } else if self.is_peer_banned(peer_id) {
             Err(Error::PeerIsBanned(*peer_id))
         } else if self
             .satisfies(peer_id, |_, state, _| state.is_connected())
@@ -325,7 +479,7 @@ impl PeerList {
 
             if self.local_addrs.contains(&peer_info.address) {
                 Err(Error::AddressIsLocal(peer_info.address.clone()))
-            } else if self.banned_addrs.contains(&peer_info.address) {
+            } else if self.is_addr_banned(&peer_info.address) {
                 Err(Error::AddressIsBanned(peer_info.address.clone()))
             } else if peer_info.relation.is_unknown()
                 && self.filter_count(|info, status, _| info.relation.is_unknown() && status.is_connected())
@@ -351,7 +505,7 @@ impl PeerList {
         // - Deny dialing an already connected peer (with that address).
         if self.local_addrs.contains(addr) {
             Err(Error::AddressIsLocal(addr.clone()))
-        } else if self.banned_addrs.contains(addr) {
+        } else if self.is_addr_banned(addr) {
             Err(Error::AddressIsBanned(addr.clone()))
         } else if let Some(peer_id) = self.find_peer_if_connected(addr) {
             Err(Error::PeerIsConnected(peer_id))
@@ -373,6 +527,7 @@ mod tests {
     use libp2p::{identity::ed25519::Keypair, multiaddr::Protocol};
 
     use super::*;
+    use crate::config::DEFAULT_MAX_DISCOVERED_PEERS;
 
     #[test]
     fn new_list() {
@@ -438,6 +593,65 @@ mod tests {
         pl.accepts_incoming_peer(&peer_id, &peer_info.address).unwrap();
     }
 
+    #[test]
+    fn deny_discovered_peers_beyond_limit() {
+        global::set_max_discovered_peers(DEFAULT_MAX_DISCOVERED_PEERS);
+
+        let local_id = gen_constant_peer_id();
+        let mut pl = PeerList::new(local_id);
+
+        assert!(matches!(
+            pl.accepts_discovered_peer(&local_id, &gen_deterministic_addr(0)),
+            Err(Error::PeerIsLocal(_))
+        ));
+
+        for i in 1..=global::max_discovered_peers() as u16 {
+            let peer_id = gen_random_peer_id();
+            let peer_info = gen_deterministic_peer_info(i, PeerRelation::Discovered);
+
+            pl.accepts_discovered_peer(&peer_id, &peer_info.address).unwrap();
+            pl.add(peer_id, peer_info.clone()).unwrap();
+
+            assert!(matches!(
+                pl.accepts_discovered_peer(&peer_id, &peer_info.address),
+                Err(Error::PeerIsDuplicate(_))
+            ));
+        }
+
+        assert!(matches!(
+            pl.accepts_discovered_peer(&gen_random_peer_id(), &gen_deterministic_addr(0)),
+            Err(Error::ExceedsDiscoveredPeerLimit(_))
+        ));
+    }
+
+    #[test]
+    fn deny_discovered_peers_of_another_network_until_expired() {
+        global::set_max_discovered_peers(DEFAULT_MAX_DISCOVERED_PEERS);
+
+        let mut pl = PeerList::new(gen_constant_peer_id());
+
+        let peer_id = gen_random_peer_id();
+        let peer_info = gen_deterministic_peer_info(1, PeerRelation::Discovered);
+
+        pl.accepts_discovered_peer(&peer_id, &peer_info.address).unwrap();
+        pl.add(peer_id, peer_info.clone()).unwrap();
+
+        // Identify revealed that the peer is part of another network.
+        assert!(pl.filter_remove(&peer_id, |info, _, _| info.relation.is_discovered()));
+        pl.mark_foreign(peer_id);
+
+        assert!(matches!(
+            pl.accepts_discovered_peer(&peer_id, &peer_info.address),
+            Err(Error::PeerIsForeign(_))
+        ));
+
+        // Its mDNS record expired.
+        assert!(pl.unmark_foreign(&peer_id));
+        assert!(!pl.unmark_foreign(&peer_id));
+
+        pl.accepts_discovered_peer(&peer_id, &peer_info.address).unwrap();
+    }
+
     #[test]
     fn conditional_remove() {
         let local_id = gen_constant_peer_id();
@@ -471,6 +685,7 @@ mod tests {
             address: gen_deterministic_addr(port),
             alias: port.to_string(),
             relation,
+            reconnect: ReconnectInfo::default(),
         }
     }
 
@@ -479,6 +694,7 @@ mod tests {
             address: gen_deterministic_addr(1),
             alias: String::new(),
             relation: PeerRelation::Known,
+            reconnect: ReconnectInfo::default(),
         }
     }
 
@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod ban;
 pub mod error;
 pub mod info;
 pub mod list;
+pub mod stores;
@@ -0,0 +1,206 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use libp2p::{Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+
+use crate::peer::{ban::BanInfo, stores::BanStore};
+
+/// Errors raised by the [`FileBanStore`].
+#[derive(Debug, thiserror::Error)]
+pub enum FileBanStoreError {
+    /// Reading or writing the file failed.
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    /// The file content is not valid JSON.
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The file contains an invalid peer id.
+    #[error("invalid peer id: {0}")]
+    InvalidPeerId(String),
+    /// The file contains an invalid address.
+    #[error("invalid address: {0}")]
+    InvalidAddress(String),
+}
+
+/// The config for the file ban store.
+#[derive(Clone)]
+pub struct FileBanStoreConfig {
+    path: PathBuf,
+}
+
+impl FileBanStoreConfig {
+    /// Creates a new config for the file ban store.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Bans {
+    #[serde(default)]
+    peers: BTreeMap<String, BanInfo>,
+    #[serde(default)]
+    addresses: BTreeMap<String, BanInfo>,
+}
+
+/// A (persistent) ban store, that keeps all bans in a single human-readable JSON file.
+#[derive(Clone)]
+pub struct FileBanStore {
+    path: PathBuf,
+    bans: Arc<Mutex<Bans>>,
+}
+
+impl FileBanStore {
+    fn lock(&self) -> MutexGuard<Bans> {
+        self.bans.lock().expect("error getting bans lock")
+    }
+
+    fn update<U: FnOnce(&mut Bans) -> bool>(&self, update: U) -> Result<bool, FileBanStoreError> {
+        let mut bans = self.lock();
+
+        if !update(&mut bans) {
+            return Ok(false);
+        }
+
+        // Write to a temporary file first, so a crash can't leave a truncated file behind.
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&*bans)?)?;
+        fs::rename(&tmp, &self.path)?;
+
+        Ok(true)
+    }
+}
+
+impl BanStore for FileBanStore {
+    type Config = FileBanStoreConfig;
+
+    type Error = FileBanStoreError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        let bans = match fs::read(&config.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                if let Some(parent) = config.path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                Bans::default()
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path: config.path,
+            bans: Arc::new(Mutex::new(bans)),
+        })
+    }
+
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), Self::Error> {
+        self.update(|bans| {
+            bans.peers.insert(peer_id.to_base58(), info.clone());
+            true
+        })
+        .map(drop)
+    }
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), Self::Error> {
+        self.update(|bans| {
+            bans.addresses.insert(address.to_string(), info.clone());
+            true
+        })
+        .map(drop)
+    }
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, Self::Error> {
+        self.lock()
+            .peers
+            .iter()
+            .map(|(peer_id, info)| {
+                peer_id
+                    .parse()
+                    .map(|peer_id| (peer_id, info.clone()))
+                    .map_err(|_| FileBanStoreError::InvalidPeerId(peer_id.clone()))
+            })
+            .collect()
+    }
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, Self::Error> {
+        self.lock()
+            .addresses
+            .iter()
+            .map(|(address, info)| {
+                address
+                    .parse()
+                    .map(|address| (address, info.clone()))
+                    .map_err(|_| FileBanStoreError::InvalidAddress(address.clone()))
+            })
+            .collect()
+    }
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, Self::Error> {
+        self.update(|bans| bans.peers.remove(&peer_id.to_base58()).is_some())
+    }
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, Self::Error> {
+        self.update(|bans| bans.addresses.remove(&address.to_string()).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libp2p::identity::ed25519::Keypair;
+
+    use super::*;
+
+    struct Janitor<P: AsRef<Path>>(P);
+
+    impl<P: AsRef<Path>> Drop for Janitor<P> {
+        fn drop(&mut self) {
+            if let Err(e) = fs::remove_file(self.0.as_ref()) {
+                if e.kind() != ErrorKind::NotFound {
+                    panic!("{}", e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn store_fetch_and_delete_bans() {
+        let path = "file_ban_store_store_fetch_and_delete_bans.json";
+        let _janitor = Janitor(path);
+
+        let peer_id = PeerId::from_public_key(&libp2p_core::PublicKey::Ed25519(Keypair::generate().public()));
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/15600".parse().unwrap();
+        let info = BanInfo::new(Some(42), Some("spam".into()));
+
+        let store = FileBanStore::new(FileBanStoreConfig::new(path)).unwrap();
+        store.store_peer_ban(&peer_id, &info).unwrap();
+        store.store_address_ban(&address, &BanInfo::default()).unwrap();
+
+        // Reopen the store to make sure the bans were persisted.
+        let store = FileBanStore::new(FileBanStoreConfig::new(path)).unwrap();
+        assert_eq!(store.fetch_all_peer_bans().unwrap(), vec![(peer_id, info)]);
+        assert_eq!(
+            store.fetch_all_address_bans().unwrap(),
+            vec![(address.clone(), BanInfo::default())]
+        );
+
+        assert!(store.delete_peer_ban(&peer_id).unwrap());
+        assert!(!store.delete_peer_ban(&peer_id).unwrap());
+        assert!(store.delete_address_ban(&address).unwrap());
+
+        let store = FileBanStore::new(FileBanStoreConfig::new(path)).unwrap();
+        assert!(store.fetch_all_peer_bans().unwrap().is_empty());
+        assert!(store.fetch_all_address_bans().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use libp2p::{Multiaddr, PeerId};
+
+use crate::peer::{ban::BanInfo, stores::BanStore};
+
+/// A non-persistent/in-memory ban store.
+#[derive(Clone, Default)]
+pub struct InMemoryBanStore {
+    inner: Arc<RwLock<InMemoryBanStoreInner>>,
+}
+
+#[derive(Default)]
+struct InMemoryBanStoreInner {
+    peers: HashMap<PeerId, BanInfo>,
+    addresses: HashMap<Multiaddr, BanInfo>,
+}
+
+impl InMemoryBanStore {
+    fn read(&self) -> RwLockReadGuard<InMemoryBanStoreInner> {
+        self.inner.read().expect("error getting read access")
+    }
+
+    fn write(&self) -> RwLockWriteGuard<InMemoryBanStoreInner> {
+        self.inner.write().expect("error getting write access")
+    }
+}
+
+impl BanStore for InMemoryBanStore {
+    type Config = ();
+
+    type Error = Infallible;
+
+    fn new(_: Self::Config) -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), Self::Error> {
+        let _ = self.write().peers.insert(*peer_id, info.clone());
+
+        Ok(())
+    }
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), Self::Error> {
+        let _ = self.write().addresses.insert(address.clone(), info.clone());
+
+        Ok(())
+    }
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, Self::Error> {
+        Ok(self.read().peers.iter().map(|(p, i)| (*p, i.clone())).collect())
+    }
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, Self::Error> {
+        Ok(self
+            .read()
+            .addresses
+            .iter()
+            .map(|(a, i)| (a.clone(), i.clone()))
+            .collect())
+    }
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, Self::Error> {
+        Ok(self.write().peers.remove(peer_id).is_some())
+    }
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, Self::Error> {
+        Ok(self.write().addresses.remove(address).is_some())
+    }
+}
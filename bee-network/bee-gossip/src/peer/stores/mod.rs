@@ -0,0 +1,123 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Persistent storage of banned peers and addresses.
+
+#![cfg(feature = "full")]
+
+mod file;
+mod in_memory;
+#[cfg(feature = "rocksdb")]
+mod rocksdb;
+#[cfg(feature = "sled")]
+mod sled;
+
+use std::{error::Error, fmt, ops::Deref, sync::Arc};
+
+use libp2p::{Multiaddr, PeerId};
+
+#[cfg(feature = "rocksdb")]
+pub use self::rocksdb::*;
+#[cfg(feature = "sled")]
+pub use self::sled::*;
+pub use self::{file::*, in_memory::*};
+use super::{ban::BanInfo, error::Error as PeerError};
+
+/// Mandatory functionality of any ban store.
+pub trait BanStore: Clone + Send + Sync + 'static {
+    /// The ban store configuration.
+    type Config;
+
+    /// Error raised when a ban store operation fails.
+    type Error: Error + Send;
+
+    /// Creates a new ban store from config.
+    fn new(config: Self::Config) -> Result<Self, Self::Error>;
+
+    /// Stores the ban of a peer.
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), Self::Error>;
+
+    /// Stores the ban of an address.
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), Self::Error>;
+
+    /// Fetches all banned peers.
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, Self::Error>;
+
+    /// Fetches all banned addresses.
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, Self::Error>;
+
+    /// Deletes the ban of a peer.
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, Self::Error>;
+
+    /// Deletes the ban of an address.
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, Self::Error>;
+}
+
+/// An object-safe version of [`BanStore`], so the peer list doesn't need to be generic over the store.
+pub(crate) trait DynBanStore: Send + Sync {
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), PeerError>;
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), PeerError>;
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, PeerError>;
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, PeerError>;
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, PeerError>;
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, PeerError>;
+}
+
+fn store_error(e: impl Error) -> PeerError {
+    PeerError::BanStoreFailed(e.to_string())
+}
+
+impl<S: BanStore> DynBanStore for S {
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), PeerError> {
+        BanStore::store_peer_ban(self, peer_id, info).map_err(store_error)
+    }
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), PeerError> {
+        BanStore::store_address_ban(self, address, info).map_err(store_error)
+    }
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, PeerError> {
+        BanStore::fetch_all_peer_bans(self).map_err(store_error)
+    }
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, PeerError> {
+        BanStore::fetch_all_address_bans(self).map_err(store_error)
+    }
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, PeerError> {
+        BanStore::delete_peer_ban(self, peer_id).map_err(store_error)
+    }
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, PeerError> {
+        BanStore::delete_address_ban(self, address).map_err(store_error)
+    }
+}
+
+/// A shared handle to a type-erased ban store.
+#[derive(Clone)]
+pub(crate) struct BanStoreHandle(Arc<dyn DynBanStore>);
+
+impl BanStoreHandle {
+    pub(crate) fn new<S: BanStore>(store: S) -> Self {
+        Self(Arc::new(store))
+    }
+}
+
+impl Deref for BanStoreHandle {
+    type Target = dyn DynBanStore;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl fmt::Debug for BanStoreHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BanStoreHandle").finish_non_exhaustive()
+    }
+}
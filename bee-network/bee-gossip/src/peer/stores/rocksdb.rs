@@ -0,0 +1,214 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use libp2p::{Multiaddr, PeerId};
+use log::warn;
+pub use rocksdb::Options;
+use rocksdb::{AsColumnFamilyRef, DBWithThreadMode, IteratorMode, MultiThreaded};
+
+use crate::peer::{ban::BanInfo, stores::BanStore};
+
+const BANNED_PEERS_CF: &str = "banned_peers";
+const BANNED_ADDRESSES_CF: &str = "banned_addresses";
+
+/// The config for the RocksDB ban store.
+#[derive(Clone)]
+pub struct RocksDbBanStoreConfig {
+    path: PathBuf,
+    options: Options,
+}
+
+impl RocksDbBanStoreConfig {
+    /// Creates a new config for the RocksDB ban store.
+    pub fn new<P: AsRef<Path>>(path: P, options: Options) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            options,
+        }
+    }
+}
+
+/// The (persistent) RocksDb ban store.
+#[derive(Clone)]
+pub struct RocksDbBanStore {
+    db: Arc<DBWithThreadMode<MultiThreaded>>,
+}
+
+impl RocksDbBanStore {
+    fn open_cf(&self, cf_str: &'static str) -> impl AsColumnFamilyRef + '_ {
+        self.db.cf_handle(cf_str).unwrap()
+    }
+
+    /// Fetches all the bans of a column family, dropping the entries that can't be decoded.
+    fn fetch_all<K>(
+        &self,
+        cf_str: &'static str,
+        decode_key: impl Fn(&[u8]) -> Option<K>,
+    ) -> Result<Vec<(K, BanInfo)>, rocksdb::Error> {
+        let cf = self.open_cf(cf_str);
+        let mut bans = Vec::new();
+
+        for (key, value) in self.db.iterator_cf(&cf, IteratorMode::Start) {
+            match (decode_key(&*key), BanInfo::from_bytes(&value)) {
+                (Some(key), Ok(info)) => bans.push((key, info)),
+                _ => {
+                    warn!("Dropping corrupt ban entry from {}.", cf_str);
+                    self.db.delete_cf(&cf, key)?;
+                }
+            }
+        }
+
+        Ok(bans)
+    }
+}
+
+impl BanStore for RocksDbBanStore {
+    type Config = RocksDbBanStoreConfig;
+
+    type Error = rocksdb::Error;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        let db = DBWithThreadMode::open_cf(&config.options, &config.path, &[BANNED_PEERS_CF, BANNED_ADDRESSES_CF])?;
+
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), Self::Error> {
+        let cf = self.open_cf(BANNED_PEERS_CF);
+
+        self.db.put_cf(&cf, peer_id.to_bytes(), info.to_bytes())
+    }
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), Self::Error> {
+        let cf = self.open_cf(BANNED_ADDRESSES_CF);
+
+        self.db.put_cf(&cf, address.to_vec(), info.to_bytes())
+    }
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, Self::Error> {
+        self.fetch_all(BANNED_PEERS_CF, |key| PeerId::from_bytes(key).ok())
+    }
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, Self::Error> {
+        self.fetch_all(BANNED_ADDRESSES_CF, |key| Multiaddr::try_from(key.to_vec()).ok())
+    }
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, Self::Error> {
+        let cf = self.open_cf(BANNED_PEERS_CF);
+        let key = peer_id.to_bytes();
+        let existed = self.db.get_pinned_cf(&cf, &key)?.is_some();
+
+        self.db.delete_cf(&cf, key)?;
+
+        Ok(existed)
+    }
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, Self::Error> {
+        let cf = self.open_cf(BANNED_ADDRESSES_CF);
+        let key = address.to_vec();
+        let existed = self.db.get_pinned_cf(&cf, &key)?.is_some();
+
+        self.db.delete_cf(&cf, key)?;
+
+        Ok(existed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libp2p::identity::ed25519::Keypair;
+
+    use super::*;
+
+    struct Janitor<P: AsRef<Path>>(P);
+
+    impl<P: AsRef<Path>> Drop for Janitor<P> {
+        fn drop(&mut self) {
+            if let Err(e) = std::fs::remove_dir_all(self.0.as_ref()) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    panic!("{}", e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn store_fetch_and_delete_bans() {
+        let path = "rocksdb_ban_store_store_fetch_and_delete_bans";
+        let _janitor = Janitor(path);
+
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        let store = RocksDbBanStore::new(RocksDbBanStoreConfig::new(path, options)).unwrap();
+
+        let peer_id = PeerId::from_public_key(&libp2p_core::PublicKey::Ed25519(Keypair::generate().public()));
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/15600".parse().unwrap();
+        let info = BanInfo::new(Some(42), Some("spam".into()));
+
+        store.store_peer_ban(&peer_id, &info).unwrap();
+        store.store_address_ban(&address, &info).unwrap();
+
+        assert_eq!(store.fetch_all_peer_bans().unwrap(), vec![(peer_id, info.clone())]);
+        assert_eq!(store.fetch_all_address_bans().unwrap(), vec![(address.clone(), info)]);
+
+        assert!(store.delete_peer_ban(&peer_id).unwrap());
+        assert!(!store.delete_peer_ban(&peer_id).unwrap());
+        assert!(store.delete_address_ban(&address).unwrap());
+        assert!(store.fetch_all_peer_bans().unwrap().is_empty());
+        assert!(store.fetch_all_address_bans().unwrap().is_empty());
+    }
+
+    #[test]
+    fn drop_corrupt_bans() {
+        let path = "rocksdb_ban_store_drop_corrupt_bans";
+        let _janitor = Janitor(path);
+
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        let store = RocksDbBanStore::new(RocksDbBanStoreConfig::new(path, options)).unwrap();
+
+        let peer_id = PeerId::from_public_key(&libp2p_core::PublicKey::Ed25519(Keypair::generate().public()));
+        let info = BanInfo::permanent(None);
+
+        store.store_peer_ban(&peer_id, &info).unwrap();
+        store
+            .db
+            .put_cf(&store.open_cf(BANNED_PEERS_CF), b"not a peer id", info.to_bytes())
+            .unwrap();
+        store
+            .db
+            .put_cf(
+                &store.open_cf(BANNED_ADDRESSES_CF),
+                b"\x04\x7f\x00\x00\x01",
+                b"not a ban",
+            )
+            .unwrap();
+
+        assert_eq!(store.fetch_all_peer_bans().unwrap(), vec![(peer_id, info)]);
+        assert!(store.fetch_all_address_bans().unwrap().is_empty());
+        // The corrupt entries are gone.
+        assert_eq!(
+            store
+                .db
+                .iterator_cf(&store.open_cf(BANNED_PEERS_CF), IteratorMode::Start)
+                .count(),
+            1
+        );
+        assert_eq!(
+            store
+                .db
+                .iterator_cf(&store.open_cf(BANNED_ADDRESSES_CF), IteratorMode::Start)
+                .count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use libp2p::{Multiaddr, PeerId};
+use log::warn;
+use sled::Db;
+
+use crate::peer::{ban::BanInfo, stores::BanStore};
+
+const BANNED_PEERS_TREE: &str = "banned_peers";
+const BANNED_ADDRESSES_TREE: &str = "banned_addresses";
+
+/// The config for the Sled ban store.
+pub type SledBanStoreConfig = sled::Config;
+
+/// The (persistent) Sled ban store.
+#[derive(Clone)]
+pub struct SledBanStore {
+    db: Db,
+}
+
+impl SledBanStore {
+    /// Fetches all the bans of a tree, dropping the entries that can't be decoded.
+    fn fetch_all<K>(
+        &self,
+        tree_str: &'static str,
+        decode_key: impl Fn(&[u8]) -> Option<K>,
+    ) -> Result<Vec<(K, BanInfo)>, sled::Error> {
+        let tree = self.db.open_tree(tree_str)?;
+        let mut bans = Vec::new();
+
+        for result in tree.iter() {
+            let (key, value) = result?;
+
+            match (decode_key(&*key), BanInfo::from_bytes(&value)) {
+                (Some(key), Ok(info)) => bans.push((key, info)),
+                _ => {
+                    warn!("Dropping corrupt ban entry from {}.", tree_str);
+                    tree.remove(key)?;
+                }
+            }
+        }
+
+        Ok(bans)
+    }
+}
+
+impl BanStore for SledBanStore {
+    type Config = SledBanStoreConfig;
+
+    type Error = sled::Error;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        let db = config.open()?;
+
+        db.open_tree(BANNED_PEERS_TREE)?;
+        db.open_tree(BANNED_ADDRESSES_TREE)?;
+
+        Ok(Self { db })
+    }
+
+    fn store_peer_ban(&self, peer_id: &PeerId, info: &BanInfo) -> Result<(), Self::Error> {
+        let tree = self.db.open_tree(BANNED_PEERS_TREE)?;
+
+        tree.insert(peer_id.to_bytes(), info.to_bytes())?;
+
+        Ok(())
+    }
+
+    fn store_address_ban(&self, address: &Multiaddr, info: &BanInfo) -> Result<(), Self::Error> {
+        let tree = self.db.open_tree(BANNED_ADDRESSES_TREE)?;
+
+        tree.insert(address.to_vec(), info.to_bytes())?;
+
+        Ok(())
+    }
+
+    fn fetch_all_peer_bans(&self) -> Result<Vec<(PeerId, BanInfo)>, Self::Error> {
+        self.fetch_all(BANNED_PEERS_TREE, |key| PeerId::from_bytes(key).ok())
+    }
+
+    fn fetch_all_address_bans(&self) -> Result<Vec<(Multiaddr, BanInfo)>, Self::Error> {
+        self.fetch_all(BANNED_ADDRESSES_TREE, |key| Multiaddr::try_from(key.to_vec()).ok())
+    }
+
+    fn delete_peer_ban(&self, peer_id: &PeerId) -> Result<bool, Self::Error> {
+        let tree = self.db.open_tree(BANNED_PEERS_TREE)?;
+
+        Ok(tree.remove(peer_id.to_bytes())?.is_some())
+    }
+
+    fn delete_address_ban(&self, address: &Multiaddr) -> Result<bool, Self::Error> {
+        let tree = self.db.open_tree(BANNED_ADDRESSES_TREE)?;
+
+        Ok(tree.remove(address.to_vec())?.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libp2p::identity::ed25519::Keypair;
+
+    use super::*;
+
+    #[test]
+    fn store_fetch_and_delete_bans() {
+        let store = SledBanStore::new(SledBanStoreConfig::new().temporary(true)).unwrap();
+
+        let peer_id = PeerId::from_public_key(&libp2p_core::PublicKey::Ed25519(Keypair::generate().public()));
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/15600".parse().unwrap();
+        let info = BanInfo::new(None, Some("spam".into()));
+
+        store.store_peer_ban(&peer_id, &info).unwrap();
+        store.store_address_ban(&address, &info).unwrap();
+
+        assert_eq!(store.fetch_all_peer_bans().unwrap(), vec![(peer_id, info.clone())]);
+        assert_eq!(store.fetch_all_address_bans().unwrap(), vec![(address.clone(), info)]);
+
+        assert!(store.delete_peer_ban(&peer_id).unwrap());
+        assert!(!store.delete_peer_ban(&peer_id).unwrap());
+        assert!(store.delete_address_ban(&address).unwrap());
+        assert!(store.fetch_all_peer_bans().unwrap().is_empty());
+        assert!(store.fetch_all_address_bans().unwrap().is_empty());
+    }
+
+    #[test]
+    fn drop_corrupt_bans() {
+        let store = SledBanStore::new(SledBanStoreConfig::new().temporary(true)).unwrap();
+
+        let peer_id = PeerId::from_public_key(&libp2p_core::PublicKey::Ed25519(Keypair::generate().public()));
+        let info = BanInfo::permanent(None);
+        let peers = store.db.open_tree(BANNED_PEERS_TREE).unwrap();
+        let addresses = store.db.open_tree(BANNED_ADDRESSES_TREE).unwrap();
+
+        store.store_peer_ban(&peer_id, &info).unwrap();
+        peers.insert(b"not a peer id", info.to_bytes()).unwrap();
+        addresses.insert(b"\x04\x7f\x00\x00\x01", &b"not a ban"[..]).unwrap();
+
+        assert_eq!(store.fetch_all_peer_bans().unwrap(), vec![(peer_id, info)]);
+        assert!(store.fetch_all_address_bans().unwrap().is_empty());
+        // The corrupt entries are gone.
+        assert_eq!(peers.len(), 1);
+        assert!(addresses.is_empty());
+    }
+}
@@ -5,7 +5,7 @@ use libp2p_core::{Multiaddr, PeerId};
 use tokio::sync::mpsc;
 
 use super::error::Error;
-use crate::peer::info::PeerRelation;
+use crate::peer::{ban::BanInfo, info::PeerRelation};
 
 pub type CommandReceiver = mpsc::UnboundedReceiver<Command>;
 pub type CommandSender = mpsc::UnboundedSender<Command>;
@@ -53,6 +53,8 @@ pub enum Command {
     BanPeer {
         /// The peer's id.
         peer_id: PeerId,
+        /// The ban's expiry and reason.
+        info: BanInfo,
     },
     /// Unbans a peer.
     UnbanPeer {
@@ -63,6 +65,8 @@ pub enum Command {
     BanAddress {
         /// The peer's address.
         address: Multiaddr,
+        /// The ban's expiry and reason.
+        info: BanInfo,
     },
     /// Unbans an address.
     UnbanAddress {
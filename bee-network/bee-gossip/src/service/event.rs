@@ -8,8 +8,11 @@ use tokio::sync::mpsc;
 use super::command::Command;
 use crate::{
     network::origin::Origin,
-    peer::{error::Error as PeerError, info::PeerInfo},
-    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender},
+    peer::{
+        error::Error as PeerError,
+        info::{DialFailure, PeerInfo, ReconnectInfo},
+    },
+    swarm::protocols::iota_gossip::{GossipReceiver, GossipSender, GossipVersion},
 };
 
 pub type EventSender = mpsc::UnboundedSender<Event>;
@@ -81,6 +84,8 @@ pub enum Event {
         gossip_in: GossipReceiver,
         /// The peer's message send channel.
         gossip_out: GossipSender,
+        /// The version of the gossip protocol negotiated with the peer.
+        gossip_version: GossipVersion,
     },
 
     /// A peer was disconnected.
@@ -89,6 +94,14 @@ pub enum Event {
         peer_id: PeerId,
     },
 
+    /// Dialing a peer failed, and the next attempt was scheduled.
+    PeerDialFailed {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The peer's updated reconnect state.
+        reconnect: ReconnectInfo,
+    },
+
     /// A peer was removed.
     PeerRemoved {
         /// The peer's id.
@@ -129,6 +142,8 @@ pub enum InternalEvent {
         origin: Origin,
         /// The negotiated substream the protocol is running on.
         substream: Box<NegotiatedSubstream>,
+        /// The negotiated version of the protocol.
+        version: GossipVersion,
     },
 
     /// The gossip protocol with a peer was stopped.
@@ -143,10 +158,34 @@ pub enum InternalEvent {
         peer_id: PeerId,
     },
 
+    /// Dialing a peer failed.
+    PeerDialFailed {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The reason of the failure.
+        failure: DialFailure,
+    },
+
     /// A peer has identified itself via the `libp2p` Identify protocol.
     PeerIdentified {
         /// The peer's id.
         peer_id: PeerId,
+        /// Whether the peer supports the gossip protocol of our network.
+        same_network: bool,
+    },
+
+    /// A peer was discovered in the local network via mDNS.
+    PeerDiscovered {
+        /// The peer's id.
+        peer_id: PeerId,
+        /// The peer's address.
+        address: Multiaddr,
+    },
+
+    /// The mDNS record of a previously discovered peer expired.
+    PeerExpired {
+        /// The peer's id.
+        peer_id: PeerId,
     },
 }
 
@@ -154,7 +193,10 @@ pub enum InternalEvent {
 pub struct NetworkEventReceiver(EventReceiver);
 
 impl NetworkEventReceiver {
-    pub(crate) fn new(inner: EventReceiver) -> Self {
+    /// Creates a new [`NetworkEventReceiver`] from a raw event receiver.
+    ///
+    /// This allows to sit between the network layer and its consumer, e.g. to observe or alter the published events.
+    pub fn new(inner: EventReceiver) -> Self {
         Self(inner)
     }
 
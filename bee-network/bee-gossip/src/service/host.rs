@@ -24,8 +24,9 @@ use crate::{
     alias,
     init::global::{self, reconnect_interval_secs},
     peer::{
+        ban::unix_time_secs,
         error::Error as PeerError,
-        info::{PeerInfo, PeerRelation},
+        info::{PeerInfo, PeerRelation, ReconnectInfo},
         list::PeerListWrapper as PeerList,
     },
     swarm::protocols::iota_gossip,
@@ -33,6 +34,8 @@ use crate::{
 
 const MAX_PEER_STATE_CHECKER_DELAY_MILLIS: u64 = 2000;
 const MAX_DIALS: usize = 3;
+// The reconnect delay doubles with every failed dial, up to `2^MAX_BACKOFF_EXPONENT` times the reconnect interval.
+const MAX_BACKOFF_EXPONENT: u32 = 5;
 
 pub struct ServiceHostConfig {
     pub local_keys: identity::Keypair,
@@ -195,7 +198,6 @@ async fn event_processor(shutdown: Shutdown, events: InternalEventReceiver, send
     debug!("Event processor stopped.");
 }
 
-// TODO: implement exponential back-off to not spam the peer with reconnect attempts.
 async fn peerstate_checker(shutdown: Shutdown, senders: Senders, peerlist: PeerList) {
     debug!("Peer checker running.");
 
@@ -214,6 +216,21 @@ async fn peerstate_checker(shutdown: Shutdown, senders: Senders, peerlist: PeerL
     // Check, if there are any disconnected known peers, and schedule a reconnect attempt for each
     // of those.
     while interval.next().await.is_some() {
+        // Lift the bans that expired in the meantime.
+        match peerlist.0.write().await.remove_expired_bans() {
+            Ok((peers, addresses)) => {
+                for peer_id in peers {
+                    debug!("Ban of {} expired.", alias!(peer_id));
+                    let _ = senders.events.send(Event::PeerUnbanned { peer_id });
+                }
+                for address in addresses {
+                    debug!("Ban of {} expired.", address);
+                    let _ = senders.events.send(Event::AddressUnbanned { address });
+                }
+            }
+            Err(e) => warn!("Failed to remove expired bans. Cause: {}", e),
+        }
+
         let read = peerlist.0.read().await;
 
         // To how many known peers are we currently connected.
@@ -242,18 +259,30 @@ async fn peerstate_checker(shutdown: Shutdown, senders: Senders, peerlist: PeerL
             num_disconnected,
         );
 
+        let now = unix_time_secs();
+
         // Automatically try to reconnect known **and** discovered peers. The removal of discovered peers is a decision
         // that needs to be made in the autopeering service.
         for (peer_id, peer_info, peer_metrics) in read.filter(|info, state, _| {
             (info.relation.is_known() || info.relation.is_discovered()) && state.is_disconnected()
         }) {
-            if peer_metrics.num_dials >= MAX_DIALS {
+            // Known peers are never given up on, the back-off makes sure we don't spam them with reconnect attempts.
+            if peer_metrics.num_dials >= MAX_DIALS && !peer_info.relation.is_known() {
                 log::debug!("Peer {} is unreachable.", peer_id);
 
                 let _ = senders.events.send(Event::PeerUnreachable { peer_id, peer_info });
                 continue;
             }
 
+            if !peer_info.reconnect.allows_dialing_at(now) {
+                trace!(
+                    "Backing off from reconnecting to: {} ({}).",
+                    peer_info.alias,
+                    alias!(peer_id)
+                );
+                continue;
+            }
+
             debug!("Trying to reconnect to: {} ({}).", peer_info.alias, alias!(peer_id));
 
             // Ignore if the command fails. We can always retry the next time.
@@ -282,8 +311,8 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
             let _ = senders.internal_commands.send(Command::DialPeer { peer_id });
         }
 
-        Command::BanAddress { address } => {
-            peerlist.0.write().await.ban_address(address.clone())?;
+        Command::BanAddress { address, info } => {
+            peerlist.0.write().await.ban_address(address.clone(), info)?;
 
             senders
                 .events
@@ -291,13 +320,25 @@ async fn process_command(command: Command, senders: &Senders, peerlist: &PeerLis
                 .map_err(|_| Error::SendingEventFailed)?;
         }
 
-        Command::BanPeer { peer_id } => {
-            peerlist.0.write().await.ban_peer(peer_id)?;
+        Command::BanPeer { peer_id, info } => {
+            peerlist.0.write().await.ban_peer(peer_id, info)?;
 
             senders
                 .events
                 .send(Event::PeerBanned { peer_id })
                 .map_err(|_| Error::SendingEventFailed)?;
+
+            let connected = peerlist
+                .0
+                .read()
+                .await
+                .satisfies(&peer_id, |_, state, _| state.is_connected())
+                .unwrap_or(false);
+
+            // A banned peer shouldn't be able to keep gossiping with us.
+            if connected {
+                disconnect_peer(peer_id, senders, peerlist).await?;
+            }
         }
 
         Command::ChangeRelation { peer_id, to } => {
@@ -404,6 +445,7 @@ async fn process_internal_event(
             peer_addr,
             origin,
             substream,
+            version,
         } => {
             let mut peerlist = peerlist.0.write().await;
             let mut peer_added = false;
@@ -415,12 +457,16 @@ async fn process_internal_event(
             let accepted = peerlist.accepts_incoming_peer(&peer_id, &peer_addr);
 
             if accepted.is_ok() {
+                // A successful connection resets the reconnect back-off.
+                let _ = peerlist.update_info(&peer_id, |info| info.reconnect = ReconnectInfo::default());
+
                 // If the peer doesn't exist yet - but is accepted as an "unknown" peer, we insert it now.
                 if !peerlist.contains(&peer_id) {
                     let peer_info = PeerInfo {
                         address: peer_addr,
                         alias: alias!(peer_id).to_string(),
                         relation: PeerRelation::Unknown,
+                        reconnect: ReconnectInfo::default(),
                     };
                     peerlist.add(peer_id, peer_info).map_err(|(_, _, e)| e)?;
                     peer_added = true;
@@ -472,8 +518,9 @@ async fn process_internal_event(
                 }
 
                 info!(
-                    "Established ({}) protocol with {} ({}).",
+                    "Established ({}) protocol {} with {} ({}).",
                     origin,
+                    version,
                     peer_info.alias,
                     alias!(peer_id)
                 );
@@ -485,6 +532,7 @@ async fn process_internal_event(
                         info: peer_info,
                         gossip_in,
                         gossip_out,
+                        gossip_version: version,
                     })
                     .map_err(|_| Error::SendingEventFailed)?;
             } else {
@@ -503,8 +551,61 @@ async fn process_internal_event(
             }
         }
 
-        InternalEvent::PeerIdentified { peer_id } => {
-            let _ = peerlist.0.write().await.update_metrics(&peer_id, |m| {
+        InternalEvent::PeerDialFailed { peer_id, failure } => {
+            let mut reconnect = None;
+
+            let _ = peerlist.0.write().await.update_info(&peer_id, |info| {
+                let delay_secs = reconnect_delay_secs(info.reconnect.failed_dials.saturating_add(1), failure);
+
+                info.reconnect.record_failure(failure, unix_time_secs(), delay_secs);
+                reconnect = Some(info.reconnect.clone());
+            });
+
+            if let Some(reconnect) = reconnect {
+                debug!(
+                    "Dialing {} failed {} time(s) ({}). Next attempt in {}s.",
+                    alias!(peer_id),
+                    reconnect.failed_dials,
+                    failure,
+                    reconnect
+                        .next_dial_at
+                        .unwrap_or_default()
+                        .saturating_sub(unix_time_secs())
+                );
+
+                senders
+                    .events
+                    .send(Event::PeerDialFailed { peer_id, reconnect })
+                    .map_err(|_| Error::SendingEventFailed)?;
+            }
+        }
+
+        InternalEvent::PeerIdentified { peer_id, same_network } => {
+            let mut peerlist = peerlist.0.write().await;
+
+            // Discovered peers that turn out to be part of another network are of no use to us. mDNS can't be scoped
+            // to a network, so they are remembered to not add and dial them again each time they are rediscovered.
+            if !same_network
+                && peerlist
+                    .satisfies(&peer_id, |peer_info, _, _| peer_info.relation.is_discovered())
+                    .unwrap_or(false)
+            {
+                peerlist.mark_foreign(peer_id);
+
+                // We no longer need to hold the lock.
+                drop(peerlist);
+
+                debug!("Removing discovered peer {} of another network.", alias!(peer_id));
+
+                senders
+                    .internal_commands
+                    .send(Command::RemovePeer { peer_id })
+                    .map_err(|_| Error::SendingCommandFailed)?;
+
+                return Ok(());
+            }
+
+            let _ = peerlist.update_metrics(&peer_id, |m| {
                 // Reset dial count.
                 m.num_dials = 0;
                 // Update Identify timestamp.
@@ -516,11 +617,90 @@ async fn process_internal_event(
                 );
             });
         }
+
+        InternalEvent::PeerDiscovered { peer_id, address } => {
+            let mut peerlist = peerlist.0.write().await;
+
+            if let Err(e) = peerlist.accepts_discovered_peer(&peer_id, &address) {
+                trace!("Ignoring discovered peer {}. Cause: {}", alias!(peer_id), e);
+                return Ok(());
+            }
+
+            let peer_info = PeerInfo {
+                address,
+                alias: alias!(peer_id).to_string(),
+                relation: PeerRelation::Discovered,
+                reconnect: ReconnectInfo::default(),
+            };
+            peerlist.add(peer_id, peer_info.clone()).map_err(|(_, _, e)| e)?;
+
+            // We no longer need to hold the lock.
+            drop(peerlist);
+
+            senders
+                .events
+                .send(Event::PeerAdded {
+                    peer_id,
+                    info: peer_info,
+                })
+                .map_err(|_| Error::SendingEventFailed)?;
+
+            // Immediatedly try to dial that peer.
+            senders
+                .internal_commands
+                .send(Command::DialPeer { peer_id })
+                .map_err(|_| Error::SendingCommandFailed)?;
+        }
+
+        InternalEvent::PeerExpired { peer_id } => {
+            let mut peerlist = peerlist.0.write().await;
+
+            // A peer of another network may have joined ours when it is rediscovered.
+            peerlist.unmark_foreign(&peer_id);
+
+            // The record is refreshed for as long as the peer is announced, so it left the local network. Discovered
+            // peers are redialed like known peers, hence it needs to be removed even if it is still
+            // connected.
+            if peerlist
+                .satisfies(&peer_id, |peer_info, _, _| peer_info.relation.is_discovered())
+                .unwrap_or(false)
+            {
+                // We no longer need to hold the lock.
+                drop(peerlist);
+
+                debug!(
+                    "Removing discovered peer {} after its mDNS record expired.",
+                    alias!(peer_id)
+                );
+
+                senders
+                    .internal_commands
+                    .send(Command::RemovePeer { peer_id })
+                    .map_err(|_| Error::SendingCommandFailed)?;
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Returns the delay until the next reconnect attempt after the given number of consecutive failed dials, the last one
+/// failing because of `failure`.
+///
+/// The delay grows exponentially, and is randomized by up to +/-25% so that peers that went offline at the same time
+/// aren't redialed all at once. A failed handshake means the peer is reachable but incompatible, which redialing soon
+/// won't change, so it is directly backed off the most.
+fn reconnect_delay_secs(failed_dials: u32, failure: DialFailure) -> u64 {
+    let exponent = match failure {
+        DialFailure::Refused | DialFailure::Unreachable => failed_dials.saturating_sub(1).min(MAX_BACKOFF_EXPONENT),
+        DialFailure::HandshakeFailed => MAX_BACKOFF_EXPONENT,
+    };
+    let delay = reconnect_interval_secs().saturating_mul(1 << exponent);
+    let jitter = delay / 4;
+
+    delay - jitter + rand::thread_rng().gen_range(0..=2 * jitter)
+}
+
 async fn add_peer(
     peer_id: PeerId,
     address: Multiaddr,
@@ -533,6 +713,7 @@ async fn add_peer(
         address,
         alias,
         relation,
+        reconnect: ReconnectInfo::default(),
     };
 
     let mut peerlist = peerlist.0.write().await;
@@ -674,3 +855,39 @@ async fn disconnect_peer(peer_id: PeerId, senders: &Senders, peerlist: &PeerList
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnect_delay_backs_off_exponentially() {
+        global::set_reconnect_interval_secs(30);
+        // Other tests might have set the interval already.
+        let interval = reconnect_interval_secs();
+
+        for failed_dials in 1..10 {
+            let delay = interval * (1 << (failed_dials - 1).min(MAX_BACKOFF_EXPONENT));
+
+            for failure in [DialFailure::Refused, DialFailure::Unreachable] {
+                let actual = reconnect_delay_secs(failed_dials, failure);
+
+                assert!(actual >= delay - delay / 4 && actual <= delay + delay / 4);
+            }
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_backs_off_failed_handshakes_the_most() {
+        global::set_reconnect_interval_secs(30);
+        // Other tests might have set the interval already.
+        let interval = reconnect_interval_secs();
+        let delay = interval * (1 << MAX_BACKOFF_EXPONENT);
+
+        for failed_dials in 1..10 {
+            let actual = reconnect_delay_secs(failed_dials, DialFailure::HandshakeFailed);
+
+            assert!(actual >= delay - delay / 4 && actual <= delay + delay / 4);
+        }
+    }
+}
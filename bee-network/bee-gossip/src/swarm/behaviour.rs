@@ -45,10 +45,17 @@ impl NetworkBehaviourEventProcess<IdentifyEvent> for SwarmBehaviour {
             IdentifyEvent::Received { peer_id, info } => {
                 trace!("Received Identify response from {}: {:?}.", alias!(peer_id), info,);
 
+                // The gossip protocol identifiers contain the network id, so a peer supporting one of them is part of
+                // our network.
+                let same_network = info
+                    .protocols
+                    .iter()
+                    .any(|protocol| self.gossip.ids().iter().any(|id| protocol.as_bytes() == id.as_ref()));
+
                 // Panic: we made sure that the sender (network host) is always dropped before the receiver (service
                 // host) through the worker dependencies, hence this can never panic.
                 self.internal_sender
-                    .send(InternalEvent::PeerIdentified { peer_id })
+                    .send(InternalEvent::PeerIdentified { peer_id, same_network })
                     .expect("send internal event");
             }
             IdentifyEvent::Sent { peer_id } => {
@@ -84,8 +91,13 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                 peer_addr,
                 origin,
                 substream,
+                version,
             } => {
-                trace!("Successfully negotiated IOTA gossip protocol with {}.", alias!(peer_id));
+                trace!(
+                    "Successfully negotiated IOTA gossip protocol {} with {}.",
+                    version,
+                    alias!(peer_id)
+                );
 
                 self.internal_sender
                     .send(InternalEvent::ProtocolEstablished {
@@ -93,6 +105,7 @@ impl NetworkBehaviourEventProcess<IotaGossipEvent> for SwarmBehaviour {
                         peer_addr,
                         origin,
                         substream,
+                        version,
                     })
                     .expect("send internal event");
             }
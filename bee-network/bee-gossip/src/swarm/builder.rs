@@ -19,6 +19,7 @@ const DEFAULT_CONNECTION_TIMEOUT_SECS: u64 = 10;
 pub fn build_swarm(
     local_keys: &identity::Keypair,
     internal_sender: InternalEventSender,
+    in_memory: bool,
 ) -> Result<Swarm<SwarmBehaviour>, Error> {
     let local_pk = local_keys.public();
     let local_id = local_pk.to_peer_id();
@@ -31,7 +32,7 @@ pub fn build_swarm(
     let mpx_config = mplex::MplexConfig::default();
     let ymx_config = yamux::YamuxConfig::default();
 
-    let transport = if cfg!(test) {
+    let transport = if in_memory {
         use libp2p_core::transport::MemoryTransport;
 
         MemoryTransport::default()
@@ -8,6 +8,7 @@ use libp2p::{
     Multiaddr, PeerId,
 };
 
+use super::id::GossipVersion;
 use crate::network::origin::Origin;
 
 /// Gossip events that may occur while establishing the IOTA gossip protocol with a peer.
@@ -25,6 +26,7 @@ pub enum IotaGossipEvent {
         peer_addr: Multiaddr,
         origin: Origin,
         substream: Box<NegotiatedSubstream>,
+        version: GossipVersion,
     },
 
     /// An error occured during negotiation.
@@ -47,7 +49,10 @@ pub enum IotaGossipHandlerEvent {
     SentUpgradeRequest { to: PeerId },
 
     /// Successfully upgraded to the IOTA gossip protocol.
-    UpgradeCompleted { substream: Box<NegotiatedSubstream> },
+    UpgradeCompleted {
+        substream: Box<NegotiatedSubstream>,
+        version: GossipVersion,
+    },
 
     /// An errror occured during the upgrade.
     UpgradeError {
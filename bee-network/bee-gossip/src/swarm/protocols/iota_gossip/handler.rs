@@ -20,12 +20,16 @@ use libp2p::{
 };
 use log::*;
 
-use super::{event::IotaGossipHandlerEvent, id::IotaGossipIdentifier, upgrade::IotaGossipProtocolUpgrade};
+use super::{
+    event::IotaGossipHandlerEvent,
+    id::{GossipVersion, IotaGossipIdentifier},
+    upgrade::IotaGossipProtocolUpgrade,
+};
 use crate::network::origin::Origin;
 
 pub struct GossipProtocolHandler {
-    /// Exchanged protocol information necessary during negotiation.
-    info: IotaGossipIdentifier,
+    /// Exchanged protocol information necessary during negotiation, the preferred version first.
+    info: Vec<IotaGossipIdentifier>,
 
     /// Keep alive setting.
     keep_alive: KeepAlive,
@@ -40,7 +44,7 @@ pub struct IotaGossipHandlerInEvent {
 }
 
 impl GossipProtocolHandler {
-    pub fn new(info: IotaGossipIdentifier) -> Self {
+    pub fn new(info: Vec<IotaGossipIdentifier>) -> Self {
         Self {
             info,
             keep_alive: KeepAlive::Yes,
@@ -96,9 +100,14 @@ impl ConnectionHandler for GossipProtocolHandler {
     /// **libp2p docs**:
     ///
     /// Injects the output of a successful upgrade on a new inbound substream.
-    fn inject_fully_negotiated_inbound(&mut self, new_inbound: NegotiatedSubstream, _: Self::InboundOpenInfo) {
+    fn inject_fully_negotiated_inbound(
+        &mut self,
+        (new_inbound, version): (NegotiatedSubstream, GossipVersion),
+        _: Self::InboundOpenInfo,
+    ) {
         let negotiated_inbound = ConnectionHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
             substream: Box::new(new_inbound),
+            version,
         });
 
         debug!("gossip handler: fully negotiated inbound.");
@@ -112,9 +121,14 @@ impl ConnectionHandler for GossipProtocolHandler {
     ///
     /// The second argument is the information that was previously passed to
     /// [`ConnectionHandlerEvent::OutboundSubstreamRequest`].
-    fn inject_fully_negotiated_outbound(&mut self, new_outbound: NegotiatedSubstream, _: Self::OutboundOpenInfo) {
+    fn inject_fully_negotiated_outbound(
+        &mut self,
+        (new_outbound, version): (NegotiatedSubstream, GossipVersion),
+        _: Self::OutboundOpenInfo,
+    ) {
         let negotiated_outbound = ConnectionHandlerEvent::Custom(IotaGossipHandlerEvent::UpgradeCompleted {
             substream: Box::new(new_outbound),
+            version,
         });
 
         debug!("gossip handler: fully negotiated outbound.");
@@ -3,12 +3,43 @@
 
 use std::fmt;
 
+/// Versions of the IOTA gossip protocol.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GossipVersion {
+    /// `1.0.0`, the original version.
+    V1_0,
+    /// `1.1.0`, whose peers accept the optional extensions of the packets of `1.0.0`.
+    V1_1,
+}
+
+impl GossipVersion {
+    /// Versions supported by this node, the preferred one first.
+    pub(crate) const SUPPORTED: [Self; 2] = [Self::V1_1, Self::V1_0];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1_0 => "1.0.0",
+            Self::V1_1 => "1.1.0",
+        }
+    }
+}
+
+impl fmt::Display for GossipVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct IotaGossipIdentifier(String);
+pub struct IotaGossipIdentifier(String, GossipVersion);
 
 impl IotaGossipIdentifier {
-    pub fn new(name: impl AsRef<str>, network_id: u64, version: impl AsRef<str>) -> Self {
-        Self(format!("/{}/{}/{}", name.as_ref(), network_id, version.as_ref()))
+    pub fn new(name: impl AsRef<str>, network_id: u64, version: GossipVersion) -> Self {
+        Self(format!("/{}/{}/{}", name.as_ref(), network_id, version), version)
+    }
+
+    pub fn version(&self) -> GossipVersion {
+        self.1
     }
 }
 
@@ -23,3 +54,20 @@ impl AsRef<[u8]> for IotaGossipIdentifier {
         self.0.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(
+            IotaGossipIdentifier::new("iota-gossip", 42, GossipVersion::V1_0).to_string(),
+            "/iota-gossip/42/1.0.0"
+        );
+        assert_eq!(
+            IotaGossipIdentifier::new("iota-gossip", 42, GossipVersion::V1_1).to_string(),
+            "/iota-gossip/42/1.1.0"
+        );
+    }
+}
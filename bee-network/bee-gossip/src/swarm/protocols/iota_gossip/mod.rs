@@ -8,4 +8,4 @@ mod io;
 mod protocol;
 mod upgrade;
 
-pub use self::{event::*, io::*, protocol::*};
+pub use self::{event::*, id::GossipVersion, io::*, protocol::*};
@@ -16,12 +16,11 @@ use log::debug;
 use super::{
     event::{IotaGossipEvent, IotaGossipHandlerEvent},
     handler::{GossipProtocolHandler, IotaGossipHandlerInEvent},
-    id::IotaGossipIdentifier,
+    id::{GossipVersion, IotaGossipIdentifier},
 };
 use crate::{alias, init::global::network_id, network::origin::Origin};
 
 const IOTA_GOSSIP_NAME: &str = "iota-gossip";
-const IOTA_GOSSIP_VERSION: &str = "1.0.0";
 
 type GossipBehaviourAction = NetworkBehaviourAction<IotaGossipEvent, GossipProtocolHandler, IotaGossipHandlerInEvent>;
 
@@ -30,10 +29,10 @@ struct ConnectionInfo {
     origin: Origin,
 }
 
-/// Substream upgrade protocol for `/iota-gossip/1.0.0`.
+/// Substream upgrade protocol for `/iota-gossip/1.0.0` and `/iota-gossip/1.1.0`.
 pub struct IotaGossipProtocol {
-    /// The gossip protocol identifier.
-    id: IotaGossipIdentifier,
+    /// The gossip protocol identifiers, the preferred version first.
+    ids: Vec<IotaGossipIdentifier>,
 
     /// Counts the number of handlers created.
     num_handlers: usize,
@@ -55,12 +54,19 @@ impl IotaGossipProtocol {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn ids(&self) -> &[IotaGossipIdentifier] {
+        &self.ids
+    }
 }
 
 impl Default for IotaGossipProtocol {
     fn default() -> Self {
         Self {
-            id: IotaGossipIdentifier::new(IOTA_GOSSIP_NAME, network_id(), IOTA_GOSSIP_VERSION),
+            ids: GossipVersion::SUPPORTED
+                .iter()
+                .map(|version| IotaGossipIdentifier::new(IOTA_GOSSIP_NAME, network_id(), *version))
+                .collect(),
             num_handlers: 0,
             num_inbounds: 0,
             num_outbounds: 0,
@@ -92,7 +98,7 @@ impl NetworkBehaviour for IotaGossipProtocol {
         self.num_handlers += 1;
         debug!("gossip protocol: new handler ({}).", self.num_handlers);
 
-        GossipProtocolHandler::new(self.id.clone())
+        GossipProtocolHandler::new(self.ids.clone())
     }
 
     /// **libp2p docs**:
@@ -175,13 +181,14 @@ impl NetworkBehaviour for IotaGossipProtocol {
             IotaGossipHandlerEvent::SentUpgradeRequest { to } => {
                 NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::SentUpgradeRequest { to })
             }
-            IotaGossipHandlerEvent::UpgradeCompleted { substream } => {
+            IotaGossipHandlerEvent::UpgradeCompleted { substream, version } => {
                 if let Some(conn_info) = self.peers.remove(&peer_id) {
                     NetworkBehaviourAction::GenerateEvent(IotaGossipEvent::UpgradeCompleted {
                         peer_id,
                         peer_addr: conn_info.addr,
                         origin: conn_info.origin,
                         substream,
+                        version,
                     })
                 } else {
                     return;
@@ -4,7 +4,7 @@
 #![cfg(feature = "full")]
 
 use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
-use crate::{standalone::init, Command, PeerRelation};
+use crate::{standalone::init, Command, InMemoryBanStore, PeerRelation};
 
 #[tokio::test]
 #[serial_test::serial]
@@ -17,10 +17,10 @@ async fn add_peer() {
 
     let network_id = gen_constant_net_id();
 
-    let (tx1, mut rx1) = init(config1, keys1, network_id, shutdown(10))
+    let (tx1, mut rx1) = init::<InMemoryBanStore>(config1, keys1, network_id, (), shutdown(10))
         .await
         .expect("init failed");
-    let (_, mut rx2) = init(config2, keys2, network_id, shutdown(10))
+    let (_, mut rx2) = init::<InMemoryBanStore>(config2, keys2, network_id, (), shutdown(10))
         .await
         .expect("init failed");
 
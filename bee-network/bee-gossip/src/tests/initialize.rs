@@ -4,7 +4,7 @@
 #![cfg(feature = "full")]
 
 use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
-use crate::standalone::init;
+use crate::{standalone::init, InMemoryBanStore};
 
 #[tokio::test]
 #[serial_test::serial]
@@ -15,7 +15,9 @@ async fn initialize() {
     let keys = get_constant_keys();
     let network_id = gen_constant_net_id();
 
-    let (_, mut rx) = init(config, keys, network_id, shutdown(10)).await.expect("init failed");
+    let (_, mut rx) = init::<InMemoryBanStore>(config, keys, network_id, (), shutdown(10))
+        .await
+        .expect("init failed");
 
     let local_id = get_local_id(&mut rx).await;
     // println!("Local Id: {}", local_id);
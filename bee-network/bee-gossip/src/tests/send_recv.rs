@@ -6,7 +6,7 @@
 use tokio_stream::StreamExt;
 
 use super::common::{await_events::*, keys_and_ids::*, network_config::*, shutdown::*};
-use crate::{standalone::init, Command, PeerRelation};
+use crate::{standalone::init, Command, InMemoryBanStore, PeerRelation};
 
 #[tokio::test]
 #[serial_test::serial]
@@ -19,10 +19,10 @@ async fn send_recv() {
 
     let network_id = gen_constant_net_id();
 
-    let (tx1, mut rx1) = init(config1, keys1, network_id, shutdown(10))
+    let (tx1, mut rx1) = init::<InMemoryBanStore>(config1, keys1, network_id, (), shutdown(10))
         .await
         .expect("init failed");
-    let (_tx2, mut rx2) = init(config2, keys2, network_id, shutdown(10))
+    let (_tx2, mut rx2) = init::<InMemoryBanStore>(config2, keys2, network_id, (), shutdown(10))
         .await
         .expect("init failed");
 
@@ -12,6 +12,8 @@ use std::{fs, path::Path};
 use bee_autopeering::config::{AutopeeringConfig, AutopeeringConfigBuilder};
 use bee_gossip::{NetworkConfig, NetworkConfigBuilder};
 use bee_ledger::workers::{
+    base_token::{BaseTokenConfig, BaseTokenConfigBuilder},
+    config::{LedgerConfig, LedgerConfigBuilder},
     pruning::config::{PruningConfig, PruningConfigBuilder},
     snapshot::config::{SnapshotConfig, SnapshotConfigBuilder},
 };
@@ -44,11 +46,13 @@ pub enum NodeConfigError {
 pub struct NodeConfig<S: NodeStorageBackend> {
     pub(crate) alias: String,
     pub(crate) network_spec: NetworkSpec,
+    pub(crate) base_token: BaseTokenConfig,
     pub(crate) logger: LoggerConfig,
     pub(crate) network: NetworkConfig,
     pub(crate) autopeering: AutopeeringConfig,
     pub(crate) protocol: ProtocolConfig,
     pub(crate) rest_api: RestApiConfig,
+    pub(crate) ledger: LedgerConfig,
     pub(crate) snapshot: SnapshotConfig,
     pub(crate) pruning: PruningConfig,
     pub(crate) storage: S::Config,
@@ -89,12 +93,15 @@ pub struct NodeConfigBuilder<S: NodeStorageBackend> {
     pub(crate) bech32_hrp: Option<String>,
     #[serde(alias = "networkId")]
     pub(crate) network_id: Option<String>,
+    #[serde(alias = "baseToken")]
+    pub(crate) base_token: Option<BaseTokenConfigBuilder>,
     pub(crate) logger: Option<LoggerConfigBuilder>,
     pub(crate) network: Option<NetworkConfigBuilder>,
     pub(crate) autopeering: Option<AutopeeringConfigBuilder>,
     pub(crate) protocol: Option<ProtocolConfigBuilder>,
     #[serde(alias = "restApi")]
     pub(crate) rest_api: Option<RestApiConfigBuilder>,
+    pub(crate) ledger: Option<LedgerConfigBuilder>,
     pub(crate) snapshot: Option<SnapshotConfigBuilder>,
     pub(crate) pruning: Option<PruningConfigBuilder>,
     pub(crate) storage: Option<S::ConfigBuilder>,
@@ -117,11 +124,13 @@ where
             alias: self_alias,
             bech32_hrp: self_bech32_hrp,
             network_id: self_network_id,
+            base_token: self_base_token,
             logger: self_logger,
             network: self_network,
             autopeering: self_autopeering,
             protocol: self_protocol,
             rest_api: self_rest_api,
+            ledger: self_ledger,
             snapshot: self_snapshot,
             pruning: self_pruning,
             storage: self_storage,
@@ -134,11 +143,13 @@ where
             && (self_alias == &other.alias)
             && (self_bech32_hrp == &other.bech32_hrp)
             && (self_network_id == &other.network_id)
+            && (self_base_token == &other.base_token)
             && (self_logger == &other.logger)
             && (self_network == &other.network)
             && (self_autopeering == &other.autopeering)
             && (self_protocol == &other.protocol)
             && (self_rest_api == &other.rest_api)
+            && (self_ledger == &other.ledger)
             && (self_snapshot == &other.snapshot)
             && (self_pruning == &other.pruning)
             && (self_storage == &other.storage)
@@ -214,6 +225,11 @@ where
             NodeConfig {
                 alias: self.alias.unwrap_or_else(|| ALIAS_DEFAULT.to_owned()),
                 network_spec,
+                base_token: self
+                    .base_token
+                    .unwrap_or_default()
+                    .finish()
+                    .expect("faulty base token configuration"),
                 logger: self.logger.unwrap_or_default().finish(),
                 network: self
                     .network
@@ -223,6 +239,7 @@ where
                 autopeering: self.autopeering.unwrap_or_default().finish(),
                 protocol: self.protocol.unwrap_or_default().finish(),
                 rest_api: self.rest_api.unwrap_or_default().finish(),
+                ledger: self.ledger.unwrap_or_default().finish(),
                 snapshot: self.snapshot.unwrap_or_default().finish(),
                 pruning: self.pruning.unwrap_or_default().finish(),
                 storage: self.storage.unwrap_or_default().into(),
@@ -2,8 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    any::{type_name, Any, TypeId},
-    collections::HashMap,
+    any::{Any, TypeId},
     convert::Infallible,
 };
 
@@ -16,29 +15,22 @@ use bee_gossip::Keypair;
 use bee_plugin_version_checker::VersionCheckerPlugin;
 use bee_rest_api::endpoints::InitEntryNodeConfig;
 use bee_runtime::{
+    core::CoreBuilder,
     event::Bus,
     node::{Node, NodeBuilder},
     shutdown_stream::ShutdownStream,
     worker::Worker,
 };
 use futures::StreamExt;
-use fxhash::FxBuildHasher;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use super::{config::EntryNodeConfig, EntryNode, EntryNodeError};
-use crate::{
-    core::{Core, ResourceRegister, TopologicalOrder, WorkerStart, WorkerStop},
-    shutdown, util, AUTOPEERING_VERSION,
-};
+use crate::{shutdown, util, AUTOPEERING_VERSION};
 
 /// A builder to create a Bee entry node (autopeering).
 pub struct EntryNodeBuilder {
     config: EntryNodeConfig,
-    deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
-    worker_starts: HashMap<TypeId, Box<WorkerStart<EntryNode>>>,
-    worker_stops: HashMap<TypeId, Box<WorkerStop<EntryNode>>>,
-    worker_names: HashMap<TypeId, &'static str>,
-    resource_registers: Vec<Box<ResourceRegister<EntryNode>>>,
+    core: CoreBuilder<EntryNode>,
 }
 
 impl EntryNodeBuilder {
@@ -57,11 +49,7 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
     fn new(config: Self::Config) -> Result<Self, Self::Error> {
         Ok(Self {
             config,
-            deps: HashMap::default(),
-            worker_starts: HashMap::default(),
-            worker_stops: HashMap::default(),
-            worker_names: HashMap::default(),
-            resource_registers: Vec::default(),
+            core: CoreBuilder::default(),
         })
     }
 
@@ -75,39 +63,13 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
 
     /// Adds a worker (with config) to the entry node builder.
     fn with_worker_cfg<W: Worker<EntryNode> + 'static>(mut self, config: W::Config) -> Self {
-        self.deps.insert(TypeId::of::<W>(), W::dependencies());
-        self.worker_starts.insert(
-            TypeId::of::<W>(),
-            Box::new(|node| {
-                Box::pin(async move {
-                    log::debug!("Starting worker {}...", type_name::<W>());
-                    match W::start(node, config).await {
-                        Ok(w) => node.core.add_worker(w),
-                        Err(e) => panic!("Worker `{}` failed to start: {:?}.", type_name::<W>(), e),
-                    }
-                })
-            }),
-        );
-        self.worker_stops.insert(
-            TypeId::of::<W>(),
-            Box::new(|node| {
-                Box::pin(async move {
-                    match node.core.remove_worker::<W>().stop(node).await {
-                        Ok(()) => {}
-                        Err(e) => panic!("Worker `{}` failed to stop: {:?}.", type_name::<W>(), e),
-                    }
-                })
-            }),
-        );
-        self.worker_names.insert(TypeId::of::<W>(), type_name::<W>());
+        self.core = self.core.with_worker_cfg::<W>(config);
         self
     }
 
     /// Adds a resource to the entry node builder.
     fn with_resource<R: Any + Send + Sync>(mut self, res: R) -> Self {
-        self.resource_registers.push(Box::new(move |node| {
-            node.register_resource(res);
-        }));
+        self.core = self.core.with_resource(res);
         self
     }
 
@@ -146,31 +108,10 @@ impl NodeBuilder<EntryNode> for EntryNodeBuilder {
         // Start the autopeering event logger.
         let builder = builder.with_worker_cfg::<AutopeeringEventLogger>(autopeering_rx);
 
-        let EntryNodeBuilder {
-            config,
-            deps,
-            mut worker_starts,
-            worker_stops,
-            worker_names,
-            resource_registers,
-        } = builder;
-
-        let worker_order = TopologicalOrder::sort(deps);
+        let EntryNodeBuilder { config, core } = builder;
 
-        let core = Core::new(worker_stops, worker_order, worker_names);
-
-        let mut entry_node = EntryNode { config, core };
-
-        // Add all resources.
-        for f in resource_registers {
-            f(&mut entry_node);
-        }
-
-        // Start all workers in topological order.
-        for id in &entry_node.core.worker_order.clone() {
-            // Panic: unwrap cannot fail by design.
-            worker_starts.remove(id).unwrap()(&mut entry_node).await;
-        }
+        // Add all resources and start all workers in topological order.
+        let entry_node = core.finish(|core| EntryNode { config, core }).await;
 
         log::info!("Initialized.");
 
@@ -4,18 +4,20 @@
 pub mod builder;
 pub mod config;
 
-use std::any::{type_name, Any, TypeId};
+use std::any::Any;
 
 use async_trait::async_trait;
-use bee_runtime::{event::Bus, node::Node, resource::ResourceHandle, worker::Worker};
+use bee_runtime::{
+    core::{Core, CoreNode},
+    node::Node,
+    resource::ResourceHandle,
+    worker::Worker,
+};
 use bee_storage_null::Storage as NullStorage;
-use futures::{channel::oneshot, Future};
+use futures::Future;
 
 use self::{builder::EntryNodeBuilder, config::EntryNodeConfig};
-use crate::{
-    core::{Core, CoreError},
-    shutdown::ShutdownRx,
-};
+use crate::{core::CoreError, shutdown::ShutdownRx};
 
 /// Entry node related errors.
 #[derive(Debug, thiserror::Error)]
@@ -59,6 +61,16 @@ impl EntryNode {
     }
 }
 
+impl CoreNode for EntryNode {
+    fn core(&self) -> &Core<Self> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<Self> {
+        &mut self.core
+    }
+}
+
 #[async_trait]
 impl Node for EntryNode {
     type Builder = EntryNodeBuilder;
@@ -66,19 +78,16 @@ impl Node for EntryNode {
     type Error = EntryNodeError;
 
     fn register_resource<R: Any + Send + Sync>(&mut self, res: R) {
-        self.core.resources.insert(ResourceHandle::new(res));
+        self.core.register_resource(res);
     }
 
     fn remove_resource<R: Any + Send + Sync>(&mut self) -> Option<R> {
-        self.core.resources.remove::<ResourceHandle<R>>()?.try_unwrap()
+        self.core.remove_resource()
     }
 
     #[track_caller]
     fn resource<R: Any + Send + Sync>(&self) -> ResourceHandle<R> {
-        match self.core.resources.get::<ResourceHandle<R>>() {
-            Some(res) => res.clone(),
-            None => panic!("Unable to fetch node resource {}.", type_name::<R>(),),
-        }
+        self.core.resource()
     }
 
     #[track_caller]
@@ -88,41 +97,19 @@ impl Node for EntryNode {
         G: FnOnce(ShutdownRx) -> F,
         F: Future<Output = ()> + Send + 'static,
     {
-        let (tx, rx) = oneshot::channel();
-        let future = g(rx);
-
-        let task = tokio::spawn(future);
-
-        self.core
-            .tasks
-            .entry(TypeId::of::<W>())
-            .or_default()
-            .push((tx, Box::new(task)));
+        self.core.spawn::<W, _, _>(g);
     }
 
     fn worker<W>(&self) -> Option<&W>
     where
         W: Worker<Self> + Send + Sync,
     {
-        self.core.workers.get::<W>()
+        self.core.worker()
     }
 
     /// Stops the entry node.
     async fn stop(mut self) -> Result<(), Self::Error> {
-        for worker_id in self.core.worker_order.clone().into_iter().rev() {
-            // Panic: unwrapping is fine since worker_id is from the list of workers.
-            log::debug!("Stopping worker {}...", self.core.worker_names.get(&worker_id).unwrap());
-
-            for (shutdown, task_fut) in self.core.tasks.remove(&worker_id).unwrap_or_default() {
-                let _ = shutdown.send(());
-                // TODO: Should we handle this error?
-                let _ = task_fut.await;
-            }
-
-            // Panic: unwrapping cannot fail by design.
-            self.core.worker_stops.remove(&worker_id).unwrap()(&mut self).await;
-            self.resource::<Bus>().remove_listeners_by_id(worker_id);
-        }
+        Core::stop_workers(&mut self).await;
 
         Ok(())
     }
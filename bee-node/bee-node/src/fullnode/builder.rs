@@ -1,45 +1,27 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{
-    any::{type_name, Any, TypeId},
-    collections::HashMap,
-};
+use std::any::{Any, TypeId};
 
 use async_trait::async_trait;
 use bee_autopeering::{
     stores::{Options as RocksDbPeerStoreConfigOptions, RocksDbPeerStore, RocksDbPeerStoreConfig},
     NeighborValidator, ServiceProtocol, AUTOPEERING_SERVICE_NAME,
 };
-use bee_gossip::{Keypair, NetworkEventReceiver, Protocol};
+use bee_gossip::{FileBanStore, FileBanStoreConfig, Keypair, NetworkEventReceiver, Protocol};
 use bee_plugin_version_checker::VersionCheckerPlugin;
 use bee_rest_api::endpoints::InitFullNodeConfig;
-use bee_runtime::{
-    event::Bus,
-    node::{Node, NodeBuilder},
-    worker::Worker,
-};
+use bee_runtime::{core::CoreBuilder, event::Bus, node::NodeBuilder, worker::Worker};
 use bee_storage::system::StorageHealth;
-use fxhash::FxBuildHasher;
 
 use super::{config::FullNodeConfig, FullNode, FullNodeError};
-use crate::{
-    config::NetworkSpec,
-    core::{Core, CoreError, ResourceRegister, TopologicalOrder, WorkerStart, WorkerStop},
-    shutdown,
-    storage::NodeStorageBackend,
-    util, AUTOPEERING_VERSION,
-};
+use crate::{config::NetworkSpec, core::CoreError, shutdown, storage::NodeStorageBackend, util, AUTOPEERING_VERSION};
 
 /// A builder to create a Bee full node.
 #[must_use]
 pub struct FullNodeBuilder<S: NodeStorageBackend> {
     config: FullNodeConfig<S>,
-    deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
-    worker_starts: HashMap<TypeId, Box<WorkerStart<FullNode<S>>>>,
-    worker_stops: HashMap<TypeId, Box<WorkerStop<FullNode<S>>>>,
-    worker_names: HashMap<TypeId, &'static str>,
-    resource_registers: Vec<Box<ResourceRegister<FullNode<S>>>>,
+    core: CoreBuilder<FullNode<S>>,
 }
 
 impl<S: NodeStorageBackend> FullNodeBuilder<S> {
@@ -71,11 +53,7 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
     fn new(config: Self::Config) -> Result<Self, Self::Error> {
         Ok(Self {
             config,
-            deps: HashMap::default(),
-            worker_starts: HashMap::default(),
-            worker_stops: HashMap::default(),
-            worker_names: HashMap::default(),
-            resource_registers: Vec::default(),
+            core: CoreBuilder::default(),
         })
     }
 
@@ -89,39 +67,13 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
 
     /// Adds a worker (with config) to the full node.
     fn with_worker_cfg<W: Worker<FullNode<S>> + 'static>(mut self, config: W::Config) -> Self {
-        self.deps.insert(TypeId::of::<W>(), W::dependencies());
-        self.worker_starts.insert(
-            TypeId::of::<W>(),
-            Box::new(|node| {
-                Box::pin(async move {
-                    log::debug!("Starting worker {}...", type_name::<W>());
-                    match W::start(node, config).await {
-                        Ok(w) => node.core.add_worker(w),
-                        Err(e) => panic!("Worker `{}` failed to start: {:?}.", type_name::<W>(), e),
-                    }
-                })
-            }),
-        );
-        self.worker_stops.insert(
-            TypeId::of::<W>(),
-            Box::new(|node| {
-                Box::pin(async move {
-                    match node.core.remove_worker::<W>().stop(node).await {
-                        Ok(()) => {}
-                        Err(e) => panic!("Worker `{}` failed to stop: {:?}.", type_name::<W>(), e),
-                    }
-                })
-            }),
-        );
-        self.worker_names.insert(TypeId::of::<W>(), type_name::<W>());
+        self.core = self.core.with_worker_cfg::<W>(config);
         self
     }
 
     /// Adds a resource to the full node.
     fn with_resource<R: Any + Send + Sync>(mut self, res: R) -> Self {
-        self.resource_registers.push(Box::new(move |node| {
-            node.register_resource(res);
-        }));
+        self.core = self.core.with_resource(res);
         self
     }
 
@@ -146,10 +98,14 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
 
         // Initialize everything.
         let (gossip_rx, builder) = initialize_gossip_layer(builder).await?;
-        let (autopeering_rx, builder) = initialize_autopeering(builder).await?;
+        let (autopeering, builder) = initialize_autopeering(builder).await?;
+        let (autopeering_rx, autopeering_local) = match autopeering {
+            Some((rx, local)) => (Some(rx), Some(local)),
+            None => (None, None),
+        };
         let builder = initialize_ledger(builder);
         let builder = initialize_protocol(builder, gossip_rx, autopeering_rx);
-        let builder = initialize_api(builder);
+        let builder = initialize_api(builder, autopeering_local);
         let builder = initialize_tangle(builder);
 
         // Start the version checker.
@@ -159,30 +115,10 @@ impl<S: NodeStorageBackend> NodeBuilder<FullNode<S>> for FullNodeBuilder<S> {
         #[cfg(feature = "dashboard")]
         let builder = initialize_dashboard(builder);
 
-        let FullNodeBuilder {
-            config,
-            deps,
-            mut worker_starts,
-            worker_stops,
-            worker_names,
-            resource_registers,
-            ..
-        } = builder;
-
-        let worker_order = TopologicalOrder::sort(deps);
+        let FullNodeBuilder { config, core } = builder;
 
-        let core = Core::new(worker_stops, worker_order, worker_names);
-
-        let mut full_node = FullNode { config, core };
-
-        for f in resource_registers {
-            f(&mut full_node);
-        }
-
-        // Start all workers in topological order.
-        for id in &full_node.core.worker_order.clone() {
-            worker_starts.remove(id).unwrap()(&mut full_node).await;
-        }
+        // Add all resources and start all workers in topological order.
+        let full_node = core.finish(|core| FullNode { config, core }).await;
 
         log::info!("Initialized.");
 
@@ -248,11 +184,17 @@ async fn initialize_gossip_layer<S: NodeStorageBackend>(
     let keypair = config.local().keypair().clone();
     let network_id = config.network_spec().id();
     let gossip_cfg = config.network.clone();
+    let ban_store_cfg = FileBanStoreConfig::new(gossip_cfg.ban_storage_path());
 
-    let (builder, network_events) =
-        bee_gossip::integrated::init::<FullNode<S>>(gossip_cfg, keypair, network_id, builder)
-            .await
-            .map_err(FullNodeError::GossipLayerInitialization)?;
+    let (builder, network_events) = bee_gossip::integrated::init::<FullNode<S>, FileBanStore>(
+        gossip_cfg,
+        keypair,
+        network_id,
+        ban_store_cfg,
+        builder,
+    )
+    .await
+    .map_err(FullNodeError::GossipLayerInitialization)?;
 
     Ok((network_events, builder))
 }
@@ -264,10 +206,12 @@ fn initialize_ledger<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> Full
     let config = builder.config();
 
     let network_id = config.network_spec().id();
+    let token_supply = config.base_token().token_supply();
+    let ledger_cfg = config.ledger.clone();
     let snapshot_cfg = config.snapshot.clone();
     let pruning_cfg = config.pruning.clone();
 
-    bee_ledger::workers::init::<FullNode<S>>(builder, network_id, snapshot_cfg, pruning_cfg)
+    bee_ledger::workers::init::<FullNode<S>>(builder, network_id, token_supply, ledger_cfg, snapshot_cfg, pruning_cfg)
 }
 
 /// Initializes the protocol.
@@ -298,9 +242,16 @@ fn initialize_protocol<S: NodeStorageBackend>(
 }
 
 /// Initializes the (optional) autopeering service.
+#[allow(clippy::type_complexity)]
 async fn initialize_autopeering<S: NodeStorageBackend>(
     builder: FullNodeBuilder<S>,
-) -> Result<(Option<bee_autopeering::event::EventRx>, FullNodeBuilder<S>), FullNodeError> {
+) -> Result<
+    (
+        Option<(bee_autopeering::event::EventRx, bee_autopeering::Local)>,
+        FullNodeBuilder<S>,
+    ),
+    FullNodeError,
+> {
     let config = builder.config();
 
     if !config.autopeering.enabled() {
@@ -330,7 +281,7 @@ async fn initialize_autopeering<S: NodeStorageBackend>(
             autopeering_cfg,
             AUTOPEERING_VERSION,
             network_name,
-            local,
+            local.clone(),
             peerstore_cfg,
             quit_signal,
             neighbor_validator,
@@ -338,7 +289,7 @@ async fn initialize_autopeering<S: NodeStorageBackend>(
         .await
         .map_err(|e| FullNodeError::AutopeeringInitialization(e))?;
 
-        Ok((Some(autopeering_rx), builder))
+        Ok((Some((autopeering_rx, local)), builder))
     }
 }
 
@@ -373,7 +324,10 @@ fn create_local_autopeering_entity<S: NodeStorageBackend>(
 }
 
 /// Initializes the API.
-fn initialize_api<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> FullNodeBuilder<S> {
+fn initialize_api<S: NodeStorageBackend>(
+    builder: FullNodeBuilder<S>,
+    autopeering_local: Option<bee_autopeering::Local>,
+) -> FullNodeBuilder<S> {
     log::info!("Initializing REST API...");
 
     let config = builder.config();
@@ -391,8 +345,12 @@ fn initialize_api<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> FullNod
         protocol_config: config.protocol.clone(),
         network_name,
         bech32_hrp,
+        base_token: config.base_token().clone(),
+        autopeering_local,
         #[cfg(feature = "dashboard")]
         dashboard_username: config.dashboard.auth().user().to_owned(),
+        #[cfg(not(feature = "dashboard"))]
+        dashboard_username: String::new(),
     };
 
     bee_rest_api::endpoints::init_full_node::<FullNode<S>>(init_config, builder)
@@ -420,6 +378,7 @@ fn initialize_dashboard<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> F
     let node_keypair = config.local().keypair().clone();
     let node_alias = config.alias().clone();
     let bech32_hrp = config.network_spec().hrp().to_string();
+    let base_token = config.base_token().clone();
 
     bee_plugin_dashboard::init::<FullNode<S>>(
         dashboard_cfg,
@@ -428,6 +387,7 @@ fn initialize_dashboard<S: NodeStorageBackend>(builder: FullNodeBuilder<S>) -> F
         node_keypair,
         node_alias,
         bech32_hrp,
+        base_token,
         builder,
     )
 }
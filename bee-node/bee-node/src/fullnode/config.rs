@@ -3,7 +3,9 @@
 
 use bee_autopeering::config::AutopeeringConfig;
 use bee_gossip::NetworkConfig;
-use bee_ledger::workers::{pruning::config::PruningConfig, snapshot::config::SnapshotConfig};
+use bee_ledger::workers::{
+    base_token::BaseTokenConfig, config::LedgerConfig, pruning::config::PruningConfig, snapshot::config::SnapshotConfig,
+};
 #[cfg(feature = "dashboard")]
 use bee_plugin_dashboard::config::DashboardConfig;
 use bee_protocol::workers::config::ProtocolConfig;
@@ -21,6 +23,8 @@ pub struct FullNodeConfig<S: NodeStorageBackend> {
     pub local: Local,
     /// The specification of the network the node wants to participate in.
     pub network_spec: NetworkSpec,
+    /// The base token of the network.
+    pub base_token: BaseTokenConfig,
     /// Logger.
     pub logger: LoggerConfig,
     /// Network layer.
@@ -31,6 +35,8 @@ pub struct FullNodeConfig<S: NodeStorageBackend> {
     pub protocol: ProtocolConfig,
     /// Node REST API.
     pub rest_api: RestApiConfig,
+    /// Ledger.
+    pub ledger: LedgerConfig,
     /// Snapshots.
     pub snapshot: SnapshotConfig,
     /// Pruning.
@@ -60,16 +66,23 @@ impl<S: NodeStorageBackend> FullNodeConfig<S> {
         &self.network_spec
     }
 
+    /// Returns the base token configuration.
+    pub fn base_token(&self) -> &BaseTokenConfig {
+        &self.base_token
+    }
+
     pub fn from(local: Local, node_cfg: NodeConfig<S>) -> Self {
         Self {
             alias: node_cfg.alias,
             local,
             network_spec: node_cfg.network_spec,
+            base_token: node_cfg.base_token,
             logger: node_cfg.logger,
             network: node_cfg.network,
             autopeering: node_cfg.autopeering,
             protocol: node_cfg.protocol,
             rest_api: node_cfg.rest_api,
+            ledger: node_cfg.ledger,
             snapshot: node_cfg.snapshot,
             pruning: node_cfg.pruning,
             storage: node_cfg.storage,
@@ -86,11 +99,13 @@ impl<S: NodeStorageBackend> Clone for FullNodeConfig<S> {
             alias: self.alias.clone(),
             local: self.local.clone(),
             network_spec: self.network_spec.clone(),
+            base_token: self.base_token.clone(),
             logger: self.logger.clone(),
             network: self.network.clone(),
             autopeering: self.autopeering.clone(),
             protocol: self.protocol.clone(),
             rest_api: self.rest_api.clone(),
+            ledger: self.ledger.clone(),
             snapshot: self.snapshot.clone(),
             pruning: self.pruning.clone(),
             storage: self.storage.clone(),
@@ -4,18 +4,21 @@
 pub mod builder;
 pub mod config;
 
-use std::any::{type_name, Any, TypeId};
+use std::any::Any;
 
 use async_trait::async_trait;
-use bee_runtime::{event::Bus, node::Node, resource::ResourceHandle, worker::Worker};
-use futures::{channel::oneshot, future::Future};
+use bee_runtime::{
+    core::{Core, CoreNode},
+    node::Node,
+    resource::ResourceHandle,
+    worker::Worker,
+};
+use bee_storage::system::StorageHealth;
+use bee_tangle::Tangle;
+use futures::future::{self, Either, Future};
 
 use self::{builder::FullNodeBuilder, config::FullNodeConfig};
-use crate::{
-    core::{Core, CoreError},
-    shutdown::ShutdownRx,
-    storage::NodeStorageBackend,
-};
+use crate::{core::CoreError, shutdown::ShutdownRx, storage::NodeStorageBackend};
 
 /// Full node related errors.
 #[derive(Debug, thiserror::Error)]
@@ -30,6 +33,8 @@ pub enum FullNodeError {
     AutopeeringInitialization(Box<dyn std::error::Error>),
     #[error("{0}")]
     Core(#[from] CoreError),
+    #[error("the node was stopped because of a storage failure")]
+    StorageFailure,
 }
 
 /// Represents a Bee full node.
@@ -48,10 +53,23 @@ impl<S: NodeStorageBackend> FullNode<S> {
     pub async fn run(mut self) -> Result<(), FullNodeError> {
         log::info!("Full node running.");
 
+        let tangle = self.resource::<Tangle<S>>();
         // Panic: unwrapping is fine because the builder added this resource.
-        if let Err(e) = self.remove_resource::<ShutdownRx>().unwrap().await {
-            log::warn!("awaiting shutdown failed: {:?}", e);
-        }
+        let shutdown = self.remove_resource::<ShutdownRx>().unwrap();
+
+        // The node is stopped either on request or as soon as the tangle can not access the storage anymore.
+        let storage_failed = match future::select(shutdown, Box::pin(tangle.storage_failure())).await {
+            Either::Left((res, _)) => {
+                if let Err(e) = res {
+                    log::warn!("awaiting shutdown failed: {:?}", e);
+                }
+                false
+            }
+            Either::Right(_) => true,
+        };
+
+        // The tangle worker waits for all the handles of the tangle to be dropped before stopping.
+        drop(tangle);
 
         log::info!("Stopping full node...");
 
@@ -59,10 +77,24 @@ impl<S: NodeStorageBackend> FullNode<S> {
 
         log::info!("Full node stopped.");
 
+        if storage_failed {
+            return Err(FullNodeError::StorageFailure);
+        }
+
         Ok(())
     }
 }
 
+impl<S: NodeStorageBackend> CoreNode for FullNode<S> {
+    fn core(&self) -> &Core<Self> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<Self> {
+        &mut self.core
+    }
+}
+
 #[async_trait]
 impl<S: NodeStorageBackend> Node for FullNode<S> {
     type Builder = FullNodeBuilder<S>;
@@ -70,19 +102,16 @@ impl<S: NodeStorageBackend> Node for FullNode<S> {
     type Error = FullNodeError;
 
     fn register_resource<R: Any + Send + Sync>(&mut self, res: R) {
-        self.core.resources.insert(ResourceHandle::new(res));
+        self.core.register_resource(res);
     }
 
     fn remove_resource<R: Any + Send + Sync>(&mut self) -> Option<R> {
-        self.core.resources.remove::<ResourceHandle<R>>()?.try_unwrap()
+        self.core.remove_resource()
     }
 
     #[track_caller]
     fn resource<R: Any + Send + Sync>(&self) -> ResourceHandle<R> {
-        match self.core.resources.get::<ResourceHandle<R>>() {
-            Some(res) => res.clone(),
-            None => panic!("Unable to fetch node resource {}.", type_name::<R>(),),
-        }
+        self.core.resource()
     }
 
     #[track_caller]
@@ -92,47 +121,30 @@ impl<S: NodeStorageBackend> Node for FullNode<S> {
         G: FnOnce(ShutdownRx) -> F,
         F: Future<Output = ()> + Send + 'static,
     {
-        let (tx, rx) = oneshot::channel();
-        let future = g(rx);
-
-        let task = tokio::spawn(future);
-
-        self.core
-            .tasks
-            .entry(TypeId::of::<W>())
-            .or_default()
-            .push((tx, Box::new(task)));
+        self.core.spawn::<W, _, _>(g);
     }
 
     fn worker<W>(&self) -> Option<&W>
     where
         W: Worker<Self> + Send + Sync,
     {
-        self.core.workers.get::<W>()
+        self.core.worker()
     }
 
     /// Stops the full node.
     async fn stop(mut self) -> Result<(), Self::Error> {
-        for worker_id in self.core.worker_order.clone().into_iter().rev() {
-            // Panic: unwrapping is fine since worker_id is from the list of workers.
-            log::debug!("Stopping worker {}...", self.core.worker_names.get(&worker_id).unwrap());
-
-            for (shutdown, task_fut) in self.core.tasks.remove(&worker_id).unwrap_or_default() {
-                let _ = shutdown.send(());
-                // TODO: Should we handle this error?
-                let _ = task_fut.await;
-            }
+        Core::stop_workers(&mut self).await;
+
+        // Panic: unwrapping is fine since the node register the backend itself.
+        let storage = self.remove_resource::<Self::Backend>().unwrap();
 
-            // Panic: unwrapping cannot fail by design.
-            self.core.worker_stops.remove(&worker_id).unwrap()(&mut self).await;
-            self.resource::<Bus>().remove_listeners_by_id(worker_id);
+        // Shutting down the storage would mark it as healthy again, so a corrupted storage is only closed.
+        if let Ok(Some(StorageHealth::Corrupted)) = storage.get_health() {
+            log::error!("The storage is corrupted and needs to be repaired before restarting the node.");
+            return Ok(());
         }
 
-        // Panic: unwrapping is fine since the node register the backend itself.
-        self.remove_resource::<Self::Backend>()
-            .unwrap()
-            .shutdown()
-            .map_err(|e| CoreError::StorageBackend(Box::new(e)))?;
+        storage.shutdown().map_err(|e| CoreError::StorageBackend(Box::new(e)))?;
 
         Ok(())
     }
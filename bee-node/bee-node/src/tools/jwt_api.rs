@@ -1,8 +1,13 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use auth_helper::jwt::{ClaimsBuilder, JsonWebToken};
-use bee_rest_api::endpoints::auth::API_AUDIENCE_CLAIM;
+use bee_rest_api::endpoints::{
+    auth::API_AUDIENCE_CLAIM,
+    tokens::{self, Scope, TokenError, TokenStore},
+};
 use structopt::StructOpt;
 use thiserror::Error;
 
@@ -12,23 +17,86 @@ use crate::{Local, NodeConfig, NodeStorageBackend};
 pub enum JwtApiError {
     #[error("{0}")]
     GeneratorError(#[from] auth_helper::jwt::Error),
+    #[error("{0}")]
+    Token(#[from] TokenError),
+    #[error("cannot set an expiry on a token without scopes")]
+    ExpiryWithoutScopes,
 }
 
 #[derive(Clone, Debug, StructOpt)]
-pub struct JwtApiTool {}
+pub struct JwtApiTool {
+    /// Comma separated scopes of the token: read, submit, peers, debug, control.
+    /// Without scopes, a legacy token with the scopes of `legacy_token_scopes` is generated.
+    #[structopt(long)]
+    scopes: Option<String>,
+    /// Number of seconds after which the token expires.
+    #[structopt(long)]
+    expires_in: Option<u64>,
+    /// Lists the issued scoped tokens.
+    #[structopt(long, conflicts_with_all = &["scopes", "expires-in", "revoke"])]
+    list: bool,
+    /// Revokes the scoped token with the given identifier.
+    #[structopt(long, conflicts_with_all = &["scopes", "expires-in"])]
+    revoke: Option<String>,
+}
 
 pub fn exec<B: NodeStorageBackend>(
-    _tool: &JwtApiTool,
+    tool: &JwtApiTool,
     local: &Local,
     node_config: &NodeConfig<B>,
 ) -> Result<(), JwtApiError> {
-    let claims = ClaimsBuilder::new(
-        local.peer_id().to_string(),
-        node_config.rest_api.jwt_salt().to_owned(),
-        API_AUDIENCE_CLAIM.to_owned(),
-    )
-    .build()?;
-    let jwt = JsonWebToken::new(claims, local.keypair().secret().as_ref())?;
+    let store = || TokenStore::open(node_config.rest_api.token_store_path());
+
+    if tool.list {
+        for token in store()?.tokens()? {
+            let scopes = token.scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(",");
+            let expires_at = token.expires_at.map_or_else(|| "never".to_owned(), |e| e.to_string());
+            let state = if token.revoked { "revoked" } else { "active" };
+
+            println!(
+                "{} scopes={} issued_at={} expires_at={} {}",
+                token.id, scopes, token.issued_at, expires_at, state
+            );
+        }
+
+        return Ok(());
+    }
+
+    if let Some(id) = &tool.revoke {
+        store()?.revoke(id)?;
+        println!("Revoked token {id}. A running node picks up the revocation within a second.");
+
+        return Ok(());
+    }
+
+    let jwt = match &tool.scopes {
+        Some(scopes) => {
+            let (record, jwt) = tokens::issue_token(
+                &local.peer_id().to_string(),
+                node_config.rest_api.jwt_salt(),
+                local.keypair().secret().as_ref(),
+                &Scope::parse_list(scopes)?,
+                tool.expires_in.map(Duration::from_secs),
+            )?;
+            store()?.insert(record)?;
+
+            jwt
+        }
+        None => {
+            if tool.expires_in.is_some() {
+                return Err(JwtApiError::ExpiryWithoutScopes);
+            }
+
+            let claims = ClaimsBuilder::new(
+                local.peer_id().to_string(),
+                node_config.rest_api.jwt_salt().to_owned(),
+                API_AUDIENCE_CLAIM.to_owned(),
+            )
+            .build()?;
+
+            JsonWebToken::new(claims, local.keypair().secret().as_ref())?.to_string()
+        }
+    };
 
     println!("{jwt}");
 
@@ -9,6 +9,7 @@ mod rocksdb;
 #[cfg(feature = "sled")]
 mod sled;
 mod snapshot_info;
+mod utxo_diffs;
 
 use structopt::StructOpt;
 use thiserror::Error;
@@ -32,6 +33,8 @@ pub enum Tool {
     Password(password::PasswordTool),
     /// Generates a JWT for the Node API.
     JwtApi(jwt_api::JwtApiTool),
+    /// Exports the ledger changes of a range of milestones.
+    UtxoDiffs(utxo_diffs::UtxoDiffsTool),
 }
 
 #[derive(Debug, Error)]
@@ -50,6 +53,8 @@ pub enum ToolError {
     Password(#[from] password::PasswordError),
     #[error("{0}")]
     JwtApi(#[from] jwt_api::JwtApiError),
+    #[error("{0}")]
+    UtxoDiffs(#[from] utxo_diffs::UtxoDiffsError),
 }
 
 pub fn exec<B: NodeStorageBackend>(tool: &Tool, local: &Local, node_config: &NodeConfig<B>) -> Result<(), ToolError> {
@@ -62,6 +67,7 @@ pub fn exec<B: NodeStorageBackend>(tool: &Tool, local: &Local, node_config: &Nod
         Tool::SnapshotInfo(tool) => snapshot_info::exec(tool)?,
         Tool::Password(tool) => password::exec(tool)?,
         Tool::JwtApi(tool) => jwt_api::exec(tool, local, node_config)?,
+        Tool::UtxoDiffs(tool) => utxo_diffs::exec(tool, node_config)?,
     }
 
     Ok(())
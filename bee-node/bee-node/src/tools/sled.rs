@@ -4,13 +4,16 @@
 use std::str::FromStr;
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{AsIterator, Exist, Fetch},
@@ -22,7 +25,10 @@ use bee_storage_sled::{
     trees::*,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use structopt::StructOpt;
@@ -257,6 +263,38 @@ fn exec_inner(tool: &SledTool, storage: &Storage) -> Result<(), SledError> {
                 }
             }
         },
+        TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS => match &tool.command {
+            SledCommand::Fetch { key } => {
+                let key = MilestoneIndex(u32::from_str(key).map_err(|_| SledError::InvalidKey(key.clone()))?);
+                let value = Fetch::<MilestoneIndex, ProtocolParameters>::fetch(storage, &key)?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<MilestoneIndex, ProtocolParameters>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC => match &tool.command {
+            SledCommand::Fetch { key } => {
+                let key = BlockId::from_str(key).map_err(|_| SledError::InvalidKey(key.clone()))?;
+                let value = Fetch::<BlockId, ConflictDiagnostic>::fetch(storage, &key)?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<BlockId, ConflictDiagnostic>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
         TREE_MILESTONE_INDEX_TO_UNREFERENCED_BLOCK => match &tool.command {
             SledCommand::Fetch { key } => {
                 let key = MilestoneIndex(u32::from_str(key).map_err(|_| SledError::InvalidKey(key.clone()))?);
@@ -305,6 +343,70 @@ fn exec_inner(tool: &SledTool, storage: &Storage) -> Result<(), SledError> {
                 }
             }
         },
+        TREE_PADDED_TAG_TO_TAGGED_BLOCK => match &tool.command {
+            SledCommand::Fetch { key } => {
+                let key = hex::decode(key.trim_start_matches("0x"))
+                    .ok()
+                    .and_then(|tag| PaddedTag::new(&tag))
+                    .ok_or_else(|| SledError::InvalidKey(key.clone()))?;
+                let value = Fetch::<PaddedTag, Vec<TaggedBlock>>::fetch(storage, &key)?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<(PaddedTag, TaggedBlock), ()>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        TREE_ADDRESS_TO_HISTORY_ENTRY => match &tool.command {
+            SledCommand::Fetch { key } => {
+                let key = Address::try_from_bech32(key)
+                    .map(|(_, address)| address)
+                    .map_err(|_| SledError::InvalidKey(key.clone()))?;
+                let value = Fetch::<Address, Vec<AddressHistoryEntry>>::fetch(storage, &key)?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<(Address, AddressHistoryEntry), ()>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS => match &tool.command {
+            SledCommand::Fetch { key } => {
+                let key = MilestoneIndex(u32::from_str(key).map_err(|_| SledError::InvalidKey(key.clone()))?);
+                let value = Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, &key)?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<(MilestoneIndex, Address), ()>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        TREE_ADDRESS_HISTORY_INDEX => match &tool.command {
+            SledCommand::Fetch { key: _key } => return Err(SledError::UnsupportedCommand),
+            SledCommand::Iterator => {
+                let iterator = AsIterator::<(), AddressHistoryIndex>::iter(storage)?;
+
+                for result in iterator {
+                    let (key, value) = result?;
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
 
         _ => return Err(SledError::UnknownTree(tool.tree[..].to_owned())),
     }
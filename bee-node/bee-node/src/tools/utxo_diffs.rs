@@ -0,0 +1,103 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    str::FromStr,
+};
+
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::workers::{
+    error::Error as LedgerError,
+    storage::{check_milestone_utxo_diff_range, fetch_milestone_utxo_diff},
+};
+use bee_rest_api::types::dtos::MilestoneUtxoDiffDto;
+use packable::PackableExt;
+use structopt::StructOpt;
+use thiserror::Error;
+
+use crate::{NodeConfig, NodeStorageBackend};
+
+#[derive(Debug, Error)]
+pub enum UtxoDiffsError {
+    #[error("{0}")]
+    Io(#[from] io::Error),
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Ledger(#[from] LedgerError),
+    #[error("storage backend error: {0}")]
+    Storage(Box<dyn std::error::Error + Send>),
+    #[error("invalid format: {0}, expected json or binary")]
+    InvalidFormat(String),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum UtxoDiffsFormat {
+    /// Newline-delimited JSON, one milestone per line.
+    Json,
+    /// Concatenated `Packable` milestone diffs.
+    Binary,
+}
+
+impl FromStr for UtxoDiffsFormat {
+    type Err = UtxoDiffsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "binary" => Ok(Self::Binary),
+            _ => Err(UtxoDiffsError::InvalidFormat(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct UtxoDiffsTool {
+    /// Index of the first milestone of the range, above the pruning index.
+    start: u32,
+    /// Index of the last milestone of the range, at most the ledger index.
+    end: u32,
+    /// Format of the exported diffs: json or binary.
+    #[structopt(long, default_value = "json")]
+    format: UtxoDiffsFormat,
+    /// File to export the diffs to, instead of the standard output.
+    #[structopt(long)]
+    output: Option<String>,
+}
+
+fn exec_inner<B: NodeStorageBackend>(tool: &UtxoDiffsTool, storage: &B) -> Result<(), UtxoDiffsError> {
+    check_milestone_utxo_diff_range(storage, MilestoneIndex(tool.start), MilestoneIndex(tool.end))?;
+
+    let mut writer: BufWriter<Box<dyn Write>> = BufWriter::new(match &tool.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    });
+
+    for index in tool.start..=tool.end {
+        let index = MilestoneIndex(index);
+        let diff = fetch_milestone_utxo_diff(storage, index)?.ok_or(LedgerError::MissingOutputDiff(index))?;
+
+        match tool.format {
+            UtxoDiffsFormat::Json => {
+                serde_json::to_writer(&mut writer, &MilestoneUtxoDiffDto::from(&diff))?;
+                writer.write_all(b"\n")?;
+            }
+            UtxoDiffsFormat::Binary => writer.write_all(&diff.pack_to_vec())?,
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+pub fn exec<B: NodeStorageBackend>(tool: &UtxoDiffsTool, node_config: &NodeConfig<B>) -> Result<(), UtxoDiffsError> {
+    let storage = B::start(node_config.storage.clone()).map_err(|e| UtxoDiffsError::Storage(Box::new(e)))?;
+    let res = exec_inner(tool, &storage);
+
+    storage.shutdown().map_err(|e| UtxoDiffsError::Storage(Box::new(e)))?;
+
+    res
+}
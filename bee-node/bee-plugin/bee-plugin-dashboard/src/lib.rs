@@ -23,9 +23,12 @@ use std::{
 
 use async_trait::async_trait;
 use bee_gossip::{Keypair, PeerId};
-use bee_ledger::workers::event::MilestoneConfirmed;
+use bee_ledger::workers::{
+    base_token::BaseTokenConfig,
+    event::{DatabasePruned, MilestoneConfirmed},
+};
 use bee_protocol::workers::{
-    event::{BlockSolidified, MpsMetricsUpdated, TipAdded, TipRemoved, VertexCreated},
+    event::{BlockSolidified, MpsMetricsUpdated, ThroughputMetricsUpdated, TipAdded, TipRemoved, VertexCreated},
     MetricsWorker, PeerManagerResWorker,
 };
 use bee_rest_api::{
@@ -53,8 +56,7 @@ use crate::{
         WsUsers,
     },
     workers::{
-        confirmed_ms_metrics::confirmed_ms_metrics_worker, db_size_metrics::db_size_metrics_worker,
-        node_status::node_status_worker, peer_metric::peer_metric_worker,
+        db_size_metrics::db_size_metrics_worker, node_status::node_status_worker, peer_metric::peer_metric_worker,
     },
 };
 
@@ -71,6 +73,7 @@ pub fn init<N: Node>(
     node_keypair: Keypair,
     node_alias: NodeAlias,
     bech32_hrp: Bech32Hrp,
+    base_token: BaseTokenConfig,
     node_builder: N::Builder,
 ) -> N::Builder
 where
@@ -83,6 +86,7 @@ where
         node_keypair,
         node_alias,
         bech32_hrp,
+        base_token,
     ))
 }
 
@@ -133,7 +137,15 @@ impl<N: Node> Worker<N> for DashboardPlugin
 where
     N::Backend: StorageBackend,
 {
-    type Config = (DashboardConfig, RestApiConfig, PeerId, Keypair, NodeAlias, Bech32Hrp);
+    type Config = (
+        DashboardConfig,
+        RestApiConfig,
+        PeerId,
+        Keypair,
+        NodeAlias,
+        Bech32Hrp,
+        BaseTokenConfig,
+    );
     type Error = Infallible;
 
     fn dependencies() -> &'static [TypeId] {
@@ -147,7 +159,7 @@ where
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         // TODO: load them differently if possible
-        let (config, rest_api_config, node_id, node_keypair, node_alias, bech32_hrp) = config;
+        let (config, rest_api_config, node_id, node_keypair, node_alias, bech32_hrp, base_token) = config;
         let tangle = node.resource::<Tangle<N::Backend>>();
         let storage = node.storage();
 
@@ -197,11 +209,32 @@ where
         );
         topic_handler(node, "TipInfo", &users, true, <WsEvent as From<TipAdded>>::from);
         topic_handler(node, "TipInfo", &users, true, <WsEvent as From<TipRemoved>>::from);
+        topic_handler(
+            node,
+            "ConfirmedMilestoneMetrics",
+            &users,
+            false,
+            <WsEvent as From<ThroughputMetricsUpdated>>::from,
+        );
+        topic_handler(
+            node,
+            "DatabaseCleanupEvent",
+            &users,
+            false,
+            <WsEvent as From<DatabasePruned>>::from,
+        );
 
         // run sub-workers
-        confirmed_ms_metrics_worker(node, &users);
         db_size_metrics_worker(node, &users);
-        node_status_worker(node, node_id, node_alias, bech32_hrp, &users);
+        node_status_worker(
+            node,
+            node_id,
+            node_alias,
+            bech32_hrp,
+            base_token,
+            rest_api_config.health().clone(),
+            &users,
+        );
         peer_metric_worker(node, &users);
 
         node.spawn::<Self, _, _>(|shutdown| async move {
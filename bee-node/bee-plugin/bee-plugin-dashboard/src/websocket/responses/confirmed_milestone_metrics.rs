@@ -1,27 +1,25 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use bee_protocol::workers::event::ThroughputMetricsUpdated;
 use serde::Serialize;
 
-use crate::{
-    websocket::{
-        responses::{WsEvent, WsEventInner},
-        topics::WsTopic,
-    },
-    workers::confirmed_ms_metrics::ConfirmedMilestoneMetrics,
+use crate::websocket::{
+    responses::{WsEvent, WsEventInner},
+    topics::WsTopic,
 };
 
 #[derive(Clone, Debug, Serialize)]
 pub(crate) struct ConfirmedMilestoneMetricsResponse {
     ms_index: u32,
-    mps: u64,
-    rmps: u64,
+    mps: f64,
+    rmps: f64,
     referenced_rate: f64,
     time_since_last_ms: u64,
 }
 
-impl From<ConfirmedMilestoneMetrics> for WsEvent {
-    fn from(val: ConfirmedMilestoneMetrics) -> Self {
+impl From<ThroughputMetricsUpdated> for WsEvent {
+    fn from(val: ThroughputMetricsUpdated) -> Self {
         Self::new(
             WsTopic::ConfirmedMilestoneMetrics,
             WsEventInner::ConfirmedMilestoneMetrics(val.into()),
@@ -29,14 +27,14 @@ impl From<ConfirmedMilestoneMetrics> for WsEvent {
     }
 }
 
-impl From<ConfirmedMilestoneMetrics> for ConfirmedMilestoneMetricsResponse {
-    fn from(val: ConfirmedMilestoneMetrics) -> Self {
+impl From<ThroughputMetricsUpdated> for ConfirmedMilestoneMetricsResponse {
+    fn from(ThroughputMetricsUpdated(val): ThroughputMetricsUpdated) -> Self {
         Self {
-            ms_index: val.ms_index,
-            mps: val.mps,
-            rmps: val.rmps,
+            ms_index: *val.milestone_index,
+            mps: val.blocks_per_second,
+            rmps: val.referenced_blocks_per_second,
             referenced_rate: val.referenced_rate,
-            time_since_last_ms: val.time_since_last_ms,
+            time_since_last_ms: val.time_since_last_milestone as u64,
         }
     }
 }
@@ -0,0 +1,42 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::workers::{event::DatabasePruned, pruning::PruningReason};
+use serde::Serialize;
+
+use crate::websocket::{
+    responses::{WsEvent, WsEventInner},
+    topics::WsTopic,
+};
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct DatabaseCleanupResponse {
+    start: u64,
+    end: u64,
+    start_index: u32,
+    end_index: u32,
+    reason: &'static str,
+}
+
+impl From<DatabasePruned> for WsEvent {
+    fn from(val: DatabasePruned) -> Self {
+        Self::new(WsTopic::DatabaseCleanupEvent, WsEventInner::DatabaseCleanup(val.into()))
+    }
+}
+
+impl From<DatabasePruned> for DatabaseCleanupResponse {
+    fn from(val: DatabasePruned) -> Self {
+        Self {
+            start: val.start_timestamp,
+            end: val.end_timestamp,
+            start_index: *val.start_index,
+            end_index: *val.target_index,
+            reason: match val.reason {
+                PruningReason::Milestones => "milestones",
+                PruningReason::Size => "size",
+                PruningReason::Age => "age",
+                PruningReason::Manual => "manual",
+            },
+        }
+    }
+}
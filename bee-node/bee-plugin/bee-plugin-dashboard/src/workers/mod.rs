@@ -1,7 +1,6 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-pub(crate) mod confirmed_ms_metrics;
 pub(crate) mod db_size_metrics;
 pub(crate) mod node_status;
 pub(crate) mod peer_metric;
@@ -7,8 +7,9 @@ use std::{
 };
 
 use bee_gossip::PeerId;
+use bee_ledger::workers::base_token::BaseTokenConfig;
 use bee_protocol::{types::metrics::NodeMetrics, workers::PeerManager};
-use bee_rest_api::endpoints::routes::health::is_healthy;
+use bee_rest_api::endpoints::{config::HealthConfig, routes::health::is_healthy};
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream};
 use bee_tangle::Tangle;
 use cap::Cap;
@@ -30,6 +31,8 @@ pub(crate) fn node_status_worker<N>(
     node_id: PeerId,
     node_alias: NodeAlias,
     bech32_hrp: Bech32Hrp,
+    base_token: BaseTokenConfig,
+    health_config: HealthConfig,
     users: &WsUsers,
 ) where
     N: Node,
@@ -40,6 +43,15 @@ pub(crate) fn node_status_worker<N>(
     let metrics = node.resource::<NodeMetrics>();
     let node_info = node.info();
     let users = users.clone();
+    let base_token = BaseToken {
+        name: base_token.name().to_string(),
+        ticker_symbol: base_token.ticker_symbol().to_string(),
+        unit: base_token.unit().to_string(),
+        subunit: base_token.subunit().map(ToString::to_string),
+        decimals: base_token.decimals(),
+        use_metric_prefix: base_token.use_metric_prefix(),
+        token_supply: base_token.token_supply().to_string(),
+    };
 
     node.spawn::<DashboardPlugin, _, _>(|shutdown| async move {
         debug!("Ws PublicNodeStatus/NodeStatus topics handler running.");
@@ -54,7 +66,7 @@ pub(crate) fn node_status_worker<N>(
             let public_node_status = PublicNodeStatus {
                 snapshot_index: *tangle.get_snapshot_index(),
                 pruning_index: *tangle.get_pruning_index(),
-                is_healthy: is_healthy(&tangle, &peer_manager),
+                is_healthy: is_healthy(&tangle, &peer_manager, &health_config),
                 is_synced: tangle.is_synced(),
             };
 
@@ -65,6 +77,7 @@ pub(crate) fn node_status_worker<N>(
                 node_id: node_id.to_string(),
                 node_alias: node_alias.clone(),
                 bech32_hrp: bech32_hrp.clone(),
+                base_token: base_token.clone(),
                 connected_peers_count: 0,
                 current_requested_ms: 0,
                 request_queue_queued: 0,
@@ -134,6 +147,7 @@ pub struct NodeStatus {
     pub node_id: String,
     pub node_alias: String,
     pub bech32_hrp: String,
+    pub base_token: BaseToken,
     pub connected_peers_count: usize,
     pub current_requested_ms: usize,
     pub request_queue_queued: usize,
@@ -145,6 +159,17 @@ pub struct NodeStatus {
     pub caches: Caches,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct BaseToken {
+    pub name: String,
+    pub ticker_symbol: String,
+    pub unit: String,
+    pub subunit: Option<String>,
+    pub decimals: u8,
+    pub use_metric_prefix: bool,
+    pub token_supply: String,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ServerMetrics {
     pub all_blocks: usize,
@@ -5,5 +5,6 @@
 
 pub mod node;
 pub mod peer;
+pub mod throughput;
 
-pub use self::{node::NodeMetrics, peer::PeerMetrics};
+pub use self::{node::NodeMetrics, peer::PeerMetrics, throughput::ThroughputMetrics};
@@ -3,7 +3,15 @@
 
 //! Module that provides a type to hold metrics related to nodes.
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use bee_block::semantic::ConflictReason;
 
 /// Holds metrics related to a node.
 #[derive(Default, Debug)]
@@ -14,11 +22,15 @@ pub struct NodeMetrics {
     blocks_received: AtomicU64,
     block_requests_received: AtomicU64,
     heartbeats_received: AtomicU64,
+    milestone_cone_requests_received: AtomicU64,
+    milestone_cone_responses_received: AtomicU64,
 
     milestone_requests_sent: AtomicU64,
     blocks_sent: AtomicU64,
     block_requests_sent: AtomicU64,
     heartbeats_sent: AtomicU64,
+    milestone_cone_requests_sent: AtomicU64,
+    milestone_cone_responses_sent: AtomicU64,
 
     invalid_blocks: AtomicU64,
     new_blocks: AtomicU64,
@@ -28,6 +40,7 @@ pub struct NodeMetrics {
     referenced_blocks: AtomicU64,
     excluded_no_transaction_blocks: AtomicU64,
     excluded_conflicting_blocks: AtomicU64,
+    excluded_conflicting_blocks_by_reason: Mutex<BTreeMap<u8, u64>>,
     included_blocks: AtomicU64,
 
     created_outputs: AtomicU64,
@@ -100,6 +113,26 @@ impl NodeMetrics {
         self.heartbeats_received.fetch_add(1, Ordering::SeqCst)
     }
 
+    /// Returns the number of received milestone cone requests of the `NodeMetrics`.
+    pub fn milestone_cone_requests_received(&self) -> u64 {
+        self.milestone_cone_requests_received.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of received milestone cone requests of the `NodeMetrics`.
+    pub fn milestone_cone_requests_received_inc(&self) -> u64 {
+        self.milestone_cone_requests_received.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of received milestone cone responses of the `NodeMetrics`.
+    pub fn milestone_cone_responses_received(&self) -> u64 {
+        self.milestone_cone_responses_received.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of received milestone cone responses of the `NodeMetrics`.
+    pub fn milestone_cone_responses_received_inc(&self) -> u64 {
+        self.milestone_cone_responses_received.fetch_add(1, Ordering::SeqCst)
+    }
+
     /// Returns the number of sent milestone requests of the `NodeMetrics`.
     pub fn milestone_requests_sent(&self) -> u64 {
         self.milestone_requests_sent.load(Ordering::Relaxed)
@@ -140,6 +173,26 @@ impl NodeMetrics {
         self.heartbeats_sent.fetch_add(1, Ordering::SeqCst)
     }
 
+    /// Returns the number of sent milestone cone requests of the `NodeMetrics`.
+    pub fn milestone_cone_requests_sent(&self) -> u64 {
+        self.milestone_cone_requests_sent.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of sent milestone cone requests of the `NodeMetrics`.
+    pub fn milestone_cone_requests_sent_inc(&self) -> u64 {
+        self.milestone_cone_requests_sent.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of sent milestone cone responses of the `NodeMetrics`.
+    pub fn milestone_cone_responses_sent(&self) -> u64 {
+        self.milestone_cone_responses_sent.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of sent milestone cone responses of the `NodeMetrics`.
+    pub fn milestone_cone_responses_sent_inc(&self) -> u64 {
+        self.milestone_cone_responses_sent.fetch_add(1, Ordering::SeqCst)
+    }
+
     /// Returns the number of invalid blocks of the `NodeMetrics`.
     pub fn invalid_blocks(&self) -> u64 {
         self.invalid_blocks.load(Ordering::Relaxed)
@@ -210,6 +263,28 @@ impl NodeMetrics {
         self.excluded_conflicting_blocks.fetch_add(value, Ordering::SeqCst)
     }
 
+    /// Returns the number of excluded blocks - because conflicting - of the `NodeMetrics`, per conflict reason.
+    pub fn excluded_conflicting_blocks_by_reason(&self) -> Vec<(ConflictReason, u64)> {
+        // Panic: the lock is never held across a panic.
+        self.excluded_conflicting_blocks_by_reason
+            .lock()
+            .unwrap()
+            .iter()
+            // Keys are only ever inserted from a valid `ConflictReason`.
+            .filter_map(|(reason, value)| ConflictReason::try_from(*reason).ok().map(|reason| (reason, *value)))
+            .collect()
+    }
+
+    /// Increments the number of excluded blocks - because conflicting - of the `NodeMetrics`, for a conflict reason.
+    pub fn excluded_conflicting_blocks_by_reason_inc(&self, reason: ConflictReason, value: u64) -> u64 {
+        // Panic: the lock is never held across a panic.
+        let mut by_reason = self.excluded_conflicting_blocks_by_reason.lock().unwrap();
+        let counter = by_reason.entry(reason as u8).or_default();
+        let previous = *counter;
+        *counter += value;
+        previous
+    }
+
     /// Returns the number of included blocks of the `NodeMetrics`.
     pub fn included_blocks(&self) -> u64 {
         self.included_blocks.load(Ordering::Relaxed)
@@ -315,10 +390,14 @@ mod tests {
         assert_eq!(metrics.blocks_received(), 0);
         assert_eq!(metrics.block_requests_received(), 0);
         assert_eq!(metrics.heartbeats_received(), 0);
+        assert_eq!(metrics.milestone_cone_requests_received(), 0);
+        assert_eq!(metrics.milestone_cone_responses_received(), 0);
         assert_eq!(metrics.milestone_requests_sent(), 0);
         assert_eq!(metrics.blocks_sent(), 0);
         assert_eq!(metrics.block_requests_sent(), 0);
         assert_eq!(metrics.heartbeats_sent(), 0);
+        assert_eq!(metrics.milestone_cone_requests_sent(), 0);
+        assert_eq!(metrics.milestone_cone_responses_sent(), 0);
         assert_eq!(metrics.invalid_blocks(), 0);
         assert_eq!(metrics.new_blocks(), 0);
         assert_eq!(metrics.known_blocks(), 0);
@@ -326,6 +405,7 @@ mod tests {
         assert_eq!(metrics.referenced_blocks(), 0);
         assert_eq!(metrics.excluded_no_transaction_blocks(), 0);
         assert_eq!(metrics.excluded_conflicting_blocks(), 0);
+        assert!(metrics.excluded_conflicting_blocks_by_reason().is_empty());
         assert_eq!(metrics.included_blocks(), 0);
         assert_eq!(metrics.created_outputs(), 0);
         assert_eq!(metrics.consumed_outputs(), 0);
@@ -341,10 +421,14 @@ mod tests {
         metrics.blocks_received_inc();
         metrics.block_requests_received_inc();
         metrics.heartbeats_received_inc();
+        metrics.milestone_cone_requests_received_inc();
+        metrics.milestone_cone_responses_received_inc();
         metrics.milestone_requests_sent_inc();
         metrics.blocks_sent_inc();
         metrics.block_requests_sent_inc();
         metrics.heartbeats_sent_inc();
+        metrics.milestone_cone_requests_sent_inc();
+        metrics.milestone_cone_responses_sent_inc();
         metrics.invalid_blocks_inc();
         metrics.new_blocks_inc();
         metrics.known_blocks_inc();
@@ -352,6 +436,7 @@ mod tests {
         metrics.referenced_blocks_inc(1);
         metrics.excluded_no_transaction_blocks_inc(1);
         metrics.excluded_conflicting_blocks_inc(1);
+        metrics.excluded_conflicting_blocks_by_reason_inc(ConflictReason::InputUtxoNotFound, 1);
         metrics.included_blocks_inc(1);
         metrics.created_outputs_inc(1);
         metrics.consumed_outputs_inc(1);
@@ -367,10 +452,14 @@ mod tests {
         assert_eq!(metrics.blocks_received(), 1);
         assert_eq!(metrics.block_requests_received(), 1);
         assert_eq!(metrics.heartbeats_received(), 1);
+        assert_eq!(metrics.milestone_cone_requests_received(), 1);
+        assert_eq!(metrics.milestone_cone_responses_received(), 1);
         assert_eq!(metrics.milestone_requests_sent(), 1);
         assert_eq!(metrics.blocks_sent(), 1);
         assert_eq!(metrics.block_requests_sent(), 1);
         assert_eq!(metrics.heartbeats_sent(), 1);
+        assert_eq!(metrics.milestone_cone_requests_sent(), 1);
+        assert_eq!(metrics.milestone_cone_responses_sent(), 1);
         assert_eq!(metrics.invalid_blocks(), 1);
         assert_eq!(metrics.new_blocks(), 1);
         assert_eq!(metrics.known_blocks(), 1);
@@ -378,6 +467,10 @@ mod tests {
         assert_eq!(metrics.referenced_blocks(), 1);
         assert_eq!(metrics.excluded_no_transaction_blocks(), 1);
         assert_eq!(metrics.excluded_conflicting_blocks(), 1);
+        assert_eq!(
+            metrics.excluded_conflicting_blocks_by_reason(),
+            vec![(ConflictReason::InputUtxoNotFound, 1)]
+        );
         assert_eq!(metrics.included_blocks(), 1);
         assert_eq!(metrics.created_outputs(), 1);
         assert_eq!(metrics.consumed_outputs(), 1);
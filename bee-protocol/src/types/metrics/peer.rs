@@ -16,10 +16,14 @@ pub struct PeerMetrics {
     blocks_received: AtomicU64,
     block_requests_received: AtomicU64,
     heartbeats_received: AtomicU64,
+    milestone_cone_requests_received: AtomicU64,
+    milestone_cone_responses_received: AtomicU64,
     milestone_requests_sent: AtomicU64,
     blocks_sent: AtomicU64,
     block_requests_sent: AtomicU64,
     heartbeats_sent: AtomicU64,
+    milestone_cone_requests_sent: AtomicU64,
+    milestone_cone_responses_sent: AtomicU64,
 }
 
 impl PeerMetrics {
@@ -103,6 +107,26 @@ impl PeerMetrics {
         self.heartbeats_received.fetch_add(1, Ordering::SeqCst)
     }
 
+    /// Returns the number of received milestone cone requests of the `PeerMetrics`.
+    pub fn milestone_cone_requests_received(&self) -> u64 {
+        self.milestone_cone_requests_received.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of received milestone cone requests of the `PeerMetrics`.
+    pub fn milestone_cone_requests_received_inc(&self) -> u64 {
+        self.milestone_cone_requests_received.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of received milestone cone responses of the `PeerMetrics`.
+    pub fn milestone_cone_responses_received(&self) -> u64 {
+        self.milestone_cone_responses_received.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of received milestone cone responses of the `PeerMetrics`.
+    pub fn milestone_cone_responses_received_inc(&self) -> u64 {
+        self.milestone_cone_responses_received.fetch_add(1, Ordering::SeqCst)
+    }
+
     /// Returns the number of sent milestone requests of the `PeerMetrics`.
     pub fn milestone_requests_sent(&self) -> u64 {
         self.milestone_requests_sent.load(Ordering::Relaxed)
@@ -142,6 +166,26 @@ impl PeerMetrics {
     pub fn heartbeats_sent_inc(&self) -> u64 {
         self.heartbeats_sent.fetch_add(1, Ordering::SeqCst)
     }
+
+    /// Returns the number of sent milestone cone requests of the `PeerMetrics`.
+    pub fn milestone_cone_requests_sent(&self) -> u64 {
+        self.milestone_cone_requests_sent.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of sent milestone cone requests of the `PeerMetrics`.
+    pub fn milestone_cone_requests_sent_inc(&self) -> u64 {
+        self.milestone_cone_requests_sent.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Returns the number of sent milestone cone responses of the `PeerMetrics`.
+    pub fn milestone_cone_responses_sent(&self) -> u64 {
+        self.milestone_cone_responses_sent.load(Ordering::Relaxed)
+    }
+
+    /// Increments the number of sent milestone cone responses of the `PeerMetrics`.
+    pub fn milestone_cone_responses_sent_inc(&self) -> u64 {
+        self.milestone_cone_responses_sent.fetch_add(1, Ordering::SeqCst)
+    }
 }
 
 #[cfg(test)]
@@ -175,18 +219,24 @@ mod tests {
         assert_eq!(metrics.blocks_received(), 0);
         assert_eq!(metrics.block_requests_received(), 0);
         assert_eq!(metrics.heartbeats_received(), 0);
+        assert_eq!(metrics.milestone_cone_requests_received(), 0);
+        assert_eq!(metrics.milestone_cone_responses_received(), 0);
 
         metrics.invalid_packets_inc();
         metrics.milestone_requests_received_inc();
         metrics.blocks_received_inc();
         metrics.block_requests_received_inc();
         metrics.heartbeats_received_inc();
+        metrics.milestone_cone_requests_received_inc();
+        metrics.milestone_cone_responses_received_inc();
 
         assert_eq!(metrics.invalid_packets(), 1);
         assert_eq!(metrics.milestone_requests_received(), 1);
         assert_eq!(metrics.blocks_received(), 1);
         assert_eq!(metrics.block_requests_received(), 1);
         assert_eq!(metrics.heartbeats_received(), 1);
+        assert_eq!(metrics.milestone_cone_requests_received(), 1);
+        assert_eq!(metrics.milestone_cone_responses_received(), 1);
     }
 
     #[test]
@@ -197,15 +247,21 @@ mod tests {
         assert_eq!(metrics.blocks_sent(), 0);
         assert_eq!(metrics.block_requests_sent(), 0);
         assert_eq!(metrics.heartbeats_sent(), 0);
+        assert_eq!(metrics.milestone_cone_requests_sent(), 0);
+        assert_eq!(metrics.milestone_cone_responses_sent(), 0);
 
         metrics.milestone_requests_sent_inc();
         metrics.blocks_sent_inc();
         metrics.block_requests_sent_inc();
         metrics.heartbeats_sent_inc();
+        metrics.milestone_cone_requests_sent_inc();
+        metrics.milestone_cone_responses_sent_inc();
 
         assert_eq!(metrics.milestone_requests_sent(), 1);
         assert_eq!(metrics.blocks_sent(), 1);
         assert_eq!(metrics.block_requests_sent(), 1);
         assert_eq!(metrics.heartbeats_sent(), 1);
+        assert_eq!(metrics.milestone_cone_requests_sent(), 1);
+        assert_eq!(metrics.milestone_cone_responses_sent(), 1);
     }
 }
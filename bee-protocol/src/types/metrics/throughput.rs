@@ -0,0 +1,138 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module that provides a type to hold throughput metrics computed per milestone interval.
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use bee_block::payload::milestone::MilestoneIndex;
+
+/// The number of milestone intervals that are kept in the history.
+pub const THROUGHPUT_HISTORY_SIZE: usize = 60;
+
+/// Throughput of the node between a confirmed milestone and its predecessor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneThroughput {
+    /// The index of the confirmed milestone.
+    pub milestone_index: MilestoneIndex,
+    /// The time since the previous milestone, in seconds.
+    pub time_since_last_milestone: u32,
+    /// The number of new blocks per second.
+    pub blocks_per_second: f64,
+    /// The number of blocks referenced by the milestone per second.
+    pub referenced_blocks_per_second: f64,
+    /// The percentage of new blocks that were referenced by the milestone.
+    pub referenced_rate: f64,
+}
+
+#[derive(Default)]
+struct ThroughputMetricsInner {
+    // Timestamp of the previous milestone and new blocks counter at that time.
+    previous: Option<(u32, u64)>,
+    history: VecDeque<MilestoneThroughput>,
+}
+
+/// Holds the throughput metrics of the last milestone intervals.
+#[derive(Default)]
+pub struct ThroughputMetrics {
+    inner: Mutex<ThroughputMetricsInner>,
+}
+
+impl ThroughputMetrics {
+    /// Creates a new `ThroughputMetrics`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the confirmation of a milestone and returns the throughput of the interval it closes, if any.
+    ///
+    /// `new_blocks` is the total number of new blocks seen by the node at the time of the confirmation.
+    pub fn record(
+        &self,
+        milestone_index: MilestoneIndex,
+        timestamp: u32,
+        new_blocks: u64,
+        referenced_blocks: u64,
+    ) -> Option<MilestoneThroughput> {
+        // Panic: the lock is never held across a panic.
+        let mut inner = self.inner.lock().unwrap();
+        let previous = inner.previous.replace((timestamp, new_blocks));
+        let (previous_timestamp, previous_new_blocks) = previous?;
+
+        // Two milestones with the same timestamp (or a clock going backward) don't form a meaningful interval.
+        let time_since_last_milestone = timestamp.checked_sub(previous_timestamp).filter(|diff| *diff > 0)?;
+        let new_blocks = new_blocks.saturating_sub(previous_new_blocks);
+
+        let throughput = MilestoneThroughput {
+            milestone_index,
+            time_since_last_milestone,
+            blocks_per_second: new_blocks as f64 / time_since_last_milestone as f64,
+            referenced_blocks_per_second: referenced_blocks as f64 / time_since_last_milestone as f64,
+            referenced_rate: if new_blocks > 0 {
+                referenced_blocks as f64 / new_blocks as f64 * 100.0
+            } else {
+                0.0
+            },
+        };
+
+        if inner.history.len() >= THROUGHPUT_HISTORY_SIZE {
+            inner.history.pop_front();
+        }
+        inner.history.push_back(throughput.clone());
+
+        Some(throughput)
+    }
+
+    /// Returns the throughput of the last milestone interval.
+    pub fn latest(&self) -> Option<MilestoneThroughput> {
+        // Panic: the lock is never held across a panic.
+        self.inner.lock().unwrap().history.back().cloned()
+    }
+
+    /// Returns the throughput of the last milestone intervals, oldest first.
+    pub fn history(&self) -> Vec<MilestoneThroughput> {
+        // Panic: the lock is never held across a panic.
+        self.inner.lock().unwrap().history.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record() {
+        let metrics = ThroughputMetrics::new();
+
+        assert!(metrics.record(MilestoneIndex(1), 100, 0, 0).is_none());
+
+        let throughput = metrics.record(MilestoneIndex(2), 110, 100, 50).unwrap();
+
+        assert_eq!(throughput.time_since_last_milestone, 10);
+        assert_eq!(throughput.blocks_per_second, 10.0);
+        assert_eq!(throughput.referenced_blocks_per_second, 5.0);
+        assert_eq!(throughput.referenced_rate, 50.0);
+        assert_eq!(metrics.latest(), Some(throughput));
+
+        // Same timestamp, no interval.
+        assert!(metrics.record(MilestoneIndex(3), 110, 100, 0).is_none());
+        assert_eq!(metrics.history().len(), 1);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let metrics = ThroughputMetrics::new();
+
+        for index in 0..=THROUGHPUT_HISTORY_SIZE as u32 + 10 {
+            metrics.record(MilestoneIndex(index), index * 10, index as u64, 1);
+        }
+
+        let history = metrics.history();
+
+        assert_eq!(history.len(), THROUGHPUT_HISTORY_SIZE);
+        assert_eq!(
+            history.last().unwrap().milestone_index,
+            MilestoneIndex(THROUGHPUT_HISTORY_SIZE as u32 + 10)
+        );
+    }
+}
@@ -9,7 +9,7 @@ use std::{
 };
 
 use bee_block::payload::milestone::MilestoneIndex;
-use bee_gossip::{Multiaddr, PeerId, PeerInfo, PeerRelation};
+use bee_gossip::{DialFailure, Multiaddr, PeerId, PeerInfo, PeerRelation, ReconnectInfo};
 
 use crate::types::metrics::PeerMetrics;
 
@@ -26,13 +26,40 @@ pub struct Peer {
     latest_milestone_index: AtomicU32,
     connected_peers: AtomicU8,
     synced_peers: AtomicU8,
+    supports_milestone_cone_requests: AtomicBool,
     heartbeat_sent_timestamp: AtomicU64,
     heartbeat_received_timestamp: AtomicU64,
+    failed_dials: AtomicU32,
+    last_failure: AtomicU8,
+    next_dial_at: AtomicU64,
+}
+
+// Encodes a dial failure in an `AtomicU8`, 0 meaning none.
+fn encode_dial_failure(failure: Option<DialFailure>) -> u8 {
+    match failure {
+        None => 0,
+        Some(DialFailure::Refused) => 1,
+        Some(DialFailure::Unreachable) => 2,
+        Some(DialFailure::HandshakeFailed) => 3,
+    }
+}
+
+fn decode_dial_failure(failure: u8) -> Option<DialFailure> {
+    match failure {
+        1 => Some(DialFailure::Refused),
+        2 => Some(DialFailure::Unreachable),
+        3 => Some(DialFailure::HandshakeFailed),
+        _ => None,
+    }
 }
 
 impl Peer {
     /// Creates a new `Peer`.
     pub fn new(id: PeerId, info: PeerInfo) -> Self {
+        let failed_dials = AtomicU32::new(info.reconnect.failed_dials);
+        let last_failure = AtomicU8::new(encode_dial_failure(info.reconnect.last_failure));
+        let next_dial_at = AtomicU64::new(info.reconnect.next_dial_at.unwrap_or_default());
+
         Self {
             id,
             info,
@@ -43,8 +70,12 @@ impl Peer {
             latest_milestone_index: AtomicU32::new(0),
             connected_peers: AtomicU8::new(0),
             synced_peers: AtomicU8::new(0),
+            supports_milestone_cone_requests: AtomicBool::new(false),
             heartbeat_sent_timestamp: AtomicU64::new(0),
             heartbeat_received_timestamp: AtomicU64::new(0),
+            failed_dials,
+            last_failure,
+            next_dial_at,
         }
     }
 
@@ -133,6 +164,16 @@ impl Peer {
         self.synced_peers.load(Ordering::Relaxed)
     }
 
+    /// Sets whether the `Peer` answers milestone cone requests or not.
+    pub fn set_supports_milestone_cone_requests(&self, supports: bool) {
+        self.supports_milestone_cone_requests.store(supports, Ordering::Relaxed);
+    }
+
+    /// Returns whether the `Peer` answers milestone cone requests or not.
+    pub fn supports_milestone_cone_requests(&self) -> bool {
+        self.supports_milestone_cone_requests.load(Ordering::Relaxed)
+    }
+
     /// Sets the timestamp of the last heartbeat sent by the `Peer`.
     pub fn set_heartbeat_sent_timestamp(&self) {
         self.heartbeat_sent_timestamp.store(
@@ -165,6 +206,34 @@ impl Peer {
         self.heartbeat_received_timestamp.load(Ordering::Relaxed)
     }
 
+    /// Sets the reconnect state of the `Peer`.
+    pub fn set_reconnect_info(&self, reconnect: &ReconnectInfo) {
+        let next_dial_at = reconnect.next_dial_at.unwrap_or_default();
+
+        self.failed_dials.store(reconnect.failed_dials, Ordering::Relaxed);
+        self.last_failure
+            .store(encode_dial_failure(reconnect.last_failure), Ordering::Relaxed);
+        self.next_dial_at.store(next_dial_at, Ordering::Relaxed);
+    }
+
+    /// Returns the number of consecutive failed attempts to dial the `Peer`.
+    pub fn failed_dials(&self) -> u32 {
+        self.failed_dials.load(Ordering::Relaxed)
+    }
+
+    /// Returns the reason why the last attempt to dial the `Peer` failed, if it did.
+    pub fn last_failure(&self) -> Option<DialFailure> {
+        decode_dial_failure(self.last_failure.load(Ordering::Relaxed))
+    }
+
+    /// Returns the UNIX timestamp (in seconds) of the next attempt to dial the `Peer`, if one is scheduled.
+    pub fn next_dial_at(&self) -> Option<u64> {
+        match self.next_dial_at.load(Ordering::Relaxed) {
+            0 => None,
+            timestamp => Some(timestamp),
+        }
+    }
+
     /// Returns whether the `Peer` is synced or not.
     pub fn is_synced(&self) -> bool {
         self.is_synced_threshold(SYNCED_THRESHOLD)
@@ -4,7 +4,7 @@
 use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
-use bee_block::BlockId;
+use bee_block::{payload::milestone::MilestoneIndex, BlockId};
 use bee_gossip::PeerId;
 use bee_pow::score;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
@@ -28,6 +28,8 @@ pub(crate) struct HasherWorkerEvent {
     pub(crate) from: Option<PeerId>,
     pub(crate) block_packet: BlockPacket,
     pub(crate) notifier: Option<Sender<Result<BlockId, BlockSubmitterError>>>,
+    /// Index of the milestone whose requested cone the block belongs to, if it was received as part of one.
+    pub(crate) milestone_cone: Option<MilestoneIndex>,
 }
 
 pub(crate) struct HasherWorker {
@@ -69,6 +71,7 @@ where
                 from,
                 block_packet,
                 notifier,
+                milestone_cone,
             }) = receiver.next().await
             {
                 if !cache.insert(&block_packet.bytes) {
@@ -99,6 +102,7 @@ where
                     block_packet,
                     pow_score,
                     notifier,
+                    milestone_cone,
                 }) {
                     warn!("Sending event to the processor worker failed: {}.", e);
                 }
@@ -92,7 +92,12 @@ fn process<B: StorageBackend>(
 
         match validate(block_id, &block, milestone_payload, key_manager) {
             Ok(milestone_metadata) => {
-                tangle.add_milestone(index, milestone_metadata.clone(), milestone_payload.deref().clone());
+                if let Err(e) =
+                    tangle.add_milestone(index, milestone_metadata.clone(), milestone_payload.deref().clone())
+                {
+                    error!("Failed to add milestone {}: {}.", *index, e);
+                    return;
+                }
                 if index > tangle.get_latest_milestone_index() {
                     info!("New milestone {} {}.", index, milestone_metadata.block_id());
                     tangle.update_latest_milestone_index(index);
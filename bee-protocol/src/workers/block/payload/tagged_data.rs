@@ -4,9 +4,15 @@
 use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
+use bee_ledger::workers::event::MilestoneConfirmed;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_storage::access::Insert;
+use bee_tangle::{
+    tagged_block::{PaddedTag, TaggedBlock},
+    Tangle, TangleWorker,
+};
 use futures::{future::FutureExt, stream::StreamExt};
-use log::{debug, info};
+use log::{debug, error, info};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -21,20 +27,49 @@ pub(crate) struct TaggedDataPayloadWorker {
     pub(crate) tx: mpsc::UnboundedSender<TaggedDataPayloadWorkerEvent>,
 }
 
+// Blocks are indexed once confirmed, at the index of the milestone that confirmed them, which is also the index at
+// which they are pruned.
+fn index_confirmed_blocks<B: StorageBackend>(tangle: &Tangle<B>, storage: &B, event: &MilestoneConfirmed) {
+    let block_ids = event
+        .excluded_no_transaction_blocks
+        .iter()
+        .chain(event.excluded_conflicting_blocks.iter().map(|(block_id, _)| block_id))
+        .chain(event.included_blocks.iter());
+
+    for block_id in block_ids {
+        let block = match tangle.get(block_id) {
+            Ok(Some(block)) => block,
+            Ok(None) => continue,
+            Err(e) => {
+                error!("Getting block {} failed: {:?}.", block_id, e);
+                continue;
+            }
+        };
+
+        if let Some(padded_tag) = PaddedTag::from_block(&block) {
+            let tagged_block = TaggedBlock::new(event.index, *block_id);
+
+            if let Err(e) = Insert::<(PaddedTag, TaggedBlock), ()>::insert(storage, &(padded_tag, tagged_block), &()) {
+                error!("Inserting tagged block {} failed: {:?}.", block_id, e);
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl<N> Worker<N> for TaggedDataPayloadWorker
 where
     N: Node,
     N::Backend: StorageBackend,
 {
-    type Config = ();
+    type Config = bool;
     type Error = Infallible;
 
     fn dependencies() -> &'static [TypeId] {
-        vec![TypeId::of::<MetricsWorker>()].leak()
+        vec![TypeId::of::<TangleWorker>(), TypeId::of::<MetricsWorker>()].leak()
     }
 
-    async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let metrics = node.resource::<NodeMetrics>();
         let (tx, rx) = mpsc::unbounded_channel();
 
@@ -63,6 +98,32 @@ where
             info!("Stopped.");
         });
 
+        if config {
+            let tangle = node.resource::<Tangle<N::Backend>>();
+            let storage = node.storage();
+            let (confirmed_tx, confirmed_rx) = mpsc::unbounded_channel::<MilestoneConfirmed>();
+
+            node.bus().add_listener::<Self, MilestoneConfirmed, _>(move |event| {
+                // The blocks are indexed by a separate task to keep storage accesses out of the listener.
+                let _ = confirmed_tx.send(event.clone());
+            });
+
+            node.spawn::<Self, _, _>(|shutdown| async move {
+                let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(confirmed_rx));
+
+                while let Some(event) = receiver.next().await {
+                    index_confirmed_blocks(&tangle, &*storage, &event);
+                }
+
+                // The confirmed blocks are not otherwise recoverable once the worker stopped.
+                let (_, mut receiver) = receiver.split();
+
+                while let Some(Some(event)) = receiver.next().now_or_never() {
+                    index_confirmed_blocks(&tangle, &*storage, &event);
+                }
+            });
+        }
+
         Ok(Self { tx })
     }
 }
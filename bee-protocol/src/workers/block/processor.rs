@@ -1,21 +1,23 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{any::TypeId, convert::Infallible, time::Instant};
+use std::{any::TypeId, convert::Infallible};
 
 use async_trait::async_trait;
 use bee_block::{
     constant::PROTOCOL_VERSION,
     output::ByteCostConfig,
-    payload::{transaction::TransactionEssence, Payload},
+    payload::{milestone::MilestoneIndex, transaction::TransactionEssence, Payload},
+    protocol::ProtocolParameters,
     Block, BlockId,
 };
 use bee_gossip::PeerId;
+use bee_ledger::workers::protocol_parameters::ProtocolParametersSchedule;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{block_metadata::BlockMetadata, Tangle, TangleWorker};
 use futures::{channel::oneshot::Sender, stream::StreamExt};
 use log::{error, info, trace};
-use tokio::sync::mpsc;
+use tokio::{sync::mpsc, time::Instant};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::{
@@ -38,6 +40,26 @@ pub(crate) struct ProcessorWorkerEvent {
     pub(crate) block_packet: BlockPacket,
     pub(crate) pow_score: f64,
     pub(crate) notifier: Option<Sender<Result<BlockId, BlockSubmitterError>>>,
+    pub(crate) milestone_cone: Option<MilestoneIndex>,
+}
+
+// How a block that was not known yet came to the node.
+#[derive(Debug, Eq, PartialEq)]
+enum Arrival {
+    // The block was requested on its own, at the given milestone index and time.
+    Requested(MilestoneIndex, Instant),
+    // The block was sent as part of the requested cone of a milestone, along with its parents.
+    MilestoneCone,
+    // The block was not requested, it is new to the network and has to be broadcast.
+    New,
+}
+
+fn arrival(requested: Option<(MilestoneIndex, Instant)>, milestone_cone: Option<MilestoneIndex>) -> Arrival {
+    match (requested, milestone_cone) {
+        (Some((index, instant)), _) => Arrival::Requested(index, instant),
+        (None, Some(_)) => Arrival::MilestoneCone,
+        (None, None) => Arrival::New,
+    }
 }
 
 pub(crate) struct ProcessorWorker {
@@ -86,6 +108,7 @@ where
         let requested_blocks = node.resource::<RequestedBlocks>();
         let metrics = node.resource::<NodeMetrics>();
         let peer_manager = node.resource::<PeerManager>();
+        let schedule = node.resource::<ProtocolParametersSchedule>();
         let bus = node.bus();
 
         node.spawn::<Self, _, _>(|shutdown| async move {
@@ -108,6 +131,7 @@ where
                 let requested_blocks = requested_blocks.clone();
                 let metrics = metrics.clone();
                 let peer_manager = peer_manager.clone();
+                let schedule = schedule.clone();
                 let bus = bus.clone();
                 let config = config.clone();
 
@@ -117,6 +141,7 @@ where
                         block_packet,
                         pow_score,
                         notifier,
+                        milestone_cone,
                     }) = rx.recv().await
                     {
                         trace!("Processing received block...");
@@ -129,12 +154,21 @@ where
                             }
                         };
 
-                        if block.protocol_version() != PROTOCOL_VERSION {
+                        // New blocks are going to be referenced by the next milestone at the earliest.
+                        let parameters = schedule.active_at(tangle.get_latest_milestone_index() + 1);
+                        let protocol_version = parameters
+                            .as_ref()
+                            .map_or(PROTOCOL_VERSION, ProtocolParameters::protocol_version);
+                        let minimum_pow_score = parameters
+                            .as_ref()
+                            .map_or(config.minimum_pow_score, |parameters| parameters.min_pow_score() as f64);
+
+                        if block.protocol_version() != protocol_version {
                             notify_invalid_block(
                                 format!(
                                     "Incompatible protocol version {} != {}.",
                                     block.protocol_version(),
-                                    PROTOCOL_VERSION
+                                    protocol_version
                                 ),
                                 &metrics,
                                 notifier,
@@ -151,9 +185,9 @@ where
                                 );
                                 continue;
                             }
-                        } else if pow_score < config.minimum_pow_score {
+                        } else if pow_score < minimum_pow_score {
                             notify_invalid_block(
-                                format!("Insufficient pow score: {} < {}.", pow_score, config.minimum_pow_score),
+                                format!("Insufficient pow score: {} < {}.", pow_score, minimum_pow_score),
                                 &metrics,
                                 notifier,
                             );
@@ -176,8 +210,12 @@ where
                                 continue 'next_event;
                             }
 
+                            let byte_cost = parameters
+                                .as_ref()
+                                .map_or_else(|| config.byte_cost.clone(), ProtocolParameters::byte_cost);
+
                             for (i, output) in essence.outputs().iter().enumerate() {
-                                if let Err(error) = output.verify_storage_deposit(&config.byte_cost) {
+                                if let Err(error) = output.verify_storage_deposit(&byte_cost) {
                                     notify_invalid_block(
                                         format!("Invalid output i={i}: {}", error),
                                         &metrics,
@@ -190,7 +228,15 @@ where
 
                         let block_id = block.id();
 
-                        if tangle.contains(&block_id) {
+                        let known = match tangle.contains(&block_id) {
+                            Ok(known) => known,
+                            Err(e) => {
+                                error!("Failed to look up block {}: {}.", block_id, e);
+                                continue 'next_event;
+                            }
+                        };
+
+                        if known {
                             metrics.known_blocks_inc();
                             if let Some(ref peer_id) = from {
                                 peer_manager
@@ -209,7 +255,10 @@ where
                             // - `BlockMetadata` is not overwritten.
                             // - Some extra code is executing due to not calling `continue` but
                             // this does not create inconsistencies.
-                            tangle.insert(&block, &block_id, &metadata);
+                            if let Err(e) = tangle.insert(&block, &block_id, &metadata) {
+                                error!("Failed to insert block {}: {}.", block_id, e);
+                                continue 'next_event;
+                            }
                         }
 
                         // Send the propagation event ASAP to allow the propagator to do its thing
@@ -217,9 +266,9 @@ where
                             error!("Failed to send block id {} to propagator: {:?}.", block_id, e);
                         }
 
-                        match requested_blocks.remove(&block_id) {
+                        match arrival(requested_blocks.remove(&block_id), milestone_cone) {
                             // Block was requested.
-                            Some((index, instant)) => {
+                            Arrival::Requested(index, instant) => {
                                 latency_num += 1;
                                 latency_sum += (Instant::now() - instant).as_millis() as u64;
                                 metrics.blocks_average_latency_set(latency_sum / latency_num);
@@ -228,8 +277,11 @@ where
                                     request_block(&tangle, &block_requester, &*requested_blocks, *parent, index).await;
                                 }
                             }
+                            // The parents of the block are part of the same cone, and the solidifier requests the
+                            // blocks of the cone that don't arrive one by one.
+                            Arrival::MilestoneCone => {}
                             // Block was not requested.
-                            None => {
+                            Arrival::New => {
                                 if let Err(e) = broadcaster.send(BroadcasterWorkerEvent {
                                     source: from,
                                     block: block_packet,
@@ -282,3 +334,33 @@ where
         Ok(Self { tx })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requested_block_arrival() {
+        let instant = Instant::now();
+
+        assert_eq!(
+            arrival(Some((MilestoneIndex(42), instant)), None),
+            Arrival::Requested(MilestoneIndex(42), instant)
+        );
+        assert_eq!(
+            arrival(Some((MilestoneIndex(42), instant)), Some(MilestoneIndex(43))),
+            Arrival::Requested(MilestoneIndex(42), instant)
+        );
+    }
+
+    #[test]
+    fn milestone_cone_block_is_not_new() {
+        // Blocks that are not new are neither broadcast nor inserted as unreferenced blocks.
+        assert_eq!(arrival(None, Some(MilestoneIndex(42))), Arrival::MilestoneCone);
+    }
+
+    #[test]
+    fn unrequested_block_is_new() {
+        assert_eq!(arrival(None, None), Arrival::New);
+    }
+}
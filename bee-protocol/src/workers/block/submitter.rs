@@ -90,6 +90,7 @@ where
                     from: None,
                     block_packet: BlockPacket::new(block),
                     notifier: Some(notifier),
+                    milestone_cone: None,
                 };
                 if let Err(e) = hasher.send(event) {
                     error!("Sending HasherWorkerEvent failed: {}.", e);
@@ -15,6 +15,7 @@ const DEFAULT_COO_PUBLIC_KEY_RANGES: [(&str, MilestoneIndex, MilestoneIndex); 0]
 const DEFAULT_BLOCK_WORKER_CACHE: usize = 10000;
 const DEFAULT_STATUS_INTERVAL: u64 = 10;
 const DEFAULT_MILESTONE_SYNC_COUNT: u32 = 200;
+const DEFAULT_TAG_INDEX: bool = false;
 
 #[derive(Default, Deserialize, PartialEq)]
 #[must_use]
@@ -34,6 +35,8 @@ struct ProtocolWorkersConfigBuilder {
     status_interval: Option<u64>,
     #[serde(alias = "milestoneSyncCount")]
     milestone_sync_count: Option<u32>,
+    #[serde(alias = "tagIndex")]
+    tag_index: Option<bool>,
 }
 
 /// Builder for a `ProtocolConfig`.
@@ -90,6 +93,12 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    /// Sets whether confirmed blocks carrying tagged data payloads are indexed by tag.
+    pub fn tag_index(mut self, tag_index: bool) -> Self {
+        self.workers.tag_index.replace(tag_index);
+        self
+    }
+
     /// Finishes the `ProtocolConfigBuilder` into a `ProtocolConfig`.
     #[must_use]
     pub fn finish(self) -> ProtocolConfig {
@@ -114,6 +123,7 @@ impl ProtocolConfigBuilder {
                     .workers
                     .milestone_sync_count
                     .unwrap_or(DEFAULT_MILESTONE_SYNC_COUNT),
+                tag_index: self.workers.tag_index.unwrap_or(DEFAULT_TAG_INDEX),
             },
             byte_cost: self.byte_cost.finish(),
         }
@@ -133,6 +143,7 @@ pub struct ProtocolWorkersConfig {
     pub(crate) block_worker_cache: usize,
     pub(crate) status_interval: u64,
     pub(crate) milestone_sync_count: u32,
+    pub(crate) tag_index: bool,
 }
 
 /// Configuration for the protocol.
@@ -164,4 +175,9 @@ impl ProtocolConfig {
     pub fn byte_cost(&self) -> &ByteCostConfig {
         &self.byte_cost
     }
+
+    /// Returns whether confirmed blocks carrying tagged data payloads are indexed by tag.
+    pub fn tag_index(&self) -> bool {
+        self.workers.tag_index
+    }
 }
@@ -3,6 +3,8 @@
 
 use bee_block::BlockId;
 
+use crate::types::metrics::throughput::MilestoneThroughput;
+
 /// An event that indicates that a block was processed.
 #[derive(Clone)]
 pub struct BlockProcessed {
@@ -17,6 +19,10 @@ pub struct BlockSolidified {
     pub block_id: BlockId,
 }
 
+/// An event that indicates that the throughput metrics were updated after a milestone confirmation.
+#[derive(Clone)]
+pub struct ThroughputMetricsUpdated(pub MilestoneThroughput);
+
 /// An event that indicates that the MPS metrics were updated.
 #[derive(Clone)]
 pub struct MpsMetricsUpdated {
@@ -6,9 +6,9 @@ use std::{any::TypeId, collections::HashSet, convert::Infallible};
 use async_trait::async_trait;
 use bee_block::{payload::milestone::MilestoneIndex, BlockId};
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
-use bee_tangle::{block_metadata::IndexId, milestone_metadata::MilestoneMetadata, Tangle, TangleWorker};
+use bee_tangle::{block_metadata::IndexId, milestone_metadata::MilestoneMetadata, Tangle, TangleError, TangleWorker};
 use futures::{future::FutureExt, stream::StreamExt};
-use log::{debug, info};
+use log::{debug, error, info};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -44,7 +44,9 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             while let Some(IndexUpdaterWorkerEvent(index, milestone)) = receiver.next().await {
-                process(&tangle, milestone, index).await;
+                if let Err(e) = process(&tangle, milestone, index).await {
+                    error!("Updating the cones of milestone {} failed: {}.", *index, e);
+                }
             }
 
             // Before the worker completely stops, the receiver needs to be drained for milestone cones to be updated.
@@ -54,7 +56,9 @@ where
             let mut count: usize = 0;
 
             while let Some(Some(IndexUpdaterWorkerEvent(index, milestone))) = receiver.next().now_or_never() {
-                process(&tangle, milestone, index).await;
+                if let Err(e) = process(&tangle, milestone, index).await {
+                    error!("Updating the cones of milestone {} failed: {}.", *index, e);
+                }
                 count += 1;
             }
 
@@ -67,27 +71,33 @@ where
     }
 }
 
-async fn process<B: StorageBackend>(tangle: &Tangle<B>, milestone: MilestoneMetadata, index: MilestoneIndex) {
-    if let Some(parents) = tangle.get(milestone.block_id()).map(|block| block.parents().to_vec()) {
+async fn process<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    milestone: MilestoneMetadata,
+    index: MilestoneIndex,
+) -> Result<(), TangleError> {
+    if let Some(parents) = tangle.get(milestone.block_id())?.map(|block| block.parents().to_vec()) {
         // Update the past cone of this milestone by setting its milestone index, and return them.
-        let roots = update_past_cone(tangle, parents, index).await;
+        let roots = update_past_cone(tangle, parents, index).await?;
 
         // Note: For tip-selection only the most recent tangle is relevent. That means that during synchronization we do
         // not need to update xMRSI values or tip scores before (LATEST_MILESTONE_INDEX - BELOW_MAX_DEPTH).
         if index > tangle.get_latest_milestone_index() - tangle.config().below_max_depth() {
-            update_future_cone(tangle, roots);
+            update_future_cone(tangle, roots)?;
 
             // Update tip pool after all values got updated.
             tangle.update_tip_scores().await;
         }
     }
+
+    Ok(())
 }
 
 async fn update_past_cone<B: StorageBackend>(
     tangle: &Tangle<B>,
     mut parents: Vec<BlockId>,
     index: MilestoneIndex,
-) -> HashSet<BlockId> {
+) -> Result<HashSet<BlockId>, TangleError> {
     let mut updated = HashSet::new();
 
     while let Some(parent_id) = parents.pop() {
@@ -99,7 +109,7 @@ async fn update_past_cone<B: StorageBackend>(
         if updated.contains(&parent_id)
             || tangle.is_solid_entry_point(&parent_id).await
             || tangle
-                .get_metadata(&parent_id)
+                .get_metadata(&parent_id)?
                 // TODO: I don't think unwrapping here is safe. Investigate!
                 .unwrap()
                 .milestone_index()
@@ -113,9 +123,9 @@ async fn update_past_cone<B: StorageBackend>(
 
             let index = IndexId::new(index, parent_id);
             metadata.set_omrsi_and_ymrsi(index, index);
-        });
+        })?;
 
-        if let Some(parent) = tangle.get(&parent_id) {
+        if let Some(parent) = tangle.get(&parent_id)? {
             parents.extend_from_slice(parent.parents())
         }
 
@@ -129,19 +139,19 @@ async fn update_past_cone<B: StorageBackend>(
 
     debug!("Set milestone index {} to {} blocks.", index, updated.len());
 
-    updated
+    Ok(updated)
 }
 
 // NOTE: Once a milestone comes in we have to walk the future cones of the root transactions and update their OMRSI and
 // YMRSI; during that time we need to block the propagator, otherwise it will propagate outdated data.
-fn update_future_cone<B: StorageBackend>(tangle: &Tangle<B>, roots: HashSet<BlockId>) {
+fn update_future_cone<B: StorageBackend>(tangle: &Tangle<B>, roots: HashSet<BlockId>) -> Result<(), TangleError> {
     let mut to_process = roots.into_iter().collect::<Vec<_>>();
     let mut processed = HashSet::new();
 
     while let Some(parent_id) = to_process.pop() {
-        if let Some(children) = tangle.get_children(&parent_id) {
+        if let Some(children) = tangle.get_children(&parent_id)? {
             // Unwrap is safe with very high probability.
-            let parent_omrsi_and_ymrsi = tangle.get_metadata(&parent_id).map(|md| md.omrsi_and_ymrsi()).unwrap();
+            let parent_omrsi_and_ymrsi = tangle.get_metadata(&parent_id)?.map(|md| md.omrsi_and_ymrsi()).unwrap();
 
             // TODO: investigate data race
             // Skip vertices with unset omrsi/ymrsi
@@ -172,7 +182,7 @@ fn update_future_cone<B: StorageBackend>(tangle: &Tangle<B>, roots: HashSet<Bloc
                                 });
 
                                 true
-                            })
+                            })?
                             .unwrap_or_default();
 
                         // Continue the future walk for that child, if we haven't landed on it earlier already.
@@ -188,4 +198,6 @@ fn update_future_cone<B: StorageBackend>(tangle: &Tangle<B>, roots: HashSet<Bloc
     }
 
     debug!("Updated xMRSI values for {} blocks.", processed.len());
+
+    Ok(())
 }
@@ -8,10 +8,13 @@ use bee_ledger::workers::event::{MilestoneConfirmed, PrunedIndex, SnapshottedInd
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use futures::StreamExt;
 use log::info;
-use tokio::time::interval;
-use tokio_stream::wrappers::IntervalStream;
+use tokio::{sync::mpsc, time::interval};
+use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
 
-use crate::types::metrics::NodeMetrics;
+use crate::{
+    types::metrics::{NodeMetrics, ThroughputMetrics},
+    workers::event::ThroughputMetricsUpdated,
+};
 
 const METRICS_INTERVAL: Duration = Duration::from_secs(60);
 
@@ -24,16 +27,24 @@ impl<N: Node> Worker<N> for MetricsWorker {
 
     async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
         node.register_resource(NodeMetrics::new());
+        node.register_resource(ThroughputMetrics::new());
+
+        let (tx, rx) = mpsc::unbounded_channel::<MilestoneConfirmed>();
 
         let metrics = node.resource::<NodeMetrics>();
         node.bus().add_listener::<Self, MilestoneConfirmed, _>(move |event| {
             metrics.referenced_blocks_inc(event.referenced_blocks as u64);
             metrics.excluded_no_transaction_blocks_inc(event.excluded_no_transaction_blocks.len() as u64);
             metrics.excluded_conflicting_blocks_inc(event.excluded_conflicting_blocks.len() as u64);
+            for (_, reason) in event.excluded_conflicting_blocks.iter() {
+                metrics.excluded_conflicting_blocks_by_reason_inc(*reason, 1);
+            }
             metrics.included_blocks_inc(event.included_blocks.len() as u64);
             metrics.created_outputs_inc(event.created_outputs as u64);
             metrics.consumed_outputs_inc(event.consumed_outputs as u64);
             metrics.receipts_inc(event.receipt as u64);
+            // The throughput is computed by a separate task as events can't be dispatched from within a listener.
+            let _ = tx.send(event.clone());
         });
 
         let metrics = node.resource::<NodeMetrics>();
@@ -46,6 +57,25 @@ impl<N: Node> Worker<N> for MetricsWorker {
             metrics.prunings_inc(1);
         });
 
+        let metrics = node.resource::<NodeMetrics>();
+        let throughput = node.resource::<ThroughputMetrics>();
+        let bus = node.bus();
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+
+            while let Some(event) = receiver.next().await {
+                if let Some(throughput) = throughput.record(
+                    event.index,
+                    event.timestamp,
+                    metrics.new_blocks(),
+                    event.referenced_blocks as u64,
+                ) {
+                    bus.dispatch(ThroughputMetricsUpdated(throughput));
+                }
+            }
+        });
+
         let metrics = node.resource::<NodeMetrics>();
 
         node.spawn::<Self, _, _>(|shutdown| async move {
@@ -45,7 +45,8 @@ pub(crate) use self::{
     propagator::{PropagatorWorker, PropagatorWorkerEvent},
     requester::{MilestoneRequesterWorker, MilestoneRequesterWorkerEvent},
     responder::{
-        BlockResponderWorker, BlockResponderWorkerEvent, MilestoneResponderWorker, MilestoneResponderWorkerEvent,
+        BlockResponderWorker, BlockResponderWorkerEvent, MilestoneConeResponderWorker,
+        MilestoneConeResponderWorkerEvent, MilestoneResponderWorker, MilestoneResponderWorkerEvent,
     },
     solidifier::{MilestoneSolidifierWorker, MilestoneSolidifierWorkerEvent},
     status::StatusWorker,
@@ -77,12 +78,13 @@ where
         })
         .with_worker::<BlockResponderWorker>()
         .with_worker::<MilestoneResponderWorker>()
+        .with_worker::<MilestoneConeResponderWorker>()
         .with_worker::<BlockRequesterWorker>()
         .with_worker::<MilestoneRequesterWorker>()
         .with_worker::<PayloadWorker>()
         .with_worker::<TransactionPayloadWorker>()
         .with_worker_cfg::<MilestonePayloadWorker>(config.clone())
-        .with_worker::<TaggedDataPayloadWorker>()
+        .with_worker_cfg::<TaggedDataPayloadWorker>(config.workers.tag_index)
         .with_worker::<PayloadWorker>()
         .with_worker::<BroadcasterWorker>()
         .with_worker::<PropagatorWorker>()
@@ -5,6 +5,8 @@
 
 use std::ops::Range;
 
+use bee_gossip::GossipVersion;
+
 use crate::workers::packets::Packet;
 
 const SOLID_MILESTONE_INDEX_SIZE: usize = 4;
@@ -12,18 +14,27 @@ const PRUNED_INDEX_SIZE: usize = 4;
 const LATEST_MILESTONE_INDEX_SIZE: usize = 4;
 const CONNECTED_PEERS_SIZE: usize = 1;
 const SYNCED_PEERS_SIZE: usize = 1;
+const FEATURES_SIZE: usize = 1;
 const CONSTANT_SIZE: usize = SOLID_MILESTONE_INDEX_SIZE
     + PRUNED_INDEX_SIZE
     + LATEST_MILESTONE_INDEX_SIZE
     + CONNECTED_PEERS_SIZE
     + SYNCED_PEERS_SIZE;
 
+/// Feature flag of the nodes answering `MilestoneConeRequestPacket`s.
+pub(crate) const FEATURE_MILESTONE_CONE_REQUESTS: u8 = 1 << 0;
+/// Features supported by this node.
+pub(crate) const FEATURES: u8 = FEATURE_MILESTONE_CONE_REQUESTS;
+
 /// A packet that informs about the part of the tangle currently being fully stored by a node.
 /// This packet is sent when a node:
 /// - just got paired to another node;
 /// - did a snapshot and pruned away a part of the tangle;
 /// - solidified a new milestone;
 /// It also helps other nodes to know if they can ask it a specific block.
+///
+/// The packet may be extended by a byte of feature flags. Since nodes that don't know about features reject the
+/// extended packet, it is only sent when a node gets paired through version `1.1.0` of the gossip protocol or later.
 #[derive(Clone)]
 pub(crate) struct HeartbeatPacket {
     /// Index of the latest solid milestone.
@@ -36,6 +47,8 @@ pub(crate) struct HeartbeatPacket {
     pub(crate) connected_peers: u8,
     /// Number of synced peers.
     pub(crate) synced_peers: u8,
+    /// Features supported by the node, if advertised.
+    pub(crate) features: Option<u8>,
 }
 
 impl HeartbeatPacket {
@@ -52,6 +65,21 @@ impl HeartbeatPacket {
             latest_milestone_index,
             connected_peers,
             synced_peers,
+            features: None,
+        }
+    }
+
+    pub(crate) fn with_features(mut self, features: u8) -> Self {
+        self.features = Some(features);
+        self
+    }
+
+    /// Advertises the features of this node, if the version of the gossip protocol of the peer accepts them.
+    pub(crate) fn with_features_for(self, gossip_version: GossipVersion) -> Self {
+        if gossip_version >= GossipVersion::V1_1 {
+            self.with_features(FEATURES)
+        } else {
+            self
         }
     }
 }
@@ -60,7 +88,7 @@ impl Packet for HeartbeatPacket {
     const ID: u8 = 0x04;
 
     fn size_range() -> Range<usize> {
-        (CONSTANT_SIZE)..(CONSTANT_SIZE + 1)
+        (CONSTANT_SIZE)..(CONSTANT_SIZE + FEATURES_SIZE + 1)
     }
 
     fn from_bytes(bytes: &[u8]) -> Self {
@@ -76,20 +104,23 @@ impl Packet for HeartbeatPacket {
         let (bytes, next) = next.split_at(CONNECTED_PEERS_SIZE);
         let connected_peers = u8::from_le_bytes(bytes.try_into().expect("Invalid buffer size"));
 
-        let (bytes, _) = next.split_at(SYNCED_PEERS_SIZE);
+        let (bytes, next) = next.split_at(SYNCED_PEERS_SIZE);
         let synced_peers = u8::from_le_bytes(bytes.try_into().expect("Invalid buffer size"));
 
+        let features = next.first().copied();
+
         Self {
             solid_milestone_index,
             pruned_index,
             latest_milestone_index,
             connected_peers,
             synced_peers,
+            features,
         }
     }
 
     fn size(&self) -> usize {
-        CONSTANT_SIZE
+        CONSTANT_SIZE + self.features.map_or(0, |_| FEATURES_SIZE)
     }
 
     fn to_bytes(&self, bytes: &mut [u8]) {
@@ -101,8 +132,11 @@ impl Packet for HeartbeatPacket {
         bytes.copy_from_slice(&self.latest_milestone_index.to_le_bytes());
         let (bytes, next) = next.split_at_mut(CONNECTED_PEERS_SIZE);
         bytes.copy_from_slice(&self.connected_peers.to_le_bytes());
-        let (bytes, _) = next.split_at_mut(SYNCED_PEERS_SIZE);
+        let (bytes, next) = next.split_at_mut(SYNCED_PEERS_SIZE);
         bytes.copy_from_slice(&self.synced_peers.to_le_bytes());
+        if let Some(features) = self.features {
+            next.copy_from_slice(&features.to_le_bytes());
+        }
     }
 }
 
@@ -126,7 +160,8 @@ mod tests {
     fn size_range() {
         assert!(!HeartbeatPacket::size_range().contains(&(CONSTANT_SIZE - 1)));
         assert!(HeartbeatPacket::size_range().contains(&CONSTANT_SIZE));
-        assert!(!HeartbeatPacket::size_range().contains(&(CONSTANT_SIZE + 1)));
+        assert!(HeartbeatPacket::size_range().contains(&(CONSTANT_SIZE + FEATURES_SIZE)));
+        assert!(!HeartbeatPacket::size_range().contains(&(CONSTANT_SIZE + FEATURES_SIZE + 1)));
     }
 
     #[test]
@@ -160,5 +195,40 @@ mod tests {
         assert_eq!(packet_to.latest_milestone_index, LATEST_MILESTONE_INDEX);
         assert_eq!(packet_to.connected_peers, CONNECTED_PEERS);
         assert_eq!(packet_to.synced_peers, SYNCED_PEERS);
+        assert_eq!(packet_to.features, None);
+    }
+
+    #[test]
+    fn into_from_with_features() {
+        let packet_from = HeartbeatPacket::new(
+            SOLID_MILESTONE_INDEX,
+            PRUNED_INDEX,
+            LATEST_MILESTONE_INDEX,
+            CONNECTED_PEERS,
+            SYNCED_PEERS,
+        )
+        .with_features(FEATURES);
+        let mut bytes = vec![0u8; packet_from.size()];
+        packet_from.to_bytes(&mut bytes);
+        let packet_to = HeartbeatPacket::from_bytes(&bytes);
+
+        assert_eq!(bytes.len(), CONSTANT_SIZE + FEATURES_SIZE);
+        assert_eq!(packet_to.solid_milestone_index, SOLID_MILESTONE_INDEX);
+        assert_eq!(packet_to.synced_peers, SYNCED_PEERS);
+        assert_eq!(packet_to.features, Some(FEATURES));
+    }
+
+    #[test]
+    fn features_for_gossip_versions() {
+        let packet = HeartbeatPacket::new(
+            SOLID_MILESTONE_INDEX,
+            PRUNED_INDEX,
+            LATEST_MILESTONE_INDEX,
+            CONNECTED_PEERS,
+            SYNCED_PEERS,
+        );
+
+        assert_eq!(packet.clone().with_features_for(GossipVersion::V1_0).features, None);
+        assert_eq!(packet.with_features_for(GossipVersion::V1_1).features, Some(FEATURES));
     }
 }
@@ -0,0 +1,106 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! MilestoneConeRequest packet of the protocol.
+
+use std::ops::Range;
+
+use crate::workers::packets::Packet;
+
+const INDEX_SIZE: usize = 4;
+const SOLID_MILESTONE_INDEX_SIZE: usize = 4;
+const CONSTANT_SIZE: usize = INDEX_SIZE + SOLID_MILESTONE_INDEX_SIZE;
+
+/// A packet to request the blocks of the past cone of a milestone that are not in the past cone of the solid milestone
+/// of the requester.
+/// The blocks are sent back as `MilestoneConeResponsePacket`s, parents before children.
+#[derive(Clone)]
+pub(crate) struct MilestoneConeRequestPacket {
+    /// Index of the milestone whose cone is requested.
+    pub(crate) index: u32,
+    /// Index of the latest solid milestone of the requester.
+    pub(crate) solid_milestone_index: u32,
+}
+
+impl MilestoneConeRequestPacket {
+    /// Maximum distance between the requested milestone and the solid milestone of the requester.
+    pub(crate) const DISTANCE_MAX: u32 = 8;
+
+    pub(crate) fn new(index: u32, solid_milestone_index: u32) -> Self {
+        Self {
+            index,
+            solid_milestone_index,
+        }
+    }
+}
+
+impl Packet for MilestoneConeRequestPacket {
+    const ID: u8 = 0x05;
+
+    fn size_range() -> Range<usize> {
+        (CONSTANT_SIZE)..(CONSTANT_SIZE + 1)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let (bytes, next) = bytes.split_at(INDEX_SIZE);
+        let index = u32::from_le_bytes(bytes.try_into().expect("Invalid buffer size"));
+
+        let (bytes, _) = next.split_at(SOLID_MILESTONE_INDEX_SIZE);
+        let solid_milestone_index = u32::from_le_bytes(bytes.try_into().expect("Invalid buffer size"));
+
+        Self {
+            index,
+            solid_milestone_index,
+        }
+    }
+
+    fn size(&self) -> usize {
+        CONSTANT_SIZE
+    }
+
+    fn to_bytes(&self, bytes: &mut [u8]) {
+        let (bytes, next) = bytes.split_at_mut(INDEX_SIZE);
+        bytes.copy_from_slice(&self.index.to_le_bytes());
+        let (bytes, _) = next.split_at_mut(SOLID_MILESTONE_INDEX_SIZE);
+        bytes.copy_from_slice(&self.solid_milestone_index.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INDEX: u32 = 0x81f7_df7c;
+    const SOLID_MILESTONE_INDEX: u32 = 0x0118_1f9b;
+
+    #[test]
+    fn id() {
+        assert_eq!(MilestoneConeRequestPacket::ID, 5);
+    }
+
+    #[test]
+    fn size_range() {
+        assert!(!MilestoneConeRequestPacket::size_range().contains(&(CONSTANT_SIZE - 1)));
+        assert!(MilestoneConeRequestPacket::size_range().contains(&CONSTANT_SIZE));
+        assert!(!MilestoneConeRequestPacket::size_range().contains(&(CONSTANT_SIZE + 1)));
+    }
+
+    #[test]
+    fn size() {
+        let packet = MilestoneConeRequestPacket::new(INDEX, SOLID_MILESTONE_INDEX);
+
+        assert_eq!(packet.size(), CONSTANT_SIZE);
+    }
+
+    #[test]
+    fn into_from() {
+        let packet_from = MilestoneConeRequestPacket::new(INDEX, SOLID_MILESTONE_INDEX);
+        let mut bytes = vec![0u8; packet_from.size()];
+        packet_from.to_bytes(&mut bytes);
+        let packet_to = MilestoneConeRequestPacket::from_bytes(&bytes);
+
+        assert_eq!(packet_to.index, INDEX);
+        assert_eq!(packet_to.solid_milestone_index, SOLID_MILESTONE_INDEX);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! MilestoneConeResponse packet of the protocol.
+
+use std::ops::Range;
+
+use bee_block::Block;
+
+use crate::workers::packets::Packet;
+
+const INDEX_SIZE: usize = 4;
+
+/// A packet to send a block of the cone of a milestone in response to a `MilestoneConeRequestPacket`.
+#[derive(Clone)]
+pub(crate) struct MilestoneConeResponsePacket {
+    /// Index of the milestone whose cone was requested.
+    pub(crate) index: u32,
+    /// Block to send.
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl MilestoneConeResponsePacket {
+    pub(crate) fn new(index: u32, bytes: Vec<u8>) -> Self {
+        Self { index, bytes }
+    }
+}
+
+impl Packet for MilestoneConeResponsePacket {
+    const ID: u8 = 0x06;
+
+    fn size_range() -> Range<usize> {
+        (INDEX_SIZE + Block::LENGTH_MIN)..(INDEX_SIZE + Block::LENGTH_MAX + 1)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let (index, bytes) = bytes.split_at(INDEX_SIZE);
+
+        Self {
+            index: u32::from_le_bytes(index.try_into().expect("Invalid buffer size")),
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    fn size(&self) -> usize {
+        INDEX_SIZE + self.bytes.len()
+    }
+
+    fn to_bytes(&self, bytes: &mut [u8]) {
+        let (index, next) = bytes.split_at_mut(INDEX_SIZE);
+        index.copy_from_slice(&self.index.to_le_bytes());
+        next.copy_from_slice(&self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INDEX: u32 = 0x60be_20c2;
+    const BLOCK: [u8; 64] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+        30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57,
+        58, 59, 60, 61, 62, 63,
+    ];
+
+    #[test]
+    fn id() {
+        assert_eq!(MilestoneConeResponsePacket::ID, 6);
+    }
+
+    #[test]
+    fn size_range() {
+        let size_range = MilestoneConeResponsePacket::size_range();
+
+        assert!(!size_range.contains(&(INDEX_SIZE + Block::LENGTH_MIN - 1)));
+        assert!(size_range.contains(&(INDEX_SIZE + Block::LENGTH_MIN)));
+        assert!(size_range.contains(&(INDEX_SIZE + Block::LENGTH_MAX)));
+        assert!(!size_range.contains(&(INDEX_SIZE + Block::LENGTH_MAX + 1)));
+    }
+
+    #[test]
+    fn size() {
+        let packet = MilestoneConeResponsePacket::new(INDEX, BLOCK.to_vec());
+
+        assert_eq!(packet.size(), INDEX_SIZE + BLOCK.len());
+    }
+
+    #[test]
+    fn into_from() {
+        let packet_from = MilestoneConeResponsePacket::new(INDEX, BLOCK.to_vec());
+        let mut bytes = vec![0u8; packet_from.size()];
+        packet_from.to_bytes(&mut bytes);
+        let packet_to = MilestoneConeResponsePacket::from_bytes(&bytes);
+
+        assert_eq!(packet_to.index, INDEX);
+        assert_eq!(packet_to.bytes, BLOCK);
+    }
+}
@@ -7,6 +7,8 @@ mod block;
 mod block_request;
 mod header;
 mod heartbeat;
+mod milestone_cone_request;
+mod milestone_cone_response;
 mod milestone_request;
 mod tlv;
 
@@ -16,7 +18,9 @@ pub(crate) use self::{
     block::BlockPacket,
     block_request::BlockRequestPacket,
     header::{HeaderPacket, HEADER_SIZE},
-    heartbeat::HeartbeatPacket,
+    heartbeat::{HeartbeatPacket, FEATURE_MILESTONE_CONE_REQUESTS},
+    milestone_cone_request::MilestoneConeRequestPacket,
+    milestone_cone_response::MilestoneConeResponsePacket,
     milestone_request::MilestoneRequestPacket,
     tlv::{tlv_from_bytes, tlv_to_bytes, Error as TlvError},
 };
@@ -21,8 +21,8 @@ use crate::{
         heartbeater::{new_heartbeat, send_heartbeat},
         peer::PeerManager,
         storage::StorageBackend,
-        BlockResponderWorker, HasherWorker, MetricsWorker, MilestoneRequesterWorker, MilestoneResponderWorker,
-        PeerManagerResWorker, PeerWorker, RequestedMilestones,
+        BlockResponderWorker, HasherWorker, MetricsWorker, MilestoneConeResponderWorker, MilestoneRequesterWorker,
+        MilestoneResponderWorker, PeerManagerResWorker, PeerWorker, RequestedMilestones,
     },
 };
 
@@ -51,6 +51,7 @@ where
             TypeId::of::<HasherWorker>(),
             TypeId::of::<BlockResponderWorker>(),
             TypeId::of::<MilestoneResponderWorker>(),
+            TypeId::of::<MilestoneConeResponderWorker>(),
             TypeId::of::<MilestoneRequesterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
         ]
@@ -67,6 +68,7 @@ where
         let hasher = node.worker::<HasherWorker>().unwrap().tx.clone();
         let block_responder = node.worker::<BlockResponderWorker>().unwrap().tx.clone();
         let milestone_responder = node.worker::<MilestoneResponderWorker>().unwrap().tx.clone();
+        let milestone_cone_responder = node.worker::<MilestoneConeResponderWorker>().unwrap().tx.clone();
         let milestone_requester = node.worker::<MilestoneRequesterWorker>().unwrap().tx.clone();
 
         let PeerManagerConfig {
@@ -94,6 +96,9 @@ where
                         AutopeeringEvent::PeeringDropped { peer_id } => {
                             handle_peering_dropped(peer_id, &gossip_command_tx);
                         }
+                        AutopeeringEvent::ExternalAddressUpdated { address } => {
+                            info!("Peers agreed on the external address {}.", address);
+                        }
                         _ => {}
                     }
                 }
@@ -127,15 +132,17 @@ where
                     }
                     NetworkEvent::PeerConnected {
                         peer_id,
-                        info: _,
+                        info,
                         gossip_in: receiver,
                         gossip_out: sender,
+                        gossip_version,
                     } => {
                         {
                             let metrics = metrics.clone();
                             let hasher = hasher.clone();
                             let block_responder = block_responder.clone();
                             let milestone_responder = milestone_responder.clone();
+                            let milestone_cone_responder = milestone_cone_responder.clone();
                             let milestone_requester = milestone_requester.clone();
                             let tangle = tangle.clone();
                             let requested_milestones = requested_milestones.clone();
@@ -145,6 +152,7 @@ where
                                     let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
                                     peer.0.set_connected(true);
+                                    peer.0.set_reconnect_info(&info.reconnect);
                                     peer.1 = Some((sender, shutdown_tx));
 
                                     tokio::spawn(
@@ -154,6 +162,7 @@ where
                                             hasher,
                                             block_responder,
                                             milestone_responder,
+                                            milestone_cone_responder,
                                             milestone_requester,
                                         )
                                         .run(
@@ -170,8 +179,9 @@ where
                         }
 
                         // TODO can't do it in the if because of deadlock, but it's not really right to do it here.
+                        // The features are only advertised to the peers whose gossip protocol accepts them.
                         send_heartbeat(
-                            &new_heartbeat(&*tangle, &*peer_manager),
+                            &new_heartbeat(&*tangle, &*peer_manager).with_features_for(gossip_version),
                             &peer_id,
                             &*peer_manager,
                             &*metrics,
@@ -180,6 +190,7 @@ where
                     NetworkEvent::PeerDisconnected { peer_id } => peer_manager
                         .get_mut_map(&peer_id, |peer| {
                             peer.0.set_connected(false);
+                            peer.0.set_supports_milestone_cone_requests(false);
                             if let Some((_, shutdown)) = peer.1.take() {
                                 if let Err(e) = shutdown.send(()) {
                                     warn!("Sending shutdown to {} failed: {:?}.", peer.0.alias(), e);
@@ -188,6 +199,9 @@ where
                             info!("Disconnected peer {}.", peer.0.alias());
                         })
                         .unwrap_or_default(),
+                    NetworkEvent::PeerDialFailed { peer_id, reconnect } => peer_manager
+                        .get_map(&peer_id, |peer| peer.0.set_reconnect_info(&reconnect))
+                        .unwrap_or_default(),
                     NetworkEvent::PeerUnreachable { peer_id, peer_info } => {
                         if peer_info.relation.is_discovered() {
                             // Remove that discovered peer.
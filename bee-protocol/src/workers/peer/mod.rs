@@ -21,14 +21,14 @@ use crate::{
     types::{metrics::NodeMetrics, peer::Peer},
     workers::{
         packets::{
-            tlv_from_bytes, BlockPacket, BlockRequestPacket, HeaderPacket, HeartbeatPacket, MilestoneRequestPacket,
-            Packet, TlvError,
+            tlv_from_bytes, BlockPacket, BlockRequestPacket, HeaderPacket, HeartbeatPacket, MilestoneConeRequestPacket,
+            MilestoneConeResponsePacket, MilestoneRequestPacket, Packet, TlvError, FEATURE_MILESTONE_CONE_REQUESTS,
         },
         peer::packet_handler::PacketHandler,
         requester::request_latest_milestone,
         storage::StorageBackend,
-        BlockResponderWorkerEvent, HasherWorkerEvent, MilestoneRequesterWorkerEvent, MilestoneResponderWorkerEvent,
-        RequestedMilestones,
+        BlockResponderWorkerEvent, HasherWorkerEvent, MilestoneConeResponderWorkerEvent, MilestoneRequesterWorkerEvent,
+        MilestoneResponderWorkerEvent, RequestedMilestones,
     },
 };
 
@@ -50,6 +50,7 @@ pub struct PeerWorker {
     hasher: mpsc::UnboundedSender<HasherWorkerEvent>,
     block_responder: mpsc::UnboundedSender<BlockResponderWorkerEvent>,
     milestone_responder: mpsc::UnboundedSender<MilestoneResponderWorkerEvent>,
+    milestone_cone_responder: mpsc::UnboundedSender<MilestoneConeResponderWorkerEvent>,
     milestone_requester: mpsc::UnboundedSender<MilestoneRequesterWorkerEvent>,
 }
 
@@ -60,6 +61,7 @@ impl PeerWorker {
         hasher: mpsc::UnboundedSender<HasherWorkerEvent>,
         block_responder: mpsc::UnboundedSender<BlockResponderWorkerEvent>,
         milestone_responder: mpsc::UnboundedSender<MilestoneResponderWorkerEvent>,
+        milestone_cone_responder: mpsc::UnboundedSender<MilestoneConeResponderWorkerEvent>,
         milestone_requester: mpsc::UnboundedSender<MilestoneRequesterWorkerEvent>,
     ) -> Self {
         Self {
@@ -68,6 +70,7 @@ impl PeerWorker {
             hasher,
             block_responder,
             milestone_responder,
+            milestone_cone_responder,
             milestone_requester,
         }
     }
@@ -137,6 +140,7 @@ impl PeerWorker {
                     from: Some(*self.peer.id()),
                     block_packet: packet,
                     notifier: None,
+                    milestone_cone: None,
                 });
 
                 self.peer.metrics().blocks_received_inc();
@@ -155,6 +159,35 @@ impl PeerWorker {
                 self.peer.metrics().block_requests_received_inc();
                 self.metrics.block_requests_received_inc();
             }
+            MilestoneConeRequestPacket::ID => {
+                trace!("[{}] Reading MilestoneConeRequestPacket...", self.peer.alias());
+
+                let packet = tlv_from_bytes::<MilestoneConeRequestPacket>(header, bytes)?;
+
+                let _ = self.milestone_cone_responder.send(MilestoneConeResponderWorkerEvent {
+                    peer_id: *self.peer.id(),
+                    request: packet,
+                });
+
+                self.peer.metrics().milestone_cone_requests_received_inc();
+                self.metrics.milestone_cone_requests_received_inc();
+            }
+            MilestoneConeResponsePacket::ID => {
+                trace!("[{}] Reading MilestoneConeResponsePacket...", self.peer.alias());
+
+                let packet = tlv_from_bytes::<MilestoneConeResponsePacket>(header, bytes)?;
+
+                // The blocks of a cone are processed like requested blocks, they are neither new nor to be broadcast.
+                let _ = self.hasher.send(HasherWorkerEvent {
+                    from: Some(*self.peer.id()),
+                    block_packet: BlockPacket::new(packet.bytes),
+                    notifier: None,
+                    milestone_cone: Some(MilestoneIndex(packet.index)),
+                });
+
+                self.peer.metrics().milestone_cone_responses_received_inc();
+                self.metrics.milestone_cone_responses_received_inc();
+            }
             HeartbeatPacket::ID => {
                 trace!("[{}] Reading HeartbeatPacket...", self.peer.alias());
 
@@ -166,6 +199,10 @@ impl PeerWorker {
                     .set_latest_milestone_index(packet.latest_milestone_index.into());
                 self.peer.set_connected_peers(packet.connected_peers);
                 self.peer.set_synced_peers(packet.synced_peers);
+                if let Some(features) = packet.features {
+                    self.peer
+                        .set_supports_milestone_cone_requests(features & FEATURE_MILESTONE_CONE_REQUESTS != 0);
+                }
                 self.peer.set_heartbeat_received_timestamp();
 
                 if !tangle.is_synced()
@@ -6,7 +6,7 @@ use std::{any::TypeId, convert::Infallible};
 use async_trait::async_trait;
 use bee_block::{payload::milestone::MilestoneIndex, BlockId};
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
-use bee_tangle::{block_metadata::IndexId, solid_entry_point::SolidEntryPoint, Tangle, TangleWorker};
+use bee_tangle::{block_metadata::IndexId, solid_entry_point::SolidEntryPoint, Tangle, TangleError, TangleWorker};
 use futures::{future::FutureExt, stream::StreamExt};
 use log::*;
 use ref_cast::RefCast;
@@ -28,19 +28,19 @@ async fn propagate<B: StorageBackend>(
     block_id: BlockId,
     tangle: &Tangle<B>,
     solidified_tx: &async_channel::Sender<(BlockId, Vec<BlockId>, Option<MilestoneIndex>)>,
-) {
+) -> Result<(), TangleError> {
     let mut children = vec![block_id];
 
     'outer: while let Some(ref block_id) = children.pop() {
         // Skip blocks that are already solid.
-        if tangle.is_solid_block(block_id).await {
+        if tangle.is_solid_block(block_id).await? {
             continue 'outer;
         }
 
-        if let Some(block) = tangle.get(block_id) {
+        if let Some(block) = tangle.get(block_id)? {
             // If one of the parents is not yet solid, we skip the current block.
             for parent in block.parents().iter() {
-                if !tangle.is_solid_block(parent).await {
+                if !tangle.is_solid_block(parent).await? {
                     continue 'outer;
                 }
             }
@@ -63,7 +63,7 @@ async fn propagate<B: StorageBackend>(
                     Some(parent_sepi) => (IndexId::new(parent_sepi, *parent), IndexId::new(parent_sepi, *parent)),
                     // SAFETY: 'unwrap' is safe, see explanation above.
                     None => tangle
-                        .get_metadata(parent)
+                        .get_metadata(parent)?
                         .map(|parent_md| {
                             parent_md
                                 .omrsi_and_ymrsi()
@@ -91,11 +91,11 @@ async fn propagate<B: StorageBackend>(
                         metadata.set_omrsi_and_ymrsi(*child_omrsi, *child_ymrsi);
                         None
                     }
-                })
+                })?
                 .expect("Failed to fetch metadata.");
 
             // Try to propagate as far as possible into the future.
-            if let Some(block_children) = tangle.get_children(block_id) {
+            if let Some(block_children) = tangle.get_children(block_id)? {
                 for child in block_children {
                     children.push(child);
                 }
@@ -110,6 +110,8 @@ async fn propagate<B: StorageBackend>(
             }
         }
     }
+
+    Ok(())
 }
 
 #[async_trait]
@@ -176,7 +178,9 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             while let Some(PropagatorWorkerEvent(block_id)) = receiver.next().await {
-                propagate(block_id, &tangle, &solidified_tx).await;
+                if let Err(e) = propagate(block_id, &tangle, &solidified_tx).await {
+                    error!("Propagating the solidity of {} failed: {}.", block_id, e);
+                }
             }
 
             // Before the worker completely stops, the receiver needs to be drained for statuses to be propagated.
@@ -186,7 +190,9 @@ where
             let mut count: usize = 0;
 
             while let Some(Some(PropagatorWorkerEvent(block_id))) = receiver.next().now_or_never() {
-                propagate(block_id, &tangle, &solidified_tx).await;
+                if let Err(e) = propagate(block_id, &tangle, &solidified_tx).await {
+                    error!("Propagating the solidity of {} failed: {}.", block_id, e);
+                }
                 count += 1;
             }
 
@@ -7,7 +7,7 @@ use std::{
     collections::HashMap,
     convert::Infallible,
     sync::Arc,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 use async_priority_queue::PriorityQueue;
@@ -19,7 +19,7 @@ use futures::StreamExt;
 use fxhash::FxBuildHasher;
 use log::{debug, info, trace};
 use parking_lot::RwLock;
-use tokio::time::interval;
+use tokio::time::{interval, Instant};
 use tokio_stream::wrappers::IntervalStream;
 
 use crate::{
@@ -39,7 +39,8 @@ pub async fn request_block<B: StorageBackend>(
     block_id: BlockId,
     index: MilestoneIndex,
 ) {
-    if !tangle.contains(&block_id)
+    // Nothing is requested if the storage can not be read since the node is stopping anyway.
+    if !tangle.contains(&block_id).unwrap_or(true)
         && !tangle.is_solid_entry_point(&block_id).await
         && !requested_blocks.contains(&block_id)
     {
@@ -162,7 +163,7 @@ fn retry_requests<B: StorageBackend>(
     }
 
     for (block_id, index) in to_retry {
-        if tangle.contains(&block_id) {
+        if tangle.contains(&block_id).unwrap_or(true) {
             requested_blocks.remove(&block_id);
         } else {
             process_request_unchecked(block_id, index, peer_manager, metrics);
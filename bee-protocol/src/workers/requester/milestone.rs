@@ -1,12 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{
-    any::TypeId,
-    collections::HashMap,
-    convert::Infallible,
-    time::{Duration, Instant},
-};
+use std::{any::TypeId, collections::HashMap, convert::Infallible, time::Duration};
 
 use async_trait::async_trait;
 use bee_block::payload::milestone::MilestoneIndex;
@@ -17,14 +12,20 @@ use futures::StreamExt;
 use fxhash::FxBuildHasher;
 use log::{debug, info, warn};
 use parking_lot::RwLock;
-use tokio::{sync::mpsc, time::interval};
+use tokio::{
+    sync::mpsc,
+    time::{interval, Instant},
+};
 use tokio_stream::wrappers::{IntervalStream, UnboundedReceiverStream};
 
 use crate::{
     types::metrics::NodeMetrics,
     workers::{
-        packets::MilestoneRequestPacket, peer::PeerManager, sender::Sender, storage::StorageBackend, MetricsWorker,
-        PeerManagerResWorker,
+        packets::{MilestoneConeRequestPacket, MilestoneRequestPacket},
+        peer::PeerManager,
+        sender::Sender,
+        storage::StorageBackend,
+        MetricsWorker, PeerManagerResWorker,
     },
 };
 
@@ -37,7 +38,8 @@ pub(crate) fn request_milestone<B: StorageBackend>(
     index: MilestoneIndex,
     to: Option<PeerId>,
 ) {
-    if !requested_milestones.contains(&index) && !tangle.contains_milestone_metadata(index) {
+    // Nothing is requested if the storage can not be read since the node is stopping anyway.
+    if !requested_milestones.contains(&index) && !tangle.contains_milestone_metadata(index).unwrap_or(true) {
         if let Err(e) = milestone_requester.send(MilestoneRequesterWorkerEvent(index, to)) {
             warn!("Requesting milestone failed: {}.", e);
         }
@@ -53,6 +55,28 @@ pub(crate) fn request_latest_milestone<B: StorageBackend>(
     request_milestone(tangle, milestone_requester, requested_milestones, MilestoneIndex(0), to)
 }
 
+/// Requests the blocks of the cone of a milestone that are not in the cone of the solid milestone from a peer that
+/// supports milestone cone requests and has the data. Returns whether such a peer was found.
+pub(crate) fn request_milestone_cone(
+    peer_manager: &PeerManager,
+    metrics: &NodeMetrics,
+    index: MilestoneIndex,
+    solid_milestone_index: MilestoneIndex,
+) -> bool {
+    match peer_manager.fair_find(|peer| peer.supports_milestone_cone_requests() && peer.has_data(index)) {
+        Some(peer_id) => {
+            Sender::<MilestoneConeRequestPacket>::send(
+                &MilestoneConeRequestPacket::new(*index, *solid_milestone_index),
+                &peer_id,
+                peer_manager,
+                metrics,
+            );
+            true
+        }
+        None => false,
+    }
+}
+
 #[derive(Default)]
 pub struct RequestedMilestones(RwLock<HashMap<MilestoneIndex, Instant, FxBuildHasher>>);
 
@@ -154,7 +178,7 @@ fn retry_requests<B: StorageBackend>(
     }
 
     for index in to_retry {
-        if tangle.contains_milestone_metadata(index) {
+        if tangle.contains_milestone_metadata(index).unwrap_or(true) {
             requested_milestones.remove(&index);
         } else {
             process_request_unchecked(index, None, peer_manager, metrics);
@@ -200,7 +224,7 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             while let Some(MilestoneRequesterWorkerEvent(index, peer_id)) = receiver.next().await {
-                if !tangle.contains_milestone_metadata(index) {
+                if !tangle.contains_milestone_metadata(index).unwrap_or(true) {
                     debug!("Requesting milestone {}.", *index);
                     process_request(index, peer_id, &peer_manager, &metrics, &requested_milestones);
                 }
@@ -5,7 +5,8 @@ mod block;
 mod milestone;
 
 pub(crate) use self::milestone::{
-    request_latest_milestone, request_milestone, MilestoneRequesterWorker, MilestoneRequesterWorkerEvent,
+    request_latest_milestone, request_milestone, request_milestone_cone, MilestoneRequesterWorker,
+    MilestoneRequesterWorkerEvent,
 };
 pub use self::{
     block::{request_block, BlockRequesterWorker, BlockRequesterWorkerEvent, RequestedBlocks},
@@ -8,7 +8,7 @@ use bee_gossip::PeerId;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{Tangle, TangleWorker};
 use futures::stream::StreamExt;
-use log::info;
+use log::{info, warn};
 use packable::PackableExt;
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -63,13 +63,15 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             while let Some(BlockResponderWorkerEvent { peer_id, request }) = receiver.next().await {
-                if let Some(block) = tangle.get(&request.block_id) {
-                    Sender::<BlockPacket>::send(
+                match tangle.get(&request.block_id) {
+                    Ok(Some(block)) => Sender::<BlockPacket>::send(
                         &BlockPacket::new(block.pack_to_vec()),
                         &peer_id,
                         &peer_manager,
                         &metrics,
-                    );
+                    ),
+                    Ok(None) => {}
+                    Err(e) => warn!("Responding to block request of {} failed: {}.", peer_id, e),
                 }
             }
 
@@ -8,7 +8,7 @@ use bee_gossip::PeerId;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{Tangle, TangleWorker};
 use futures::stream::StreamExt;
-use log::info;
+use log::{info, warn};
 use packable::PackableExt;
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -69,13 +69,15 @@ where
                     request.index.into()
                 };
 
-                if let Some(block) = tangle.get_milestone_block(index) {
-                    Sender::<BlockPacket>::send(
+                match tangle.get_milestone_block(index) {
+                    Ok(Some(block)) => Sender::<BlockPacket>::send(
                         &BlockPacket::new(block.pack_to_vec()),
                         &peer_id,
                         &peer_manager,
                         &metrics,
-                    );
+                    ),
+                    Ok(None) => {}
+                    Err(e) => warn!("Responding to milestone request of {} failed: {}.", peer_id, e),
                 }
             }
 
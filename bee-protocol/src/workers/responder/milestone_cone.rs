@@ -0,0 +1,404 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{any::TypeId, collections::HashMap, convert::Infallible, time::Duration};
+
+use async_trait::async_trait;
+use bee_block::{payload::milestone::MilestoneIndex, Block, BlockId};
+use bee_gossip::PeerId;
+use bee_runtime::{node::Node, resource::ResourceHandle, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_tangle::{
+    solid_entry_point::SolidEntryPoint,
+    walker::{WalkItem, WalkOrder},
+    Tangle, TangleError, TangleWorker,
+};
+use futures::stream::StreamExt;
+use log::{debug, info, warn};
+use packable::PackableExt;
+use tokio::{
+    sync::mpsc::{self, UnboundedSender},
+    task,
+    time::Instant,
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    types::metrics::NodeMetrics,
+    workers::{
+        packets::{MilestoneConeRequestPacket, MilestoneConeResponsePacket},
+        peer::PeerManager,
+        sender::Sender,
+        storage::StorageBackend,
+        MetricsWorker, PeerManagerResWorker,
+    },
+};
+
+// Bounds of the work done to respond to a single request.
+const MILESTONE_CONE_LIMITS: ConeLimits = ConeLimits {
+    blocks: 10_000,
+    visited: 50_000,
+};
+// Maximum number of requests of a peer that are responded to per interval.
+const MILESTONE_CONE_REQUESTS_MAX: u32 = 10;
+const MILESTONE_CONE_REQUEST_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Copy)]
+struct ConeLimits {
+    // Maximum number of blocks sent.
+    blocks: usize,
+    // Maximum number of vertices visited by the walk, including the ones that are not sent.
+    visited: usize,
+}
+
+pub(crate) struct MilestoneConeResponderWorkerEvent {
+    pub(crate) peer_id: PeerId,
+    pub(crate) request: MilestoneConeRequestPacket,
+}
+
+pub(crate) struct MilestoneConeResponderWorker {
+    pub(crate) tx: UnboundedSender<MilestoneConeResponderWorkerEvent>,
+}
+
+/// Fixed-window counters of the requests of the peers.
+#[derive(Default)]
+struct RequestWindows {
+    windows: HashMap<PeerId, (Instant, u32)>,
+    swept_at: Option<Instant>,
+}
+
+impl RequestWindows {
+    // Records a request of the peer and returns whether it is within the quota.
+    fn check(&mut self, peer_id: PeerId, now: Instant) -> bool {
+        // The windows of the peers that stopped requesting are dropped once per interval.
+        if self.swept_at.map_or(true, |swept_at| {
+            now.saturating_duration_since(swept_at) >= MILESTONE_CONE_REQUEST_INTERVAL
+        }) {
+            self.windows
+                .retain(|_, (start, _)| now.saturating_duration_since(*start) < MILESTONE_CONE_REQUEST_INTERVAL);
+            self.swept_at = Some(now);
+        }
+
+        let (start, count) = self.windows.entry(peer_id).or_insert((now, 0));
+
+        if now.saturating_duration_since(*start) >= MILESTONE_CONE_REQUEST_INTERVAL {
+            *start = now;
+            *count = 0;
+        }
+
+        if *count >= MILESTONE_CONE_REQUESTS_MAX {
+            false
+        } else {
+            *count += 1;
+            true
+        }
+    }
+}
+
+// Only the cones of solid milestones are complete, and only the cones of the milestones following closely the solid
+// milestone of the requester are sent, to bound the work done for a single request.
+fn is_answerable(index: MilestoneIndex, requester_smi: MilestoneIndex, smi: MilestoneIndex) -> bool {
+    index > requester_smi && index <= smi && *index - *requester_smi <= MilestoneConeRequestPacket::DISTANCE_MAX
+}
+
+// Walks the past cone of the milestone block and sends the blocks that are not in the cone of the solid milestone of
+// the requester, parents before children. Returns the number of sent blocks.
+fn walk_cone<B: StorageBackend>(
+    tangle: &Tangle<B>,
+    block_id: BlockId,
+    requester_smi: MilestoneIndex,
+    solid_entry_points: impl IntoIterator<Item = SolidEntryPoint>,
+    limits: ConeLimits,
+    mut send: impl FnMut(&Block),
+) -> Result<usize, TangleError> {
+    // The blocks referenced by the solid milestone of the requester, or before it, are in its cone already.
+    // The post-order walk yields the parents of a block before the block itself.
+    let walker = tangle
+        .walk_past_cone([block_id])
+        .with_order(WalkOrder::DepthFirstPostOrder)
+        .with_solid_entry_points(solid_entry_points)
+        .with_predicate(|_, _, metadata| {
+            metadata
+                .milestone_index()
+                .map_or(true, |referenced| referenced > requester_smi)
+        })
+        .finish();
+    let mut sent = 0;
+
+    for item in walker.take(limits.visited) {
+        if let WalkItem::Matched { block, .. } = item? {
+            send(&block);
+
+            sent += 1;
+            if sent == limits.blocks {
+                break;
+            }
+        }
+    }
+
+    Ok(sent)
+}
+
+async fn respond<B: StorageBackend>(
+    tangle: &ResourceHandle<Tangle<B>>,
+    peer_manager: &ResourceHandle<PeerManager>,
+    metrics: &ResourceHandle<NodeMetrics>,
+    peer_id: PeerId,
+    request: &MilestoneConeRequestPacket,
+) -> Result<usize, TangleError> {
+    let index = MilestoneIndex(request.index);
+    let requester_smi = MilestoneIndex(request.solid_milestone_index);
+
+    if !is_answerable(index, requester_smi, tangle.get_solid_milestone_index()) {
+        return Ok(0);
+    }
+
+    let block_id = match tangle.get_milestone_block_id(index)? {
+        Some(block_id) => block_id,
+        None => return Ok(0),
+    };
+
+    let solid_entry_points = tangle.get_solid_entry_points().await;
+    let tangle = tangle.clone();
+    let peer_manager = peer_manager.clone();
+    let metrics = metrics.clone();
+
+    // The walk reads the storage, so it is run on a blocking thread rather than on the worker.
+    task::spawn_blocking(move || {
+        walk_cone(
+            &tangle,
+            block_id,
+            requester_smi,
+            solid_entry_points.into_keys(),
+            MILESTONE_CONE_LIMITS,
+            |block| {
+                Sender::<MilestoneConeResponsePacket>::send(
+                    &MilestoneConeResponsePacket::new(*index, block.pack_to_vec()),
+                    &peer_id,
+                    &peer_manager,
+                    &metrics,
+                )
+            },
+        )
+    })
+    .await
+    .unwrap_or_else(|e| {
+        warn!("Walking the cone of milestone {} failed: {}.", index, e);
+        Ok(0)
+    })
+}
+
+#[async_trait]
+impl<N: Node> Worker<N> for MilestoneConeResponderWorker
+where
+    N::Backend: StorageBackend,
+{
+    type Config = ();
+    type Error = Infallible;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![
+            TypeId::of::<TangleWorker>(),
+            TypeId::of::<MetricsWorker>(),
+            TypeId::of::<PeerManagerResWorker>(),
+        ]
+        .leak()
+    }
+
+    async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let tangle = node.resource::<Tangle<N::Backend>>();
+        let metrics = node.resource::<NodeMetrics>();
+        let peer_manager = node.resource::<PeerManager>();
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+            let mut request_windows = RequestWindows::default();
+
+            while let Some(MilestoneConeResponderWorkerEvent { peer_id, request }) = receiver.next().await {
+                if !request_windows.check(peer_id, Instant::now()) {
+                    debug!(
+                        "Ignored cone request of milestone {} of {}: too many requests.",
+                        request.index, peer_id
+                    );
+                    continue;
+                }
+
+                match respond(&tangle, &peer_manager, &metrics, peer_id, &request).await {
+                    Ok(sent) => debug!(
+                        "Responded to cone request of milestone {} of {} with {} blocks.",
+                        request.index, peer_id, sent
+                    ),
+                    Err(e) => warn!(
+                        "Responding to cone request of milestone {} of {} failed: {}.",
+                        request.index, peer_id, e
+                    ),
+                }
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self { tx })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_block::parent::Parents;
+    use bee_storage_memory::Storage;
+    use bee_tangle::{block_metadata::BlockMetadata, config::TangleConfig};
+    use bee_test::rand::block::{rand_block, rand_block_with_parents};
+
+    use super::*;
+
+    const LIMITS: ConeLimits = ConeLimits {
+        blocks: usize::MAX,
+        visited: usize::MAX,
+    };
+
+    fn insert(tangle: &Tangle<Storage>, block: &Block, referenced_by: u32) -> BlockId {
+        let block_id = block.id();
+        let mut metadata = BlockMetadata::arrived();
+        metadata.set_milestone_index(MilestoneIndex(referenced_by));
+        tangle.insert(block, &block_id, &metadata).unwrap();
+        block_id
+    }
+
+    fn child(tangle: &Tangle<Storage>, parents: Vec<BlockId>, referenced_by: u32) -> BlockId {
+        insert(
+            tangle,
+            &rand_block_with_parents(Parents::new(parents).unwrap()),
+            referenced_by,
+        )
+    }
+
+    // A milestone 2 referencing two blocks on top of a block referenced by milestone 1.
+    fn cone() -> (Tangle<Storage>, BlockId, [BlockId; 2], BlockId) {
+        let tangle = Tangle::new(
+            TangleConfig::build().finish(),
+            ResourceHandle::new(Storage::start(()).unwrap()),
+        );
+        let old = insert(&tangle, &rand_block(), 1);
+        let first = child(&tangle, vec![old], 2);
+        let second = child(&tangle, vec![old], 2);
+        let milestone = child(&tangle, vec![first, second], 2);
+
+        (tangle, old, [first, second], milestone)
+    }
+
+    #[test]
+    fn answerable_requests() {
+        let smi = MilestoneIndex(100);
+
+        assert!(is_answerable(MilestoneIndex(100), MilestoneIndex(99), smi));
+        assert!(is_answerable(
+            MilestoneIndex(100),
+            MilestoneIndex(100 - MilestoneConeRequestPacket::DISTANCE_MAX),
+            smi
+        ));
+        // The requester has the cone already.
+        assert!(!is_answerable(MilestoneIndex(90), MilestoneIndex(90), smi));
+        assert!(!is_answerable(MilestoneIndex(90), MilestoneIndex(95), smi));
+        // The milestone is not solid yet.
+        assert!(!is_answerable(MilestoneIndex(101), MilestoneIndex(99), smi));
+        // The cone is too far from the solid milestone of the requester.
+        assert!(!is_answerable(
+            MilestoneIndex(100),
+            MilestoneIndex(99 - MilestoneConeRequestPacket::DISTANCE_MAX),
+            smi
+        ));
+        assert!(!is_answerable(MilestoneIndex(100), MilestoneIndex(0), smi));
+    }
+
+    #[test]
+    fn cone_is_sent_parents_first() {
+        let (tangle, old, parents, milestone) = cone();
+        let mut sent = Vec::new();
+
+        let len = walk_cone(&tangle, milestone, MilestoneIndex(1), [], LIMITS, |block| {
+            sent.push(block.id())
+        })
+        .unwrap();
+
+        assert_eq!(len, 3);
+        assert_eq!(sent.len(), 3);
+        // The block referenced by the solid milestone of the requester is not sent.
+        assert!(!sent.contains(&old));
+        assert!(parents.iter().all(|parent| sent[..2].contains(parent)));
+        assert_eq!(sent[2], milestone);
+    }
+
+    #[test]
+    fn cone_stops_at_solid_entry_points() {
+        let (tangle, _, parents, milestone) = cone();
+        let mut sent = Vec::new();
+
+        walk_cone(
+            &tangle,
+            milestone,
+            MilestoneIndex(1),
+            [SolidEntryPoint::from(parents[0])],
+            LIMITS,
+            |block| sent.push(block.id()),
+        )
+        .unwrap();
+
+        assert_eq!(sent, vec![parents[1], milestone]);
+    }
+
+    #[test]
+    fn cone_is_bounded() {
+        let (tangle, _, _, milestone) = cone();
+        let mut sent = 0;
+
+        let len = walk_cone(
+            &tangle,
+            milestone,
+            MilestoneIndex(1),
+            [],
+            ConeLimits {
+                blocks: 2,
+                visited: usize::MAX,
+            },
+            |_| sent += 1,
+        )
+        .unwrap();
+        assert_eq!((len, sent), (2, 2));
+
+        // The walk yields the block referenced by milestone 1 first, then a parent of the milestone.
+        let len = walk_cone(
+            &tangle,
+            milestone,
+            MilestoneIndex(1),
+            [],
+            ConeLimits {
+                blocks: usize::MAX,
+                visited: 2,
+            },
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(len, 1);
+    }
+
+    #[test]
+    fn requests_are_limited_per_peer() {
+        let mut windows = RequestWindows::default();
+        let (first, second) = (PeerId::random(), PeerId::random());
+        let start = Instant::now();
+
+        for _ in 0..MILESTONE_CONE_REQUESTS_MAX {
+            assert!(windows.check(first, start));
+        }
+        assert!(!windows.check(first, start + Duration::from_secs(1)));
+        assert!(windows.check(second, start + Duration::from_secs(1)));
+
+        // A new window starts once the interval has elapsed, and the windows that elapsed are dropped.
+        assert!(windows.check(first, start + MILESTONE_CONE_REQUEST_INTERVAL));
+        assert!(windows.check(first, start + MILESTONE_CONE_REQUEST_INTERVAL * 3));
+        assert_eq!(windows.windows.len(), 1);
+    }
+}
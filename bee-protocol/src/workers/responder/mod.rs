@@ -3,8 +3,10 @@
 
 mod block;
 mod milestone;
+mod milestone_cone;
 
 pub(crate) use self::{
     block::{BlockResponderWorker, BlockResponderWorkerEvent},
     milestone::{MilestoneResponderWorker, MilestoneResponderWorkerEvent},
+    milestone_cone::{MilestoneConeResponderWorker, MilestoneConeResponderWorkerEvent},
 };
@@ -9,7 +9,10 @@ use log::warn;
 use crate::{
     types::metrics::NodeMetrics,
     workers::{
-        packets::{tlv_to_bytes, BlockPacket, BlockRequestPacket, HeartbeatPacket, MilestoneRequestPacket, Packet},
+        packets::{
+            tlv_to_bytes, BlockPacket, BlockRequestPacket, HeartbeatPacket, MilestoneConeRequestPacket,
+            MilestoneConeResponsePacket, MilestoneRequestPacket, Packet,
+        },
         peer::PeerManager,
     },
 };
@@ -43,6 +46,56 @@ impl Sender<MilestoneRequestPacket> {
     }
 }
 
+impl Sender<MilestoneConeRequestPacket> {
+    pub(crate) fn send(
+        packet: &MilestoneConeRequestPacket,
+        id: &PeerId,
+        peer_manager: &PeerManager,
+        metrics: &NodeMetrics,
+    ) {
+        peer_manager
+            .get_map(id, |peer| {
+                if let Some(ref sender) = peer.1 {
+                    match sender.0.send(tlv_to_bytes(packet)) {
+                        Ok(_) => {
+                            peer.0.metrics().milestone_cone_requests_sent_inc();
+                            metrics.milestone_cone_requests_sent_inc();
+                        }
+                        Err(e) => {
+                            warn!("Sending MilestoneConeRequestPacket to {} failed: {:?}.", id, e);
+                        }
+                    }
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Sender<MilestoneConeResponsePacket> {
+    pub(crate) fn send(
+        packet: &MilestoneConeResponsePacket,
+        id: &PeerId,
+        peer_manager: &PeerManager,
+        metrics: &NodeMetrics,
+    ) {
+        peer_manager
+            .get_map(id, |peer| {
+                if let Some(ref sender) = peer.1 {
+                    match sender.0.send(tlv_to_bytes(packet)) {
+                        Ok(_) => {
+                            peer.0.metrics().milestone_cone_responses_sent_inc();
+                            metrics.milestone_cone_responses_sent_inc();
+                        }
+                        Err(e) => {
+                            warn!("Sending MilestoneConeResponsePacket to {} failed: {:?}.", id, e);
+                        }
+                    }
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl Sender<BlockPacket> {
     pub(crate) fn send(packet: &BlockPacket, id: &PeerId, peer_manager: &PeerManager, metrics: &NodeMetrics) {
         peer_manager
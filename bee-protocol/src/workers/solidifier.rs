@@ -1,7 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{any::TypeId, cmp, convert::Infallible};
+use std::{any::TypeId, cmp, collections::HashMap, convert::Infallible, time::Duration};
 
 use async_trait::async_trait;
 use bee_block::{
@@ -11,27 +11,61 @@ use bee_block::{
 use bee_ledger::workers::consensus::{ConsensusWorker, ConsensusWorkerCommand};
 use bee_runtime::{event::Bus, node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{
-    event::SolidMilestoneChanged, milestone_metadata::MilestoneMetadata, traversal, Tangle, TangleWorker,
+    event::SolidMilestoneChanged, milestone_metadata::MilestoneMetadata, walker::WalkItem, Tangle, TangleError,
+    TangleWorker,
 };
 use futures::StreamExt;
 use log::{debug, error, info, warn};
-use tokio::sync::mpsc;
+use tokio::{sync::mpsc, time::Instant};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 use crate::{
     types::metrics::NodeMetrics,
     workers::{
         heartbeater::broadcast_heartbeat,
+        packets::MilestoneConeRequestPacket,
         peer::PeerManager,
-        requester::{request_block, request_milestone},
+        requester::{request_block, request_milestone, request_milestone_cone},
         storage::StorageBackend,
         BlockRequesterWorker, IndexUpdaterWorker, IndexUpdaterWorkerEvent, MetricsWorker, MilestoneRequesterWorker,
         PeerManagerResWorker, RequestedBlocks, RequestedMilestones,
     },
 };
 
+// Number of milestones a node has to be behind for the cones of milestones to be requested at once.
+const MILESTONE_CONE_SYNC_THRESHOLD: u32 = 5;
+// Time after which the missing blocks of a cone that was requested at once are requested one by one.
+const MILESTONE_CONE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub(crate) struct MilestoneSolidifierWorkerEvent(pub MilestoneIndex);
 
+// Returns whether the missing blocks of the cone of the target milestone are expected from a cone request, requesting
+// the cone through `request` if the node is far enough behind and it wasn't requested yet.
+fn is_cone_requested(
+    requested_cones: &mut HashMap<MilestoneIndex, Instant>,
+    target: MilestoneIndex,
+    smi: MilestoneIndex,
+    lmi: MilestoneIndex,
+    now: Instant,
+    request: impl FnOnce() -> bool,
+) -> bool {
+    match requested_cones.get(&target) {
+        Some(requested_at) => now.saturating_duration_since(*requested_at) < MILESTONE_CONE_REQUEST_TIMEOUT,
+        None => {
+            // Peers don't respond to requests of cones too far from the solid milestone.
+            if (*lmi).saturating_sub(*smi) > MILESTONE_CONE_SYNC_THRESHOLD
+                && (*target).saturating_sub(*smi) <= MilestoneConeRequestPacket::DISTANCE_MAX
+                && request()
+            {
+                requested_cones.insert(target, now);
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
+
 pub(crate) struct MilestoneSolidifierWorker {
     pub(crate) tx: mpsc::UnboundedSender<MilestoneSolidifierWorkerEvent>,
 }
@@ -42,26 +76,23 @@ async fn heavy_solidification<B: StorageBackend>(
     requested_blocks: &RequestedBlocks,
     target_index: MilestoneIndex,
     target_id: BlockId,
-) -> usize {
-    // TODO: This wouldn't be necessary if the traversal code wasn't closure-driven
-    let mut missing = Vec::new();
-
-    traversal::visit_parents_depth_first(
-        tangle,
-        target_id,
-        |id, _, metadata| !metadata.flags().is_solid() && !requested_blocks.contains(id),
-        |_, _, _| {},
-        |_, _, _| {},
-        |missing_id| missing.push(*missing_id),
-    );
-
-    let missing_len = missing.len();
-
-    for missing_id in missing {
-        request_block(tangle, block_requester, requested_blocks, missing_id, target_index).await;
+) -> Result<usize, TangleError> {
+    let solid_entry_points = tangle.get_solid_entry_points().await;
+    let walker = tangle
+        .walk_past_cone([target_id])
+        .with_solid_entry_points(solid_entry_points.into_keys())
+        .with_predicate(|id, _, metadata| !metadata.flags().is_solid() && !requested_blocks.contains(id))
+        .finish();
+    let mut missing_len = 0;
+
+    for item in walker {
+        if let WalkItem::Missing { block_id, .. } = item? {
+            request_block(tangle, block_requester, requested_blocks, block_id, target_index).await;
+            missing_len += 1;
+        }
     }
 
-    missing_len
+    Ok(missing_len)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -143,11 +174,14 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             let mut next = tangle.get_solid_milestone_index() + MilestoneIndex(1);
+            let mut requested_cones = HashMap::new();
 
             while let Some(MilestoneSolidifierWorkerEvent(index)) = receiver.next().await {
                 let smi = tangle.get_solid_milestone_index();
                 let lmi = tangle.get_latest_milestone_index();
 
+                requested_cones.retain(|cone_index, _| *cone_index > smi);
+
                 // Request all milestones within a range.
                 while next <= cmp::min(smi + MilestoneIndex(milestone_sync_count), lmi) {
                     request_milestone(&tangle, &milestone_requester, &*requested_milestones, next, None);
@@ -155,31 +189,46 @@ where
                 }
 
                 if index < next {
-                    if let Some(block_id) = tangle.get_milestone_block_id(index) {
-                        if let Some(block) = tangle.get(&block_id) {
-                            debug!(
-                                "Light solidification of milestone {} {} in [{};{}].",
-                                index,
-                                block_id,
-                                *smi + 1,
-                                *next - 1
-                            );
-                            for parent in block.parents().iter() {
-                                request_block(&tangle, &block_requester, &requested_blocks, *parent, index).await;
+                    match tangle.get_milestone_block_id(index) {
+                        Ok(Some(block_id)) => match tangle.get(&block_id) {
+                            Ok(Some(block)) => {
+                                debug!(
+                                    "Light solidification of milestone {} {} in [{};{}].",
+                                    index,
+                                    block_id,
+                                    *smi + 1,
+                                    *next - 1
+                                );
+                                for parent in block.parents().iter() {
+                                    request_block(&tangle, &block_requester, &requested_blocks, *parent, index).await;
+                                }
+                            }
+                            Ok(None) => error!("Requested milestone {} block not present in the tangle.", index),
+                            Err(e) => error!("Reading milestone {} block failed: {}.", index, e),
+                        },
+                        Ok(None) => {
+                            if *index != 0 {
+                                error!("Requested milestone {} block id not present in the tangle.", index)
                             }
-                        } else {
-                            error!("Requested milestone {} block not present in the tangle.", index)
                         }
-                    } else if *index != 0 {
-                        error!("Requested milestone {} block id not present in the tangle.", index)
+                        Err(e) => error!("Reading milestone {} block id failed: {}.", index, e),
                     }
                 }
 
                 let mut target = smi + MilestoneIndex(1);
 
                 while target <= lmi {
-                    if let Some(id) = tangle.get_milestone_block_id(target) {
-                        if tangle.is_solid_block(&id).await {
+                    let id = match tangle.get_milestone_block_id(target) {
+                        Ok(Some(id)) => id,
+                        Ok(None) => break,
+                        Err(e) => {
+                            error!("Reading milestone {} block id failed: {}.", target, e);
+                            break;
+                        }
+                    };
+
+                    match tangle.is_solid_block(&id).await {
+                        Ok(true) => {
                             solidify(
                                 &tangle,
                                 &consensus_worker,
@@ -190,22 +239,40 @@ where
                                 id,
                                 target,
                             );
-                        } else {
+                        }
+                        Ok(false) => {
+                            // Without a peer supporting cone requests, or if the cone didn't arrive in time, the
+                            // missing blocks are requested one by one.
+                            let request = || {
+                                let requested = request_milestone_cone(&peer_manager, &metrics, target, smi);
+                                if requested {
+                                    debug!("Requested the cone of milestone {} {}.", target, id);
+                                }
+                                requested
+                            };
+
+                            if is_cone_requested(&mut requested_cones, target, smi, lmi, Instant::now(), request) {
+                                break;
+                            }
+
                             // TODO Is this actually necessary ?
-                            let missing_len =
-                                heavy_solidification(&tangle, &block_requester, &requested_blocks, target, id).await;
-                            debug!(
-                                "Heavy solidification of milestone {} {}: {} blocks requested in [{};{}].",
-                                target,
-                                id,
-                                missing_len,
-                                *smi + 1,
-                                *next - 1
-                            );
+                            match heavy_solidification(&tangle, &block_requester, &requested_blocks, target, id).await {
+                                Ok(missing_len) => debug!(
+                                    "Heavy solidification of milestone {} {}: {} blocks requested in [{};{}].",
+                                    target,
+                                    id,
+                                    missing_len,
+                                    *smi + 1,
+                                    *next - 1
+                                ),
+                                Err(e) => error!("Heavy solidification of milestone {} failed: {}.", target, e),
+                            }
+                            break;
+                        }
+                        Err(e) => {
+                            error!("Reading the solidity of milestone {} failed: {}.", target, e);
                             break;
                         }
-                    } else {
-                        break;
                     }
                     target = target + MilestoneIndex(1);
                 }
@@ -219,3 +286,70 @@ where
         Ok(Self { tx })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMI: MilestoneIndex = MilestoneIndex(100);
+    const TARGET: MilestoneIndex = MilestoneIndex(101);
+    const LMI: MilestoneIndex = MilestoneIndex(200);
+
+    #[test]
+    fn cone_is_requested_once_behind() {
+        let mut requested_cones = HashMap::new();
+        let now = Instant::now();
+
+        assert!(is_cone_requested(&mut requested_cones, TARGET, SMI, LMI, now, || true));
+        assert_eq!(requested_cones.get(&TARGET), Some(&now));
+
+        // The cone is expected until the request times out.
+        let later = now + MILESTONE_CONE_REQUEST_TIMEOUT - Duration::from_millis(1);
+        assert!(is_cone_requested(&mut requested_cones, TARGET, SMI, LMI, later, || {
+            panic!("the cone was requested already")
+        }));
+    }
+
+    #[test]
+    fn blocks_are_requested_one_by_one_after_timeout() {
+        let mut requested_cones = HashMap::new();
+        let now = Instant::now();
+
+        assert!(is_cone_requested(&mut requested_cones, TARGET, SMI, LMI, now, || true));
+
+        let later = now + MILESTONE_CONE_REQUEST_TIMEOUT;
+        assert!(!is_cone_requested(
+            &mut requested_cones,
+            TARGET,
+            SMI,
+            LMI,
+            later,
+            || { panic!("the cone is not requested again") }
+        ));
+    }
+
+    #[test]
+    fn blocks_are_requested_one_by_one_without_cone_request() {
+        let mut requested_cones = HashMap::new();
+        let now = Instant::now();
+
+        // Close to the latest milestone.
+        let lmi = SMI + MilestoneIndex(MILESTONE_CONE_SYNC_THRESHOLD);
+        assert!(!is_cone_requested(&mut requested_cones, TARGET, SMI, lmi, now, || {
+            panic!("the cone is not requested")
+        }));
+
+        // Too far from the solid milestone for peers to respond.
+        let target = SMI + MilestoneIndex(MilestoneConeRequestPacket::DISTANCE_MAX + 1);
+        assert!(!is_cone_requested(&mut requested_cones, target, SMI, LMI, now, || {
+            panic!("the cone is not requested")
+        }));
+
+        // No peer supports cone requests.
+        assert!(!is_cone_requested(&mut requested_cones, TARGET, SMI, LMI, now, || {
+            false
+        }));
+
+        assert!(requested_cones.is_empty());
+    }
+}
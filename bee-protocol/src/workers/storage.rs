@@ -4,14 +4,23 @@
 use bee_block::payload::milestone::MilestoneIndex;
 use bee_ledger::workers::StorageBackend as LedgerStorageBackend;
 use bee_storage::{access::Insert, backend};
-use bee_tangle::unreferenced_block::UnreferencedBlock;
+use bee_tangle::{
+    tagged_block::{PaddedTag, TaggedBlock},
+    unreferenced_block::UnreferencedBlock,
+};
 
 pub trait StorageBackend:
-    backend::StorageBackend + Insert<(MilestoneIndex, UnreferencedBlock), ()> + LedgerStorageBackend
+    backend::StorageBackend
+    + Insert<(MilestoneIndex, UnreferencedBlock), ()>
+    + Insert<(PaddedTag, TaggedBlock), ()>
+    + LedgerStorageBackend
 {
 }
 
 impl<T> StorageBackend for T where
-    T: backend::StorageBackend + Insert<(MilestoneIndex, UnreferencedBlock), ()> + LedgerStorageBackend
+    T: backend::StorageBackend
+        + Insert<(MilestoneIndex, UnreferencedBlock), ()>
+        + Insert<(PaddedTag, TaggedBlock), ()>
+        + LedgerStorageBackend
 {
 }
@@ -0,0 +1,275 @@
+// Copyright 2021-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Worker, resource and task management shared by node implementations.
+
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::{HashMap, HashSet},
+    pin::Pin,
+};
+
+use anymap::{any::Any as AnyMapAny, Map};
+use futures::{channel::oneshot, future::Future};
+use fxhash::FxBuildHasher;
+use tokio::task;
+
+use crate::{node::Node, resource::ResourceHandle, worker::Worker};
+
+type WorkerStart<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = ()> + 'a>>;
+type WorkerStop<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send;
+type ResourceRegister<N> = dyn for<'a> FnOnce(&'a mut N);
+type Task = Box<dyn Future<Output = Result<(), task::JoinError>> + Send + Sync + Unpin>;
+
+/// A node that keeps its workers, tasks and resources in a [`Core`].
+pub trait CoreNode: Node {
+    /// Returns the core of the node.
+    fn core(&self) -> &Core<Self>;
+
+    /// Returns the core of the node mutably.
+    fn core_mut(&mut self) -> &mut Core<Self>;
+}
+
+/// Encapsulates worker, resource, task management and shutdown logic.
+pub struct Core<N: Node> {
+    workers: Map<dyn AnyMapAny + Send + Sync>,
+    tasks: HashMap<TypeId, Vec<(oneshot::Sender<()>, Task)>>,
+    resources: Map<dyn AnyMapAny + Send + Sync>,
+    worker_stops: HashMap<TypeId, Box<WorkerStop<N>>>,
+    worker_order: Vec<TypeId>,
+    worker_names: HashMap<TypeId, &'static str>,
+}
+
+impl<N: CoreNode> Core<N> {
+    /// Returns a reference to the state of a worker.
+    pub fn worker<W: Worker<N> + Send + Sync>(&self) -> Option<&W> {
+        self.workers.get::<W>()
+    }
+
+    /// Registers a resource with the core.
+    pub fn register_resource<R: Any + Send + Sync>(&mut self, res: R) {
+        self.resources.insert(ResourceHandle::new(res));
+    }
+
+    /// Removes a resource from the core, returning `None` if it was not registered or is still in use.
+    pub fn remove_resource<R: Any + Send + Sync>(&mut self) -> Option<R> {
+        self.resources.remove::<ResourceHandle<R>>()?.try_unwrap()
+    }
+
+    /// Returns an owning handle to a resource.
+    #[track_caller]
+    pub fn resource<R: Any + Send + Sync>(&self) -> ResourceHandle<R> {
+        match self.resources.get::<ResourceHandle<R>>() {
+            Some(res) => res.clone(),
+            None => panic!("Unable to fetch node resource {}.", type_name::<R>()),
+        }
+    }
+
+    /// Spawns a task associated with a worker, to be shut down with that worker.
+    #[track_caller]
+    pub fn spawn<W, G, F>(&mut self, g: G)
+    where
+        W: Worker<N>,
+        G: FnOnce(oneshot::Receiver<()>) -> F,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let task = tokio::spawn(g(rx));
+
+        self.tasks
+            .entry(TypeId::of::<W>())
+            .or_default()
+            .push((tx, Box::new(task)));
+    }
+
+    /// Stops the workers of a node, and their tasks, in the reverse topological order.
+    pub async fn stop_workers(node: &mut N) {
+        for worker_id in node.core().worker_order.clone().into_iter().rev() {
+            // Panic: unwrapping is fine since worker_id is from the list of workers.
+            log::debug!(
+                "Stopping worker {}...",
+                node.core().worker_names.get(&worker_id).unwrap()
+            );
+
+            let tasks = node.core_mut().tasks.remove(&worker_id).unwrap_or_default();
+
+            for (shutdown, task) in tasks {
+                let _ = shutdown.send(());
+                // TODO: Should we handle this error?
+                let _ = task.await;
+            }
+
+            // Panic: unwrapping cannot fail by design.
+            let stop = node.core_mut().worker_stops.remove(&worker_id).unwrap();
+
+            stop(node).await;
+            node.bus().remove_listeners_by_id(worker_id);
+        }
+    }
+
+    fn add_worker<W: Worker<N> + Send + Sync>(&mut self, worker: W) {
+        self.workers.insert(worker);
+    }
+
+    fn remove_worker<W: Worker<N> + Send + Sync>(&mut self) -> W {
+        self.workers
+            .remove()
+            .unwrap_or_else(|| panic!("failed to remove worker `{}`", type_name::<W>()))
+    }
+}
+
+/// A builder to create the [`Core`] of a node and start its workers.
+#[must_use]
+pub struct CoreBuilder<N: Node> {
+    deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
+    worker_starts: HashMap<TypeId, Box<WorkerStart<N>>>,
+    worker_stops: HashMap<TypeId, Box<WorkerStop<N>>>,
+    worker_names: HashMap<TypeId, &'static str>,
+    resource_registers: Vec<Box<ResourceRegister<N>>>,
+}
+
+impl<N: Node> Default for CoreBuilder<N> {
+    fn default() -> Self {
+        Self {
+            deps: HashMap::default(),
+            worker_starts: HashMap::default(),
+            worker_stops: HashMap::default(),
+            worker_names: HashMap::default(),
+            resource_registers: Vec::default(),
+        }
+    }
+}
+
+impl<N: CoreNode> CoreBuilder<N> {
+    /// Adds a worker, with the given configuration state, to be started with the node.
+    pub fn with_worker_cfg<W: Worker<N> + 'static>(mut self, config: W::Config) -> Self {
+        self.deps.insert(TypeId::of::<W>(), W::dependencies());
+        self.worker_starts.insert(
+            TypeId::of::<W>(),
+            Box::new(|node| {
+                Box::pin(async move {
+                    log::debug!("Starting worker {}...", type_name::<W>());
+                    match W::start(node, config).await {
+                        Ok(w) => node.core_mut().add_worker(w),
+                        Err(e) => panic!("Worker `{}` failed to start: {:?}.", type_name::<W>(), e),
+                    }
+                })
+            }),
+        );
+        self.worker_stops.insert(
+            TypeId::of::<W>(),
+            Box::new(|node| {
+                Box::pin(async move {
+                    match node.core_mut().remove_worker::<W>().stop(node).await {
+                        Ok(()) => {}
+                        Err(e) => panic!("Worker `{}` failed to stop: {:?}.", type_name::<W>(), e),
+                    }
+                })
+            }),
+        );
+        self.worker_names.insert(TypeId::of::<W>(), type_name::<W>());
+        self
+    }
+
+    /// Adds a resource to be registered with the node.
+    pub fn with_resource<R: Any + Send + Sync>(mut self, res: R) -> Self {
+        self.resource_registers.push(Box::new(move |node| {
+            node.register_resource(res);
+        }));
+        self
+    }
+
+    /// Creates the node around the core, registers all resources and starts all workers in topological order.
+    pub async fn finish(self, node: impl FnOnce(Core<N>) -> N) -> N {
+        let CoreBuilder {
+            deps,
+            mut worker_starts,
+            worker_stops,
+            worker_names,
+            resource_registers,
+        } = self;
+
+        let worker_order = TopologicalOrder::sort(deps);
+
+        log_topological_order(&worker_order, &worker_names);
+
+        let mut node = node(Core {
+            workers: Map::new(),
+            tasks: HashMap::new(),
+            resources: Map::new(),
+            worker_stops,
+            worker_order,
+            worker_names,
+        });
+
+        for f in resource_registers {
+            f(&mut node);
+        }
+
+        for id in node.core().worker_order.clone() {
+            // Panic: unwrapping cannot fail by design.
+            worker_starts.remove(&id).unwrap()(&mut node).await;
+        }
+
+        node
+    }
+}
+
+struct TopologicalOrder {
+    graph: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
+    non_visited: HashSet<TypeId, FxBuildHasher>,
+    being_visited: HashSet<TypeId, FxBuildHasher>,
+    order: Vec<TypeId>,
+}
+
+impl TopologicalOrder {
+    fn visit(&mut self, id: TypeId) {
+        // Dependencies that were never registered as workers are not visited.
+        if !self.non_visited.contains(&id) {
+            return;
+        }
+
+        if !self.being_visited.insert(id) {
+            panic!("Cyclic dependency detected.");
+        }
+
+        for &id in self.graph[&id] {
+            self.visit(id);
+        }
+
+        self.being_visited.remove(&id);
+        self.non_visited.remove(&id);
+        self.order.push(id);
+    }
+
+    fn sort(graph: HashMap<TypeId, &'static [TypeId], FxBuildHasher>) -> Vec<TypeId> {
+        let non_visited = graph.keys().copied().collect();
+
+        let mut this = Self {
+            graph,
+            non_visited,
+            being_visited: HashSet::default(),
+            order: vec![],
+        };
+
+        while let Some(&id) = this.non_visited.iter().next() {
+            this.visit(id);
+        }
+
+        this.order
+    }
+}
+
+fn log_topological_order(worker_order: &[TypeId], worker_names: &HashMap<TypeId, &'static str>) {
+    let mut topol_order = String::with_capacity(512);
+
+    for worker_id in worker_order.iter() {
+        // Panic: unwrapping is fine since worker_id is from the list of workers.
+        let worker_name = *worker_names.get(worker_id).unwrap();
+
+        topol_order.push_str(worker_name);
+        topol_order.push(' ');
+    }
+
+    log::debug!("Workers topological order:{}", topol_order);
+}
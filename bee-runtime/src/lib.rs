@@ -6,6 +6,7 @@
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 #![deny(missing_docs, warnings)]
 
+pub mod core;
 pub mod event;
 pub mod node;
 pub mod resource;
@@ -4,21 +4,26 @@
 //! Batch access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{Batch, BatchBuilder},
     backend::StorageBackend,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -40,9 +45,15 @@ pub struct StorageBatch {
     snapshot_info: TableBatch<(), SnapshotInfo>,
     solid_entry_point_to_milestone_index: TableBatch<SolidEntryPoint, MilestoneIndex>,
     milestone_index_to_output_diff: TableBatch<MilestoneIndex, OutputDiff>,
+    milestone_index_to_protocol_parameters: TableBatch<MilestoneIndex, ProtocolParameters>,
+    block_id_to_conflict_diagnostic: TableBatch<BlockId, ConflictDiagnostic>,
     milestone_index_to_unreferenced_block: TableBatch<(MilestoneIndex, UnreferencedBlock), ()>,
     milestone_index_to_receipt: TableBatch<(MilestoneIndex, Receipt), ()>,
     spent_to_treasury_output: TableBatch<(bool, TreasuryOutput), ()>,
+    padded_tag_to_tagged_block: TableBatch<(PaddedTag, TaggedBlock), ()>,
+    address_to_history_entry: TableBatch<(Address, AddressHistoryEntry), ()>,
+    milestone_index_to_history_address: TableBatch<(MilestoneIndex, Address), ()>,
+    address_history_index: TableBatch<(), AddressHistoryIndex>,
 }
 
 impl BatchBuilder for Storage {
@@ -74,9 +85,15 @@ impl BatchBuilder for Storage {
         apply_batch!(snapshot_info);
         apply_batch!(solid_entry_point_to_milestone_index);
         apply_batch!(milestone_index_to_output_diff);
+        apply_batch!(milestone_index_to_protocol_parameters);
+        apply_batch!(block_id_to_conflict_diagnostic);
         apply_batch!(milestone_index_to_unreferenced_block);
         apply_batch!(milestone_index_to_receipt);
         apply_batch!(spent_to_treasury_output);
+        apply_batch!(padded_tag_to_tagged_block);
+        apply_batch!(address_to_history_entry);
+        apply_batch!(milestone_index_to_history_address);
+        apply_batch!(address_history_index);
 
         Ok(())
     }
@@ -118,6 +135,12 @@ impl_batch!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_batch!((), SnapshotInfo, snapshot_info);
 impl_batch!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_batch!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_batch!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_batch!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_batch!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -125,3 +148,7 @@ impl_batch!(
 );
 impl_batch!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_batch!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_batch!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_batch!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_batch!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_batch!((), AddressHistoryIndex, address_history_index);
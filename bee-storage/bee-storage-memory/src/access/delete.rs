@@ -4,18 +4,23 @@
 //! Delete access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Delete, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -46,6 +51,12 @@ impl_delete!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_delete!((), SnapshotInfo, snapshot_info);
 impl_delete!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_delete!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_delete!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_delete!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_delete!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -53,3 +64,7 @@ impl_delete!(
 );
 impl_delete!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_delete!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_delete!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_delete!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_delete!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_delete!((), AddressHistoryIndex, address_history_index);
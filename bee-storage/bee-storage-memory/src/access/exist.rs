@@ -4,18 +4,23 @@
 //! Exist access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Exist, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -44,6 +49,12 @@ impl_exist!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_exist!((), SnapshotInfo, snapshot_info);
 impl_exist!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_exist!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_exist!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_exist!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_exist!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -51,3 +62,7 @@ impl_exist!(
 );
 impl_exist!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_exist!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_exist!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_exist!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_exist!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_exist!((), AddressHistoryIndex, address_history_index);
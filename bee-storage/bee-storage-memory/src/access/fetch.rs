@@ -4,17 +4,23 @@
 //! Fetch access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput,
+    CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
 };
 use bee_storage::{access::Fetch, backend::StorageBackend, system::System};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock, TaggedBlockPage},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -43,6 +49,12 @@ impl_fetch!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_fetch!((), SnapshotInfo, snapshot_info);
 impl_fetch!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_fetch!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_fetch!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_fetch!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_fetch!(
     MilestoneIndex,
     Vec<UnreferencedBlock>,
@@ -50,3 +62,30 @@ impl_fetch!(
 );
 impl_fetch!(MilestoneIndex, Vec<Receipt>, milestone_index_to_receipt);
 impl_fetch!(bool, Vec<TreasuryOutput>, spent_to_treasury_output);
+impl_fetch!(PaddedTag, Vec<TaggedBlock>, padded_tag_to_tagged_block);
+impl_fetch!(Address, Vec<AddressHistoryEntry>, address_to_history_entry);
+impl_fetch!(MilestoneIndex, Vec<Address>, milestone_index_to_history_address);
+impl_fetch!((), AddressHistoryIndex, address_history_index);
+
+impl Fetch<TaggedBlockPage, Vec<TaggedBlock>> for Storage {
+    fn fetch(&self, page: &TaggedBlockPage) -> Result<Option<Vec<TaggedBlock>>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .read()?
+            .padded_tag_to_tagged_block
+            .fetch_page(page.padded_tag(), page.start(), page.limit()))
+    }
+}
+
+impl Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>> for Storage {
+    fn fetch(
+        &self,
+        page: &AddressHistoryPage,
+    ) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .read()?
+            .address_to_history_entry
+            .fetch_page(page.address(), page.start(), page.limit()))
+    }
+}
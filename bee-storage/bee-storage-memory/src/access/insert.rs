@@ -4,14 +4,16 @@
 //! Insert access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{Insert, InsertStrict},
@@ -19,7 +21,10 @@ use bee_storage::{
     system::System,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -50,6 +55,12 @@ impl_insert!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_insert!((), SnapshotInfo, snapshot_info);
 impl_insert!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_insert!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_insert!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_insert!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_insert!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -57,6 +68,10 @@ impl_insert!(
 );
 impl_insert!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_insert!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_insert!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_insert!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_insert!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_insert!((), AddressHistoryIndex, address_history_index);
 
 impl InsertStrict<BlockId, BlockMetadata> for Storage {
     fn insert_strict(&self, k: &BlockId, v: &BlockMetadata) -> Result<(), <Self as StorageBackend>::Error> {
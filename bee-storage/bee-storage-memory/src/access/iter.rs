@@ -4,17 +4,23 @@
 //! Iter access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::AsIterator, backend::StorageBackend, system::System};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -68,6 +74,12 @@ impl_iter!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_iter!((), SnapshotInfo, snapshot_info);
 impl_iter!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_iter!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_iter!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_iter!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_iter!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -75,3 +87,7 @@ impl_iter!(
 );
 impl_iter!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_iter!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_iter!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_iter!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_iter!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_iter!((), AddressHistoryIndex, address_history_index);
@@ -8,6 +8,8 @@ use std::{iter::Map, vec::IntoIter};
 use bee_block::{
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{ConsumedOutput, CreatedOutput, OutputDiff};
@@ -42,3 +44,9 @@ impl_multi_fetch!(MilestoneIndex, MilestoneMetadata, milestone_index_to_mileston
 impl_multi_fetch!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload);
 impl_multi_fetch!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_multi_fetch!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_multi_fetch!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_multi_fetch!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
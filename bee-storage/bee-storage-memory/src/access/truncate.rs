@@ -4,17 +4,23 @@
 //! Truncate access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Truncate, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -45,6 +51,12 @@ impl_truncate!(MilestoneId, MilestonePayload, milestone_id_to_milestone_payload)
 impl_truncate!((), SnapshotInfo, snapshot_info);
 impl_truncate!(SolidEntryPoint, MilestoneIndex, solid_entry_point_to_milestone_index);
 impl_truncate!(MilestoneIndex, OutputDiff, milestone_index_to_output_diff);
+impl_truncate!(
+    MilestoneIndex,
+    ProtocolParameters,
+    milestone_index_to_protocol_parameters
+);
+impl_truncate!(BlockId, ConflictDiagnostic, block_id_to_conflict_diagnostic);
 impl_truncate!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -52,3 +64,7 @@ impl_truncate!(
 );
 impl_truncate!((MilestoneIndex, Receipt), (), milestone_index_to_receipt);
 impl_truncate!((bool, TreasuryOutput), (), spent_to_treasury_output);
+impl_truncate!((PaddedTag, TaggedBlock), (), padded_tag_to_tagged_block);
+impl_truncate!((Address, AddressHistoryEntry), (), address_to_history_entry);
+impl_truncate!((MilestoneIndex, Address), (), milestone_index_to_history_address);
+impl_truncate!((), AddressHistoryIndex, address_history_index);
@@ -6,13 +6,16 @@
 use std::sync::{PoisonError, RwLock};
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{Fetch, Insert},
@@ -20,7 +23,10 @@ use bee_storage::{
     system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY},
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use thiserror::Error;
@@ -69,9 +75,15 @@ pub(crate) struct InnerStorage {
     pub(crate) snapshot_info: SingletonTable<SnapshotInfo>,
     pub(crate) solid_entry_point_to_milestone_index: Table<SolidEntryPoint, MilestoneIndex>,
     pub(crate) milestone_index_to_output_diff: Table<MilestoneIndex, OutputDiff>,
+    pub(crate) milestone_index_to_protocol_parameters: Table<MilestoneIndex, ProtocolParameters>,
+    pub(crate) block_id_to_conflict_diagnostic: Table<BlockId, ConflictDiagnostic>,
     pub(crate) milestone_index_to_unreferenced_block: VecTable<MilestoneIndex, UnreferencedBlock>,
     pub(crate) milestone_index_to_receipt: VecTable<MilestoneIndex, Receipt>,
     pub(crate) spent_to_treasury_output: VecTable<bool, TreasuryOutput>,
+    pub(crate) padded_tag_to_tagged_block: VecBinTable<PaddedTag, TaggedBlock>,
+    pub(crate) address_to_history_entry: VecBinTable<Address, AddressHistoryEntry>,
+    pub(crate) milestone_index_to_history_address: VecBinTable<MilestoneIndex, Address>,
+    pub(crate) address_history_index: SingletonTable<AddressHistoryIndex>,
 }
 
 impl Storage {
@@ -197,6 +197,13 @@ impl<K: Hash + Eq + Clone, V: Clone + Eq + Ord> VecBinTable<K, V> {
         self.inner.get(k).cloned().or_else(|| Some(vec![]))
     }
 
+    pub(crate) fn fetch_page(&self, k: &K, start: Option<&V>, limit: usize) -> Option<Vec<V>> {
+        let vs = self.inner.get(k).map_or(&[][..], Vec::as_slice);
+        let from = start.map_or(0, |start| vs.partition_point(|v| v < start));
+
+        Some(vs[from..].iter().take(limit).cloned().collect())
+    }
+
     pub(crate) fn exist(&self, (k, v): &(K, V)) -> bool {
         self.inner.get(k).map_or(false, |vs| vs.binary_search(v).is_ok())
     }
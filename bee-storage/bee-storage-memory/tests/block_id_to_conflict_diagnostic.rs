@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(
+    block_id_to_conflict_diagnostic_access_memory,
+    block_id_to_conflict_diagnostic_access
+);
@@ -2,18 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::access::{Batch, BatchBuilder};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::{Packable, PackableExt};
@@ -485,6 +490,76 @@ impl Batch<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Batch<MilestoneIndex, ProtocolParameters> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+        parameters: &ProtocolParameters,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        parameters.pack(&mut batch.value_buf).unwrap();
+
+        batch.inner.put_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?,
+            &batch.key_buf,
+            &batch.value_buf,
+        );
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+
+        batch.inner.delete_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?,
+            &batch.key_buf,
+        );
+
+        Ok(())
+    }
+}
+
+impl Batch<BlockId, ConflictDiagnostic> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        block_id: &BlockId,
+        diagnostic: &ConflictDiagnostic,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        diagnostic.pack(&mut batch.value_buf).unwrap();
+
+        batch.inner.put_cf(
+            self.cf_handle(CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?,
+            block_id,
+            &batch.value_buf,
+        );
+
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, block_id: &BlockId) -> Result<(), <Self as StorageBackend>::Error> {
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?, block_id);
+
+        Ok(())
+    }
+}
+
 impl Batch<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn batch_insert(
         &self,
@@ -592,3 +667,137 @@ impl Batch<(bool, TreasuryOutput), ()> for Storage {
         Ok(())
     }
 }
+
+impl Batch<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(padded_tag.as_ref());
+        batch.key_buf.extend_from_slice(&tagged_block.pack_to_vec());
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_PADDED_TAG_TO_TAGGED_BLOCK)?, &batch.key_buf, []);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(padded_tag.as_ref());
+        batch.key_buf.extend_from_slice(&tagged_block.pack_to_vec());
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_PADDED_TAG_TO_TAGGED_BLOCK)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<(Address, AddressHistoryEntry), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Address, AddressHistoryEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+        batch.key_buf.extend_from_slice(&entry.pack_to_vec());
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_ADDRESS_TO_HISTORY_ENTRY)?, &batch.key_buf, []);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Address, AddressHistoryEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+        batch.key_buf.extend_from_slice(&entry.pack_to_vec());
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_ADDRESS_TO_HISTORY_ENTRY)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<(MilestoneIndex, Address), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (index, address): &(MilestoneIndex, Address),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.pack_to_vec());
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+
+        batch.inner.put_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?,
+            &batch.key_buf,
+            [],
+        );
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (index, address): &(MilestoneIndex, Address),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.pack_to_vec());
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?, &batch.key_buf);
+
+        Ok(())
+    }
+}
+
+impl Batch<(), AddressHistoryIndex> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (): &(),
+        index: &AddressHistoryIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .put_cf(self.cf_handle(CF_ADDRESS_HISTORY_INDEX)?, [0x00u8], &batch.value_buf);
+
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        batch
+            .inner
+            .delete_cf(self.cf_handle(CF_ADDRESS_HISTORY_INDEX)?, [0x00u8]);
+
+        Ok(())
+    }
+}
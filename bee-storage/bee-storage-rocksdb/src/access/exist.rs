@@ -2,18 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::access::Exist;
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -164,6 +169,27 @@ impl Exist<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Exist<MilestoneIndex, ProtocolParameters> for Storage {
+    fn exist(&self, index: &MilestoneIndex) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(
+                self.cf_handle(CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?,
+                index.pack_to_vec(),
+            )?
+            .is_some())
+    }
+}
+
+impl Exist<BlockId, ConflictDiagnostic> for Storage {
+    fn exist(&self, block_id: &BlockId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?, block_id)?
+            .is_some())
+    }
+}
+
 impl Exist<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn exist(
         &self,
@@ -202,3 +228,54 @@ impl Exist<(bool, TreasuryOutput), ()> for Storage {
             .is_some())
     }
 }
+
+impl Exist<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn exist(
+        &self,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = padded_tag.as_ref().to_vec();
+        key.extend_from_slice(&tagged_block.pack_to_vec());
+
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_PADDED_TAG_TO_TAGGED_BLOCK)?, key)?
+            .is_some())
+    }
+}
+
+impl Exist<(Address, AddressHistoryEntry), ()> for Storage {
+    fn exist(
+        &self,
+        (address, entry): &(Address, AddressHistoryEntry),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&entry.pack_to_vec());
+
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_ADDRESS_TO_HISTORY_ENTRY)?, key)?
+            .is_some())
+    }
+}
+
+impl Exist<(MilestoneIndex, Address), ()> for Storage {
+    fn exist(&self, (index, address): &(MilestoneIndex, Address)) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = index.pack_to_vec();
+        key.extend_from_slice(&address.pack_to_vec());
+
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?, key)?
+            .is_some())
+    }
+}
+
+impl Exist<(), AddressHistoryIndex> for Storage {
+    fn exist(&self, (): &()) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_ADDRESS_HISTORY_INDEX)?, [0x00u8])?
+            .is_some())
+    }
+}
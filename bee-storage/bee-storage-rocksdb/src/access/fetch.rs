@@ -2,20 +2,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput,
+    CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, PACKED_ADDRESS_LENGTH,
 };
 use bee_storage::{access::Fetch, system::System};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock, TaggedBlockPage},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
+use rocksdb::{Direction, IteratorMode, ReadOptions};
 
 use crate::{
     column_families::*,
@@ -177,6 +184,29 @@ impl Fetch<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Fetch<MilestoneIndex, ProtocolParameters> for Storage {
+    fn fetch(&self, index: &MilestoneIndex) -> Result<Option<ProtocolParameters>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(
+                self.cf_handle(CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?,
+                index.pack_to_vec(),
+            )?
+            // Unpacking from storage is fine.
+            .map(|v| ProtocolParameters::unpack_unverified(&mut &*v).unwrap()))
+    }
+}
+
+impl Fetch<BlockId, ConflictDiagnostic> for Storage {
+    fn fetch(&self, block_id: &BlockId) -> Result<Option<ConflictDiagnostic>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?, block_id)?
+            // Unpacking from storage is fine.
+            .map(|v| ConflictDiagnostic::unpack_unverified(&mut &*v).unwrap()))
+    }
+}
+
 impl Fetch<MilestoneIndex, Vec<UnreferencedBlock>> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<UnreferencedBlock>>, <Self as StorageBackend>::Error> {
         Ok(Some(
@@ -227,3 +257,122 @@ impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
         ))
     }
 }
+
+impl Fetch<PaddedTag, Vec<TaggedBlock>> for Storage {
+    fn fetch(&self, padded_tag: &PaddedTag) -> Result<Option<Vec<TaggedBlock>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .prefix_iterator_cf(self.cf_handle(CF_PADDED_TAG_TO_TAGGED_BLOCK)?, padded_tag)
+                .map(|(key, _)| {
+                    let (_, mut tagged_block) = key.split_at(PaddedTag::LENGTH);
+                    // Unpacking from storage is fine.
+                    TaggedBlock::unpack_unverified(&mut tagged_block).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<TaggedBlockPage, Vec<TaggedBlock>> for Storage {
+    fn fetch(&self, page: &TaggedBlockPage) -> Result<Option<Vec<TaggedBlock>>, <Self as StorageBackend>::Error> {
+        let mut key = page.padded_tag().as_ref().to_vec();
+
+        if let Some(start) = page.start() {
+            key.extend_from_slice(&start.pack_to_vec());
+        }
+
+        let mut options = ReadOptions::default();
+        options.set_prefix_same_as_start(true);
+
+        Ok(Some(
+            self.inner
+                .iterator_cf_opt(
+                    self.cf_handle(CF_PADDED_TAG_TO_TAGGED_BLOCK)?,
+                    options,
+                    IteratorMode::From(&key, Direction::Forward),
+                )
+                .take(page.limit())
+                .map(|(key, _)| {
+                    let (_, mut tagged_block) = key.split_at(PaddedTag::LENGTH);
+                    // Unpacking from storage is fine.
+                    TaggedBlock::unpack_unverified(&mut tagged_block).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<Address, Vec<AddressHistoryEntry>> for Storage {
+    fn fetch(&self, address: &Address) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .prefix_iterator_cf(self.cf_handle(CF_ADDRESS_TO_HISTORY_ENTRY)?, address.pack_to_vec())
+                .map(|(key, _)| {
+                    let (_, mut entry) = key.split_at(PACKED_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    AddressHistoryEntry::unpack_unverified(&mut entry).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>> for Storage {
+    fn fetch(
+        &self,
+        page: &AddressHistoryPage,
+    ) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        let mut key = page.address().pack_to_vec();
+
+        if let Some(start) = page.start() {
+            key.extend_from_slice(&start.pack_to_vec());
+        }
+
+        let mut options = ReadOptions::default();
+        options.set_prefix_same_as_start(true);
+
+        Ok(Some(
+            self.inner
+                .iterator_cf_opt(
+                    self.cf_handle(CF_ADDRESS_TO_HISTORY_ENTRY)?,
+                    options,
+                    IteratorMode::From(&key, Direction::Forward),
+                )
+                .take(page.limit())
+                .map(|(key, _)| {
+                    let (_, mut entry) = key.split_at(PACKED_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    AddressHistoryEntry::unpack_unverified(&mut entry).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<MilestoneIndex, Vec<Address>> for Storage {
+    fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<Address>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .prefix_iterator_cf(
+                    self.cf_handle(CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?,
+                    index.pack_to_vec(),
+                )
+                .map(|(key, _)| {
+                    let (_, mut address) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+                    // Unpacking from storage is fine.
+                    Address::unpack_unverified(&mut address).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Fetch<(), AddressHistoryIndex> for Storage {
+    fn fetch(&self, (): &()) -> Result<Option<AddressHistoryIndex>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_pinned_cf(self.cf_handle(CF_ADDRESS_HISTORY_INDEX)?, [0x00u8])?
+            // Unpacking from storage is fine.
+            .map(|v| AddressHistoryIndex::unpack_unverified(&mut &*v).unwrap()))
+    }
+}
@@ -4,17 +4,23 @@
 use std::marker::PhantomData;
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent, PACKED_ADDRESS_LENGTH,
 };
 use bee_storage::{access::AsIterator, system::System};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -237,6 +243,28 @@ impl<'a> StorageIterator<'a, MilestoneIndex, OutputDiff> {
     }
 }
 
+impl<'a> StorageIterator<'a, MilestoneIndex, ProtocolParameters> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MilestoneIndex, ProtocolParameters) {
+        (
+            // Unpacking from storage is fine.
+            MilestoneIndex::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            ProtocolParameters::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, BlockId, ConflictDiagnostic> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (BlockId, ConflictDiagnostic) {
+        (
+            // Unpacking from storage is fine.
+            BlockId::unpack_unverified(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            ConflictDiagnostic::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
 impl<'a> StorageIterator<'a, (MilestoneIndex, UnreferencedBlock), ()> {
     fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MilestoneIndex, UnreferencedBlock), ()) {
         let (mut index, mut unreferenced_block) = key.split_at(std::mem::size_of::<MilestoneIndex>());
@@ -285,6 +313,64 @@ impl<'a> StorageIterator<'a, (bool, TreasuryOutput), ()> {
     }
 }
 
+impl<'a> StorageIterator<'a, (PaddedTag, TaggedBlock), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((PaddedTag, TaggedBlock), ()) {
+        let (mut padded_tag, mut tagged_block) = key.split_at(PaddedTag::LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                PaddedTag::unpack_unverified(&mut padded_tag).unwrap(),
+                // Unpacking from storage is fine.
+                TaggedBlock::unpack_unverified(&mut tagged_block).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (Address, AddressHistoryEntry), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((Address, AddressHistoryEntry), ()) {
+        let (mut address, mut entry) = key.split_at(PACKED_ADDRESS_LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                Address::unpack_unverified(&mut address).unwrap(),
+                // Unpacking from storage is fine.
+                AddressHistoryEntry::unpack_unverified(&mut entry).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (MilestoneIndex, Address), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MilestoneIndex, Address), ()) {
+        let (mut index, mut address) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+
+        (
+            (
+                // Unpacking from storage is fine.
+                MilestoneIndex::unpack_unverified(&mut index).unwrap(),
+                // Unpacking from storage is fine.
+                Address::unpack_unverified(&mut address).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageIterator<'a, (), AddressHistoryIndex> {
+    fn unpack_key_value(_: &[u8], mut value: &[u8]) -> ((), AddressHistoryIndex) {
+        (
+            (),
+            // Unpacking from storage is fine.
+            AddressHistoryIndex::unpack_unverified(&mut value).unwrap(),
+        )
+    }
+}
+
 impl_iter!(u8, System, CF_SYSTEM);
 impl_iter!(BlockId, Block, CF_BLOCK_ID_TO_BLOCK);
 impl_iter!((BlockId, BlockId), (), CF_BLOCK_ID_TO_BLOCK_ID);
@@ -302,6 +388,12 @@ impl_iter!(MilestoneId, MilestonePayload, CF_MILESTONE_ID_TO_MILESTONE_PAYLOAD);
 impl_iter!((), SnapshotInfo, CF_SNAPSHOT_INFO);
 impl_iter!(SolidEntryPoint, MilestoneIndex, CF_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX);
 impl_iter!(MilestoneIndex, OutputDiff, CF_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_iter!(
+    MilestoneIndex,
+    ProtocolParameters,
+    CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS
+);
+impl_iter!(BlockId, ConflictDiagnostic, CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC);
 impl_iter!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -309,6 +401,10 @@ impl_iter!(
 );
 impl_iter!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_iter!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
+impl_iter!((PaddedTag, TaggedBlock), (), CF_PADDED_TAG_TO_TAGGED_BLOCK);
+impl_iter!((Address, AddressHistoryEntry), (), CF_ADDRESS_TO_HISTORY_ENTRY);
+impl_iter!((MilestoneIndex, Address), (), CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS);
+impl_iter!((), AddressHistoryIndex, CF_ADDRESS_HISTORY_INDEX);
 
 impl<'a> AsIterator<'a, BlockId, BlockMetadata> for Storage {
     type AsIter = StorageIterator<'a, BlockId, BlockMetadata>;
@@ -2,17 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::access::Truncate;
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -76,6 +82,12 @@ impl_truncate!(MilestoneId, MilestonePayload, CF_MILESTONE_ID_TO_MILESTONE_PAYLO
 impl_truncate!((), SnapshotInfo, CF_SNAPSHOT_INFO);
 impl_truncate!(SolidEntryPoint, MilestoneIndex, CF_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX);
 impl_truncate!(MilestoneIndex, OutputDiff, CF_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_truncate!(
+    MilestoneIndex,
+    ProtocolParameters,
+    CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS
+);
+impl_truncate!(BlockId, ConflictDiagnostic, CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC);
 impl_truncate!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -83,6 +95,10 @@ impl_truncate!(
 );
 impl_truncate!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_truncate!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
+impl_truncate!((PaddedTag, TaggedBlock), (), CF_PADDED_TAG_TO_TAGGED_BLOCK);
+impl_truncate!((Address, AddressHistoryEntry), (), CF_ADDRESS_TO_HISTORY_ENTRY);
+impl_truncate!((MilestoneIndex, Address), (), CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS);
+impl_truncate!((), AddressHistoryIndex, CF_ADDRESS_HISTORY_INDEX);
 
 impl Truncate<BlockId, BlockMetadata> for Storage {
     fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_block::{address::Ed25519Address, payload::milestone::MilestoneIndex, BlockId};
+use bee_ledger::types::PACKED_ADDRESS_LENGTH;
 pub use bee_storage::{
     access::{Fetch, Insert},
     backend::StorageBackend,
     system::{StorageHealth, StorageVersion, System, SYSTEM_HEALTH_KEY, SYSTEM_VERSION_KEY},
 };
+use bee_tangle::tagged_block::PaddedTag;
 use parking_lot::RwLock;
 use rocksdb::{
     ColumnFamily, ColumnFamilyDescriptor, DBCompactionStyle, DBCompressionType, Env, FlushOptions, MergeOperands,
@@ -78,6 +80,12 @@ impl Storage {
         let cf_milestone_index_to_output_diff =
             ColumnFamilyDescriptor::new(CF_MILESTONE_INDEX_TO_OUTPUT_DIFF, Options::default());
 
+        let cf_milestone_index_to_protocol_parameters =
+            ColumnFamilyDescriptor::new(CF_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS, Options::default());
+
+        let cf_block_id_to_conflict_diagnostic =
+            ColumnFamilyDescriptor::new(CF_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC, Options::default());
+
         let mut options = Options::default();
         options.set_prefix_extractor(SliceTransform::create_fixed_prefix(
             std::mem::size_of::<MilestoneIndex>(),
@@ -95,6 +103,23 @@ impl Storage {
         options.set_prefix_extractor(SliceTransform::create_fixed_prefix(std::mem::size_of::<bool>()));
         let cf_spent_to_treasury = ColumnFamilyDescriptor::new(CF_SPENT_TO_TREASURY_OUTPUT, options);
 
+        let mut options = Options::default();
+        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(PaddedTag::LENGTH));
+        let cf_padded_tag_to_tagged_block = ColumnFamilyDescriptor::new(CF_PADDED_TAG_TO_TAGGED_BLOCK, options);
+
+        let mut options = Options::default();
+        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(PACKED_ADDRESS_LENGTH));
+        let cf_address_to_history_entry = ColumnFamilyDescriptor::new(CF_ADDRESS_TO_HISTORY_ENTRY, options);
+
+        let mut options = Options::default();
+        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(
+            std::mem::size_of::<MilestoneIndex>(),
+        ));
+        let cf_milestone_index_to_history_address =
+            ColumnFamilyDescriptor::new(CF_MILESTONE_INDEX_TO_HISTORY_ADDRESS, options);
+
+        let cf_address_history_index = ColumnFamilyDescriptor::new(CF_ADDRESS_HISTORY_INDEX, Options::default());
+
         let mut opts = Options::default();
         opts.create_if_missing(config.create_if_missing);
         opts.create_missing_column_families(config.create_missing_column_families);
@@ -144,9 +169,15 @@ impl Storage {
                 cf_snapshot_info,
                 cf_solid_entry_point_to_milestone_index,
                 cf_milestone_index_to_output_diff,
+                cf_milestone_index_to_protocol_parameters,
+                cf_block_id_to_conflict_diagnostic,
                 cf_milestone_index_to_unreferenced_block,
                 cf_milestone_index_to_receipt,
                 cf_spent_to_treasury,
+                cf_padded_tag_to_tagged_block,
+                cf_address_to_history_entry,
+                cf_milestone_index_to_history_address,
+                cf_address_history_index,
             ],
         )?;
 
@@ -221,4 +252,8 @@ impl StorageBackend for Storage {
     fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error> {
         Insert::<u8, System>::insert(self, &SYSTEM_HEALTH_KEY, &System::Health(health))
     }
+
+    fn fetch_edge_limit(&self) -> Option<usize> {
+        Some(self.config.fetch_edge_limit)
+    }
 }
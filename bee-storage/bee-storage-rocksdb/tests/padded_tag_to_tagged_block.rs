@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+mod access;
+
+impl_access_test!(
+    padded_tag_to_tagged_block_access_rocksdb,
+    padded_tag_to_tagged_block_access
+);
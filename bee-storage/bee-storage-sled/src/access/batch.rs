@@ -6,21 +6,26 @@
 use std::{collections::BTreeMap, convert::Infallible};
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{Batch, BatchBuilder},
     backend::StorageBackend,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::{Packable, PackableExt};
@@ -535,6 +540,79 @@ impl Batch<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Batch<MilestoneIndex, ProtocolParameters> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+        parameters: &ProtocolParameters,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        parameters.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .entry(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)
+            .or_default()
+            .insert(batch.key_buf.as_slice(), batch.value_buf.as_slice());
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+
+        batch
+            .inner
+            .entry(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)
+            .or_default()
+            .remove(batch.key_buf.as_slice());
+
+        Ok(())
+    }
+}
+
+impl Batch<BlockId, ConflictDiagnostic> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        block_id: &BlockId,
+        diagnostic: &ConflictDiagnostic,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        diagnostic.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .entry(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)
+            .or_default()
+            .insert(block_id.as_ref(), batch.value_buf.as_slice());
+
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, block_id: &BlockId) -> Result<(), <Self as StorageBackend>::Error> {
+        batch
+            .inner
+            .entry(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)
+            .or_default()
+            .remove(block_id.as_ref());
+
+        Ok(())
+    }
+}
+
 impl Batch<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn batch_insert(
         &self,
@@ -651,3 +729,151 @@ impl Batch<(bool, TreasuryOutput), ()> for Storage {
         Ok(())
     }
 }
+
+impl Batch<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(padded_tag.as_ref());
+        batch.key_buf.extend_from_slice(&tagged_block.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_PADDED_TAG_TO_TAGGED_BLOCK)
+            .or_default()
+            .insert(batch.key_buf.as_slice(), &[]);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(padded_tag.as_ref());
+        batch.key_buf.extend_from_slice(&tagged_block.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_PADDED_TAG_TO_TAGGED_BLOCK)
+            .or_default()
+            .remove(batch.key_buf.as_slice());
+
+        Ok(())
+    }
+}
+
+impl Batch<(Address, AddressHistoryEntry), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Address, AddressHistoryEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+        batch.key_buf.extend_from_slice(&entry.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_ADDRESS_TO_HISTORY_ENTRY)
+            .or_default()
+            .insert(batch.key_buf.as_slice(), &[]);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Address, AddressHistoryEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+        batch.key_buf.extend_from_slice(&entry.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_ADDRESS_TO_HISTORY_ENTRY)
+            .or_default()
+            .remove(batch.key_buf.as_slice());
+
+        Ok(())
+    }
+}
+
+impl Batch<(MilestoneIndex, Address), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (index, address): &(MilestoneIndex, Address),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.pack_to_vec());
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)
+            .or_default()
+            .insert(batch.key_buf.as_slice(), &[]);
+
+        Ok(())
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (index, address): &(MilestoneIndex, Address),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.pack_to_vec());
+        batch.key_buf.extend_from_slice(&address.pack_to_vec());
+
+        batch
+            .inner
+            .entry(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)
+            .or_default()
+            .remove(batch.key_buf.as_slice());
+
+        Ok(())
+    }
+}
+
+impl Batch<(), AddressHistoryIndex> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (): &(),
+        index: &AddressHistoryIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.value_buf).unwrap();
+
+        batch
+            .inner
+            .entry(TREE_ADDRESS_HISTORY_INDEX)
+            .or_default()
+            .insert(&[0x00u8], batch.value_buf.as_slice());
+
+        Ok(())
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        batch
+            .inner
+            .entry(TREE_ADDRESS_HISTORY_INDEX)
+            .or_default()
+            .remove(&[0x00u8]);
+
+        Ok(())
+    }
+}
@@ -4,18 +4,23 @@
 //! Delete access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Delete, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -146,6 +151,26 @@ impl Delete<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Delete<MilestoneIndex, ProtocolParameters> for Storage {
+    fn delete(&self, index: &MilestoneIndex) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?
+            .remove(index.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
+impl Delete<BlockId, ConflictDiagnostic> for Storage {
+    fn delete(&self, block_id: &BlockId) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?
+            .remove(block_id)?;
+
+        Ok(())
+    }
+}
+
 impl Delete<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn delete(
         &self,
@@ -183,3 +208,49 @@ impl Delete<(bool, TreasuryOutput), ()> for Storage {
         Ok(())
     }
 }
+
+impl Delete<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn delete(
+        &self,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = padded_tag.as_ref().to_vec();
+        key.extend_from_slice(&tagged_block.pack_to_vec());
+
+        self.inner.open_tree(TREE_PADDED_TAG_TO_TAGGED_BLOCK)?.remove(key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<(Address, AddressHistoryEntry), ()> for Storage {
+    fn delete(&self, (address, entry): &(Address, AddressHistoryEntry)) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&entry.pack_to_vec());
+
+        self.inner.open_tree(TREE_ADDRESS_TO_HISTORY_ENTRY)?.remove(key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<(MilestoneIndex, Address), ()> for Storage {
+    fn delete(&self, (index, address): &(MilestoneIndex, Address)) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = index.pack_to_vec();
+        key.extend_from_slice(&address.pack_to_vec());
+
+        self.inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?
+            .remove(key)?;
+
+        Ok(())
+    }
+}
+
+impl Delete<(), AddressHistoryIndex> for Storage {
+    fn delete(&self, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner.open_tree(TREE_ADDRESS_HISTORY_INDEX)?.remove([0x00u8])?;
+
+        Ok(())
+    }
+}
@@ -4,18 +4,23 @@
 //! Exist access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Exist, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -136,6 +141,24 @@ impl Exist<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Exist<MilestoneIndex, ProtocolParameters> for Storage {
+    fn exist(&self, index: &MilestoneIndex) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?
+            .contains_key(index.pack_to_vec())?)
+    }
+}
+
+impl Exist<BlockId, ConflictDiagnostic> for Storage {
+    fn exist(&self, block_id: &BlockId) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?
+            .contains_key(block_id)?)
+    }
+}
+
 impl Exist<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn exist(
         &self,
@@ -171,3 +194,51 @@ impl Exist<(bool, TreasuryOutput), ()> for Storage {
         Ok(self.inner.open_tree(TREE_SPENT_TO_TREASURY_OUTPUT)?.contains_key(key)?)
     }
 }
+
+impl Exist<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn exist(
+        &self,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = padded_tag.as_ref().to_vec();
+        key.extend_from_slice(&tagged_block.pack_to_vec());
+
+        Ok(self
+            .inner
+            .open_tree(TREE_PADDED_TAG_TO_TAGGED_BLOCK)?
+            .contains_key(key)?)
+    }
+}
+
+impl Exist<(Address, AddressHistoryEntry), ()> for Storage {
+    fn exist(
+        &self,
+        (address, entry): &(Address, AddressHistoryEntry),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&entry.pack_to_vec());
+
+        Ok(self.inner.open_tree(TREE_ADDRESS_TO_HISTORY_ENTRY)?.contains_key(key)?)
+    }
+}
+
+impl Exist<(MilestoneIndex, Address), ()> for Storage {
+    fn exist(&self, (index, address): &(MilestoneIndex, Address)) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = index.pack_to_vec();
+        key.extend_from_slice(&address.pack_to_vec());
+
+        Ok(self
+            .inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?
+            .contains_key(key)?)
+    }
+}
+
+impl Exist<(), AddressHistoryIndex> for Storage {
+    fn exist(&self, (): &()) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_ADDRESS_HISTORY_INDEX)?
+            .contains_key([0x00u8])?)
+    }
+}
@@ -4,17 +4,23 @@
 //! Fetch access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, AddressHistoryPage, ConsumedOutput,
+    CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, PACKED_ADDRESS_LENGTH,
 };
 use bee_storage::{access::Fetch, backend::StorageBackend, system::System};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock, TaggedBlockPage},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -180,6 +186,28 @@ impl Fetch<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Fetch<MilestoneIndex, ProtocolParameters> for Storage {
+    fn fetch(&self, index: &MilestoneIndex) -> Result<Option<ProtocolParameters>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?
+            .get(index.pack_to_vec())?
+            // Unpacking from storage is fine.
+            .map(|v| ProtocolParameters::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
+
+impl Fetch<BlockId, ConflictDiagnostic> for Storage {
+    fn fetch(&self, block_id: &BlockId) -> Result<Option<ConflictDiagnostic>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?
+            .get(block_id)?
+            // Unpacking from storage is fine.
+            .map(|v| ConflictDiagnostic::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
+
 impl Fetch<MilestoneIndex, Vec<UnreferencedBlock>> for Storage {
     fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<UnreferencedBlock>>, <Self as StorageBackend>::Error> {
         Ok(Some(
@@ -233,3 +261,123 @@ impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
         ))
     }
 }
+
+impl Fetch<PaddedTag, Vec<TaggedBlock>> for Storage {
+    fn fetch(&self, padded_tag: &PaddedTag) -> Result<Option<Vec<TaggedBlock>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_PADDED_TAG_TO_TAGGED_BLOCK)?
+                .scan_prefix(padded_tag)
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, mut tagged_block) = key.split_at(PaddedTag::LENGTH);
+                    // Unpacking from storage is fine.
+                    Ok(TaggedBlock::unpack_unverified(&mut tagged_block).unwrap())
+                })
+                .collect::<Result<Vec<TaggedBlock>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<TaggedBlockPage, Vec<TaggedBlock>> for Storage {
+    fn fetch(&self, page: &TaggedBlockPage) -> Result<Option<Vec<TaggedBlock>>, <Self as StorageBackend>::Error> {
+        let mut key = page.padded_tag().as_ref().to_vec();
+
+        if let Some(start) = page.start() {
+            key.extend_from_slice(&start.pack_to_vec());
+        }
+
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_PADDED_TAG_TO_TAGGED_BLOCK)?
+                .range(key..)
+                .take_while(|result| {
+                    result
+                        .as_ref()
+                        .map_or(true, |(key, _)| key.starts_with(page.padded_tag().as_ref()))
+                })
+                .take(page.limit())
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, mut tagged_block) = key.split_at(PaddedTag::LENGTH);
+                    // Unpacking from storage is fine.
+                    Ok(TaggedBlock::unpack_unverified(&mut tagged_block).unwrap())
+                })
+                .collect::<Result<Vec<TaggedBlock>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<Address, Vec<AddressHistoryEntry>> for Storage {
+    fn fetch(&self, address: &Address) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_ADDRESS_TO_HISTORY_ENTRY)?
+                .scan_prefix(address.pack_to_vec())
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, mut entry) = key.split_at(PACKED_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    Ok(AddressHistoryEntry::unpack_unverified(&mut entry).unwrap())
+                })
+                .collect::<Result<Vec<AddressHistoryEntry>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>> for Storage {
+    fn fetch(
+        &self,
+        page: &AddressHistoryPage,
+    ) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        let prefix = page.address().pack_to_vec();
+        let mut key = prefix.clone();
+
+        if let Some(start) = page.start() {
+            key.extend_from_slice(&start.pack_to_vec());
+        }
+
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_ADDRESS_TO_HISTORY_ENTRY)?
+                .range(key..)
+                .take_while(|result| result.as_ref().map_or(true, |(key, _)| key.starts_with(&prefix)))
+                .take(page.limit())
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, mut entry) = key.split_at(PACKED_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    Ok(AddressHistoryEntry::unpack_unverified(&mut entry).unwrap())
+                })
+                .collect::<Result<Vec<AddressHistoryEntry>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<MilestoneIndex, Vec<Address>> for Storage {
+    fn fetch(&self, index: &MilestoneIndex) -> Result<Option<Vec<Address>>, <Self as StorageBackend>::Error> {
+        Ok(Some(
+            self.inner
+                .open_tree(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?
+                .scan_prefix(index.pack_to_vec())
+                .map(|result| {
+                    let (key, _) = result?;
+                    let (_, mut address) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+                    // Unpacking from storage is fine.
+                    Ok(Address::unpack_unverified(&mut address).unwrap())
+                })
+                .collect::<Result<Vec<Address>, Self::Error>>()?,
+        ))
+    }
+}
+
+impl Fetch<(), AddressHistoryIndex> for Storage {
+    fn fetch(&self, (): &()) -> Result<Option<AddressHistoryIndex>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .open_tree(TREE_ADDRESS_HISTORY_INDEX)?
+            .get([0x00u8])?
+            // Unpacking from storage is fine.
+            .map(|v| AddressHistoryIndex::unpack_unverified(&mut v.as_ref()).unwrap()))
+    }
+}
@@ -4,14 +4,16 @@
 //! Insert access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::info::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::info::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{
     access::{Insert, InsertStrict},
@@ -19,7 +21,10 @@ use bee_storage::{
     system::System,
 };
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 use packable::PackableExt;
@@ -184,6 +189,34 @@ impl Insert<MilestoneIndex, OutputDiff> for Storage {
     }
 }
 
+impl Insert<MilestoneIndex, ProtocolParameters> for Storage {
+    fn insert(
+        &self,
+        index: &MilestoneIndex,
+        parameters: &ProtocolParameters,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS)?
+            .insert(index.pack_to_vec(), parameters.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
+impl Insert<BlockId, ConflictDiagnostic> for Storage {
+    fn insert(
+        &self,
+        block_id: &BlockId,
+        diagnostic: &ConflictDiagnostic,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC)?
+            .insert(block_id, diagnostic.pack_to_vec())?;
+
+        Ok(())
+    }
+}
+
 impl Insert<(MilestoneIndex, UnreferencedBlock), ()> for Storage {
     fn insert(
         &self,
@@ -228,3 +261,62 @@ impl Insert<(bool, TreasuryOutput), ()> for Storage {
         Ok(())
     }
 }
+
+impl Insert<(PaddedTag, TaggedBlock), ()> for Storage {
+    fn insert(
+        &self,
+        (padded_tag, tagged_block): &(PaddedTag, TaggedBlock),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = padded_tag.as_ref().to_vec();
+        key.extend_from_slice(&tagged_block.pack_to_vec());
+
+        self.inner
+            .open_tree(TREE_PADDED_TAG_TO_TAGGED_BLOCK)?
+            .insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(Address, AddressHistoryEntry), ()> for Storage {
+    fn insert(
+        &self,
+        (address, entry): &(Address, AddressHistoryEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = address.pack_to_vec();
+        key.extend_from_slice(&entry.pack_to_vec());
+
+        self.inner.open_tree(TREE_ADDRESS_TO_HISTORY_ENTRY)?.insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(MilestoneIndex, Address), ()> for Storage {
+    fn insert(
+        &self,
+        (index, address): &(MilestoneIndex, Address),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = index.pack_to_vec();
+        key.extend_from_slice(&address.pack_to_vec());
+
+        self.inner
+            .open_tree(TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS)?
+            .insert(key, &[])?;
+
+        Ok(())
+    }
+}
+
+impl Insert<(), AddressHistoryIndex> for Storage {
+    fn insert(&self, (): &(), index: &AddressHistoryIndex) -> Result<(), <Self as StorageBackend>::Error> {
+        self.inner
+            .open_tree(TREE_ADDRESS_HISTORY_INDEX)?
+            .insert([0x00u8], index.pack_to_vec())?;
+
+        Ok(())
+    }
+}
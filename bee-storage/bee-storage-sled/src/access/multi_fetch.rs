@@ -8,6 +8,8 @@ use std::{marker::PhantomData, slice::Iter};
 use bee_block::{
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{ConsumedOutput, CreatedOutput, OutputDiff};
@@ -107,3 +109,9 @@ impl_multi_fetch!(
     TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
 );
 impl_multi_fetch!(MilestoneIndex, OutputDiff, TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_multi_fetch!(
+    MilestoneIndex,
+    ProtocolParameters,
+    TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS
+);
+impl_multi_fetch!(BlockId, ConflictDiagnostic, TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC);
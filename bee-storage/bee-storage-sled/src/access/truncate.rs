@@ -4,17 +4,23 @@
 //! Truncate access operations.
 
 use bee_block::{
-    address::Ed25519Address,
+    address::{Address, Ed25519Address},
     output::OutputId,
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
+    protocol::ProtocolParameters,
+    semantic::ConflictDiagnostic,
     Block, BlockId,
 };
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex,
+    OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_storage::{access::Truncate, backend::StorageBackend};
 use bee_tangle::{
-    block_metadata::BlockMetadata, milestone_metadata::MilestoneMetadata, solid_entry_point::SolidEntryPoint,
+    block_metadata::BlockMetadata,
+    milestone_metadata::MilestoneMetadata,
+    solid_entry_point::SolidEntryPoint,
+    tagged_block::{PaddedTag, TaggedBlock},
     unreferenced_block::UnreferencedBlock,
 };
 
@@ -53,6 +59,12 @@ impl_truncate!(
     TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
 );
 impl_truncate!(MilestoneIndex, OutputDiff, TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_truncate!(
+    MilestoneIndex,
+    ProtocolParameters,
+    TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS
+);
+impl_truncate!(BlockId, ConflictDiagnostic, TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC);
 impl_truncate!(
     (MilestoneIndex, UnreferencedBlock),
     (),
@@ -60,3 +72,7 @@ impl_truncate!(
 );
 impl_truncate!((MilestoneIndex, Receipt), (), TREE_MILESTONE_INDEX_TO_RECEIPT);
 impl_truncate!((bool, TreasuryOutput), (), TREE_SPENT_TO_TREASURY_OUTPUT);
+impl_truncate!((PaddedTag, TaggedBlock), (), TREE_PADDED_TAG_TO_TAGGED_BLOCK);
+impl_truncate!((Address, AddressHistoryEntry), (), TREE_ADDRESS_TO_HISTORY_ENTRY);
+impl_truncate!((MilestoneIndex, Address), (), TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS);
+impl_truncate!((), AddressHistoryIndex, TREE_ADDRESS_HISTORY_INDEX);
@@ -107,4 +107,8 @@ impl StorageBackend for Storage {
     fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error> {
         Insert::<u8, System>::insert(self, &SYSTEM_HEALTH_KEY, &System::Health(health))
     }
+
+    fn fetch_edge_limit(&self) -> Option<usize> {
+        Some(self.config.storage.fetch_edge_limit)
+    }
 }
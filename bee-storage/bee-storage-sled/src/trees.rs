@@ -32,9 +32,21 @@ pub const TREE_SNAPSHOT_INFO: &str = "snapshot_info";
 pub const TREE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX: &str = "solid_entry_point_to_milestone_index";
 /// Identifier for the `MilestoneIndex` to `OutputDiff` tree.
 pub const TREE_MILESTONE_INDEX_TO_OUTPUT_DIFF: &str = "milestone_index_to_output_diff";
+/// Identifier for the `MilestoneIndex` to `ProtocolParameters` tree.
+pub const TREE_MILESTONE_INDEX_TO_PROTOCOL_PARAMETERS: &str = "milestone_index_to_protocol_parameters";
+/// Identifier for the `BlockId` to `ConflictDiagnostic` tree.
+pub const TREE_BLOCK_ID_TO_CONFLICT_DIAGNOSTIC: &str = "block_id_to_conflict_diagnostic";
 /// Identifier for the `MilestoneIndex` to `Vec<UnreferencedBlock>` tree.
 pub const TREE_MILESTONE_INDEX_TO_UNREFERENCED_BLOCK: &str = "milestone_index_to_unreferenced_block";
 /// Identifier for the `MilestoneIndex` to `Vec<Receipt>` tree.
 pub const TREE_MILESTONE_INDEX_TO_RECEIPT: &str = "milestone_index_to_receipt";
 /// Identifier for the `bool` to `Vec<TreasuryOutput>` tree.
 pub const TREE_SPENT_TO_TREASURY_OUTPUT: &str = "spent_to_treasury_output";
+/// Identifier for the `PaddedTag` to `Vec<TaggedBlock>` tree.
+pub const TREE_PADDED_TAG_TO_TAGGED_BLOCK: &str = "padded_tag_to_tagged_block";
+/// Identifier for the `Address` to `Vec<AddressHistoryEntry>` tree.
+pub const TREE_ADDRESS_TO_HISTORY_ENTRY: &str = "address_to_history_entry";
+/// Identifier for the `MilestoneIndex` to `Vec<Address>` tree.
+pub const TREE_MILESTONE_INDEX_TO_HISTORY_ADDRESS: &str = "milestone_index_to_history_address";
+/// Identifier for the `AddressHistoryIndex` tree.
+pub const TREE_ADDRESS_HISTORY_INDEX: &str = "address_history_index";
@@ -0,0 +1,97 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::types::AddressHistoryIndex;
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    backend,
+};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(), AddressHistoryIndex>
+    + Fetch<(), AddressHistoryIndex>
+    + Insert<(), AddressHistoryIndex>
+    + Delete<(), AddressHistoryIndex>
+    + BatchBuilder
+    + Batch<(), AddressHistoryIndex>
+    + for<'a> AsIterator<'a, (), AddressHistoryIndex>
+    + Truncate<(), AddressHistoryIndex>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(), AddressHistoryIndex>
+        + Fetch<(), AddressHistoryIndex>
+        + Insert<(), AddressHistoryIndex>
+        + Delete<(), AddressHistoryIndex>
+        + BatchBuilder
+        + Batch<(), AddressHistoryIndex>
+        + for<'a> AsIterator<'a, (), AddressHistoryIndex>
+        + Truncate<(), AddressHistoryIndex>
+{
+}
+
+pub fn address_history_index_access<B: StorageBackend>(storage: &B) {
+    let index = AddressHistoryIndex::from(MilestoneIndex::from(42));
+
+    assert!(!Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+    assert!(Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap().is_none());
+
+    Insert::<(), AddressHistoryIndex>::insert(storage, &(), &index).unwrap();
+
+    assert!(Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+    assert_eq!(
+        Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap().unwrap(),
+        index
+    );
+
+    Delete::<(), AddressHistoryIndex>::delete(storage, &()).unwrap();
+
+    assert!(!Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+    assert!(Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap().is_none());
+
+    let mut batch = B::batch_begin();
+
+    Batch::<(), AddressHistoryIndex>::batch_insert(storage, &mut batch, &(), &index).unwrap();
+
+    storage.batch_commit(batch, true).unwrap();
+
+    assert!(Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+    assert_eq!(
+        Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap().unwrap(),
+        index
+    );
+
+    let mut batch = B::batch_begin();
+
+    Batch::<(), AddressHistoryIndex>::batch_delete(storage, &mut batch, &()).unwrap();
+
+    storage.batch_commit(batch, true).unwrap();
+
+    assert!(!Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+    assert!(Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap().is_none());
+
+    Insert::<(), AddressHistoryIndex>::insert(storage, &(), &index).unwrap();
+
+    let iter = AsIterator::<(), AddressHistoryIndex>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (_, address_history_index) = result.unwrap();
+        assert_eq!(address_history_index, index);
+        count += 1;
+    }
+
+    assert_eq!(count, 1);
+
+    Truncate::<(), AddressHistoryIndex>::truncate(storage).unwrap();
+
+    assert!(!Exist::<(), AddressHistoryIndex>::exist(storage, &()).unwrap());
+
+    let mut iter = AsIterator::<(), AddressHistoryIndex>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -0,0 +1,144 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use bee_block::address::Address;
+use bee_ledger::types::{AddressHistoryEntry, AddressHistoryPage};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    backend,
+};
+use bee_test::rand::{address::rand_address, address_history::rand_address_history_entry};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(Address, AddressHistoryEntry), ()>
+    + Fetch<Address, Vec<AddressHistoryEntry>>
+    + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+    + Insert<(Address, AddressHistoryEntry), ()>
+    + Delete<(Address, AddressHistoryEntry), ()>
+    + BatchBuilder
+    + Batch<(Address, AddressHistoryEntry), ()>
+    + for<'a> AsIterator<'a, (Address, AddressHistoryEntry), ()>
+    + Truncate<(Address, AddressHistoryEntry), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(Address, AddressHistoryEntry), ()>
+        + Fetch<Address, Vec<AddressHistoryEntry>>
+        + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+        + Insert<(Address, AddressHistoryEntry), ()>
+        + Delete<(Address, AddressHistoryEntry), ()>
+        + BatchBuilder
+        + Batch<(Address, AddressHistoryEntry), ()>
+        + for<'a> AsIterator<'a, (Address, AddressHistoryEntry), ()>
+        + Truncate<(Address, AddressHistoryEntry), ()>
+{
+}
+
+pub fn address_to_history_entry_access<B: StorageBackend>(storage: &B) {
+    let (address, entry) = (rand_address(), rand_address_history_entry());
+
+    assert!(!Exist::<(Address, AddressHistoryEntry), ()>::exist(storage, &(address, entry)).unwrap());
+    assert!(
+        Fetch::<Address, Vec<AddressHistoryEntry>>::fetch(storage, &address)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    Insert::<(Address, AddressHistoryEntry), ()>::insert(storage, &(address, entry), &()).unwrap();
+
+    assert!(Exist::<(Address, AddressHistoryEntry), ()>::exist(storage, &(address, entry)).unwrap());
+    assert_eq!(
+        Fetch::<Address, Vec<AddressHistoryEntry>>::fetch(storage, &address)
+            .unwrap()
+            .unwrap(),
+        vec![entry]
+    );
+
+    Delete::<(Address, AddressHistoryEntry), ()>::delete(storage, &(address, entry)).unwrap();
+
+    assert!(!Exist::<(Address, AddressHistoryEntry), ()>::exist(storage, &(address, entry)).unwrap());
+    assert!(
+        Fetch::<Address, Vec<AddressHistoryEntry>>::fetch(storage, &address)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (address, entry) = (rand_address(), rand_address_history_entry());
+        Insert::<(Address, AddressHistoryEntry), ()>::insert(storage, &(address, entry), &()).unwrap();
+        Batch::<(Address, AddressHistoryEntry), ()>::batch_delete(storage, &mut batch, &(address, entry)).unwrap();
+    }
+
+    let mut history = HashMap::<Address, Vec<AddressHistoryEntry>>::new();
+
+    for _ in 0..5 {
+        let address = rand_address();
+        for _ in 0..5 {
+            let entry = rand_address_history_entry();
+            Batch::<(Address, AddressHistoryEntry), ()>::batch_insert(storage, &mut batch, &(address, entry), &())
+                .unwrap();
+            history.entry(address).or_default().push(entry);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    for (address, entries) in history.iter() {
+        let mut fetched = Fetch::<Address, Vec<AddressHistoryEntry>>::fetch(storage, address)
+            .unwrap()
+            .unwrap();
+        let mut entries = entries.clone();
+
+        fetched.sort();
+        entries.sort();
+
+        assert_eq!(fetched, entries);
+
+        let mut paged = Vec::new();
+        let mut start = None;
+
+        loop {
+            let mut page = Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+                storage,
+                &AddressHistoryPage::new(*address, start, 3),
+            )
+            .unwrap()
+            .unwrap();
+
+            start = if page.len() == 3 { page.pop() } else { None };
+            paged.extend(page);
+
+            if start.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(paged, entries);
+    }
+
+    let iter = AsIterator::<(Address, AddressHistoryEntry), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((address, entry), _) = result.unwrap();
+        assert!(history.get(&address).unwrap().contains(&entry));
+        count += 1;
+    }
+
+    assert_eq!(count, history.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    Truncate::<(Address, AddressHistoryEntry), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<(Address, AddressHistoryEntry), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
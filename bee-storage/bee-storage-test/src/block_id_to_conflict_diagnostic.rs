@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{semantic::ConflictDiagnostic, BlockId};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    backend,
+};
+use bee_test::rand::{block::rand_block_id, semantic::rand_conflict_diagnostic};
+use packable::PackableExt;
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<BlockId, ConflictDiagnostic>
+    + Fetch<BlockId, ConflictDiagnostic>
+    + for<'a> MultiFetch<'a, BlockId, ConflictDiagnostic>
+    + Insert<BlockId, ConflictDiagnostic>
+    + Delete<BlockId, ConflictDiagnostic>
+    + BatchBuilder
+    + Batch<BlockId, ConflictDiagnostic>
+    + for<'a> AsIterator<'a, BlockId, ConflictDiagnostic>
+    + Truncate<BlockId, ConflictDiagnostic>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<BlockId, ConflictDiagnostic>
+        + Fetch<BlockId, ConflictDiagnostic>
+        + for<'a> MultiFetch<'a, BlockId, ConflictDiagnostic>
+        + Insert<BlockId, ConflictDiagnostic>
+        + Delete<BlockId, ConflictDiagnostic>
+        + BatchBuilder
+        + Batch<BlockId, ConflictDiagnostic>
+        + for<'a> AsIterator<'a, BlockId, ConflictDiagnostic>
+        + Truncate<BlockId, ConflictDiagnostic>
+{
+}
+
+pub fn block_id_to_conflict_diagnostic_access<B: StorageBackend>(storage: &B) {
+    let (block_id, diagnostic) = (rand_block_id(), rand_conflict_diagnostic());
+
+    assert!(!Exist::<BlockId, ConflictDiagnostic>::exist(storage, &block_id).unwrap());
+    assert!(
+        Fetch::<BlockId, ConflictDiagnostic>::fetch(storage, &block_id)
+            .unwrap()
+            .is_none()
+    );
+    let results = MultiFetch::<BlockId, ConflictDiagnostic>::multi_fetch(storage, &[block_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    Insert::<BlockId, ConflictDiagnostic>::insert(storage, &block_id, &diagnostic).unwrap();
+
+    assert!(Exist::<BlockId, ConflictDiagnostic>::exist(storage, &block_id).unwrap());
+    assert_eq!(
+        Fetch::<BlockId, ConflictDiagnostic>::fetch(storage, &block_id)
+            .unwrap()
+            .unwrap()
+            .pack_to_vec(),
+        diagnostic.pack_to_vec()
+    );
+    let results = MultiFetch::<BlockId, ConflictDiagnostic>::multi_fetch(storage, &[block_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(Some(v))) if v == &diagnostic));
+
+    Delete::<BlockId, ConflictDiagnostic>::delete(storage, &block_id).unwrap();
+
+    assert!(!Exist::<BlockId, ConflictDiagnostic>::exist(storage, &block_id).unwrap());
+    assert!(
+        Fetch::<BlockId, ConflictDiagnostic>::fetch(storage, &block_id)
+            .unwrap()
+            .is_none()
+    );
+    let results = MultiFetch::<BlockId, ConflictDiagnostic>::multi_fetch(storage, &[block_id])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    let mut batch = B::batch_begin();
+    let mut block_ids = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for _ in 0..10 {
+        let (block_id, diagnostic) = (rand_block_id(), rand_conflict_diagnostic());
+        Insert::<BlockId, ConflictDiagnostic>::insert(storage, &block_id, &diagnostic).unwrap();
+        Batch::<BlockId, ConflictDiagnostic>::batch_delete(storage, &mut batch, &block_id).unwrap();
+        block_ids.push(block_id);
+        diagnostics.push((block_id, None));
+    }
+
+    for _ in 0..10 {
+        let (block_id, diagnostic) = (rand_block_id(), rand_conflict_diagnostic());
+        Batch::<BlockId, ConflictDiagnostic>::batch_insert(storage, &mut batch, &block_id, &diagnostic).unwrap();
+        block_ids.push(block_id);
+        diagnostics.push((block_id, Some(diagnostic)));
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<BlockId, ConflictDiagnostic>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (block_id, diagnostic) = result.unwrap();
+        assert!(diagnostics.contains(&(block_id, Some(diagnostic))));
+        count += 1;
+    }
+
+    assert_eq!(count, 10);
+
+    let results = MultiFetch::<BlockId, ConflictDiagnostic>::multi_fetch(storage, &block_ids)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), block_ids.len());
+
+    for ((_, expected), result) in diagnostics.into_iter().zip(results.into_iter()) {
+        assert_eq!(expected, result.unwrap());
+    }
+
+    Truncate::<BlockId, ConflictDiagnostic>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<BlockId, ConflictDiagnostic>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -3,35 +3,45 @@
 
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 
+mod address_history_index;
+mod address_to_history_entry;
 mod block_id_to_block;
 mod block_id_to_block_id;
+mod block_id_to_conflict_diagnostic;
 mod block_id_to_metadata;
 mod ed25519_address_to_output_id;
 mod ledger_index;
 mod milestone_id_to_milestone_payload;
+mod milestone_index_to_history_address;
 mod milestone_index_to_milestone_metadata;
 mod milestone_index_to_output_diff;
+mod milestone_index_to_protocol_parameters;
 mod milestone_index_to_receipt;
 mod milestone_index_to_unreferenced_block;
 mod output_id_to_consumed_output;
 mod output_id_to_created_output;
 mod output_id_unspent;
+mod padded_tag_to_tagged_block;
 mod snapshot_info;
 mod solid_entry_point_to_milestone_index;
 mod spent_to_treasury_output;
 
 pub use self::{
+    address_history_index::address_history_index_access, address_to_history_entry::address_to_history_entry_access,
     block_id_to_block::block_id_to_block_access, block_id_to_block_id::block_id_to_block_id_access,
+    block_id_to_conflict_diagnostic::block_id_to_conflict_diagnostic_access,
     block_id_to_metadata::block_id_to_metadata_access,
     ed25519_address_to_output_id::ed25519_address_to_output_id_access, ledger_index::ledger_index_access,
     milestone_id_to_milestone_payload::milestone_id_to_milestone_payload_access,
+    milestone_index_to_history_address::milestone_index_to_history_address_access,
     milestone_index_to_milestone_metadata::milestone_index_to_milestone_metadata_access,
     milestone_index_to_output_diff::milestone_index_to_output_diff_access,
+    milestone_index_to_protocol_parameters::milestone_index_to_protocol_parameters_access,
     milestone_index_to_receipt::milestone_index_to_receipt_access,
     milestone_index_to_unreferenced_block::milestone_index_to_unreferenced_block_access,
     output_id_to_consumed_output::output_id_to_consumed_output_access,
     output_id_to_created_output::output_id_to_created_output_access, output_id_unspent::output_id_unspent_access,
-    snapshot_info::snapshot_info_access,
+    padded_tag_to_tagged_block::padded_tag_to_tagged_block_access, snapshot_info::snapshot_info_access,
     solid_entry_point_to_milestone_index::solid_entry_point_to_milestone_index_access,
     spent_to_treasury_output::spent_to_treasury_output_access,
 };
@@ -0,0 +1,119 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use bee_block::{address::Address, payload::milestone::MilestoneIndex};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    backend,
+};
+use bee_test::rand::{address::rand_address, milestone::rand_milestone_index};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(MilestoneIndex, Address), ()>
+    + Fetch<MilestoneIndex, Vec<Address>>
+    + Insert<(MilestoneIndex, Address), ()>
+    + Delete<(MilestoneIndex, Address), ()>
+    + BatchBuilder
+    + Batch<(MilestoneIndex, Address), ()>
+    + for<'a> AsIterator<'a, (MilestoneIndex, Address), ()>
+    + Truncate<(MilestoneIndex, Address), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(MilestoneIndex, Address), ()>
+        + Fetch<MilestoneIndex, Vec<Address>>
+        + Insert<(MilestoneIndex, Address), ()>
+        + Delete<(MilestoneIndex, Address), ()>
+        + BatchBuilder
+        + Batch<(MilestoneIndex, Address), ()>
+        + for<'a> AsIterator<'a, (MilestoneIndex, Address), ()>
+        + Truncate<(MilestoneIndex, Address), ()>
+{
+}
+
+pub fn milestone_index_to_history_address_access<B: StorageBackend>(storage: &B) {
+    let (index, address) = (rand_milestone_index(), rand_address());
+
+    assert!(!Exist::<(MilestoneIndex, Address), ()>::exist(storage, &(index, address)).unwrap());
+    assert!(
+        Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, &index)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    Insert::<(MilestoneIndex, Address), ()>::insert(storage, &(index, address), &()).unwrap();
+
+    assert!(Exist::<(MilestoneIndex, Address), ()>::exist(storage, &(index, address)).unwrap());
+    assert_eq!(
+        Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, &index)
+            .unwrap()
+            .unwrap(),
+        vec![address]
+    );
+
+    Delete::<(MilestoneIndex, Address), ()>::delete(storage, &(index, address)).unwrap();
+
+    assert!(!Exist::<(MilestoneIndex, Address), ()>::exist(storage, &(index, address)).unwrap());
+    assert!(
+        Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, &index)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (index, address) = (rand_milestone_index(), rand_address());
+        Insert::<(MilestoneIndex, Address), ()>::insert(storage, &(index, address), &()).unwrap();
+        Batch::<(MilestoneIndex, Address), ()>::batch_delete(storage, &mut batch, &(index, address)).unwrap();
+    }
+
+    let mut history_addresses = HashMap::<MilestoneIndex, Vec<Address>>::new();
+
+    for _ in 0..5 {
+        let index = rand_milestone_index();
+        for _ in 0..5 {
+            let address = rand_address();
+            Batch::<(MilestoneIndex, Address), ()>::batch_insert(storage, &mut batch, &(index, address), &()).unwrap();
+            history_addresses.entry(index).or_default().push(address);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    for (index, addresses) in history_addresses.iter() {
+        let mut fetched = Fetch::<MilestoneIndex, Vec<Address>>::fetch(storage, index)
+            .unwrap()
+            .unwrap();
+        let mut addresses = addresses.clone();
+
+        fetched.sort();
+        addresses.sort();
+
+        assert_eq!(fetched, addresses);
+    }
+
+    let iter = AsIterator::<(MilestoneIndex, Address), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((index, address), _) = result.unwrap();
+        assert!(history_addresses.get(&index).unwrap().contains(&address));
+        count += 1;
+    }
+
+    assert_eq!(count, history_addresses.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    Truncate::<(MilestoneIndex, Address), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<(MilestoneIndex, Address), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
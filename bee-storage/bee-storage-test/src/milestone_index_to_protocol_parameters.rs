@@ -0,0 +1,132 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{payload::milestone::MilestoneIndex, protocol::ProtocolParameters};
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, MultiFetch, Truncate},
+    backend,
+};
+use bee_test::rand::{milestone::rand_milestone_index, protocol::rand_protocol_parameters};
+use packable::PackableExt;
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<MilestoneIndex, ProtocolParameters>
+    + Fetch<MilestoneIndex, ProtocolParameters>
+    + for<'a> MultiFetch<'a, MilestoneIndex, ProtocolParameters>
+    + Insert<MilestoneIndex, ProtocolParameters>
+    + Delete<MilestoneIndex, ProtocolParameters>
+    + BatchBuilder
+    + Batch<MilestoneIndex, ProtocolParameters>
+    + for<'a> AsIterator<'a, MilestoneIndex, ProtocolParameters>
+    + Truncate<MilestoneIndex, ProtocolParameters>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<MilestoneIndex, ProtocolParameters>
+        + Fetch<MilestoneIndex, ProtocolParameters>
+        + for<'a> MultiFetch<'a, MilestoneIndex, ProtocolParameters>
+        + Insert<MilestoneIndex, ProtocolParameters>
+        + Delete<MilestoneIndex, ProtocolParameters>
+        + BatchBuilder
+        + Batch<MilestoneIndex, ProtocolParameters>
+        + for<'a> AsIterator<'a, MilestoneIndex, ProtocolParameters>
+        + Truncate<MilestoneIndex, ProtocolParameters>
+{
+}
+
+pub fn milestone_index_to_protocol_parameters_access<B: StorageBackend>(storage: &B) {
+    let (index, parameters) = (rand_milestone_index(), rand_protocol_parameters());
+
+    assert!(!Exist::<MilestoneIndex, ProtocolParameters>::exist(storage, &index).unwrap());
+    assert!(
+        Fetch::<MilestoneIndex, ProtocolParameters>::fetch(storage, &index)
+            .unwrap()
+            .is_none()
+    );
+    let results = MultiFetch::<MilestoneIndex, ProtocolParameters>::multi_fetch(storage, &[index])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    Insert::<MilestoneIndex, ProtocolParameters>::insert(storage, &index, &parameters).unwrap();
+
+    assert!(Exist::<MilestoneIndex, ProtocolParameters>::exist(storage, &index).unwrap());
+    assert_eq!(
+        Fetch::<MilestoneIndex, ProtocolParameters>::fetch(storage, &index)
+            .unwrap()
+            .unwrap()
+            .pack_to_vec(),
+        parameters.pack_to_vec()
+    );
+    let results = MultiFetch::<MilestoneIndex, ProtocolParameters>::multi_fetch(storage, &[index])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(Some(v))) if v == &parameters));
+
+    Delete::<MilestoneIndex, ProtocolParameters>::delete(storage, &index).unwrap();
+
+    assert!(!Exist::<MilestoneIndex, ProtocolParameters>::exist(storage, &index).unwrap());
+    assert!(
+        Fetch::<MilestoneIndex, ProtocolParameters>::fetch(storage, &index)
+            .unwrap()
+            .is_none()
+    );
+    let results = MultiFetch::<MilestoneIndex, ProtocolParameters>::multi_fetch(storage, &[index])
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results.get(0), Some(Ok(None))));
+
+    let mut batch = B::batch_begin();
+    let mut indexes = Vec::new();
+    let mut parameters_list = Vec::new();
+
+    for _ in 0..10 {
+        let (index, parameters) = (rand_milestone_index(), rand_protocol_parameters());
+        Insert::<MilestoneIndex, ProtocolParameters>::insert(storage, &index, &parameters).unwrap();
+        Batch::<MilestoneIndex, ProtocolParameters>::batch_delete(storage, &mut batch, &index).unwrap();
+        indexes.push(index);
+        parameters_list.push((index, None));
+    }
+
+    for _ in 0..10 {
+        let (index, parameters) = (rand_milestone_index(), rand_protocol_parameters());
+        Batch::<MilestoneIndex, ProtocolParameters>::batch_insert(storage, &mut batch, &index, &parameters).unwrap();
+        indexes.push(index);
+        parameters_list.push((index, Some(parameters)));
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    let iter = AsIterator::<MilestoneIndex, ProtocolParameters>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let (index, parameters) = result.unwrap();
+        assert!(parameters_list.contains(&(index, Some(parameters))));
+        count += 1;
+    }
+
+    assert_eq!(count, 10);
+
+    let results = MultiFetch::<MilestoneIndex, ProtocolParameters>::multi_fetch(storage, &indexes)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(results.len(), indexes.len());
+
+    for ((_, expected), result) in parameters_list.into_iter().zip(results.into_iter()) {
+        assert_eq!(expected, result.unwrap());
+    }
+
+    Truncate::<MilestoneIndex, ProtocolParameters>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<MilestoneIndex, ProtocolParameters>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -0,0 +1,143 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use bee_storage::{
+    access::{AsIterator, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    backend,
+};
+use bee_tangle::tagged_block::{PaddedTag, TaggedBlock, TaggedBlockPage};
+use bee_test::rand::tagged_block::{rand_padded_tag, rand_tagged_block};
+
+pub trait StorageBackend:
+    backend::StorageBackend
+    + Exist<(PaddedTag, TaggedBlock), ()>
+    + Fetch<PaddedTag, Vec<TaggedBlock>>
+    + Fetch<TaggedBlockPage, Vec<TaggedBlock>>
+    + Insert<(PaddedTag, TaggedBlock), ()>
+    + Delete<(PaddedTag, TaggedBlock), ()>
+    + BatchBuilder
+    + Batch<(PaddedTag, TaggedBlock), ()>
+    + for<'a> AsIterator<'a, (PaddedTag, TaggedBlock), ()>
+    + Truncate<(PaddedTag, TaggedBlock), ()>
+{
+}
+
+impl<T> StorageBackend for T where
+    T: backend::StorageBackend
+        + Exist<(PaddedTag, TaggedBlock), ()>
+        + Fetch<PaddedTag, Vec<TaggedBlock>>
+        + Fetch<TaggedBlockPage, Vec<TaggedBlock>>
+        + Insert<(PaddedTag, TaggedBlock), ()>
+        + Delete<(PaddedTag, TaggedBlock), ()>
+        + BatchBuilder
+        + Batch<(PaddedTag, TaggedBlock), ()>
+        + for<'a> AsIterator<'a, (PaddedTag, TaggedBlock), ()>
+        + Truncate<(PaddedTag, TaggedBlock), ()>
+{
+}
+
+pub fn padded_tag_to_tagged_block_access<B: StorageBackend>(storage: &B) {
+    let (padded_tag, tagged_block) = (rand_padded_tag(), rand_tagged_block());
+
+    assert!(!Exist::<(PaddedTag, TaggedBlock), ()>::exist(storage, &(padded_tag, tagged_block)).unwrap());
+    assert!(
+        Fetch::<PaddedTag, Vec<TaggedBlock>>::fetch(storage, &padded_tag)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    Insert::<(PaddedTag, TaggedBlock), ()>::insert(storage, &(padded_tag, tagged_block), &()).unwrap();
+
+    assert!(Exist::<(PaddedTag, TaggedBlock), ()>::exist(storage, &(padded_tag, tagged_block)).unwrap());
+    assert_eq!(
+        Fetch::<PaddedTag, Vec<TaggedBlock>>::fetch(storage, &padded_tag)
+            .unwrap()
+            .unwrap(),
+        vec![tagged_block]
+    );
+
+    Delete::<(PaddedTag, TaggedBlock), ()>::delete(storage, &(padded_tag, tagged_block)).unwrap();
+
+    assert!(!Exist::<(PaddedTag, TaggedBlock), ()>::exist(storage, &(padded_tag, tagged_block)).unwrap());
+    assert!(
+        Fetch::<PaddedTag, Vec<TaggedBlock>>::fetch(storage, &padded_tag)
+            .unwrap()
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut batch = B::batch_begin();
+
+    for _ in 0..10 {
+        let (padded_tag, tagged_block) = (rand_padded_tag(), rand_tagged_block());
+        Insert::<(PaddedTag, TaggedBlock), ()>::insert(storage, &(padded_tag, tagged_block), &()).unwrap();
+        Batch::<(PaddedTag, TaggedBlock), ()>::batch_delete(storage, &mut batch, &(padded_tag, tagged_block)).unwrap();
+    }
+
+    let mut tagged_blocks = HashMap::<PaddedTag, Vec<TaggedBlock>>::new();
+
+    for _ in 0..5 {
+        let padded_tag = rand_padded_tag();
+        for _ in 0..5 {
+            let tagged_block = rand_tagged_block();
+            Batch::<(PaddedTag, TaggedBlock), ()>::batch_insert(storage, &mut batch, &(padded_tag, tagged_block), &())
+                .unwrap();
+            tagged_blocks.entry(padded_tag).or_default().push(tagged_block);
+        }
+    }
+
+    storage.batch_commit(batch, true).unwrap();
+
+    for (padded_tag, blocks) in tagged_blocks.iter() {
+        let mut fetched = Fetch::<PaddedTag, Vec<TaggedBlock>>::fetch(storage, padded_tag)
+            .unwrap()
+            .unwrap();
+        let mut blocks = blocks.clone();
+
+        fetched.sort();
+        blocks.sort();
+
+        assert_eq!(fetched, blocks);
+
+        let mut paged = Vec::new();
+        let mut start = None;
+
+        loop {
+            let mut page = Fetch::<TaggedBlockPage, Vec<TaggedBlock>>::fetch(
+                storage,
+                &TaggedBlockPage::new(*padded_tag, start, 3),
+            )
+            .unwrap()
+            .unwrap();
+
+            start = if page.len() == 3 { page.pop() } else { None };
+            paged.extend(page);
+
+            if start.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(paged, blocks);
+    }
+
+    let iter = AsIterator::<(PaddedTag, TaggedBlock), ()>::iter(storage).unwrap();
+    let mut count = 0;
+
+    for result in iter {
+        let ((padded_tag, tagged_block), _) = result.unwrap();
+        assert!(tagged_blocks.get(&padded_tag).unwrap().contains(&tagged_block));
+        count += 1;
+    }
+
+    assert_eq!(count, tagged_blocks.iter().fold(0, |acc, v| acc + v.1.len()));
+
+    Truncate::<(PaddedTag, TaggedBlock), ()>::truncate(storage).unwrap();
+
+    let mut iter = AsIterator::<(PaddedTag, TaggedBlock), ()>::iter(storage).unwrap();
+
+    assert!(iter.next().is_none());
+}
@@ -35,4 +35,10 @@ pub trait StorageBackend: Send + Sized + Sync + 'static {
     /// Sets the health status of the database.
     /// Not all backends may be able to provide this operation.
     fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error>;
+
+    /// Returns the maximum number of edges returned by a single fetch of the children of a block.
+    /// Backends that return all the children don't have such a limit.
+    fn fetch_edge_limit(&self) -> Option<usize> {
+        None
+    }
 }
@@ -3,8 +3,11 @@
 
 use bee_block::{semantic::ConflictReason, Block, BlockId};
 use bee_runtime::resource::ResourceHandle;
+use bee_storage::backend::StorageBackend as _;
+use bee_storage_memory::Storage as MemoryStorage;
 use bee_storage_null::Storage as NullStorage;
-use bee_tangle::{block_metadata::BlockMetadata, config::TangleConfig, Tangle};
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, storage::Storage as RocksDbStorage};
+use bee_tangle::{block_metadata::BlockMetadata, config::TangleConfig, storage::StorageBackend, Tangle};
 use bee_test::rand::{block::rand_block, block_metadata::rand_block_metadata, number::rand_number};
 use criterion::*;
 use rand::seq::SliceRandom;
@@ -17,10 +20,12 @@ fn random_input() -> (Block, BlockId, BlockMetadata) {
 }
 
 fn update_metadata(tangle: &Tangle<NullStorage>, id: &BlockId, timestamp: u32) {
-    tangle.update_metadata(id, |metadata| {
-        metadata.set_conflict(ConflictReason::InputUtxoAlreadySpent);
-        metadata.reference(timestamp);
-    });
+    tangle
+        .update_metadata(id, |metadata| {
+            metadata.set_conflict(ConflictReason::InputUtxoAlreadySpent);
+            metadata.reference(timestamp);
+        })
+        .unwrap();
 }
 
 fn insert_bench(c: &mut Criterion) {
@@ -46,7 +51,7 @@ fn update_metadata_bench(c: &mut Criterion) {
     let mut ids = vec![];
 
     for (block, id, metadata) in data {
-        tangle.insert(&block, &id, &metadata);
+        tangle.insert(&block, &id, &metadata).unwrap();
         ids.push(id);
     }
 
@@ -59,5 +64,97 @@ fn update_metadata_bench(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, insert_bench, update_metadata_bench);
+const ROCKSDB_PATH: &str = "./benches/database";
+
+/// Number of blocks the lookups are performed on, they all fit in the default cache.
+const LOOKUP_BLOCKS: usize = 1000;
+
+fn lookup_bench<B: StorageBackend>(c: &mut Criterion, backend: &str, mut storage: impl FnMut() -> B) {
+    for (cache, capacity) in [
+        ("uncached", 0),
+        ("cached", TangleConfig::build().finish().cache_capacity()),
+    ] {
+        let config = TangleConfig::build().with_cache_capacity(capacity).finish();
+        let tangle = Tangle::new(config, ResourceHandle::new(storage()));
+
+        let mut ids = Vec::with_capacity(LOOKUP_BLOCKS);
+
+        for (block, id, metadata) in (0..LOOKUP_BLOCKS).map(|_| random_input()) {
+            tangle.insert(&block, &id, &metadata).unwrap();
+            ids.push(id);
+        }
+
+        let mut group = c.benchmark_group(format!("{}/{}", backend, cache));
+
+        group.bench_function("get", |b| {
+            b.iter_batched(
+                || ids.choose(&mut rand::thread_rng()).unwrap(),
+                |id| tangle.get(id),
+                BatchSize::SmallInput,
+            );
+        });
+        group.bench_function("get_metadata", |b| {
+            b.iter_batched(
+                || ids.choose(&mut rand::thread_rng()).unwrap(),
+                |id| tangle.get_metadata(id),
+                BatchSize::SmallInput,
+            );
+        });
+        group.bench_function("get_children", |b| {
+            b.iter_batched(
+                || ids.choose(&mut rand::thread_rng()).unwrap(),
+                |id| tangle.get_children(id),
+                BatchSize::SmallInput,
+            );
+        });
+        group.bench_function("contains", |b| {
+            b.iter_batched(
+                || ids.choose(&mut rand::thread_rng()).unwrap(),
+                |id| tangle.contains(id),
+                BatchSize::SmallInput,
+            );
+        });
+        group.bench_function("update_metadata", |b| {
+            b.iter_batched(
+                || (ids.choose(&mut rand::thread_rng()).unwrap(), rand_number::<u32>()),
+                |(id, timestamp)| {
+                    tangle
+                        .update_metadata(id, |metadata| metadata.reference(timestamp))
+                        .unwrap();
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.finish();
+    }
+}
+
+fn memory_lookup_bench(c: &mut Criterion) {
+    lookup_bench(c, "memory", || MemoryStorage::start(()).unwrap());
+}
+
+fn rocksdb_lookup_bench(c: &mut Criterion) {
+    let mut runs = 0;
+
+    lookup_bench(c, "rocksdb", || {
+        let path = format!("{}/{}", ROCKSDB_PATH, runs);
+        let _ = std::fs::remove_dir_all(&path);
+        runs += 1;
+
+        let config = RocksDbConfigBuilder::default().with_path(path).finish();
+
+        RocksDbStorage::start(config).unwrap()
+    });
+
+    let _ = std::fs::remove_dir_all(ROCKSDB_PATH);
+}
+
+criterion_group!(
+    benches,
+    insert_bench,
+    update_metadata_bench,
+    memory_lookup_bench,
+    rocksdb_lookup_bench
+);
 criterion_main!(benches);
@@ -0,0 +1,435 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use bee_block::{Block, BlockId};
+use lru::LruCache;
+
+use crate::block_metadata::BlockMetadata;
+
+/// Hit and miss counters of the tangle cache.
+#[derive(Default, Debug)]
+pub struct CacheMetrics {
+    block_hits: AtomicU64,
+    block_misses: AtomicU64,
+    metadata_hits: AtomicU64,
+    metadata_misses: AtomicU64,
+    children_hits: AtomicU64,
+    children_misses: AtomicU64,
+}
+
+impl CacheMetrics {
+    /// Returns the number of block lookups served by the cache.
+    pub fn block_hits(&self) -> u64 {
+        self.block_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of block lookups that went to the storage.
+    pub fn block_misses(&self) -> u64 {
+        self.block_misses.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of metadata lookups served by the cache.
+    pub fn metadata_hits(&self) -> u64 {
+        self.metadata_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of metadata lookups that went to the storage.
+    pub fn metadata_misses(&self) -> u64 {
+        self.metadata_misses.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of children lookups served by the cache.
+    pub fn children_hits(&self) -> u64 {
+        self.children_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of children lookups that went to the storage.
+    pub fn children_misses(&self) -> u64 {
+        self.children_misses.load(Ordering::Relaxed)
+    }
+}
+
+fn count(counter: &AtomicU64) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A size-bounded least-recently-used map, disabled if its capacity is zero.
+struct Lru<K: Hash + Eq, V>(Option<Mutex<LruCache<K, V>>>);
+
+impl<K: Hash + Eq, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self((capacity > 0).then(|| Mutex::new(LruCache::new(capacity))))
+    }
+
+    fn lock(&self) -> Option<MutexGuard<'_, LruCache<K, V>>> {
+        self.0.as_ref().map(|lru| lru.lock().expect("poisoned lock"))
+    }
+}
+
+/// The storage accesses in progress for a key.
+#[derive(Default)]
+struct Access {
+    /// The number of fetches and writes in progress.
+    pending: usize,
+    /// The number of writes that finished since the first pending access started.
+    generation: u64,
+}
+
+/// A least-recently-used map of values that change, which tracks the storage accesses in progress for its keys.
+struct Entries<V> {
+    lru: LruCache<BlockId, V>,
+    accesses: HashMap<BlockId, Access>,
+}
+
+impl<V> Entries<V> {
+    /// Registers a storage access for the key and returns the generation it started in.
+    fn begin(&mut self, key: BlockId) -> u64 {
+        let access = self.accesses.entry(key).or_default();
+        access.pending += 1;
+        access.generation
+    }
+
+    /// Ends a storage access that started in `generation`. Returns whether no other write of the key finished in the
+    /// meantime, in which case the value seen by the access is the latest one and can be cached.
+    fn end(&mut self, key: &BlockId, generation: u64, write: bool) -> bool {
+        let access = self.accesses.get_mut(key).expect("storage access was not registered");
+
+        if write {
+            access.generation += 1;
+        }
+        let latest = access.generation == generation + write as u64;
+
+        access.pending -= 1;
+        if access.pending == 0 {
+            self.accesses.remove(key);
+        }
+
+        latest
+    }
+
+    /// Drops the value of the key, including the one a storage access in progress would cache.
+    fn invalidate(&mut self, key: &BlockId) {
+        self.lru.pop(key);
+        if let Some(access) = self.accesses.get_mut(key) {
+            access.generation += 1;
+        }
+    }
+}
+
+/// A size-bounded map of values that change, disabled if its capacity is zero.
+struct Tracked<V>(Option<Mutex<Entries<V>>>);
+
+impl<V> Tracked<V> {
+    fn new(capacity: usize) -> Self {
+        Self((capacity > 0).then(|| {
+            Mutex::new(Entries {
+                lru: LruCache::new(capacity),
+                accesses: HashMap::new(),
+            })
+        }))
+    }
+
+    fn lock(&self) -> Option<MutexGuard<'_, Entries<V>>> {
+        self.0.as_ref().map(|entries| entries.lock().expect("poisoned lock"))
+    }
+}
+
+/// An in-memory cache of the most recently accessed blocks, block metadata and children.
+///
+/// The storage is never accessed while holding the lock of a map. Blocks never change once inserted, so they can be
+/// cached as fetched. Metadata and children are only cached if no write of the same key finished while they were
+/// fetched or written, so that a concurrent update can not be overwritten by a stale value. Nothing is cached if
+/// accessing the storage fails.
+pub(crate) struct TangleCache {
+    blocks: Lru<BlockId, Block>,
+    metadata: Tracked<BlockMetadata>,
+    children: Tracked<Vec<BlockId>>,
+    metrics: CacheMetrics,
+}
+
+impl TangleCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            blocks: Lru::new(capacity),
+            metadata: Tracked::new(capacity),
+            children: Tracked::new(capacity),
+            metrics: CacheMetrics::default(),
+        }
+    }
+
+    pub(crate) fn metrics(&self) -> &CacheMetrics {
+        &self.metrics
+    }
+
+    pub(crate) fn contains_block(&self, block_id: &BlockId) -> bool {
+        let hit = self.blocks.lock().map_or(false, |blocks| blocks.contains(block_id));
+
+        count(if hit {
+            &self.metrics.block_hits
+        } else {
+            &self.metrics.block_misses
+        });
+
+        hit
+    }
+
+    pub(crate) fn get_block<E>(
+        &self,
+        block_id: &BlockId,
+        fetch: impl FnOnce() -> Result<Option<Block>, E>,
+    ) -> Result<Option<Block>, E> {
+        if let Some(block) = self.blocks.lock().and_then(|mut blocks| blocks.get(block_id).cloned()) {
+            count(&self.metrics.block_hits);
+            return Ok(Some(block));
+        }
+
+        count(&self.metrics.block_misses);
+
+        let block = match fetch()? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        self.insert_block(*block_id, block.clone());
+
+        Ok(Some(block))
+    }
+
+    pub(crate) fn insert_block(&self, block_id: BlockId, block: Block) {
+        if let Some(mut blocks) = self.blocks.lock() {
+            blocks.put(block_id, block);
+        }
+    }
+
+    pub(crate) fn get_metadata<E>(
+        &self,
+        block_id: &BlockId,
+        fetch: impl FnOnce() -> Result<Option<BlockMetadata>, E>,
+    ) -> Result<Option<BlockMetadata>, E> {
+        get_or_fetch(
+            &self.metadata,
+            block_id,
+            fetch,
+            &self.metrics.metadata_hits,
+            &self.metrics.metadata_misses,
+        )
+    }
+
+    /// Applies an update of the metadata to the storage through `update`, then caches the updated metadata it returns.
+    /// The cached metadata is dropped instead if the update fails, since the storage may have been partially updated,
+    /// or if another update of the same metadata finished concurrently, since their order is unknown.
+    pub(crate) fn update_metadata<R, E>(
+        &self,
+        block_id: &BlockId,
+        update: impl FnOnce() -> Result<Option<(R, BlockMetadata)>, E>,
+    ) -> Result<Option<R>, E> {
+        let generation = self.metadata.lock().map(|mut metadata| metadata.begin(*block_id));
+
+        let result = update();
+
+        if let (Some(generation), Some(mut metadata)) = (generation, self.metadata.lock()) {
+            let latest = metadata.end(block_id, generation, true);
+
+            match &result {
+                Ok(Some((_, updated))) if latest => {
+                    metadata.lru.put(*block_id, *updated);
+                }
+                _ => {
+                    metadata.lru.pop(block_id);
+                }
+            }
+        }
+
+        result.map(|updated| updated.map(|(output, _)| output))
+    }
+
+    /// Runs `insert`, then drops the metadata of the block and the children of its parents from the cache since they
+    /// may have been changed. Fetches of them that are in progress meanwhile are not cached.
+    pub(crate) fn invalidate_on_insert<E>(
+        &self,
+        block_id: &BlockId,
+        parents: &[BlockId],
+        insert: impl FnOnce() -> Result<(), E>,
+    ) -> Result<(), E> {
+        let metadata_generation = self.metadata.lock().map(|mut metadata| metadata.begin(*block_id));
+        let children_generations = self
+            .children
+            .lock()
+            .map(|mut children| parents.iter().map(|parent| children.begin(*parent)).collect::<Vec<_>>());
+
+        let result = insert();
+
+        if let (Some(generation), Some(mut metadata)) = (metadata_generation, self.metadata.lock()) {
+            metadata.end(block_id, generation, true);
+            metadata.lru.pop(block_id);
+        }
+        if let (Some(generations), Some(mut children)) = (children_generations, self.children.lock()) {
+            for (parent, generation) in parents.iter().zip(generations) {
+                children.end(parent, generation, true);
+                children.lru.pop(parent);
+            }
+        }
+
+        result
+    }
+
+    pub(crate) fn get_children<E>(
+        &self,
+        block_id: &BlockId,
+        fetch: impl FnOnce() -> Result<Option<Vec<BlockId>>, E>,
+    ) -> Result<Option<Vec<BlockId>>, E> {
+        get_or_fetch(
+            &self.children,
+            block_id,
+            fetch,
+            &self.metrics.children_hits,
+            &self.metrics.children_misses,
+        )
+    }
+
+    pub(crate) fn remove(&self, block_id: &BlockId) {
+        if let Some(mut blocks) = self.blocks.lock() {
+            blocks.pop(block_id);
+        }
+        if let Some(mut metadata) = self.metadata.lock() {
+            metadata.invalidate(block_id);
+        }
+        if let Some(mut children) = self.children.lock() {
+            children.invalidate(block_id);
+        }
+    }
+}
+
+fn get_or_fetch<V: Clone, E>(
+    tracked: &Tracked<V>,
+    block_id: &BlockId,
+    fetch: impl FnOnce() -> Result<Option<V>, E>,
+    hits: &AtomicU64,
+    misses: &AtomicU64,
+) -> Result<Option<V>, E> {
+    let generation = match tracked.lock() {
+        Some(mut entries) => {
+            if let Some(value) = entries.lru.get(block_id) {
+                count(hits);
+                return Ok(Some(value.clone()));
+            }
+            entries.begin(*block_id)
+        }
+        None => {
+            count(misses);
+            return fetch();
+        }
+    };
+
+    count(misses);
+
+    let result = fetch();
+
+    if let Some(mut entries) = tracked.lock() {
+        // A write that finished while fetching may have made the fetched value stale.
+        if entries.end(block_id, generation, false) {
+            if let Ok(Some(value)) = &result {
+                entries.lru.put(*block_id, value.clone());
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use bee_test::rand::block::rand_block_id;
+
+    use super::*;
+
+    fn referenced(timestamp: u32) -> BlockMetadata {
+        let mut metadata = BlockMetadata::default();
+        metadata.reference(timestamp);
+        metadata
+    }
+
+    #[test]
+    fn fetch_interleaved_with_an_update_is_not_cached() {
+        let cache = TangleCache::new(16);
+        let block_id = rand_block_id();
+
+        let fetched = cache
+            .get_metadata(&block_id, || {
+                // The update finishes after the fetch read the storage but before it could cache the stale value.
+                cache
+                    .update_metadata(&block_id, || Ok::<_, ()>(Some(((), referenced(42)))))
+                    .unwrap();
+                Ok::<_, ()>(Some(BlockMetadata::default()))
+            })
+            .unwrap();
+        assert_eq!(fetched, Some(BlockMetadata::default()));
+
+        let cached = cache
+            .get_metadata(&block_id, || -> Result<_, ()> { panic!("metadata should be cached") })
+            .unwrap();
+        assert_eq!(cached, Some(referenced(42)));
+    }
+
+    #[test]
+    fn fetch_interleaved_with_an_insert_is_not_cached() {
+        let cache = TangleCache::new(16);
+        let parent_id = rand_block_id();
+
+        cache
+            .get_children(&parent_id, || {
+                cache
+                    .invalidate_on_insert(&rand_block_id(), &[parent_id], || Ok::<_, ()>(()))
+                    .unwrap();
+                Ok::<_, ()>(Some(Vec::new()))
+            })
+            .unwrap();
+        cache.get_children(&parent_id, || Ok::<_, ()>(None)).unwrap();
+
+        assert_eq!(cache.metrics().children_hits(), 0);
+        assert_eq!(cache.metrics().children_misses(), 2);
+    }
+
+    #[test]
+    fn concurrent_updates_drop_the_cached_metadata() {
+        let cache = TangleCache::new(16);
+        let block_id = rand_block_id();
+
+        cache
+            .update_metadata(&block_id, || {
+                cache
+                    .update_metadata(&block_id, || Ok::<_, ()>(Some(((), referenced(1)))))
+                    .unwrap();
+                Ok::<_, ()>(Some(((), referenced(2))))
+            })
+            .unwrap();
+
+        // The order in which the storage applied the updates is unknown, so none of them is cached.
+        cache.get_metadata(&block_id, || Ok::<_, ()>(None)).unwrap();
+        assert_eq!(cache.metrics().metadata_misses(), 1);
+    }
+
+    #[test]
+    fn removal_interleaved_with_a_fetch_is_not_cached() {
+        let cache = TangleCache::new(16);
+        let block_id = rand_block_id();
+
+        cache
+            .get_metadata(&block_id, || {
+                cache.remove(&block_id);
+                Ok::<_, ()>(Some(BlockMetadata::default()))
+            })
+            .unwrap();
+        cache.get_metadata(&block_id, || Ok::<_, ()>(None)).unwrap();
+
+        assert_eq!(cache.metrics().metadata_misses(), 2);
+    }
+}
@@ -4,6 +4,7 @@
 use serde::Deserialize;
 
 const DEFAULT_BELOW_MAX_DEPTH: u32 = 15;
+const DEFAULT_CACHE_CAPACITY: usize = 50_000;
 
 /// A builder type for a tangle configuration.
 #[derive(Default, Deserialize, PartialEq)]
@@ -11,6 +12,8 @@ const DEFAULT_BELOW_MAX_DEPTH: u32 = 15;
 pub struct TangleConfigBuilder {
     #[serde(alias = "belowMaxDepth")]
     below_max_depth: Option<u32>,
+    #[serde(alias = "cacheCapacity")]
+    cache_capacity: Option<usize>,
 }
 
 impl TangleConfigBuilder {
@@ -19,11 +22,18 @@ impl TangleConfigBuilder {
         Self::default()
     }
 
+    /// Set the number of blocks, block metadata and children lists kept in memory, `0` disables the cache.
+    pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity.replace(cache_capacity);
+        self
+    }
+
     /// Finish building tangle configuration, to create a [`TangleConfig`].
     #[must_use]
     pub fn finish(self) -> TangleConfig {
         TangleConfig {
             below_max_depth: self.below_max_depth.unwrap_or(DEFAULT_BELOW_MAX_DEPTH),
+            cache_capacity: self.cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY),
         }
     }
 }
@@ -32,6 +42,7 @@ impl TangleConfigBuilder {
 #[derive(Clone)]
 pub struct TangleConfig {
     below_max_depth: u32,
+    cache_capacity: usize,
 }
 
 impl TangleConfig {
@@ -44,4 +55,9 @@ impl TangleConfig {
     pub fn below_max_depth(&self) -> u32 {
         self.below_max_depth
     }
+
+    /// Get the value of `cache_capacity`.
+    pub fn cache_capacity(&self) -> usize {
+        self.cache_capacity
+    }
 }
@@ -0,0 +1,10 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors occurring while accessing the tangle.
+#[derive(Debug, thiserror::Error)]
+pub enum TangleError {
+    /// A storage operation failed.
+    #[error("storage operation failed: {0}")]
+    Storage(Box<dyn std::error::Error + Send>),
+}
@@ -8,8 +8,12 @@
 
 /// Block metadata, including block flags.
 pub mod block_metadata;
+/// The in-memory cache of recently accessed tangle data.
+pub mod cache;
 /// Types used for tangle configuration.
 pub mod config;
+/// Errors occurring while accessing the tangle.
+pub mod error;
 /// Types that represent tangle events.
 pub mod event;
 /// Block flags.
@@ -20,23 +24,25 @@ pub mod milestone_metadata;
 pub mod solid_entry_point;
 /// Types used for interoperation with a node's storage layer.
 pub mod storage;
+/// Types used to represent tagged blocks.
+pub mod tagged_block;
 /// Milestone-enabled tangle type.
 pub mod tangle;
 /// The overall `TangleWorker` type. Used as part of the bee runtime in a node.
 pub mod tangle_worker;
 /// A worker that periodically cleans the tip pool.
 pub mod tip_pool_cleaner_worker;
-/// Common tangle traversal functionality.
-pub mod traversal;
 /// Types used to represent unreferenced blocks.
 pub mod unreferenced_block;
 /// The URTS tips pool.
 pub mod urts;
+/// Iterators walking through the past and future cones of the tangle.
+pub mod walker;
 
 use bee_runtime::node::{Node, NodeBuilder};
 
 use self::tip_pool_cleaner_worker::TipPoolCleanerWorker;
-pub use self::{tangle::Tangle, tangle_worker::TangleWorker};
+pub use self::{error::TangleError, tangle::Tangle, tangle_worker::TangleWorker};
 
 /// Initiate the tangle on top of the given node builder.
 pub fn init<N: Node>(tangle_config: &config::TangleConfig, node_builder: N::Builder) -> N::Builder
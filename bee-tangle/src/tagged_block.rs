@@ -0,0 +1,189 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use bee_block::{
+    payload::{milestone::MilestoneIndex, tagged_data::TaggedDataPayload, transaction::TransactionEssence, Payload},
+    Block, BlockId,
+};
+use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+
+/// The tag of a tagged data payload, prefixed with its length and padded with zeroes to a fixed length.
+///
+/// The fixed length allows storage backends to use a padded tag as the prefix of a key.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, packable::Packable)]
+pub struct PaddedTag([u8; PaddedTag::LENGTH]);
+
+impl PaddedTag {
+    /// The length of a [`PaddedTag`].
+    pub const LENGTH: usize = 1 + *TaggedDataPayload::TAG_LENGTH_RANGE.end() as usize;
+
+    /// Creates a new [`PaddedTag`], or returns `None` if the tag is longer than the tag of a tagged data payload may
+    /// be.
+    pub fn new(tag: &[u8]) -> Option<Self> {
+        if tag.len() > *TaggedDataPayload::TAG_LENGTH_RANGE.end() as usize {
+            return None;
+        }
+
+        let mut bytes = [0u8; Self::LENGTH];
+        bytes[0] = tag.len() as u8;
+        bytes[1..=tag.len()].copy_from_slice(tag);
+
+        Some(Self(bytes))
+    }
+
+    /// Returns the padded tag of a block, if the block carries a tagged data payload, either directly or within the
+    /// essence of a transaction payload.
+    pub fn from_block(block: &Block) -> Option<Self> {
+        match block.payload() {
+            Some(Payload::TaggedData(tagged_data)) => Some(Self::from(&**tagged_data)),
+            Some(Payload::Transaction(transaction)) => {
+                let TransactionEssence::Regular(essence) = transaction.essence();
+
+                match essence.payload() {
+                    Some(Payload::TaggedData(tagged_data)) => Some(Self::from(&**tagged_data)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the unpadded tag.
+    pub fn tag(&self) -> &[u8] {
+        &self.0[1..=self.0[0] as usize]
+    }
+}
+
+impl From<&TaggedDataPayload> for PaddedTag {
+    fn from(tagged_data: &TaggedDataPayload) -> Self {
+        // SAFETY: unwrapping is fine because the tag of a tagged data payload has a valid length.
+        Self::new(tagged_data.tag()).unwrap()
+    }
+}
+
+impl AsRef<[u8]> for PaddedTag {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A block carrying a tagged data payload, along with the index of the milestone that confirmed it.
+///
+/// Tagged blocks are ordered by milestone index first, so that the blocks of a tag can be listed oldest first.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TaggedBlock {
+    milestone_index: MilestoneIndex,
+    block_id: BlockId,
+}
+
+impl TaggedBlock {
+    /// Creates a new [`TaggedBlock`].
+    pub fn new(milestone_index: MilestoneIndex, block_id: BlockId) -> Self {
+        Self {
+            milestone_index,
+            block_id,
+        }
+    }
+
+    /// Returns the index of the milestone that confirmed the block.
+    pub fn milestone_index(&self) -> MilestoneIndex {
+        self.milestone_index
+    }
+
+    /// Returns the ID of the block.
+    pub fn block_id(&self) -> &BlockId {
+        &self.block_id
+    }
+}
+
+// The milestone index is packed big-endian so that packed tagged blocks sort like tagged blocks do, which allows
+// storage backends to seek to a tagged block within the blocks of a tag.
+impl Packable for TaggedBlock {
+    type UnpackError = Infallible;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.milestone_index.to_be_bytes().pack(packer)?;
+        self.block_id.pack(packer)
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let milestone_index = MilestoneIndex(u32::from_be_bytes(<[u8; 4]>::unpack::<_, VERIFY>(unpacker)?));
+        let block_id = BlockId::unpack::<_, VERIFY>(unpacker)?;
+
+        Ok(Self {
+            milestone_index,
+            block_id,
+        })
+    }
+}
+
+/// A page of the tagged blocks of a tag, in the order of [`TaggedBlock`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TaggedBlockPage {
+    padded_tag: PaddedTag,
+    start: Option<TaggedBlock>,
+    limit: usize,
+}
+
+impl TaggedBlockPage {
+    /// Creates a new [`TaggedBlockPage`] of at most `limit` tagged blocks, starting from the first tagged block that is
+    /// not less than `start`, or from the first tagged block of the tag if there is no `start`.
+    pub fn new(padded_tag: PaddedTag, start: Option<TaggedBlock>, limit: usize) -> Self {
+        Self {
+            padded_tag,
+            start,
+            limit,
+        }
+    }
+
+    /// Returns the padded tag of the page.
+    pub fn padded_tag(&self) -> &PaddedTag {
+        &self.padded_tag
+    }
+
+    /// Returns the tagged block the page starts from, if any.
+    pub fn start(&self) -> Option<&TaggedBlock> {
+        self.start.as_ref()
+    }
+
+    /// Returns the maximum number of tagged blocks of the page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use packable::PackableExt;
+
+    use super::*;
+
+    #[test]
+    fn packed_tagged_blocks_sort_like_tagged_blocks() {
+        let mut tagged_blocks = [0u32, 1, 255, 256, 65_536, u32::MAX - 1]
+            .iter()
+            .flat_map(|index| {
+                [[0u8; BlockId::LENGTH], [0xff; BlockId::LENGTH]]
+                    .map(|bytes| TaggedBlock::new(MilestoneIndex(*index), BlockId::new(bytes)))
+            })
+            .collect::<Vec<_>>();
+        tagged_blocks.reverse();
+
+        let mut packed = tagged_blocks.iter().map(PackableExt::pack_to_vec).collect::<Vec<_>>();
+
+        tagged_blocks.sort();
+        packed.sort();
+
+        assert_eq!(
+            packed
+                .iter()
+                .map(|bytes| TaggedBlock::unpack_verified(bytes).unwrap())
+                .collect::<Vec<_>>(),
+            tagged_blocks
+        );
+    }
+}
@@ -1,29 +1,35 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 
 use bee_block::{
     payload::milestone::{MilestoneId, MilestoneIndex, MilestonePayload},
     Block, BlockId,
 };
 use bee_runtime::resource::ResourceHandle;
+use bee_storage::system::StorageHealth;
 use hashbrown::HashMap;
-use log::warn;
+use log::{error, warn};
 use ref_cast::RefCast;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 
 use crate::{
     block_metadata::{BlockMetadata, IndexId},
+    cache::{CacheMetrics, TangleCache},
     config::TangleConfig,
+    error::TangleError,
     milestone_metadata::MilestoneMetadata,
     solid_entry_point::SolidEntryPoint,
     storage::StorageBackend,
     urts::UrtsTipPool,
+    walker::{TangleWalkerBuilder, WalkDirection},
 };
 
 const SYNCED_THRESHOLD: u32 = 2;
 const CONFIRMED_THRESHOLD: u32 = 2;
+/// The number of storage operations that have to fail in a row before the storage is considered corrupted.
+const STORAGE_FAILURE_THRESHOLD: usize = 3;
 
 /// A Tangle wrapper designed to encapsulate milestone state.
 pub struct Tangle<B> {
@@ -37,6 +43,10 @@ pub struct Tangle<B> {
     pruning_index: AtomicU32,
     entry_point_index: AtomicU32,
     tip_pool: Mutex<UrtsTipPool>,
+    cache: TangleCache,
+    storage_failures: AtomicUsize,
+    storage_failed: AtomicBool,
+    storage_failure_notify: Notify,
 }
 
 impl<B: StorageBackend> Tangle<B> {
@@ -52,6 +62,10 @@ impl<B: StorageBackend> Tangle<B> {
             pruning_index: Default::default(),
             entry_point_index: Default::default(),
             tip_pool: Mutex::new(UrtsTipPool::new(&config)),
+            cache: TangleCache::new(config.cache_capacity()),
+            storage_failures: Default::default(),
+            storage_failed: Default::default(),
+            storage_failure_notify: Notify::new(),
             config,
         }
     }
@@ -66,23 +80,82 @@ impl<B: StorageBackend> Tangle<B> {
         &self.config
     }
 
+    /// Returns whether the storage of the tangle has failed too many times in a row to be relied upon.
+    pub fn has_storage_failed(&self) -> bool {
+        self.storage_failed.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the storage of the tangle is considered failed, after which the node is expected to stop.
+    pub async fn storage_failure(&self) {
+        loop {
+            // The future is registered before checking the flag so that a concurrent failure can not be missed.
+            let notified = self.storage_failure_notify.notified();
+
+            if self.has_storage_failed() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Turns the result of a storage operation into a tangle result. A failure is only considered fatal once
+    /// `STORAGE_FAILURE_THRESHOLD` operations failed in a row, so that a transient error does not stop the node. The
+    /// first fatal failure marks the storage as corrupted and wakes up the tasks waiting for a storage failure.
+    fn storage_result<T>(&self, result: Result<T, B::Error>) -> Result<T, TangleError> {
+        match result {
+            Ok(value) => {
+                self.storage_failures.store(0, Ordering::SeqCst);
+                Ok(value)
+            }
+            Err(e) => Err(self.storage_error(e)),
+        }
+    }
+
+    fn storage_error(&self, e: B::Error) -> TangleError {
+        let failures = self.storage_failures.fetch_add(1, Ordering::SeqCst) + 1;
+
+        if failures < STORAGE_FAILURE_THRESHOLD {
+            warn!("Storage operation failed ({}/{}): {}", failures, STORAGE_FAILURE_THRESHOLD, e);
+        } else if !self.storage_failed.swap(true, Ordering::SeqCst) {
+            error!("Storage operation failed, the node is stopping: {}", e);
+
+            if let Err(e) = self.storage.set_health(StorageHealth::Corrupted) {
+                error!("Failed to mark the storage as corrupted: {}", e);
+            }
+
+            self.storage_failure_notify.notify_waiters();
+        }
+
+        TangleError::Storage(Box::new(e))
+    }
+
+    /// Get the hit and miss counters of the cache.
+    pub fn cache_metrics(&self) -> &CacheMetrics {
+        self.cache.metrics()
+    }
+
+    /// Remove the given blocks from the cache, to be called once they have been pruned from the storage.
+    pub fn remove_from_cache<'a>(&self, block_ids: impl IntoIterator<Item = &'a BlockId>) {
+        for block_id in block_ids {
+            self.cache.remove(block_id);
+        }
+    }
+
     /// Insert a block into the tangle without overwriting its metadata if it already exists.
-    pub fn insert(&self, block: &Block, block_id: &BlockId, metadata: &BlockMetadata) {
-        self.storage
-            .insert(block_id, block)
-            .ok()
-            .and_then(|()| {
-                self.storage.insert_strict(block_id, metadata).ok()?;
-
-                let block_id = *block_id;
-                for &parent in block.parents().iter() {
-                    self.storage
-                        .insert(&(parent, block_id), &())
-                        .unwrap_or_else(|e| warn!("Failed to update approvers for block {:?}", e));
-                }
-                Some(())
-            })
-            .unwrap_or_default()
+    pub fn insert(&self, block: &Block, block_id: &BlockId, metadata: &BlockMetadata) -> Result<(), TangleError> {
+        self.cache.invalidate_on_insert(block_id, block.parents(), || {
+            self.storage_result(self.storage.insert(block_id, block))?;
+            self.cache.insert_block(*block_id, block.clone());
+            self.storage_result(self.storage.insert_strict(block_id, metadata))?;
+
+            let block_id = *block_id;
+            for &parent in block.parents().iter() {
+                self.storage_result(self.storage.insert(&(parent, block_id), &()))?;
+            }
+
+            Ok(())
+        })
     }
 
     /// Add a milestone to the tangle.
@@ -91,60 +164,53 @@ impl<B: StorageBackend> Tangle<B> {
         idx: MilestoneIndex,
         milestone_metadata: MilestoneMetadata,
         milestone_payload: MilestonePayload,
-    ) {
+    ) -> Result<(), TangleError> {
         let index = IndexId::new(idx, *milestone_metadata.block_id());
 
         self.update_metadata(milestone_metadata.block_id(), |metadata| {
             metadata.flags_mut().set_milestone(true);
             metadata.set_milestone_index(idx);
             metadata.set_omrsi_and_ymrsi(index, index);
-        });
+        })?;
         // TODO should this be batched ?
-        self.storage
-            .insert(&idx, &milestone_metadata)
-            .ok()
-            .map(|()| {
-                self.storage
-                    .insert(milestone_metadata.milestone_id(), &milestone_payload)
-                    .unwrap_or_else(|e| warn!("Failed to insert milestone block {:?}", e));
-            })
-            .unwrap_or_default()
+        self.storage_result(self.storage.insert(&idx, &milestone_metadata))?;
+        self.storage_result(
+            self.storage
+                .insert(milestone_metadata.milestone_id(), &milestone_payload),
+        )
     }
 
     /// Get the milestone metadata from the tangle that corresponds to the given milestone index.
-    pub fn get_milestone_metadata(&self, index: MilestoneIndex) -> Option<MilestoneMetadata> {
-        self.storage.fetch(&index).unwrap_or_else(|e| {
-            warn!("Failed to fetch milestone metadata {:?}", e);
-            None
-        })
+    pub fn get_milestone_metadata(&self, index: MilestoneIndex) -> Result<Option<MilestoneMetadata>, TangleError> {
+        self.storage_result(self.storage.fetch(&index))
     }
 
     /// Get the milestone payload from the tangle that corresponds to the given milestone id.
-    pub fn get_milestone(&self, id: MilestoneId) -> Option<MilestonePayload> {
-        self.storage.fetch(&id).unwrap_or_else(|e| {
-            warn!("Failed to fetch milestone payload {:?}", e);
-            None
-        })
+    pub fn get_milestone(&self, id: MilestoneId) -> Result<Option<MilestonePayload>, TangleError> {
+        self.storage_result(self.storage.fetch(&id))
     }
 
     /// Get the block associated with the given milestone index from the tangle.
-    pub fn get_milestone_block(&self, index: MilestoneIndex) -> Option<Block> {
-        self.get_milestone_block_id(index).and_then(|hash| self.get(&hash))
+    pub fn get_milestone_block(&self, index: MilestoneIndex) -> Result<Option<Block>, TangleError> {
+        match self.get_milestone_block_id(index)? {
+            Some(block_id) => self.get(&block_id),
+            None => Ok(None),
+        }
     }
 
     /// Get the block ID associated with the given milestone index from the tangle.
-    pub fn get_milestone_block_id(&self, index: MilestoneIndex) -> Option<BlockId> {
-        self.get_milestone_metadata(index).map(|m| *m.block_id())
+    pub fn get_milestone_block_id(&self, index: MilestoneIndex) -> Result<Option<BlockId>, TangleError> {
+        Ok(self.get_milestone_metadata(index)?.map(|m| *m.block_id()))
     }
 
     /// Return whether the tangle contains the given milestone metadata.
-    pub fn contains_milestone_metadata(&self, index: MilestoneIndex) -> bool {
-        self.storage.exist(&index).unwrap_or_default()
+    pub fn contains_milestone_metadata(&self, index: MilestoneIndex) -> Result<bool, TangleError> {
+        self.storage_result(self.storage.exist(&index))
     }
 
     /// Return whether the tangle contains the given milestone payload.
-    pub fn contains_milestone(&self, id: MilestoneId) -> bool {
-        self.storage.exist(&id).unwrap_or_default()
+    pub fn contains_milestone(&self, id: MilestoneId) -> Result<bool, TangleError> {
+        self.storage_result(self.storage.exist(&id))
     }
 
     /// Get the index of the latest milestone.
@@ -283,24 +349,32 @@ impl<B: StorageBackend> Tangle<B> {
     }
 
     /// Returns whether the block associated with the given block ID is solid.
-    pub async fn is_solid_block(&self, id: &BlockId) -> bool {
+    pub async fn is_solid_block(&self, id: &BlockId) -> Result<bool, TangleError> {
         if self.is_solid_entry_point(id).await {
-            true
+            Ok(true)
         } else {
-            self.get_metadata(id)
+            Ok(self
+                .get_metadata(id)?
                 .map(|metadata| metadata.flags().is_solid())
-                .unwrap_or(false)
+                .unwrap_or(false))
         }
     }
 
     /// Get the oldest and youngest milestone root snapshot index.
-    pub async fn omrsi_and_ymrsi(&self, id: &BlockId) -> Option<(IndexId, IndexId)> {
-        match self.solid_entry_points.lock().await.get(SolidEntryPoint::ref_cast(id)) {
+    pub async fn omrsi_and_ymrsi(&self, id: &BlockId) -> Result<Option<(IndexId, IndexId)>, TangleError> {
+        let sep_index = self
+            .solid_entry_points
+            .lock()
+            .await
+            .get(SolidEntryPoint::ref_cast(id))
+            .copied();
+
+        match sep_index {
             Some(sep) => {
-                let index = IndexId::new(*sep, *id);
-                Some((index, index))
+                let index = IndexId::new(sep, *id);
+                Ok(Some((index, index)))
             }
-            None => self.get_metadata(id).and_then(|metadata| metadata.omrsi_and_ymrsi()),
+            None => Ok(self.get_metadata(id)?.and_then(|metadata| metadata.omrsi_and_ymrsi())),
         }
     }
 
@@ -330,26 +404,34 @@ impl<B: StorageBackend> Tangle<B> {
     }
 
     /// Get the data of a vertex associated with the given `block_id`.
-    pub fn get(&self, block_id: &BlockId) -> Option<Block> {
-        self.storage.fetch(block_id).unwrap_or_default()
+    pub fn get(&self, block_id: &BlockId) -> Result<Option<Block>, TangleError> {
+        self.cache
+            .get_block(block_id, || self.storage_result(self.storage.fetch(block_id)))
     }
 
     /// Get the data and metadata of a vertex associated with the given `block_id`.
-    pub fn get_block_and_metadata(&self, block_id: &BlockId) -> Option<(Block, BlockMetadata)> {
-        let block = self.storage.fetch(block_id).unwrap_or_default()?;
-        let metadata = self.storage.fetch(block_id).unwrap_or_default()?;
+    pub fn get_block_and_metadata(&self, block_id: &BlockId) -> Result<Option<(Block, BlockMetadata)>, TangleError> {
+        let block = match self.get(block_id)? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
 
-        Some((block, metadata))
+        Ok(self.get_metadata(block_id)?.map(|metadata| (block, metadata)))
     }
 
     /// Returns whether the block is stored in the Tangle.
-    pub fn contains(&self, block_id: &BlockId) -> bool {
-        self.storage.exist(block_id).unwrap_or_default()
+    pub fn contains(&self, block_id: &BlockId) -> Result<bool, TangleError> {
+        if self.cache.contains_block(block_id) {
+            return Ok(true);
+        }
+
+        self.storage_result(self.storage.exist(block_id))
     }
 
     /// Get the metadata of a vertex associated with the given `block_id`.
-    pub fn get_metadata(&self, block_id: &BlockId) -> Option<BlockMetadata> {
-        self.storage.fetch(block_id).unwrap_or_default()
+    pub fn get_metadata(&self, block_id: &BlockId) -> Result<Option<BlockMetadata>, TangleError> {
+        self.cache
+            .get_metadata(block_id, || self.storage_result(self.storage.fetch(block_id)))
     }
 
     /// Updates the metadata of a vertex.
@@ -357,18 +439,33 @@ impl<B: StorageBackend> Tangle<B> {
         &self,
         block_id: &BlockId,
         update: impl FnOnce(&mut BlockMetadata) -> R + Copy,
-    ) -> Option<R> {
-        let mut output = None;
+    ) -> Result<Option<R>, TangleError> {
+        // The updated metadata is written through to the cache.
+        self.cache.update_metadata(block_id, || {
+            let mut output = None;
 
-        self.storage
-            .update(block_id, |metadata| output = Some(update(metadata)))
-            .unwrap_or_default();
+            self.storage_result(
+                self.storage
+                    .update(block_id, |metadata| output = Some((update(metadata), *metadata))),
+            )?;
+
+            Ok(output)
+        })
+    }
+
+    /// Returns a builder of a walk through the past cone of the given roots, following the parent edges.
+    pub fn walk_past_cone(&self, roots: impl IntoIterator<Item = BlockId>) -> TangleWalkerBuilder<'_, B> {
+        TangleWalkerBuilder::new(self, roots.into_iter().collect(), WalkDirection::Past)
+    }
 
-        output
+    /// Returns a builder of a walk through the future cone of the given roots, following the child edges.
+    pub fn walk_future_cone(&self, roots: impl IntoIterator<Item = BlockId>) -> TangleWalkerBuilder<'_, B> {
+        TangleWalkerBuilder::new(self, roots.into_iter().collect(), WalkDirection::Future)
     }
 
     /// Returns the children of a vertex, if we know about them.
-    pub fn get_children(&self, block_id: &BlockId) -> Option<Vec<BlockId>> {
-        self.storage.fetch(block_id).unwrap_or_default()
+    pub fn get_children(&self, block_id: &BlockId) -> Result<Option<Vec<BlockId>>, TangleError> {
+        self.cache
+            .get_children(block_id, || self.storage_result(self.storage.fetch(block_id)))
     }
 }
@@ -140,8 +140,8 @@ impl UrtsTipPool {
     }
 
     async fn tip_score<B: StorageBackend>(&self, tangle: &Tangle<B>, block_id: &BlockId) -> Score {
-        // in case the tip was pruned by the node, consider tip as lazy
-        if !tangle.contains(block_id) {
+        // in case the tip was pruned by the node, or can not be read from the storage anymore, consider tip as lazy
+        if !tangle.contains(block_id).unwrap_or(false) {
             Score::Lazy
         } else {
             let smi = *tangle.get_solid_milestone_index();
@@ -149,11 +149,11 @@ impl UrtsTipPool {
             // The tip pool only works with solid tips. Therefore, all tips added to the pool can be considered to
             // solid. The solid flag will be set together with omrsi and ymrsi values. Therefore, when a
             // block is solid, omrsi and ymrsi values are available. Therefore, unwrapping here is fine.
-            let (omrsi, ymrsi) = tangle
-                .omrsi_and_ymrsi(block_id)
-                .await
-                .map(|(o, y)| (*o.index(), *y.index()))
-                .unwrap();
+            let (omrsi, ymrsi) = match tangle.omrsi_and_ymrsi(block_id).await {
+                Ok(indexes) => indexes.map(|(o, y)| (*o.index(), *y.index())).unwrap(),
+                // The storage failure has already been reported by the tangle.
+                Err(_) => return Score::Lazy,
+            };
 
             if smi > ymrsi + YMRSI_DELTA || smi > omrsi + self.below_max_depth {
                 Score::Lazy
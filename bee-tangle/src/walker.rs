@@ -0,0 +1,330 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashSet, VecDeque};
+
+use bee_block::{Block, BlockId};
+
+use crate::{
+    block_metadata::BlockMetadata, error::TangleError, solid_entry_point::SolidEntryPoint, storage::StorageBackend,
+    tangle::Tangle,
+};
+
+/// The edges a walk follows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkDirection {
+    /// Towards the parents of the vertices, i.e. the past cone.
+    Past,
+    /// Towards the children of the vertices, i.e. the future cone.
+    Future,
+}
+
+/// The order in which a walk yields the vertices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkOrder {
+    /// Depth-first, a vertex is yielded before the vertices it leads to.
+    DepthFirst,
+    /// Depth-first, a vertex is yielded after all the vertices it leads to.
+    DepthFirstPostOrder,
+    /// Breadth-first, the vertices are yielded by increasing depth.
+    BreadthFirst,
+}
+
+/// How a walk keeps track of the vertices it already visited.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VisitedStrategy {
+    /// Every vertex is yielded at most once, at the cost of remembering all of them.
+    Once,
+    /// Vertices are not remembered and a vertex that can be reached through several paths is yielded once per path.
+    /// This is only reasonable for walks with a small depth limit.
+    None,
+}
+
+/// A vertex reached by a walk.
+#[derive(Clone, Debug)]
+pub enum WalkItem {
+    /// A vertex that matches the predicate of the walk, which continues through it.
+    Matched {
+        /// The identifier of the block.
+        block_id: BlockId,
+        /// The block.
+        block: Block,
+        /// The metadata of the block.
+        metadata: BlockMetadata,
+        /// The number of edges between the vertex and the root it was reached from.
+        depth: usize,
+    },
+    /// A vertex that doesn't match the predicate of the walk, which doesn't continue through it.
+    Unmatched {
+        /// The identifier of the block.
+        block_id: BlockId,
+        /// The block.
+        block: Block,
+        /// The metadata of the block.
+        metadata: BlockMetadata,
+        /// The number of edges between the vertex and the root it was reached from.
+        depth: usize,
+    },
+    /// A solid entry point, at which the walk stops.
+    SolidEntryPoint {
+        /// The identifier of the solid entry point.
+        block_id: BlockId,
+        /// The number of edges between the vertex and the root it was reached from.
+        depth: usize,
+    },
+    /// A vertex that is not available in the tangle.
+    Missing {
+        /// The identifier of the missing block.
+        block_id: BlockId,
+        /// The number of edges between the vertex and the root it was reached from.
+        depth: usize,
+    },
+}
+
+impl WalkItem {
+    /// Returns the identifier of the block of the vertex.
+    pub fn block_id(&self) -> &BlockId {
+        match self {
+            Self::Matched { block_id, .. }
+            | Self::Unmatched { block_id, .. }
+            | Self::SolidEntryPoint { block_id, .. }
+            | Self::Missing { block_id, .. } => block_id,
+        }
+    }
+
+    /// Returns the number of edges between the vertex and the root it was reached from.
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Matched { depth, .. }
+            | Self::Unmatched { depth, .. }
+            | Self::SolidEntryPoint { depth, .. }
+            | Self::Missing { depth, .. } => *depth,
+        }
+    }
+}
+
+type Predicate<'a> = Box<dyn FnMut(&BlockId, &Block, &BlockMetadata) -> bool + Send + 'a>;
+
+/// Builder for a [`TangleWalker`].
+#[must_use]
+pub struct TangleWalkerBuilder<'a, B> {
+    tangle: &'a Tangle<B>,
+    roots: Vec<BlockId>,
+    direction: WalkDirection,
+    order: WalkOrder,
+    max_depth: usize,
+    solid_entry_points: HashSet<BlockId>,
+    predicate: Option<Predicate<'a>>,
+    visited_strategy: VisitedStrategy,
+}
+
+impl<'a, B: StorageBackend> TangleWalkerBuilder<'a, B> {
+    pub(crate) fn new(tangle: &'a Tangle<B>, roots: Vec<BlockId>, direction: WalkDirection) -> Self {
+        Self {
+            tangle,
+            roots,
+            direction,
+            order: WalkOrder::DepthFirst,
+            max_depth: usize::MAX,
+            solid_entry_points: HashSet::new(),
+            predicate: None,
+            visited_strategy: VisitedStrategy::Once,
+        }
+    }
+
+    /// Sets the order in which the vertices are yielded, depth-first by default.
+    pub fn with_order(mut self, order: WalkOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the maximum number of edges between a root and the yielded vertices, unlimited by default.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the solid entry points at which the walk stops.
+    pub fn with_solid_entry_points(mut self, solid_entry_points: impl IntoIterator<Item = SolidEntryPoint>) -> Self {
+        self.solid_entry_points = solid_entry_points.into_iter().map(|sep| *sep).collect();
+        self
+    }
+
+    /// Sets the predicate a vertex has to match for the walk to continue through it. All vertices match by default.
+    pub fn with_predicate(
+        mut self,
+        predicate: impl FnMut(&BlockId, &Block, &BlockMetadata) -> bool + Send + 'a,
+    ) -> Self {
+        self.predicate = Some(Box::new(predicate));
+        self
+    }
+
+    /// Sets how the walk keeps track of the visited vertices, [`VisitedStrategy::Once`] by default.
+    pub fn with_visited_strategy(mut self, visited_strategy: VisitedStrategy) -> Self {
+        self.visited_strategy = visited_strategy;
+        self
+    }
+
+    /// Finishes the builder into a [`TangleWalker`].
+    pub fn finish(self) -> TangleWalker<'a, B> {
+        // The roots are stacked in reverse so that they are walked in the given order.
+        let pending = match self.order {
+            WalkOrder::DepthFirst | WalkOrder::DepthFirstPostOrder => self
+                .roots
+                .into_iter()
+                .rev()
+                .map(|root| Step::Pending(root, 0))
+                .collect(),
+            WalkOrder::BreadthFirst => self.roots.into_iter().map(|root| Step::Pending(root, 0)).collect(),
+        };
+
+        TangleWalker {
+            tangle: self.tangle,
+            direction: self.direction,
+            order: self.order,
+            max_depth: self.max_depth,
+            solid_entry_points: self.solid_entry_points,
+            predicate: self.predicate.unwrap_or_else(|| Box::new(|_, _, _| true)),
+            visited: match self.visited_strategy {
+                VisitedStrategy::Once => Some(HashSet::new()),
+                VisitedStrategy::None => None,
+            },
+            pending,
+            failed: false,
+        }
+    }
+}
+
+enum Step {
+    Pending(BlockId, usize),
+    // A matched vertex of a post-order walk, yielded once the vertices it leads to have been.
+    Expanded(Box<(BlockId, Block, BlockMetadata)>, usize),
+}
+
+/// An iterator over the vertices of a cone of the tangle.
+///
+/// Each vertex reached by the walk is yielded once according to the [`VisitedStrategy`], whether it matches the
+/// predicate of the walk or not. The walk stops at the first storage failure, which is yielded as an error.
+pub struct TangleWalker<'a, B> {
+    tangle: &'a Tangle<B>,
+    direction: WalkDirection,
+    order: WalkOrder,
+    max_depth: usize,
+    solid_entry_points: HashSet<BlockId>,
+    predicate: Predicate<'a>,
+    visited: Option<HashSet<BlockId>>,
+    pending: VecDeque<Step>,
+    failed: bool,
+}
+
+impl<'a, B: StorageBackend> TangleWalker<'a, B> {
+    fn next_step(&mut self) -> Option<Step> {
+        match self.order {
+            WalkOrder::DepthFirst | WalkOrder::DepthFirstPostOrder => self.pending.pop_back(),
+            WalkOrder::BreadthFirst => self.pending.pop_front(),
+        }
+    }
+
+    // Stacks or queues the vertices a vertex leads to and returns their number.
+    fn expand(&mut self, block_id: &BlockId, block: &Block, depth: usize) -> Result<usize, TangleError> {
+        if depth >= self.max_depth {
+            return Ok(0);
+        }
+
+        let next = match self.direction {
+            WalkDirection::Past => block.parents().to_vec(),
+            WalkDirection::Future => self.tangle.get_children(block_id)?.unwrap_or_default(),
+        };
+        let len = next.len();
+        let next = next.into_iter().map(|next| Step::Pending(next, depth + 1));
+
+        match self.order {
+            // Stacked in reverse so that the next vertices are walked in order.
+            WalkOrder::DepthFirst | WalkOrder::DepthFirstPostOrder => self.pending.extend(next.rev()),
+            WalkOrder::BreadthFirst => self.pending.extend(next),
+        }
+
+        Ok(len)
+    }
+
+    fn visit(&mut self, block_id: BlockId, depth: usize) -> Result<Option<WalkItem>, TangleError> {
+        if let Some(visited) = self.visited.as_mut() {
+            if !visited.insert(block_id) {
+                return Ok(None);
+            }
+        }
+
+        if self.solid_entry_points.contains(&block_id) {
+            return Ok(Some(WalkItem::SolidEntryPoint { block_id, depth }));
+        }
+
+        let (block, metadata) = match self.tangle.get_block_and_metadata(&block_id)? {
+            Some(block_and_metadata) => block_and_metadata,
+            None => return Ok(Some(WalkItem::Missing { block_id, depth })),
+        };
+
+        if !(self.predicate)(&block_id, &block, &metadata) {
+            return Ok(Some(WalkItem::Unmatched {
+                block_id,
+                block,
+                metadata,
+                depth,
+            }));
+        }
+
+        let expanded = self.expand(&block_id, &block, depth)?;
+
+        if self.order == WalkOrder::DepthFirstPostOrder {
+            // The vertex goes below the vertices it leads to, which are walked first.
+            let position = self.pending.len() - expanded;
+            self.pending
+                .insert(position, Step::Expanded(Box::new((block_id, block, metadata)), depth));
+
+            return Ok(None);
+        }
+
+        Ok(Some(WalkItem::Matched {
+            block_id,
+            block,
+            metadata,
+            depth,
+        }))
+    }
+}
+
+impl<'a, B: StorageBackend> Iterator for TangleWalker<'a, B> {
+    type Item = Result<WalkItem, TangleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        while let Some(step) = self.next_step() {
+            let (block_id, depth) = match step {
+                Step::Pending(block_id, depth) => (block_id, depth),
+                Step::Expanded(vertex, depth) => {
+                    let (block_id, block, metadata) = *vertex;
+
+                    return Some(Ok(WalkItem::Matched {
+                        block_id,
+                        block,
+                        metadata,
+                        depth,
+                    }));
+                }
+            };
+
+            match self.visit(block_id, depth) {
+                Ok(Some(item)) => return Some(Ok(item)),
+                Ok(None) => continue,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{parent::Parents, Block, BlockId};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::backend::StorageBackend;
+use bee_storage_memory::Storage;
+use bee_tangle::{block_metadata::BlockMetadata, config::TangleConfig, Tangle};
+use bee_test::rand::block::{rand_block, rand_block_with_parents};
+
+fn tangle(capacity: usize) -> Tangle<Storage> {
+    let config = TangleConfig::build().with_cache_capacity(capacity).finish();
+
+    Tangle::new(config, ResourceHandle::new(Storage::start(()).unwrap()))
+}
+
+fn insert(tangle: &Tangle<Storage>, block: &Block) -> BlockId {
+    let block_id = block.id();
+    tangle.insert(block, &block_id, &BlockMetadata::arrived()).unwrap();
+    block_id
+}
+
+#[test]
+fn lookups_are_served_by_the_cache() {
+    let tangle = tangle(16);
+    let block_id = insert(&tangle, &rand_block());
+
+    assert!(tangle.get(&block_id).unwrap().is_some());
+    assert!(tangle.get(&block_id).unwrap().is_some());
+    assert!(tangle.contains(&block_id).unwrap());
+    assert_eq!(tangle.cache_metrics().block_hits(), 3);
+    assert_eq!(tangle.cache_metrics().block_misses(), 0);
+
+    assert!(tangle.get_metadata(&block_id).unwrap().is_some());
+    assert!(tangle.get_metadata(&block_id).unwrap().is_some());
+    assert_eq!(tangle.cache_metrics().metadata_misses(), 1);
+    assert_eq!(tangle.cache_metrics().metadata_hits(), 1);
+}
+
+#[test]
+fn unknown_blocks_are_not_cached() {
+    let tangle = tangle(16);
+    let block_id = rand_block().id();
+
+    assert!(tangle.get(&block_id).unwrap().is_none());
+    assert!(tangle.get_metadata(&block_id).unwrap().is_none());
+    assert!(tangle.get(&block_id).unwrap().is_none());
+    assert!(!tangle.contains(&block_id).unwrap());
+    assert_eq!(tangle.cache_metrics().block_hits(), 0);
+    assert_eq!(tangle.cache_metrics().block_misses(), 3);
+}
+
+#[test]
+fn metadata_updates_are_written_through() {
+    let tangle = tangle(16);
+    let block_id = insert(&tangle, &rand_block());
+
+    assert!(!tangle.get_metadata(&block_id).unwrap().unwrap().flags().is_referenced());
+
+    tangle
+        .update_metadata(&block_id, |metadata| metadata.reference(42))
+        .unwrap();
+
+    let cached = tangle.get_metadata(&block_id).unwrap().unwrap();
+    assert!(cached.flags().is_referenced());
+    assert_eq!(cached.reference_timestamp(), 42);
+    assert_eq!(tangle.cache_metrics().metadata_hits(), 1);
+
+    // Without the cache, the update must have reached the storage as well.
+    tangle.remove_from_cache(&[block_id]);
+    assert_eq!(
+        tangle.get_metadata(&block_id).unwrap().unwrap().reference_timestamp(),
+        42
+    );
+}
+
+#[test]
+fn children_are_invalidated_on_insert() {
+    let tangle = tangle(16);
+    let parent_id = insert(&tangle, &rand_block());
+
+    let first_child = insert(
+        &tangle,
+        &rand_block_with_parents(Parents::new(vec![parent_id]).unwrap()),
+    );
+    assert_eq!(tangle.get_children(&parent_id).unwrap(), Some(vec![first_child]));
+
+    let second_child = insert(
+        &tangle,
+        &rand_block_with_parents(Parents::new(vec![parent_id]).unwrap()),
+    );
+    let mut children = tangle.get_children(&parent_id).unwrap().unwrap();
+    children.sort();
+    let mut expected = vec![first_child, second_child];
+    expected.sort();
+
+    assert_eq!(children, expected);
+    assert_eq!(tangle.cache_metrics().children_misses(), 2);
+}
+
+#[test]
+fn removed_blocks_are_fetched_again() {
+    let tangle = tangle(16);
+    let block_id = insert(&tangle, &rand_block());
+
+    tangle.remove_from_cache(&[block_id]);
+
+    assert!(tangle.get(&block_id).unwrap().is_some());
+    assert_eq!(tangle.cache_metrics().block_misses(), 1);
+}
+
+#[test]
+fn capacity_is_bounded() {
+    let tangle = tangle(2);
+    let ids = (0..3).map(|_| insert(&tangle, &rand_block())).collect::<Vec<_>>();
+
+    // The first block was evicted by the insertion of the third one.
+    assert!(tangle.get(&ids[0]).unwrap().is_some());
+    assert_eq!(tangle.cache_metrics().block_misses(), 1);
+    assert!(tangle.get(&ids[2]).unwrap().is_some());
+    assert_eq!(tangle.cache_metrics().block_hits(), 1);
+}
+
+#[test]
+fn zero_capacity_disables_the_cache() {
+    let tangle = tangle(0);
+    let block_id = insert(&tangle, &rand_block());
+
+    assert!(tangle.get(&block_id).unwrap().is_some());
+    assert!(tangle.get(&block_id).unwrap().is_some());
+    assert!(tangle.get_metadata(&block_id).unwrap().is_some());
+    assert_eq!(tangle.cache_metrics().block_hits(), 0);
+    assert_eq!(tangle.cache_metrics().block_misses(), 2);
+    assert_eq!(tangle.cache_metrics().metadata_hits(), 0);
+}
@@ -0,0 +1,211 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{parent::Parents, Block, BlockId};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::backend::StorageBackend;
+use bee_storage_memory::Storage;
+use bee_tangle::{
+    block_metadata::BlockMetadata,
+    config::TangleConfig,
+    solid_entry_point::SolidEntryPoint,
+    walker::{VisitedStrategy, WalkItem, WalkOrder},
+    Tangle,
+};
+use bee_test::rand::block::{rand_block_id, rand_block_with_parents};
+
+// The diamond `sep <- a <- (b, c) <- d`, where `b` and `c` are the parents of `d` in the order of `d`.
+struct Diamond {
+    tangle: Tangle<Storage>,
+    sep: BlockId,
+    a: BlockId,
+    b: BlockId,
+    c: BlockId,
+    d: BlockId,
+}
+
+fn insert(tangle: &Tangle<Storage>, parents: Vec<BlockId>) -> (BlockId, Block) {
+    let block = rand_block_with_parents(Parents::new(parents).unwrap());
+    let block_id = block.id();
+
+    tangle.insert(&block, &block_id, &BlockMetadata::arrived()).unwrap();
+
+    (block_id, block)
+}
+
+fn diamond() -> Diamond {
+    let tangle = Tangle::new(
+        TangleConfig::build().finish(),
+        ResourceHandle::new(Storage::start(()).unwrap()),
+    );
+    let sep = rand_block_id();
+    let (a, _) = insert(&tangle, vec![sep]);
+    let (x, _) = insert(&tangle, vec![a]);
+    let (y, _) = insert(&tangle, vec![a]);
+    let (d, block) = insert(&tangle, vec![x, y]);
+    let (b, c) = (block.parents()[0], block.parents()[1]);
+
+    Diamond {
+        tangle,
+        sep,
+        a,
+        b,
+        c,
+        d,
+    }
+}
+
+fn matched(items: &[WalkItem]) -> Vec<(BlockId, usize)> {
+    items
+        .iter()
+        .filter(|item| matches!(item, WalkItem::Matched { .. }))
+        .map(|item| (*item.block_id(), item.depth()))
+        .collect()
+}
+
+#[test]
+fn past_cone_depth_first() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_solid_entry_points([SolidEntryPoint::new(diamond.sep)])
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        matched(&items),
+        vec![(diamond.d, 0), (diamond.b, 1), (diamond.a, 2), (diamond.c, 1)]
+    );
+    assert_eq!(items.len(), 5);
+    assert!(matches!(items[3], WalkItem::SolidEntryPoint { block_id, depth: 3 } if block_id == diamond.sep));
+}
+
+#[test]
+fn past_cone_depth_first_post_order() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_order(WalkOrder::DepthFirstPostOrder)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // Without solid entry points, the parent of `a` is missing.
+    assert!(matches!(items[0], WalkItem::Missing { block_id, depth: 3 } if block_id == diamond.sep));
+    assert_eq!(
+        matched(&items),
+        vec![(diamond.a, 2), (diamond.b, 1), (diamond.c, 1), (diamond.d, 0)]
+    );
+}
+
+#[test]
+fn past_cone_breadth_first() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_order(WalkOrder::BreadthFirst)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        matched(&items),
+        vec![(diamond.d, 0), (diamond.b, 1), (diamond.c, 1), (diamond.a, 2)]
+    );
+}
+
+#[test]
+fn max_depth_limits_the_walk() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_max_depth(1)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(matched(&items), vec![(diamond.d, 0), (diamond.b, 1), (diamond.c, 1)]);
+    assert_eq!(items.len(), 3);
+}
+
+#[test]
+fn predicate_prunes_the_walk() {
+    let diamond = diamond();
+    let b = diamond.b;
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_solid_entry_points([SolidEntryPoint::new(diamond.sep)])
+        .with_predicate(move |block_id, _, _| *block_id != b)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert!(matches!(items[1], WalkItem::Unmatched { block_id, depth: 1, .. } if block_id == diamond.b));
+    // `a` is still reached through `c`.
+    assert_eq!(matched(&items), vec![(diamond.d, 0), (diamond.c, 1), (diamond.a, 2)]);
+}
+
+#[test]
+fn vertices_are_yielded_once_per_path_without_visited_set() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_past_cone([diamond.d])
+        .with_max_depth(2)
+        .with_visited_strategy(VisitedStrategy::None)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        matched(&items),
+        vec![
+            (diamond.d, 0),
+            (diamond.b, 1),
+            (diamond.a, 2),
+            (diamond.c, 1),
+            (diamond.a, 2)
+        ]
+    );
+}
+
+#[test]
+fn future_cone_breadth_first() {
+    let diamond = diamond();
+    let items = diamond
+        .tangle
+        .walk_future_cone([diamond.a])
+        .with_order(WalkOrder::BreadthFirst)
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let mut matched = matched(&items);
+
+    assert_eq!(matched.remove(0), (diamond.a, 0));
+    assert_eq!(matched.pop(), Some((diamond.d, 2)));
+    matched.sort();
+    let mut children = vec![(diamond.b, 1), (diamond.c, 1)];
+    children.sort();
+    assert_eq!(matched, children);
+}
+
+#[test]
+fn unknown_roots_are_missing() {
+    let diamond = diamond();
+    let root = rand_block_id();
+    let items = diamond
+        .tangle
+        .walk_future_cone([root])
+        .finish()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], WalkItem::Missing { block_id, depth: 0 } if block_id == root));
+}
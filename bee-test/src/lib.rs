@@ -7,5 +7,8 @@
 
 /// A module providing random generation of types of testing purposes.
 pub mod rand;
+/// A module providing an in-process multi-node network simulator.
+#[cfg(feature = "sim")]
+pub mod sim;
 /// A module providing ternary utilities for testing purposes.
 pub mod ternary;
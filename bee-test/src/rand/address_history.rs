@@ -0,0 +1,32 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{AddressHistoryEntry, AddressHistoryEntryKind};
+
+use crate::rand::{bool::rand_bool, milestone::rand_milestone_index, number::rand_number, output::rand_output_id};
+
+/// Generates a random address history entry kind.
+pub fn rand_address_history_entry_kind() -> AddressHistoryEntryKind {
+    if rand_bool() {
+        AddressHistoryEntryKind::Created
+    } else {
+        AddressHistoryEntryKind::Consumed
+    }
+}
+
+/// Generates a random address history entry.
+pub fn rand_address_history_entry() -> AddressHistoryEntry {
+    match rand_address_history_entry_kind() {
+        AddressHistoryEntryKind::Created => {
+            AddressHistoryEntry::created(rand_output_id(), rand_milestone_index(), rand_number(), rand_number())
+        }
+        AddressHistoryEntryKind::Consumed => AddressHistoryEntry::consumed(
+            rand_output_id(),
+            rand_milestone_index(),
+            rand_number(),
+            rand_number(),
+            rand_milestone_index(),
+            rand_number(),
+        ),
+    }
+}
@@ -3,6 +3,8 @@
 
 /// Module providing random address generation utilities.
 pub mod address;
+/// Module providing random address history generation utilities.
+pub mod address_history;
 /// Module providing random block generation utilities.
 pub mod block;
 /// Module providing random block metadata generation utilities.
@@ -29,14 +31,20 @@ pub mod output_diff;
 pub mod parents;
 /// Module providing random payload generation utilities.
 pub mod payload;
+/// Module providing random protocol parameters generation utilities.
+pub mod protocol;
 /// Module providing random receipt generation utilities.
 pub mod receipt;
+/// Module providing random semantic validation generation utilities.
+pub mod semantic;
 /// Module providing random snapshot generation utilities.
 pub mod snapshot;
 /// Module providing random solid entry point generation utilities.
 pub mod solid_entry_point;
 /// Module providing random string generation utilities.
 pub mod string;
+/// Module providing random tagged block generation utilities.
+pub mod tagged_block;
 /// Module providing random transaction generation utilities.
 pub mod transaction;
 /// Module providing random unreferenced block generation utilities.
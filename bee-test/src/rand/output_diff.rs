@@ -1,10 +1,13 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ledger::types::{OutputDiff, TreasuryDiff};
+use bee_ledger::types::{MilestoneUtxoDiff, OutputDiff, TreasuryDiff};
 
 use crate::rand::{
-    milestone::rand_milestone_id, number::rand_number_range, option::rand_option, output::rand_output_id,
+    milestone::{rand_milestone_id, rand_milestone_index},
+    number::rand_number_range,
+    option::rand_option,
+    output::{rand_consumed_output, rand_created_output, rand_output_id},
 };
 
 /// Generates a random treasury diff.
@@ -29,3 +32,20 @@ pub fn rand_output_diff() -> OutputDiff {
 
     OutputDiff::new(spent_outputs, created_outputs, rand_option(rand_treasury_diff())).unwrap()
 }
+
+/// Generates a random milestone UTXO diff.
+pub fn rand_milestone_utxo_diff() -> MilestoneUtxoDiff {
+    let output_diff = rand_output_diff();
+    let created_outputs = output_diff
+        .created_outputs()
+        .iter()
+        .map(|_| rand_created_output())
+        .collect();
+    let consumed_outputs = output_diff
+        .consumed_outputs()
+        .iter()
+        .map(|_| (rand_created_output(), rand_consumed_output()))
+        .collect();
+
+    MilestoneUtxoDiff::new(rand_milestone_index(), output_diff, created_outputs, consumed_outputs).unwrap()
+}
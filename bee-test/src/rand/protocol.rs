@@ -0,0 +1,25 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::protocol::ProtocolParameters;
+
+use crate::rand::{
+    number::{rand_number, rand_number_range},
+    string::rand_string,
+};
+
+/// Generates random protocol parameters.
+pub fn rand_protocol_parameters() -> ProtocolParameters {
+    ProtocolParameters::new(
+        rand_number(),
+        rand_string(rand_number_range(1..32)),
+        rand_string(rand_number_range(1..8)),
+        rand_number(),
+        rand_number(),
+        rand_number(),
+        rand_number(),
+        rand_number(),
+        rand_number(),
+    )
+    .unwrap()
+}
@@ -0,0 +1,38 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::semantic::{ConflictDiagnostic, ConflictReason};
+
+use crate::rand::{
+    address::rand_address,
+    milestone::rand_milestone_index,
+    number::{rand_number, rand_number_range},
+};
+
+/// Generates a random conflict diagnostic.
+pub fn rand_conflict_diagnostic() -> ConflictDiagnostic {
+    match rand_number_range(0..5) {
+        0 => ConflictDiagnostic::UnlockFailed {
+            input_index: rand_number(),
+            reason: ConflictReason::InvalidSignature,
+        },
+        1 => ConflictDiagnostic::TimelockNotExpired {
+            input_index: rand_number(),
+            milestone_index: rand_milestone_index(),
+            timestamp: rand_number(),
+        },
+        2 => ConflictDiagnostic::StorageDepositReturnUnfulfilled {
+            return_address: rand_address(),
+            expected: rand_number(),
+            actual: rand_number(),
+        },
+        3 => ConflictDiagnostic::UnverifiedSender {
+            output_index: rand_number(),
+            sender: rand_address(),
+        },
+        _ => ConflictDiagnostic::CreatedConsumedAmountMismatch {
+            consumed: rand_number(),
+            created: rand_number(),
+        },
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::payload::tagged_data::TaggedDataPayload;
+use bee_tangle::tagged_block::{PaddedTag, TaggedBlock};
+
+use crate::rand::{
+    block::rand_block_id, bytes::rand_bytes, milestone::rand_milestone_index, number::rand_number_range,
+};
+
+/// Generates a random padded tag.
+pub fn rand_padded_tag() -> PaddedTag {
+    PaddedTag::new(&rand_bytes(
+        rand_number_range(TaggedDataPayload::TAG_LENGTH_RANGE).into(),
+    ))
+    .unwrap()
+}
+
+/// Generates a random tagged block.
+pub fn rand_tagged_block() -> TaggedBlock {
+    TaggedBlock::new(rand_milestone_index(), rand_block_id())
+}
@@ -0,0 +1,86 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::{
+    parent::Parents,
+    payload::milestone::{MilestoneEssence, MilestoneId, MilestoneIndex, MilestoneOptions, MilestonePayload},
+    signature::{Ed25519Signature, Signature},
+    Block, BlockBuilder,
+};
+use bee_ledger::workers::{
+    consensus::{white_flag, WhiteFlagMetadata},
+    StorageBackend,
+};
+use bee_protocol::types::milestone_key_range::MilestoneKeyRange;
+use bee_tangle::Tangle;
+use crypto::signatures::ed25519::SecretKey;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use crate::sim::error::Error;
+
+/// A test coordinator issuing signed milestones for a simulated network.
+pub struct Coordinator {
+    secret_key: SecretKey,
+}
+
+impl Coordinator {
+    /// Creates a [`Coordinator`] with a key deterministically derived from a seed.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut bytes = [0u8; 32];
+        StdRng::seed_from_u64(seed).fill_bytes(&mut bytes);
+
+        Self {
+            secret_key: SecretKey::from_bytes(bytes),
+        }
+    }
+
+    /// Returns the hex encoded public key of the [`Coordinator`].
+    pub fn public_key(&self) -> String {
+        hex::encode(self.secret_key.public_key().to_bytes())
+    }
+
+    /// Returns the key range that makes nodes accept all milestones of the [`Coordinator`].
+    pub fn key_range(&self) -> MilestoneKeyRange {
+        MilestoneKeyRange::new(self.public_key(), MilestoneIndex(0), MilestoneIndex(u32::MAX))
+    }
+
+    /// Creates a milestone block approving the given parents.
+    ///
+    /// The merkle roots are computed by running white flag on the tangle and ledger of the node the coordinator is
+    /// attached to, so all parents need to be solid on that node.
+    pub async fn create_milestone<B: StorageBackend>(
+        &self,
+        tangle: &Tangle<B>,
+        storage: &B,
+        index: MilestoneIndex,
+        timestamp: u32,
+        previous_milestone_id: MilestoneId,
+        parents: Parents,
+    ) -> Result<Block, Error> {
+        let mut metadata = WhiteFlagMetadata::new(index, timestamp, Some(previous_milestone_id));
+
+        white_flag(tangle, storage, &parents, &mut metadata).await?;
+
+        let essence = MilestoneEssence::new(
+            index,
+            timestamp,
+            previous_milestone_id,
+            parents.clone(),
+            *metadata.inclusion_merkle_root(),
+            *metadata.applied_merkle_root(),
+            Vec::new(),
+            MilestoneOptions::new(Vec::new())?,
+        )?;
+
+        let signature = Ed25519Signature::new(
+            self.secret_key.public_key().to_bytes(),
+            self.secret_key.sign(&essence.hash()).to_bytes(),
+        );
+        let milestone = MilestonePayload::new(essence, vec![Signature::from(signature)])?;
+
+        Ok(BlockBuilder::<u64>::new(parents)
+            .with_payload(milestone.into())
+            .with_nonce_provider(0, 0f64)
+            .finish()?)
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::payload::milestone::MilestoneIndex;
+
+/// Errors occurring while running a simulated network.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// A storage operation failed.
+    #[error("storage operation failed: {0}")]
+    Storage(#[from] bee_storage_memory::storage::Error),
+    /// The gossip layer couldn't be initialized.
+    #[error("gossip layer initialization failed: {0}")]
+    Gossip(#[from] bee_gossip::Error),
+    /// A block was rejected by a node.
+    #[error("block rejected: {0}")]
+    BlockRejected(String),
+    /// A block couldn't be built.
+    #[error("invalid block: {0}")]
+    Block(#[from] bee_block::Error),
+    /// The white flag computation of the coordinator failed.
+    #[error("white flag computation failed: {0}")]
+    WhiteFlag(#[from] bee_ledger::workers::error::Error),
+    /// The given node index doesn't exist in the network.
+    #[error("unknown node {0}")]
+    UnknownNode(usize),
+    /// The REST API wasn't started on the given node.
+    #[error("no REST API on node {0}")]
+    NoRestApi(usize),
+    /// An I/O operation failed.
+    #[error("I/O operation failed: {0}")]
+    Io(#[from] std::io::Error),
+    /// A node didn't reach a milestone index in time.
+    #[error("node {0} didn't reach milestone index {1} in time")]
+    Timeout(usize, MilestoneIndex),
+}
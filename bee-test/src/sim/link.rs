@@ -0,0 +1,198 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use bee_gossip::{Event, GossipSender, NetworkEventReceiver, PeerId};
+use futures::StreamExt;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio::{sync::mpsc, time::Instant};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// Conditions applied to the gossip packets sent from one node to another.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinkConditions {
+    /// Delay applied to every packet.
+    pub latency: Duration,
+    /// Probability, between `0.0` and `1.0`, that a packet is dropped.
+    pub loss: f64,
+}
+
+impl LinkConditions {
+    /// Creates new [`LinkConditions`].
+    pub fn new(latency: Duration, loss: f64) -> Self {
+        Self {
+            latency,
+            loss: loss.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Conditions {
+    // The partition of every node, nodes can only communicate within the same partition.
+    partitions: Option<Vec<usize>>,
+    // Conditions of specific directed links, overriding the default ones.
+    links: HashMap<(usize, usize), LinkConditions>,
+    default: LinkConditions,
+}
+
+/// The conditions of all links of a simulated network, shared by all conditioned gossip channels.
+#[derive(Clone, Default)]
+pub(crate) struct NetworkConditions(Arc<RwLock<Conditions>>);
+
+impl NetworkConditions {
+    pub(crate) fn new(default: LinkConditions) -> Self {
+        Self(Arc::new(RwLock::new(Conditions {
+            default,
+            ..Default::default()
+        })))
+    }
+
+    pub(crate) fn partition(&self, node_count: usize, groups: &[&[usize]]) {
+        // Nodes that are not part of any group are isolated in a partition of their own.
+        let mut partitions = (groups.len()..groups.len() + node_count).collect::<Vec<_>>();
+
+        for (partition, group) in groups.iter().enumerate() {
+            for &node in group.iter().filter(|node| **node < node_count) {
+                partitions[node] = partition;
+            }
+        }
+
+        self.0.write().unwrap().partitions.replace(partitions);
+    }
+
+    pub(crate) fn heal(&self) {
+        self.0.write().unwrap().partitions = None;
+    }
+
+    pub(crate) fn set_link(&self, from: usize, to: usize, conditions: LinkConditions) {
+        self.0.write().unwrap().links.insert((from, to), conditions);
+    }
+
+    pub(crate) fn set_default(&self, conditions: LinkConditions) {
+        let mut inner = self.0.write().unwrap();
+
+        inner.links.clear();
+        inner.default = conditions;
+    }
+
+    // Returns the conditions of the link, or `None` if the link is currently cut by a partition.
+    fn get(&self, from: usize, to: usize) -> Option<LinkConditions> {
+        let inner = self.0.read().unwrap();
+
+        if let Some(partitions) = &inner.partitions {
+            if partitions.get(from) != partitions.get(to) {
+                return None;
+            }
+        }
+
+        Some(*inner.links.get(&(from, to)).unwrap_or(&inner.default))
+    }
+}
+
+/// Places itself between the gossip layer of a node and its protocol layer to condition all outgoing gossip.
+pub(crate) fn intercept(
+    index: usize,
+    mut events: NetworkEventReceiver,
+    peers: Arc<HashMap<PeerId, usize>>,
+    conditions: NetworkConditions,
+    seed: u64,
+) -> NetworkEventReceiver {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let mut connections = 0u64;
+
+        while let Some(event) = events.recv().await {
+            let event = match event {
+                Event::PeerConnected {
+                    peer_id,
+                    info,
+                    gossip_in,
+                    gossip_out,
+                    gossip_version,
+                } => match peers.get(&peer_id) {
+                    Some(&to) => {
+                        connections += 1;
+                        // Every connection gets its own, but reproducible, random source.
+                        let rng =
+                            StdRng::seed_from_u64(seed ^ ((index as u64) << 48) ^ ((to as u64) << 32) ^ connections);
+
+                        Event::PeerConnected {
+                            peer_id,
+                            info,
+                            gossip_in,
+                            gossip_out: conditioned(index, to, gossip_out, conditions.clone(), rng),
+                            gossip_version,
+                        }
+                    }
+                    None => Event::PeerConnected {
+                        peer_id,
+                        info,
+                        gossip_in,
+                        gossip_out,
+                        gossip_version,
+                    },
+                },
+                event => event,
+            };
+
+            if tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    NetworkEventReceiver::new(rx)
+}
+
+fn conditioned(
+    from: usize,
+    to: usize,
+    gossip_out: GossipSender,
+    conditions: NetworkConditions,
+    mut rng: StdRng,
+) -> GossipSender {
+    let (tx, rx) = mpsc::unbounded_channel::<Vec<u8>>();
+    let (delayed_tx, delayed_rx) = mpsc::unbounded_channel::<(Instant, Vec<u8>)>();
+
+    // Packets are stamped as soon as they are sent to measure the latency from that point, independently of the
+    // packets that are still waiting to be delivered.
+    tokio::spawn(async move {
+        let mut rx = UnboundedReceiverStream::new(rx);
+
+        while let Some(packet) = rx.next().await {
+            let conditions = match conditions.get(from, to) {
+                Some(conditions) => conditions,
+                None => continue,
+            };
+
+            if conditions.loss > 0.0 && rng.gen_bool(conditions.loss) {
+                continue;
+            }
+
+            if delayed_tx.send((Instant::now() + conditions.latency, packet)).is_err() {
+                break;
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut delayed_rx = UnboundedReceiverStream::new(delayed_rx);
+
+        while let Some((deadline, packet)) = delayed_rx.next().await {
+            tokio::time::sleep_until(deadline).await;
+
+            if gossip_out.send(packet).is_err() {
+                break;
+            }
+        }
+    });
+
+    tx
+}
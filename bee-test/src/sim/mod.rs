@@ -0,0 +1,22 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-process simulator of a network of Bee nodes.
+//!
+//! All nodes run in the current process, gossip over the libp2p memory transport and store their data in
+//! `bee-storage-memory`. A test coordinator issues milestones, and the links between nodes can be partitioned or
+//! degraded with latency and packet loss.
+
+mod coordinator;
+mod error;
+mod link;
+mod network;
+mod node;
+
+pub use self::{
+    coordinator::Coordinator,
+    error::Error,
+    link::LinkConditions,
+    network::{SimNetwork, SimNetworkBuilder, SIM_BECH32_HRP, SIM_NETWORK_ID, SIM_NETWORK_NAME},
+    node::{SimNode, SimNodeBuilder},
+};
@@ -0,0 +1,609 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr, TcpListener},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bee_block::{
+    constant::TOKEN_SUPPLY,
+    output,
+    parent::Parents,
+    payload::{
+        milestone::{MilestoneId, MilestoneIndex},
+        TaggedDataPayload,
+    },
+    Block, BlockBuilder, BlockId,
+};
+use bee_gossip::{InMemoryBanStore, Keypair, Multiaddr, NetworkConfig, PeerId, Protocol, PublicKey, SecretKey};
+use bee_ledger::{
+    types::{snapshot::SnapshotInfo, LedgerIndex, TreasuryOutput},
+    workers::{
+        base_token::BaseTokenConfig,
+        config::LedgerConfig,
+        pruning::config::{PruningConfig, PruningConfigBuilder},
+        snapshot::config::SnapshotConfigBuilder,
+    },
+};
+use bee_protocol::workers::{config::ProtocolConfig, BlockSubmitterWorker, BlockSubmitterWorkerEvent};
+use bee_rest_api::endpoints::{config::RestApiConfig, InitFullNodeConfig};
+use bee_runtime::{
+    event::Bus,
+    node::{Node, NodeBuilder, NodeInfo},
+    resource::ResourceHandle,
+};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::{config::TangleConfig, solid_entry_point::SolidEntryPoint, Tangle};
+use futures::channel::oneshot;
+use packable::PackableExt;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use tokio::time::Instant;
+
+use crate::sim::{
+    coordinator::Coordinator,
+    error::Error,
+    link::{self, LinkConditions, NetworkConditions},
+    node::{SimNode, SimNodeBuilder},
+};
+
+/// Name of the simulated network.
+pub const SIM_NETWORK_NAME: &str = "sim";
+/// Identifier of the simulated network.
+///
+/// The gossip layer keeps the network identifier in a process-wide global, so all simulated networks share it.
+pub const SIM_NETWORK_ID: u64 = 0x5349_4d5f_4e45_5457;
+/// Human-readable part of the Bech32 addresses of the simulated network.
+pub const SIM_BECH32_HRP: &str = "sim";
+
+const DEFAULT_NODE_COUNT: usize = 3;
+const DEFAULT_SEED: u64 = 0;
+// 2022-01-01T00:00:00Z.
+const DEFAULT_GENESIS_TIMESTAMP: u32 = 1_640_995_200;
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Memory transport addresses are process-wide, so concurrent simulations must not share them.
+static NEXT_MEMORY_PORT: AtomicU64 = AtomicU64::new(1);
+
+/// A builder to create a [`SimNetwork`].
+#[must_use]
+pub struct SimNetworkBuilder {
+    node_count: usize,
+    seed: u64,
+    peering: Option<Vec<(usize, usize)>>,
+    link_conditions: LinkConditions,
+    coordinator_node: usize,
+    genesis_timestamp: u32,
+    pruning_config: PruningConfigBuilder,
+    snapshot_config: SnapshotConfigBuilder,
+    rest_api: bool,
+}
+
+impl Default for SimNetworkBuilder {
+    fn default() -> Self {
+        Self {
+            node_count: DEFAULT_NODE_COUNT,
+            seed: DEFAULT_SEED,
+            peering: None,
+            link_conditions: LinkConditions::default(),
+            coordinator_node: 0,
+            genesis_timestamp: DEFAULT_GENESIS_TIMESTAMP,
+            pruning_config: PruningConfigBuilder::new().enabled(false),
+            snapshot_config: SnapshotConfigBuilder::new(),
+            rest_api: false,
+        }
+    }
+}
+
+impl SimNetworkBuilder {
+    /// Creates a new [`SimNetworkBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of nodes of the network.
+    pub fn with_node_count(mut self, node_count: usize) -> Self {
+        self.node_count = node_count;
+        self
+    }
+
+    /// Sets the seed all keys and random packet losses are derived from.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets which pairs of nodes are peered, all nodes are peered with each other by default.
+    pub fn with_peering(mut self, peering: Vec<(usize, usize)>) -> Self {
+        self.peering.replace(peering);
+        self
+    }
+
+    /// Sets the conditions applied to all links of the network.
+    pub fn with_link_conditions(mut self, link_conditions: LinkConditions) -> Self {
+        self.link_conditions = link_conditions;
+        self
+    }
+
+    /// Sets the node the coordinator is attached to.
+    pub fn with_coordinator_node(mut self, coordinator_node: usize) -> Self {
+        self.coordinator_node = coordinator_node;
+        self
+    }
+
+    /// Sets the timestamp of the genesis, the clock of the network starts from it when the network is started.
+    pub fn with_genesis_timestamp(mut self, genesis_timestamp: u32) -> Self {
+        self.genesis_timestamp = genesis_timestamp;
+        self
+    }
+
+    /// Sets the pruning configuration of all nodes, pruning is disabled by default.
+    pub fn with_pruning_config(mut self, pruning_config: PruningConfigBuilder) -> Self {
+        self.pruning_config = pruning_config;
+        self
+    }
+
+    /// Sets the snapshot configuration of all nodes, its depth bounds the pruning delay from below.
+    pub fn with_snapshot_config(mut self, snapshot_config: SnapshotConfigBuilder) -> Self {
+        self.snapshot_config = snapshot_config;
+        self
+    }
+
+    /// Starts the REST API on every node, with all routes public, on a free local port.
+    pub fn with_rest_api(mut self) -> Self {
+        self.rest_api = true;
+        self
+    }
+
+    /// Starts all nodes of the network.
+    pub async fn finish(self) -> Result<SimNetwork, Error> {
+        if self.coordinator_node >= self.node_count {
+            return Err(Error::UnknownNode(self.coordinator_node));
+        }
+
+        let coordinator = Coordinator::from_seed(self.seed);
+        let conditions = NetworkConditions::new(self.link_conditions);
+
+        let protocol_config = ProtocolConfig::build()
+            .minimum_pow_score(0.0)
+            .coo_public_key_count(1)
+            .coo_public_key_ranges(vec![coordinator.key_range()])
+            .finish();
+        let pruning_config = self.pruning_config.finish();
+        let snapshot_config = self.snapshot_config.finish();
+        let tangle_config = TangleConfig::build().finish();
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let keypairs = (0..self.node_count)
+            .map(|_| {
+                let mut bytes = [0u8; 32];
+                rng.fill_bytes(&mut bytes);
+                // Panic: any 32 bytes are a valid ed25519 secret key.
+                Keypair::from(SecretKey::from_bytes(&mut bytes).unwrap())
+            })
+            .collect::<Vec<_>>();
+        let peer_ids = keypairs
+            .iter()
+            .map(|keypair| PeerId::from_public_key(&PublicKey::Ed25519(keypair.public())))
+            .collect::<Vec<_>>();
+        let addresses = (0..self.node_count)
+            .map(|_| {
+                let mut address = Multiaddr::empty();
+                address.push(Protocol::Memory(NEXT_MEMORY_PORT.fetch_add(1, Ordering::Relaxed)));
+                address
+            })
+            .collect::<Vec<_>>();
+        let indexes = Arc::new(
+            peer_ids
+                .iter()
+                .enumerate()
+                .map(|(index, peer_id)| (*peer_id, index))
+                .collect::<HashMap<_, _>>(),
+        );
+
+        let peering = self.peering.unwrap_or_else(|| {
+            (0..self.node_count)
+                .flat_map(|a| (a + 1..self.node_count).map(move |b| (a, b)))
+                .collect()
+        });
+
+        let mut nodes = Vec::with_capacity(self.node_count);
+        let mut api_addresses = Vec::with_capacity(self.node_count);
+
+        for (index, keypair) in keypairs.into_iter().enumerate() {
+            let mut gossip_config = NetworkConfig::build_in_memory()
+                .with_bind_multiaddr(addresses[index].clone())
+                .finish();
+
+            for &(a, b) in peering.iter() {
+                let peer = match (a == index, b == index) {
+                    (true, false) => b,
+                    (false, true) => a,
+                    _ => continue,
+                };
+
+                if peer >= addresses.len() {
+                    return Err(Error::UnknownNode(peer));
+                }
+
+                // Duplicates are harmless here, the peering is just listed twice.
+                let _ = gossip_config.add_static_peer(peer_ids[peer], addresses[peer].clone(), Some(peer.to_string()));
+            }
+
+            let storage = Storage::start(())?;
+            insert_genesis(&storage, self.genesis_timestamp)?;
+
+            let builder = SimNodeBuilder::new(())?
+                .with_resource(storage)
+                .with_resource(Bus::<TypeId>::default())
+                .with_resource(NodeInfo {
+                    name: format!("bee-sim-{}", index),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                });
+
+            let (builder, events) = bee_gossip::integrated::init::<SimNode, InMemoryBanStore>(
+                gossip_config,
+                keypair.clone(),
+                SIM_NETWORK_ID,
+                (),
+                builder,
+            )
+            .await?;
+            let events = link::intercept(index, events, indexes.clone(), conditions.clone(), self.seed);
+
+            let builder = bee_ledger::workers::init::<SimNode>(
+                builder,
+                SIM_NETWORK_ID,
+                TOKEN_SUPPLY,
+                LedgerConfig::build().finish(),
+                snapshot_config.clone(),
+                pruning_config.clone(),
+            );
+            let builder = bee_protocol::workers::init::<SimNode>(
+                protocol_config.clone(),
+                (SIM_NETWORK_NAME.to_string(), SIM_NETWORK_ID),
+                events,
+                None,
+                builder,
+            );
+            let mut builder = bee_tangle::init::<SimNode>(&tangle_config, builder);
+
+            if self.rest_api {
+                let address = free_local_address()?;
+
+                builder = bee_rest_api::endpoints::init_full_node::<SimNode>(
+                    InitFullNodeConfig {
+                        node_id: peer_ids[index],
+                        node_keypair: keypair,
+                        rest_api_config: RestApiConfig::build()
+                            .with_bind_address(&format!("/ip4/{}/tcp/{}", address.ip(), address.port()))
+                            .with_public_routes(vec!["/*".to_string()])
+                            .finish(),
+                        protocol_config: protocol_config.clone(),
+                        network_name: SIM_NETWORK_NAME.to_string(),
+                        bech32_hrp: SIM_BECH32_HRP.to_string(),
+                        base_token: BaseTokenConfig::build().finish().unwrap(),
+                        autopeering_local: None,
+                        dashboard_username: String::new(),
+                    },
+                    builder,
+                );
+                api_addresses.push(address);
+            }
+
+            nodes.push(builder.finish().await?);
+        }
+
+        Ok(SimNetwork {
+            nodes,
+            peer_ids,
+            api_addresses,
+            conditions,
+            coordinator,
+            coordinator_node: self.coordinator_node,
+            latest_milestone: None,
+            latest_milestone_index: MilestoneIndex(0),
+            latest_milestone_timestamp: self.genesis_timestamp,
+            genesis_timestamp: self.genesis_timestamp,
+            started_at: Instant::now(),
+        })
+    }
+}
+
+/// A network of simulated Bee nodes gossiping over an in-memory transport.
+///
+/// The clock of the network, which timestamps the milestones, follows the time of the tokio runtime: running the
+/// network in a runtime with paused time makes it advance only when all nodes are idle.
+pub struct SimNetwork {
+    nodes: Vec<SimNode>,
+    peer_ids: Vec<PeerId>,
+    api_addresses: Vec<SocketAddr>,
+    conditions: NetworkConditions,
+    coordinator: Coordinator,
+    coordinator_node: usize,
+    latest_milestone: Option<(MilestoneId, BlockId)>,
+    latest_milestone_index: MilestoneIndex,
+    latest_milestone_timestamp: u32,
+    genesis_timestamp: u32,
+    started_at: Instant,
+}
+
+impl SimNetwork {
+    /// Returns a builder to create a [`SimNetwork`].
+    pub fn build() -> SimNetworkBuilder {
+        SimNetworkBuilder::new()
+    }
+
+    /// Returns the number of nodes of the network.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether the network has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns a node of the network.
+    pub fn node(&self, index: usize) -> Result<&SimNode, Error> {
+        self.nodes.get(index).ok_or(Error::UnknownNode(index))
+    }
+
+    /// Returns the peer id of a node of the network.
+    pub fn peer_id(&self, index: usize) -> Result<PeerId, Error> {
+        self.peer_ids.get(index).copied().ok_or(Error::UnknownNode(index))
+    }
+
+    /// Returns the address the REST API of a node of the network listens on, if it was started.
+    pub fn api_address(&self, index: usize) -> Result<SocketAddr, Error> {
+        self.node(index)?;
+        self.api_addresses.get(index).copied().ok_or(Error::NoRestApi(index))
+    }
+
+    /// Returns the tangle of a node of the network.
+    pub fn tangle(&self, index: usize) -> Result<ResourceHandle<Tangle<Storage>>, Error> {
+        Ok(self.node(index)?.resource::<Tangle<Storage>>())
+    }
+
+    /// Returns the storage of a node of the network.
+    pub fn storage(&self, index: usize) -> Result<ResourceHandle<Storage>, Error> {
+        Ok(self.node(index)?.storage())
+    }
+
+    /// Returns the current time of the clock of the network, as a Unix timestamp.
+    pub fn timestamp(&self) -> u32 {
+        self.genesis_timestamp + self.started_at.elapsed().as_secs() as u32
+    }
+
+    /// Returns the index of the latest milestone issued by the coordinator.
+    pub fn latest_milestone_index(&self) -> MilestoneIndex {
+        self.latest_milestone_index
+    }
+
+    /// Isolates groups of nodes from each other, nodes that aren't part of any group are isolated on their own.
+    pub fn partition(&self, groups: &[&[usize]]) {
+        self.conditions.partition(self.nodes.len(), groups);
+    }
+
+    /// Removes all partitions of the network.
+    pub fn heal(&self) {
+        self.conditions.heal();
+    }
+
+    /// Sets the conditions of the gossip sent from a node to another one.
+    pub fn set_link_conditions(&self, from: usize, to: usize, conditions: LinkConditions) {
+        self.conditions.set_link(from, to, conditions);
+    }
+
+    /// Sets the conditions of all links of the network, overriding the ones of specific links.
+    pub fn set_all_link_conditions(&self, conditions: LinkConditions) {
+        self.conditions.set_default(conditions);
+    }
+
+    /// Submits a block to a node, as if it was received through its API.
+    pub async fn submit_block(&self, index: usize, block: &Block) -> Result<BlockId, Error> {
+        let (notifier, waiter) = oneshot::channel();
+
+        // Panic: the block submitter is always registered by the protocol.
+        self.node(index)?
+            .worker::<BlockSubmitterWorker>()
+            .unwrap()
+            .tx
+            .send(BlockSubmitterWorkerEvent {
+                block: block.pack_to_vec(),
+                notifier,
+            })
+            .map_err(|e| Error::BlockRejected(e.to_string()))?;
+
+        waiter
+            .await
+            .map_err(|e| Error::BlockRejected(e.to_string()))?
+            .map_err(|e| Error::BlockRejected(e.to_string()))
+    }
+
+    /// Creates a tagged data block on top of the tips of a node and submits it to that node.
+    pub async fn inject_tagged_data(&self, index: usize, tag: Vec<u8>, data: Vec<u8>) -> Result<BlockId, Error> {
+        let parents = self.tips(index).await?;
+        let block = BlockBuilder::<u64>::new(parents)
+            .with_payload(TaggedDataPayload::new(tag, data)?.into())
+            .with_nonce_provider(0, 0f64)
+            .finish()?;
+
+        self.submit_block(index, &block).await
+    }
+
+    /// Issues the next milestone on top of the tips of the coordinator node.
+    pub async fn issue_milestone(&mut self) -> Result<(MilestoneIndex, BlockId), Error> {
+        let tangle = self.tangle(self.coordinator_node)?;
+        let storage = self.storage(self.coordinator_node)?;
+
+        let mut parents = tangle.get_blocks_to_approve().await.unwrap_or_default();
+        let previous_milestone_id = match self.latest_milestone {
+            Some((milestone_id, block_id)) => {
+                // The previous milestone has to be part of the past cone.
+                parents.truncate(*Parents::COUNT_RANGE.end() as usize - 1);
+                parents.push(block_id);
+                milestone_id
+            }
+            None => MilestoneId::null(),
+        };
+        if parents.is_empty() {
+            parents.push(*SolidEntryPoint::null().block_id());
+        }
+
+        let index = self.latest_milestone_index + 1;
+        let timestamp = self.timestamp().max(self.latest_milestone_timestamp + 1);
+
+        let block = self
+            .coordinator
+            .create_milestone(
+                &tangle,
+                &*storage,
+                index,
+                timestamp,
+                previous_milestone_id,
+                Parents::new(parents)?,
+            )
+            .await?;
+        let block_id = self.submit_block(self.coordinator_node, &block).await?;
+
+        if let Some(bee_block::payload::Payload::Milestone(milestone)) = block.payload() {
+            self.latest_milestone.replace((milestone.id(), block_id));
+        }
+        self.latest_milestone_index = index;
+        self.latest_milestone_timestamp = timestamp;
+
+        Ok((index, block_id))
+    }
+
+    /// Waits until a node has solidified the given milestone index.
+    pub async fn wait_for_solid_index(
+        &self,
+        index: usize,
+        milestone_index: MilestoneIndex,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let tangle = self.tangle(index)?;
+
+        self.wait(index, milestone_index, timeout, || {
+            tangle.get_solid_milestone_index() >= milestone_index
+        })
+        .await
+    }
+
+    /// Waits until a node has confirmed the given milestone index.
+    pub async fn wait_for_confirmed_index(
+        &self,
+        index: usize,
+        milestone_index: MilestoneIndex,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let tangle = self.tangle(index)?;
+
+        self.wait(index, milestone_index, timeout, || {
+            tangle.get_confirmed_milestone_index() >= milestone_index
+        })
+        .await
+    }
+
+    /// Waits until all nodes have confirmed the given milestone index.
+    pub async fn wait_for_all_confirmed_index(
+        &self,
+        milestone_index: MilestoneIndex,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        for index in 0..self.nodes.len() {
+            self.wait_for_confirmed_index(index, milestone_index, timeout).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Waits until a node has pruned up to the given milestone index.
+    pub async fn wait_for_pruning_index(
+        &self,
+        index: usize,
+        milestone_index: MilestoneIndex,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let tangle = self.tangle(index)?;
+
+        self.wait(index, milestone_index, timeout, || {
+            tangle.get_pruning_index() >= milestone_index
+        })
+        .await
+    }
+
+    async fn wait(
+        &self,
+        index: usize,
+        milestone_index: MilestoneIndex,
+        timeout: Duration,
+        reached: impl Fn() -> bool,
+    ) -> Result<(), Error> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        while !reached() {
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::Timeout(index, milestone_index));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+
+        Ok(())
+    }
+
+    /// Stops all nodes of the network.
+    pub async fn shutdown(self) -> Result<(), Error> {
+        for node in self.nodes {
+            node.stop().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn tips(&self, index: usize) -> Result<Parents, Error> {
+        let tips = self
+            .tangle(index)?
+            .get_blocks_to_approve()
+            .await
+            .unwrap_or_else(|| vec![*SolidEntryPoint::null().block_id()]);
+
+        Ok(Parents::new(tips)?)
+    }
+}
+
+// Makes the storage look like a snapshot at index 0 was imported, with the whole supply in the treasury.
+fn insert_genesis(storage: &Storage, timestamp: u32) -> Result<(), Error> {
+    let index = MilestoneIndex(0);
+
+    Insert::<(), LedgerIndex>::insert(storage, &(), &LedgerIndex::new(index))?;
+    Insert::<(), SnapshotInfo>::insert(
+        storage,
+        &(),
+        &SnapshotInfo::new(SIM_NETWORK_ID, index, index, index, timestamp),
+    )?;
+    Insert::<SolidEntryPoint, MilestoneIndex>::insert(storage, &SolidEntryPoint::null(), &index)?;
+    Insert::<(bool, TreasuryOutput), ()>::insert(
+        storage,
+        &(
+            false,
+            TreasuryOutput::new(output::TreasuryOutput::new(TOKEN_SUPPLY)?, MilestoneId::null()),
+        ),
+        &(),
+    )?;
+
+    Ok(())
+}
+
+// The port is released before the REST API binds it, which is fine as long as nothing else grabs it in between.
+fn free_local_address() -> Result<SocketAddr, Error> {
+    Ok(TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?.local_addr()?)
+}
@@ -0,0 +1,117 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::Any;
+
+use async_trait::async_trait;
+use bee_runtime::{
+    core::{Core, CoreBuilder, CoreNode},
+    node::{Node, NodeBuilder},
+    resource::ResourceHandle,
+    worker::Worker,
+};
+use bee_storage::backend::StorageBackend;
+use bee_storage_memory::storage::Storage;
+use futures::{channel::oneshot, future::Future};
+
+use crate::sim::error::Error;
+
+/// A simulated Bee node running on top of the in-memory storage backend.
+pub struct SimNode {
+    core: Core<Self>,
+}
+
+impl CoreNode for SimNode {
+    fn core(&self) -> &Core<Self> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<Self> {
+        &mut self.core
+    }
+}
+
+#[async_trait]
+impl Node for SimNode {
+    type Builder = SimNodeBuilder;
+    type Backend = Storage;
+    type Error = Error;
+
+    async fn stop(mut self) -> Result<(), Self::Error> {
+        Core::stop_workers(&mut self).await;
+
+        if let Some(storage) = self.remove_resource::<Self::Backend>() {
+            storage.shutdown().map_err(Error::Storage)?;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<W, G, F>(&mut self, g: G)
+    where
+        W: Worker<Self>,
+        G: FnOnce(oneshot::Receiver<()>) -> F,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.core.spawn::<W, _, _>(g);
+    }
+
+    fn worker<W>(&self) -> Option<&W>
+    where
+        W: Worker<Self> + Send + Sync,
+    {
+        self.core.worker()
+    }
+
+    fn register_resource<R: Any + Send + Sync>(&mut self, res: R) {
+        self.core.register_resource(res);
+    }
+
+    fn remove_resource<R: Any + Send + Sync>(&mut self) -> Option<R> {
+        self.core.remove_resource()
+    }
+
+    #[track_caller]
+    fn resource<R: Any + Send + Sync>(&self) -> ResourceHandle<R> {
+        self.core.resource()
+    }
+}
+
+/// A builder to create a [`SimNode`].
+#[must_use]
+pub struct SimNodeBuilder {
+    core: CoreBuilder<SimNode>,
+}
+
+#[async_trait(?Send)]
+impl NodeBuilder<SimNode> for SimNodeBuilder {
+    type Error = Error;
+    type Config = ();
+
+    fn new(_config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(Self {
+            core: CoreBuilder::default(),
+        })
+    }
+
+    fn with_worker<W: Worker<SimNode> + 'static>(self) -> Self
+    where
+        W::Config: Default,
+    {
+        self.with_worker_cfg::<W>(W::Config::default())
+    }
+
+    fn with_worker_cfg<W: Worker<SimNode> + 'static>(mut self, config: W::Config) -> Self {
+        self.core = self.core.with_worker_cfg::<W>(config);
+        self
+    }
+
+    fn with_resource<R: Any + Send + Sync>(mut self, res: R) -> Self {
+        self.core = self.core.with_resource(res);
+        self
+    }
+
+    async fn finish(self) -> Result<SimNode, Self::Error> {
+        Ok(self.core.finish(|core| SimNode { core }).await)
+    }
+}
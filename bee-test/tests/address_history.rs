@@ -0,0 +1,229 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeSet, HashMap};
+
+use bee_block::{
+    address::Address,
+    output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
+    payload::{milestone::MilestoneIndex, transaction::TransactionId},
+    BlockId,
+};
+use bee_ledger::{
+    types::{AddressHistoryEntry, AddressHistoryIndex, ConsumedOutput, CreatedOutput, LedgerIndex, Unspent},
+    workers::address_history::{
+        delete_milestone_batch, fetch_address_balance, initialize, insert_milestone_batch, prune,
+    },
+};
+use bee_storage::{
+    access::{AsIterator, BatchBuilder, Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+use bee_test::rand::{
+    address::rand_address,
+    number::{rand_number, rand_number_range},
+    output::rand_output_id,
+};
+
+fn created_output(address: Address, amount: u64, index: u32) -> CreatedOutput {
+    let output = BasicOutput::build_with_amount(amount)
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(address).into())
+        .finish_output()
+        .unwrap();
+
+    CreatedOutput::new(BlockId::null(), MilestoneIndex(index), index * 10, Output::from(output))
+}
+
+fn consumed_output(index: u32) -> ConsumedOutput {
+    ConsumedOutput::new(TransactionId::null(), MilestoneIndex(index), index * 10)
+}
+
+fn history(storage: &Storage) -> BTreeSet<(Address, AddressHistoryEntry)> {
+    AsIterator::<(Address, AddressHistoryEntry), ()>::iter(storage)
+        .unwrap()
+        .map(|result| result.unwrap().0)
+        .collect()
+}
+
+fn history_index(storage: &Storage) -> Option<AddressHistoryIndex> {
+    Fetch::<(), AddressHistoryIndex>::fetch(storage, &()).unwrap()
+}
+
+#[test]
+fn initialized_history_records_the_unspent_outputs() {
+    let storage = Storage::start(()).unwrap();
+    let addresses = (0..4).map(|_| rand_address()).collect::<Vec<_>>();
+    let ledger_index = LedgerIndex::new(MilestoneIndex(100));
+    let mut expected = BTreeSet::new();
+
+    for _ in 0..rand_number_range(1..=64) {
+        let output_id = rand_output_id();
+        let address = addresses[rand_number_range(0..addresses.len())];
+        let amount = rand_number_range(1_000..=1_000_000);
+        let index = rand_number_range(1..=100);
+        let output = created_output(address, amount, index);
+
+        Insert::<OutputId, CreatedOutput>::insert(&storage, &output_id, &output).unwrap();
+
+        // Outputs that are already consumed have no place in the history of a ledger that doesn't know about them.
+        if rand_number::<bool>() {
+            Insert::<Unspent, ()>::insert(&storage, &output_id.into(), &()).unwrap();
+            expected.insert((
+                address,
+                AddressHistoryEntry::created(output_id, MilestoneIndex(index), index * 10, amount),
+            ));
+        }
+    }
+
+    initialize(&storage, true, ledger_index).unwrap();
+
+    assert_eq!(history(&storage), expected);
+    assert_eq!(history_index(&storage), Some(ledger_index.0.into()));
+}
+
+#[test]
+fn disabled_history_is_discarded() {
+    let storage = Storage::start(()).unwrap();
+    let output_id = rand_output_id();
+    let address = rand_address();
+
+    Insert::<OutputId, CreatedOutput>::insert(&storage, &output_id, &created_output(address, 1_000, 1)).unwrap();
+    Insert::<Unspent, ()>::insert(&storage, &output_id.into(), &()).unwrap();
+    initialize(&storage, true, LedgerIndex::new(MilestoneIndex(1))).unwrap();
+    Insert::<(MilestoneIndex, Address), ()>::insert(&storage, &(MilestoneIndex(1), address), &()).unwrap();
+
+    assert!(!history(&storage).is_empty());
+
+    initialize(&storage, false, LedgerIndex::new(MilestoneIndex(1))).unwrap();
+
+    assert!(history(&storage).is_empty());
+    assert_eq!(
+        AsIterator::<(MilestoneIndex, Address), ()>::iter(&storage)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(history_index(&storage), None);
+}
+
+#[test]
+fn pruned_history_keeps_the_balances() {
+    let storage = Storage::start(()).unwrap();
+    let (a, b) = (rand_address(), rand_address());
+    let output_ids = (0..5).map(|_| rand_output_id()).collect::<Vec<_>>();
+    let outputs = [
+        created_output(a, 100, 1),
+        created_output(b, 50, 1),
+        created_output(a, 30, 2),
+        created_output(b, 100, 3),
+        created_output(a, 7, 5),
+    ];
+    // The outputs created and consumed by milestones 1 to 5, by position in `outputs`.
+    let milestones: [(&[usize], &[usize]); 5] = [(&[0, 1], &[]), (&[2], &[0]), (&[3], &[1]), (&[], &[2]), (&[4], &[])];
+    let expected_balances = [(100, 50), (30, 50), (30, 100), (0, 100), (7, 100)];
+
+    initialize(&storage, true, LedgerIndex::new(MilestoneIndex(0))).unwrap();
+
+    for (index, (created, consumed)) in (1..).zip(milestones.iter()) {
+        let created_outputs = created
+            .iter()
+            .map(|&i| (output_ids[i], outputs[i].clone()))
+            .collect::<HashMap<_, _>>();
+        let consumed_outputs = consumed
+            .iter()
+            .map(|&i| (output_ids[i], (outputs[i].clone(), consumed_output(index))))
+            .collect::<HashMap<_, _>>();
+        let mut batch = Storage::batch_begin();
+
+        insert_milestone_batch(&storage, &mut batch, &created_outputs, &consumed_outputs).unwrap();
+        storage.batch_commit(batch, true).unwrap();
+    }
+
+    prune(&storage, MilestoneIndex(3)).unwrap();
+
+    for (index, (balance_a, balance_b)) in (1..).zip(expected_balances).skip(2) {
+        assert_eq!(
+            fetch_address_balance(&storage, &a, MilestoneIndex(index)).unwrap(),
+            balance_a
+        );
+        assert_eq!(
+            fetch_address_balance(&storage, &b, MilestoneIndex(index)).unwrap(),
+            balance_b
+        );
+    }
+
+    // Only the outputs consumed up to the pruned milestone are gone from the history.
+    assert_eq!(
+        history(&storage),
+        BTreeSet::from([
+            (
+                a,
+                AddressHistoryEntry::created(output_ids[2], MilestoneIndex(2), 20, 30)
+            ),
+            (
+                a,
+                AddressHistoryEntry::consumed(output_ids[2], MilestoneIndex(4), 40, 30, MilestoneIndex(2), 20)
+            ),
+            (a, AddressHistoryEntry::created(output_ids[4], MilestoneIndex(5), 50, 7)),
+            (
+                b,
+                AddressHistoryEntry::created(output_ids[3], MilestoneIndex(3), 30, 100)
+            ),
+        ])
+    );
+    assert_eq!(
+        AsIterator::<(MilestoneIndex, Address), ()>::iter(&storage)
+            .unwrap()
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>(),
+        vec![(MilestoneIndex(4), a)]
+    );
+    assert_eq!(history_index(&storage), Some(MilestoneIndex(3).into()));
+}
+
+#[test]
+fn rolled_back_milestone_leaves_no_history() {
+    let storage = Storage::start(()).unwrap();
+    let (a, b) = (rand_address(), rand_address());
+    let (output_id_1, output_id_2) = (rand_output_id(), rand_output_id());
+    let (output_1, output_2) = (created_output(a, 100, 1), created_output(b, 100, 2));
+
+    initialize(&storage, true, LedgerIndex::new(MilestoneIndex(0))).unwrap();
+
+    let mut batch = Storage::batch_begin();
+    insert_milestone_batch(
+        &storage,
+        &mut batch,
+        &HashMap::from([(output_id_1, output_1.clone())]),
+        &HashMap::new(),
+    )
+    .unwrap();
+    storage.batch_commit(batch, true).unwrap();
+
+    let expected = history(&storage);
+    // Milestone 2 creates an output for `b` and consumes the output of `a`.
+    let created_outputs = HashMap::from([(output_id_2, output_2)]);
+    let consumed_outputs = HashMap::from([(output_id_1, (output_1, consumed_output(2)))]);
+
+    let mut batch = Storage::batch_begin();
+    insert_milestone_batch(&storage, &mut batch, &created_outputs, &consumed_outputs).unwrap();
+    storage.batch_commit(batch, true).unwrap();
+
+    assert_eq!(history(&storage).len(), 3);
+
+    let mut batch = Storage::batch_begin();
+    delete_milestone_batch(&storage, &mut batch, &created_outputs, &consumed_outputs).unwrap();
+    storage.batch_commit(batch, true).unwrap();
+
+    assert_eq!(history(&storage), expected);
+    assert_eq!(
+        AsIterator::<(MilestoneIndex, Address), ()>::iter(&storage)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(fetch_address_balance(&storage, &a, MilestoneIndex(2)).unwrap(), 100);
+    assert_eq!(fetch_address_balance(&storage, &b, MilestoneIndex(2)).unwrap(), 0);
+}
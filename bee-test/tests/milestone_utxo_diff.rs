@@ -0,0 +1,80 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::{
+    types::{snapshot::SnapshotInfo, LedgerIndex, MilestoneUtxoDiff, OutputDiff},
+    workers::{error::Error, storage::check_milestone_utxo_diff_range},
+};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_test::rand::{
+    number::rand_number_range,
+    output_diff::{rand_milestone_utxo_diff, rand_output_diff},
+};
+use packable::{Packable, PackableExt};
+
+#[test]
+fn milestone_utxo_diff_packing_round_trip() {
+    let diff = rand_milestone_utxo_diff();
+    let bytes = diff.pack_to_vec();
+
+    assert_eq!(bytes.len(), diff.packed_len());
+    assert_eq!(MilestoneUtxoDiff::unpack_verified(&mut bytes.as_slice()).unwrap(), diff);
+}
+
+#[test]
+fn concatenated_milestone_utxo_diffs_unpack_one_by_one() {
+    let diffs = (0..rand_number_range(1..=8))
+        .map(|_| rand_milestone_utxo_diff())
+        .collect::<Vec<_>>();
+    let bytes = diffs.iter().flat_map(PackableExt::pack_to_vec).collect::<Vec<_>>();
+    let mut slice = bytes.as_slice();
+
+    for diff in diffs.iter() {
+        assert_eq!(&MilestoneUtxoDiff::unpack::<_, true>(&mut slice).unwrap(), diff);
+    }
+
+    // Every byte belongs to one of the diffs.
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn milestone_utxo_diff_range_is_checked() {
+    let storage = Storage::start(()).unwrap();
+
+    // A ledger imported from a full snapshot at milestone 14 and pruned up to milestone 10: the output diffs are
+    // stored from milestone 15 to the ledger index.
+    Insert::<(), SnapshotInfo>::insert(
+        &storage,
+        &(),
+        &SnapshotInfo::new(1, MilestoneIndex(14), MilestoneIndex(14), MilestoneIndex(10), 0),
+    )
+    .unwrap();
+    Insert::<(), LedgerIndex>::insert(&storage, &(), &LedgerIndex::new(MilestoneIndex(20))).unwrap();
+    for index in 15..=20 {
+        Insert::<MilestoneIndex, OutputDiff>::insert(&storage, &MilestoneIndex(index), &rand_output_diff()).unwrap();
+    }
+
+    let check = |start, end| check_milestone_utxo_diff_range(&storage, MilestoneIndex(start), MilestoneIndex(end));
+
+    assert!(check(15, 20).is_ok());
+    assert!(check(17, 17).is_ok());
+    assert!(matches!(check(18, 17), Err(Error::InvalidMilestoneRange(..))));
+    assert!(matches!(check(10, 15), Err(Error::MilestoneRangeBelowPruningIndex(..))));
+    assert!(matches!(check(15, 21), Err(Error::MilestoneRangeAboveLedgerIndex(..))));
+    assert!(matches!(
+        check(12, 20),
+        Err(Error::MilestoneRangeWithoutOutputDiff(MilestoneIndex(12)))
+    ));
+}
+
+#[test]
+fn milestone_utxo_diff_range_needs_an_initialized_ledger() {
+    let storage = Storage::start(()).unwrap();
+
+    assert!(matches!(
+        check_milestone_utxo_diff_range(&storage, MilestoneIndex(1), MilestoneIndex(2)),
+        Err(Error::UninitializedLedger)
+    ));
+}
@@ -0,0 +1,265 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// All scenarios run on a single thread with paused time, so the clock only advances when every node is idle and the
+// timeouts are measured in simulated time.
+
+use std::time::Duration;
+
+use bee_block::payload::milestone::MilestoneIndex;
+use bee_ledger::workers::{pruning::config::PruningConfigBuilder, snapshot::config::SnapshotConfigBuilder};
+use bee_test::sim::{LinkConditions, SimNetwork};
+
+const TIMEOUT: Duration = Duration::from_secs(300);
+// Leaves time for the gossip in flight to be delivered.
+const SETTLE_TIME: Duration = Duration::from_secs(5);
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn milestones_are_confirmed_by_all_nodes() {
+    let mut network = SimNetwork::build().with_node_count(3).finish().await.unwrap();
+
+    for i in 0..3u8 {
+        network
+            .inject_tagged_data(usize::from(i), vec![i], vec![i; 32])
+            .await
+            .unwrap();
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+    }
+
+    for node in 0..network.len() {
+        assert_eq!(
+            network.tangle(node).unwrap().get_confirmed_milestone_index(),
+            network.latest_milestone_index()
+        );
+    }
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn partitioned_node_catches_up_after_heal() {
+    let mut network = SimNetwork::build()
+        .with_node_count(3)
+        .with_seed(42)
+        .finish()
+        .await
+        .unwrap();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+
+    network.partition(&[&[0, 1]]);
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_confirmed_index(1, index, TIMEOUT).await.unwrap();
+    assert!(
+        network
+            .wait_for_confirmed_index(2, index, Duration::from_secs(2))
+            .await
+            .is_err()
+    );
+
+    network.heal();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn milestones_are_confirmed_despite_latency_and_loss() {
+    let mut network = SimNetwork::build()
+        .with_node_count(4)
+        .with_link_conditions(LinkConditions::new(Duration::from_millis(20), 0.1))
+        .finish()
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+    }
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn missing_blocks_are_requested_to_solidify_a_milestone() {
+    let mut network = SimNetwork::build()
+        .with_node_count(3)
+        .with_seed(1)
+        .finish()
+        .await
+        .unwrap();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+
+    // Node 2 misses the blocks, but receives the milestone referencing them.
+    network.partition(&[&[0, 1]]);
+
+    let mut block_ids = Vec::new();
+
+    for i in 0..4u8 {
+        block_ids.push(
+            network
+                .inject_tagged_data(usize::from(i % 2), vec![i], vec![i; 32])
+                .await
+                .unwrap(),
+        );
+        tokio::time::sleep(SETTLE_TIME).await;
+    }
+
+    network.heal();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_solid_index(2, index, TIMEOUT).await.unwrap();
+    network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+
+    // The blocks were issued after the first milestone, so the ones that are referenced are in the cone of the last
+    // one.
+    let coordinator = network.tangle(0).unwrap();
+    let tangle = network.tangle(2).unwrap();
+    let referenced = block_ids
+        .iter()
+        .filter(|block_id| {
+            coordinator
+                .get_metadata(block_id)
+                .unwrap()
+                .map_or(false, |metadata| metadata.flags().is_referenced())
+        })
+        .collect::<Vec<_>>();
+
+    assert!(!referenced.is_empty());
+
+    for block_id in referenced {
+        assert!(tangle.contains(block_id).unwrap());
+    }
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn missed_milestones_are_requested() {
+    let mut network = SimNetwork::build()
+        .with_node_count(3)
+        .with_seed(2)
+        .finish()
+        .await
+        .unwrap();
+
+    let (first, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_all_confirmed_index(first, TIMEOUT).await.unwrap();
+
+    network.partition(&[&[0, 1]]);
+
+    for _ in 0..3 {
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_confirmed_index(1, index, TIMEOUT).await.unwrap();
+    }
+
+    tokio::time::sleep(SETTLE_TIME).await;
+    assert_eq!(network.tangle(2).unwrap().get_latest_milestone_index(), first);
+
+    network.heal();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_confirmed_index(2, index, TIMEOUT).await.unwrap();
+
+    let coordinator = network.tangle(0).unwrap();
+    let tangle = network.tangle(2).unwrap();
+
+    for milestone_index in *first..=*index {
+        let milestone_index = MilestoneIndex(milestone_index);
+
+        assert_eq!(
+            tangle.get_milestone_block_id(milestone_index).unwrap(),
+            coordinator.get_milestone_block_id(milestone_index).unwrap()
+        );
+    }
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn isolated_node_syncs_through_its_only_peer() {
+    // Node 2 is only peered with node 1, so it has to sync from a node that isn't the coordinator one.
+    let mut network = SimNetwork::build()
+        .with_node_count(3)
+        .with_seed(3)
+        .with_peering(vec![(0, 1), (1, 2)])
+        .finish()
+        .await
+        .unwrap();
+
+    network.partition(&[&[0, 1]]);
+
+    for _ in 0..10 {
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_confirmed_index(1, index, TIMEOUT).await.unwrap();
+    }
+
+    assert_eq!(
+        network.tangle(2).unwrap().get_confirmed_milestone_index(),
+        MilestoneIndex(0)
+    );
+
+    network.heal();
+
+    let (index, _) = network.issue_milestone().await.unwrap();
+    network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+
+    for node in 0..network.len() {
+        let tangle = network.tangle(node).unwrap();
+
+        assert!(tangle.is_synced());
+        assert_eq!(tangle.get_solid_milestone_index(), index);
+    }
+
+    network.shutdown().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn confirmed_milestones_are_pruned_after_the_delay() {
+    // The smallest snapshot depth and pruning delay the ledger accepts with the default below max depth.
+    const SNAPSHOT_DEPTH: u32 = 20;
+    const PRUNING_DELAY: u32 = 40;
+    const MILESTONES: u32 = PRUNING_DELAY + 5;
+
+    let mut network = SimNetwork::build()
+        .with_node_count(2)
+        .with_seed(4)
+        .with_snapshot_config(SnapshotConfigBuilder::new().depth(SNAPSHOT_DEPTH))
+        .with_pruning_config(PruningConfigBuilder::new().enabled(true).delay(PRUNING_DELAY))
+        .finish()
+        .await
+        .unwrap();
+
+    for _ in 0..MILESTONES {
+        let (index, _) = network.issue_milestone().await.unwrap();
+        network.wait_for_all_confirmed_index(index, TIMEOUT).await.unwrap();
+    }
+
+    let pruning_index = MilestoneIndex(MILESTONES - PRUNING_DELAY);
+
+    for node in 0..network.len() {
+        network
+            .wait_for_pruning_index(node, pruning_index, TIMEOUT)
+            .await
+            .unwrap();
+
+        let tangle = network.tangle(node).unwrap();
+
+        for index in 1..=*pruning_index {
+            assert!(!tangle.contains_milestone_metadata(MilestoneIndex(index)).unwrap());
+        }
+        for index in *pruning_index + 1..=MILESTONES {
+            assert!(tangle.contains_milestone_metadata(MilestoneIndex(index)).unwrap());
+        }
+        assert_eq!(tangle.get_confirmed_milestone_index(), MilestoneIndex(MILESTONES));
+    }
+
+    network.shutdown().await.unwrap();
+}
@@ -0,0 +1,150 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bee_block::{
+    constant::TOKEN_SUPPLY,
+    output::{Output, OutputId},
+    payload::milestone::MilestoneIndex,
+    BlockId,
+};
+use bee_ledger::{
+    types::{
+        snapshot::{FullSnapshotHeader, SnapshotHeader, SnapshotKind},
+        CreatedOutput, LedgerIndex, TreasuryOutput, Unspent,
+    },
+    workers::{
+        snapshot::{export::export_full_snapshot, import::import_full_snapshot},
+        storage::fetch_unspent_treasury_output,
+    },
+};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::{
+    access::{AsIterator, Fetch},
+    backend::StorageBackend,
+};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::{config::TangleConfig, solid_entry_point::SolidEntryPoint, Tangle};
+use bee_test::rand::{
+    milestone::rand_milestone_id,
+    number::{rand_number, rand_number_range},
+    output::{rand_basic_output, rand_output_id},
+    solid_entry_point::rand_solid_entry_point,
+};
+use packable::PackableExt;
+
+const NETWORK_ID: u64 = 1;
+
+fn snapshot_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bee_test_{}_{}.bin", name, rand_number::<u64>()))
+}
+
+fn unspent_outputs(storage: &Storage) -> HashSet<(OutputId, Vec<u8>)> {
+    AsIterator::<Unspent, ()>::iter(storage)
+        .unwrap()
+        .map(|result| {
+            let output_id = *result.unwrap().0.id();
+            let created_output = Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
+                .unwrap()
+                .unwrap();
+
+            (output_id, created_output.pack_to_vec())
+        })
+        .collect()
+}
+
+fn ledger_index(storage: &Storage) -> LedgerIndex {
+    Fetch::<(), LedgerIndex>::fetch(storage, &()).unwrap().unwrap()
+}
+
+/// Writes a full snapshot without milestone diffs, as a node that just started from scratch would receive it.
+fn write_genesis_snapshot(path: &Path, index: MilestoneIndex) {
+    let seps = (0..rand_number_range(1..=8))
+        .map(|_| rand_solid_entry_point())
+        .collect::<Vec<_>>();
+    let outputs = (0..rand_number_range(1..=64))
+        .map(|_| {
+            (
+                rand_output_id(),
+                CreatedOutput::new(BlockId::null(), index, 0, Output::from(rand_basic_output())),
+            )
+        })
+        .collect::<Vec<_>>();
+    let treasury = TreasuryOutput::new(
+        bee_block::output::TreasuryOutput::new(rand_number_range(0..=TOKEN_SUPPLY)).unwrap(),
+        rand_milestone_id(),
+    );
+
+    let mut bytes = SnapshotHeader::new(SnapshotKind::Full, rand_number(), NETWORK_ID, index, index).pack_to_vec();
+    bytes.extend(
+        FullSnapshotHeader::new(
+            seps.len() as u64,
+            outputs.len() as u64,
+            0,
+            *treasury.milestone_id(),
+            treasury.inner().amount(),
+        )
+        .pack_to_vec(),
+    );
+    for sep in &seps {
+        bytes.extend(sep.pack_to_vec());
+    }
+    for (output_id, created_output) in &outputs {
+        bytes.extend(output_id.pack_to_vec());
+        bytes.extend(created_output.pack_to_vec());
+    }
+
+    fs::write(path, bytes).unwrap();
+}
+
+#[tokio::test]
+async fn exported_full_snapshot_imports_the_same_ledger() {
+    let index = MilestoneIndex(rand_number_range(1..=1000));
+    let genesis_path = snapshot_path("genesis");
+    let exported_path = snapshot_path("exported");
+
+    write_genesis_snapshot(&genesis_path, index);
+
+    let storage = ResourceHandle::new(Storage::start(()).unwrap());
+    import_full_snapshot(&*storage, &genesis_path, NETWORK_ID, TOKEN_SUPPLY).unwrap();
+
+    let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+    tangle.update_entry_point_index(index);
+    for result in AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(&*storage).unwrap() {
+        let (sep, sep_index) = result.unwrap();
+        tangle.add_solid_entry_point(sep, sep_index).await;
+    }
+
+    assert_eq!(
+        export_full_snapshot(&tangle, &*storage, &exported_path).await.unwrap(),
+        (index, index)
+    );
+
+    let imported = Storage::start(()).unwrap();
+    import_full_snapshot(&imported, &exported_path, NETWORK_ID, TOKEN_SUPPLY).unwrap();
+
+    fs::remove_file(&genesis_path).unwrap();
+    fs::remove_file(&exported_path).unwrap();
+
+    assert_eq!(ledger_index(&imported), ledger_index(&storage));
+    assert_eq!(unspent_outputs(&imported), unspent_outputs(&storage));
+    assert_eq!(
+        fetch_unspent_treasury_output(&imported).unwrap(),
+        fetch_unspent_treasury_output(&*storage).unwrap()
+    );
+    assert_eq!(
+        AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(&imported)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<HashSet<_>>(),
+        AsIterator::<SolidEntryPoint, MilestoneIndex>::iter(&*storage)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<HashSet<_>>()
+    );
+}
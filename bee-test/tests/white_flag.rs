@@ -0,0 +1,365 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use bee_block::{
+    address::{Address, Ed25519Address},
+    input::{Input, UtxoInput},
+    output::{unlock_condition::AddressUnlockCondition, BasicOutput, InputsCommitment, Output, OutputId},
+    parent::Parents,
+    payload::{
+        milestone::MilestoneIndex,
+        transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
+    },
+    protocol::ProtocolParameters,
+    semantic::{ConflictDiagnostic, ConflictReason},
+    signature::{Ed25519Signature, Signature},
+    unlock::{SignatureUnlock, Unlock, Unlocks},
+    Block, BlockBuilder, BlockId,
+};
+use bee_ledger::{
+    types::{CreatedOutput, Unspent},
+    workers::consensus::{white_flag, white_flag_sequential, WhiteFlagMetadata},
+};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::storage::Storage;
+use bee_tangle::{block_metadata::BlockMetadata, config::TangleConfig, solid_entry_point::SolidEntryPoint, Tangle};
+use bee_test::rand::{
+    bool::rand_bool,
+    bytes::rand_bytes_array,
+    number::{rand_number, rand_number_range},
+    transaction::rand_transaction_id,
+};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+use rand::seq::SliceRandom;
+
+const NETWORK_ID: u64 = 1;
+const GENESIS_OUTPUTS: usize = 32;
+const BLOCKS: usize = 96;
+// Spans several of the batches a past cone is validated and applied in.
+const LARGE_CONE_BLOCKS: usize = 1_000;
+
+/// An output that can be referenced as the input of a random transaction, along with the key that unlocks it.
+struct Spendable {
+    output_id: OutputId,
+    output: Output,
+    secret_key: SecretKey,
+}
+
+fn rand_owned_output(amount: u64) -> (Output, SecretKey) {
+    let secret_key = SecretKey::from_bytes(rand_bytes_array());
+    let address = Address::from(Ed25519Address::new(
+        Blake2b256::digest(&secret_key.public_key().to_bytes()).into(),
+    ));
+    let output = BasicOutput::build_with_amount(amount)
+        .unwrap()
+        .add_unlock_condition(AddressUnlockCondition::new(address).into())
+        .finish()
+        .unwrap();
+
+    (Output::from(output), secret_key)
+}
+
+/// Creates a random transaction spending random outputs, some of them already spent, unknown to the ledger or created
+/// outside of the past cone, and randomly unbalanced or carrying an invalid signature.
+fn rand_transaction(spendables: &mut Vec<Spendable>) -> TransactionPayload {
+    let mut inputs = spendables.iter().collect::<Vec<_>>();
+    inputs.shuffle(&mut rand::thread_rng());
+    inputs.truncate(rand_number_range(1..=3));
+
+    let amount = inputs.iter().map(|input| input.output.amount()).sum::<u64>();
+    let output_count = rand_number_range(1..=3u64);
+    let mut outputs = (0..output_count)
+        .map(|index| {
+            let share = amount / output_count + if index == 0 { amount % output_count } else { 0 };
+            rand_owned_output(share)
+        })
+        .collect::<Vec<_>>();
+
+    if rand_number_range(0..8) == 0 {
+        outputs[0] = rand_owned_output(outputs[0].0.amount() + 1);
+    }
+
+    let essence = TransactionEssence::from(
+        RegularTransactionEssence::builder(
+            NETWORK_ID,
+            InputsCommitment::new(inputs.iter().map(|input| &input.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|input| Input::from(UtxoInput::from(input.output_id)))
+                .collect(),
+        )
+        .with_outputs(outputs.iter().map(|(output, _)| output.clone()).collect())
+        .finish()
+        .unwrap(),
+    );
+    let essence_hash = essence.hash();
+    let tampered = rand_number_range(0..8) == 0;
+    let unlocks = inputs
+        .iter()
+        .map(|input| {
+            let mut signature = input.secret_key.sign(&essence_hash).to_bytes();
+
+            if tampered {
+                signature[0] ^= 1;
+            }
+
+            Unlock::from(SignatureUnlock::new(Signature::from(Ed25519Signature::new(
+                input.secret_key.public_key().to_bytes(),
+                signature,
+            ))))
+        })
+        .collect();
+    let transaction = TransactionPayload::new(essence, Unlocks::new(unlocks).unwrap()).unwrap();
+    let transaction_id = transaction.id();
+
+    for (index, (output, secret_key)) in outputs.into_iter().enumerate() {
+        spendables.push(Spendable {
+            output_id: OutputId::new(transaction_id, index as u16).unwrap(),
+            output,
+            secret_key,
+        });
+    }
+
+    transaction
+}
+
+/// Creates a random ledger and a random tangle of transactions on top of it, and returns the tips of the tangle.
+async fn rand_ledger(storage: &Storage, tangle: &Tangle<Storage>, blocks: usize) -> Vec<BlockId> {
+    let mut spendables = Vec::new();
+
+    for _ in 0..GENESIS_OUTPUTS {
+        let (output, secret_key) = rand_owned_output(rand_number_range(1_000_000..1_000_000_000));
+        let output_id = OutputId::new(rand_transaction_id(), 0).unwrap();
+
+        Insert::<OutputId, CreatedOutput>::insert(
+            storage,
+            &output_id,
+            &CreatedOutput::new(BlockId::null(), MilestoneIndex(0), 0, output.clone()),
+        )
+        .unwrap();
+        Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id), &()).unwrap();
+
+        spendables.push(Spendable {
+            output_id,
+            output,
+            secret_key,
+        });
+    }
+
+    tangle
+        .add_solid_entry_point(SolidEntryPoint::null(), MilestoneIndex(0))
+        .await;
+
+    let mut block_ids = vec![BlockId::null()];
+    let mut referenced = HashSet::new();
+
+    for _ in 0..blocks {
+        let mut parents = block_ids.clone();
+        parents.shuffle(&mut rand::thread_rng());
+        parents.truncate(rand_number_range(1..=3));
+        referenced.extend(parents.iter().copied());
+
+        let mut builder = BlockBuilder::<u64>::new(Parents::new(parents).unwrap()).with_nonce_provider(0, 0f64);
+
+        // Some blocks don't carry a transaction at all.
+        if rand_bool() || rand_bool() {
+            builder = builder.with_payload(rand_transaction(&mut spendables).into());
+        }
+
+        let block: Block = builder.finish().unwrap();
+        let block_id = block.id();
+
+        tangle.insert(&block, &block_id, &BlockMetadata::default()).unwrap();
+        block_ids.push(block_id);
+    }
+
+    block_ids
+        .into_iter()
+        .filter(|block_id| !referenced.contains(block_id))
+        .collect()
+}
+
+#[tokio::test]
+async fn parallel_and_sequential_white_flag_are_identical() {
+    for _ in 0..8 {
+        let storage = ResourceHandle::new(Storage::start(()).unwrap());
+        let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+        let tips = rand_ledger(&storage, &tangle, BLOCKS).await;
+        let timestamp = rand_number();
+
+        let mut parallel = WhiteFlagMetadata::new(MilestoneIndex(1), timestamp, None);
+        white_flag(&tangle, &*storage, &tips, &mut parallel).await.unwrap();
+
+        let mut sequential = WhiteFlagMetadata::new(MilestoneIndex(1), timestamp, None);
+        white_flag_sequential(&tangle, &*storage, &tips, &mut sequential)
+            .await
+            .unwrap();
+
+        assert_eq!(parallel, sequential);
+        assert!(!sequential.excluded_conflicting_blocks().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn parallel_and_sequential_white_flag_are_identical_on_large_cones() {
+    let storage = ResourceHandle::new(Storage::start(()).unwrap());
+    let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+    let tips = rand_ledger(&storage, &tangle, LARGE_CONE_BLOCKS).await;
+    let timestamp = rand_number();
+
+    let mut parallel = WhiteFlagMetadata::new(MilestoneIndex(1), timestamp, None);
+    white_flag(&tangle, &*storage, &tips, &mut parallel).await.unwrap();
+
+    let mut sequential = WhiteFlagMetadata::new(MilestoneIndex(1), timestamp, None);
+    white_flag_sequential(&tangle, &*storage, &tips, &mut sequential)
+        .await
+        .unwrap();
+
+    assert_eq!(parallel, sequential);
+    assert!(!sequential.excluded_conflicting_blocks().is_empty());
+}
+
+/// Creates a ledger with a single output and a block with a transaction spending it to outputs of the given amounts,
+/// and returns the block.
+async fn single_transaction_ledger(
+    storage: &Storage,
+    tangle: &Tangle<Storage>,
+    input_amount: u64,
+    output_amounts: &[u64],
+) -> BlockId {
+    let (input, secret_key) = rand_owned_output(input_amount);
+    let input_id = OutputId::new(rand_transaction_id(), 0).unwrap();
+
+    Insert::<OutputId, CreatedOutput>::insert(
+        storage,
+        &input_id,
+        &CreatedOutput::new(BlockId::null(), MilestoneIndex(0), 0, input.clone()),
+    )
+    .unwrap();
+    Insert::<Unspent, ()>::insert(storage, &Unspent::new(input_id), &()).unwrap();
+
+    let essence = TransactionEssence::from(
+        RegularTransactionEssence::builder(NETWORK_ID, InputsCommitment::new([&input].into_iter()))
+            .with_inputs(vec![Input::from(UtxoInput::from(input_id))])
+            .with_outputs(
+                output_amounts
+                    .iter()
+                    .map(|amount| rand_owned_output(*amount).0)
+                    .collect(),
+            )
+            .finish()
+            .unwrap(),
+    );
+    let signature = secret_key.sign(&essence.hash()).to_bytes();
+    let unlock = Unlock::from(SignatureUnlock::new(Signature::from(Ed25519Signature::new(
+        secret_key.public_key().to_bytes(),
+        signature,
+    ))));
+    let transaction = TransactionPayload::new(essence, Unlocks::new(vec![unlock]).unwrap()).unwrap();
+
+    tangle
+        .add_solid_entry_point(SolidEntryPoint::null(), MilestoneIndex(0))
+        .await;
+
+    let block: Block = BlockBuilder::<u64>::new(Parents::new(vec![BlockId::null()]).unwrap())
+        .with_nonce_provider(0, 0f64)
+        .with_payload(transaction.into())
+        .finish()
+        .unwrap();
+    let block_id = block.id();
+
+    tangle.insert(&block, &block_id, &BlockMetadata::default()).unwrap();
+
+    block_id
+}
+
+fn protocol_parameters(v_byte_cost: u32, token_supply: u64) -> ProtocolParameters {
+    ProtocolParameters::new(
+        2,
+        "testnet".to_string(),
+        "rms".to_string(),
+        0,
+        15,
+        v_byte_cost,
+        1,
+        10,
+        token_supply,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn white_flag_applies_the_active_byte_cost() {
+    for (v_byte_cost, included) in [(1, true), (500, false)] {
+        let storage = ResourceHandle::new(Storage::start(()).unwrap());
+        let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+        let block_id = single_transaction_ledger(&storage, &tangle, 1_000_000, &[1_000, 999_000]).await;
+
+        let mut metadata = WhiteFlagMetadata::new(MilestoneIndex(1), rand_number(), None)
+            .with_protocol_parameters(Some(protocol_parameters(v_byte_cost, 1_000_000)));
+        white_flag(&tangle, &*storage, &[block_id], &mut metadata)
+            .await
+            .unwrap();
+
+        if included {
+            assert!(metadata.excluded_conflicting_blocks().is_empty());
+        } else {
+            assert_eq!(
+                metadata.excluded_conflicting_blocks(),
+                &[(block_id, ConflictReason::SemanticValidationFailed)]
+            );
+            assert!(matches!(
+                metadata.conflict_diagnostics(),
+                [(
+                    _,
+                    ConflictDiagnostic::InsufficientStorageDeposit {
+                        output_index: 0,
+                        amount: 1_000,
+                        ..
+                    }
+                )]
+            ));
+        }
+    }
+}
+
+#[tokio::test]
+async fn white_flag_applies_the_active_token_supply() {
+    let storage = ResourceHandle::new(Storage::start(()).unwrap());
+    let tangle = Tangle::new(TangleConfig::build().finish(), storage.clone());
+    let block_id = single_transaction_ledger(&storage, &tangle, 1_000_000, &[1_000_000]).await;
+
+    let mut metadata = WhiteFlagMetadata::new(MilestoneIndex(1), rand_number(), None).with_token_supply(999_999);
+    white_flag(&tangle, &*storage, &[block_id], &mut metadata)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        metadata.conflict_diagnostics(),
+        [(
+            _,
+            ConflictDiagnostic::CreatedAmountExceedsTokenSupply {
+                created: 1_000_000,
+                token_supply: 999_999
+            }
+        )]
+    ));
+
+    // Protocol parameters take precedence over the configured token supply.
+    let mut metadata = WhiteFlagMetadata::new(MilestoneIndex(1), rand_number(), None)
+        .with_token_supply(999_999)
+        .with_protocol_parameters(Some(protocol_parameters(1, 1_000_000)));
+    white_flag(&tangle, &*storage, &[block_id], &mut metadata)
+        .await
+        .unwrap();
+
+    assert!(metadata.excluded_conflicting_blocks().is_empty());
+}